@@ -1,10 +1,14 @@
 //! Ergo blockchain state (for ErgoTree evaluation)
+use std::convert::TryInto;
+
 use ergo_lib::chain;
+use ergo_lib::ergotree_ir::mir::header::Header;
 use wasm_bindgen::prelude::*;
 
 extern crate derive_more;
 use derive_more::{From, Into};
 
+use crate::block_header::BlockHeaders;
 use crate::header::PreHeader;
 
 /// Blockchain state (last headers, etc.)
@@ -14,13 +18,17 @@ pub struct ErgoStateContext(chain::ergo_state_context::ErgoStateContext);
 
 #[wasm_bindgen]
 impl ErgoStateContext {
-    /// Create new context from pre-header
+    /// Create new context from pre-header and the last 10 block headers (newest first)
     #[wasm_bindgen(constructor)]
-    pub fn new(pre_header: PreHeader) -> Self {
-        chain::ergo_state_context::ErgoStateContext {
-            pre_header: pre_header.into(),
-        }
-        .into()
+    pub fn new(pre_header: PreHeader, headers: &BlockHeaders) -> Result<ErgoStateContext, JsValue> {
+        let headers: Vec<Header> = Vec::<chain::block_header::BlockHeader>::from(headers.clone())
+            .into_iter()
+            .map(Header::from)
+            .collect();
+        let headers: [Header; 10] = headers.try_into().map_err(|hs: Vec<Header>| {
+            JsValue::from_str(&format!("expected 10 block headers, got {}", hs.len()))
+        })?;
+        Ok(chain::ergo_state_context::ErgoStateContext::new(pre_header.into(), headers).into())
     }
 
     /// empty (dummy) context (for signing P2PK tx only)
@@ -80,4 +80,11 @@ impl ErgoTree {
             .template_bytes()
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
+
+    /// Decompiles the tree into ErgoScript-like source code (with constants substituted)
+    pub fn to_ergoscript(&self) -> Result<String, JsValue> {
+        self.0
+            .to_ergoscript()
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
 }
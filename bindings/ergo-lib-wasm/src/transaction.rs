@@ -3,8 +3,10 @@
 use crate::box_coll::ErgoBoxCandidates;
 use crate::box_coll::ErgoBoxes;
 use crate::data_input::DataInputs;
+use crate::ergo_state_ctx::ErgoStateContext;
 use crate::input::{Inputs, UnsignedInputs};
 use ergo_lib::chain;
+use ergo_lib::ergotree_ir::serialization::SigmaSerializable;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use wasm_bindgen::prelude::*;
@@ -159,3 +161,51 @@ impl From<UnsignedTransaction> for chain::transaction::unsigned::UnsignedTransac
         t.0
     }
 }
+
+/// Represent `reduced` transaction, i.e. unsigned transaction where each unsigned input
+/// is augmented with ReducedInput which contains a script reduction result.
+/// After an unsigned transaction is reduced it can be signed without context.
+/// Thus, it can be serialized and transferred for example to Cold Wallet and signed
+/// in an environment where secrets are known.
+/// see EIP-19 for more details - https://github.com/ergoplatform/eips/blob/f280890a4163f2f2e988a0091c078e36912fc531/eip-0019.md
+#[wasm_bindgen]
+#[derive(PartialEq, Debug, Clone, From, Into)]
+pub struct ReducedTransaction(chain::transaction::reduced::ReducedTransaction);
+
+#[wasm_bindgen]
+impl ReducedTransaction {
+    /// Returns `reduced` transaction, i.e. unsigned transaction where each unsigned input
+    /// is augmented with ReducedInput which contains a script reduction result.
+    pub fn from_unsigned_tx(
+        unsigned_tx: &UnsignedTransaction,
+        boxes_to_spend: &ErgoBoxes,
+        data_boxes: &ErgoBoxes,
+        state_context: &ErgoStateContext,
+    ) -> Result<ReducedTransaction, JsValue> {
+        let tx_context = ergo_lib::wallet::signing::TransactionContext {
+            spending_tx: unsigned_tx.clone().into(),
+            boxes_to_spend: boxes_to_spend.clone().into(),
+            data_boxes: data_boxes.clone().into(),
+        };
+        chain::transaction::reduced::reduce_transaction(tx_context, &state_context.clone().into())
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+            .map(ReducedTransaction::from)
+    }
+
+    /// Returns serialized bytes or fails with error if cannot be serialized
+    pub fn sigma_serialize_bytes(&self) -> Vec<u8> {
+        self.0.sigma_serialize_bytes()
+    }
+
+    /// Parses ReducedTransaction or throws an error if not parsed
+    pub fn sigma_parse_bytes(data: Vec<u8>) -> Result<ReducedTransaction, JsValue> {
+        chain::transaction::reduced::ReducedTransaction::sigma_parse_bytes(&data)
+            .map(ReducedTransaction)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Returns the unsigned transaction
+    pub fn unsigned_tx(&self) -> UnsignedTransaction {
+        self.0.unsigned_tx.clone().into()
+    }
+}
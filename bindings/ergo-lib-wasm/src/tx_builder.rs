@@ -3,9 +3,10 @@ use ergo_lib::{chain, wallet};
 use wasm_bindgen::prelude::*;
 
 use crate::box_selector::BoxSelection;
+use crate::context_extension::ContextExtension;
 use crate::data_input::DataInputs;
 use crate::{
-    address::Address, box_coll::ErgoBoxCandidates, ergo_box::BoxValue,
+    address::Address, box_coll::ErgoBoxCandidates, ergo_box::BoxId, ergo_box::BoxValue,
     transaction::UnsignedTransaction,
 };
 
@@ -52,6 +53,12 @@ impl TxBuilder {
         self.0.set_data_inputs(data_inputs.into())
     }
 
+    /// Set context extension for the input box with the given id
+    pub fn set_context_extension(&mut self, box_id: &BoxId, context_extension: &ContextExtension) {
+        self.0
+            .set_context_extension(box_id.clone().into(), context_extension.clone().into())
+    }
+
     /// Build the unsigned transaction
     pub fn build(&self) -> Result<UnsignedTransaction, JsValue> {
         self.0
@@ -4,7 +4,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     box_coll::ErgoBoxes, ergo_state_ctx::ErgoStateContext, secret_key::SecretKeys,
-    transaction::Transaction, transaction::UnsignedTransaction,
+    transaction::ReducedTransaction, transaction::Transaction, transaction::UnsignedTransaction,
 };
 
 /// A collection of secret keys. This simplified signing by matching the secret keys to the correct inputs automatically.
@@ -49,4 +49,18 @@ impl Wallet {
             .map_err(|e| JsValue::from_str(&format!("{}", e)))
             .map(Transaction::from)
     }
+
+    /// Sign a transaction:
+    /// `reduced_tx` - reduced transaction, i.e. unsigned transaction where for each unsigned input
+    /// added a script reduction result.
+    #[wasm_bindgen]
+    pub fn sign_reduced_transaction(
+        &self,
+        reduced_tx: &ReducedTransaction,
+    ) -> Result<Transaction, JsValue> {
+        self.0
+            .sign_reduced_transaction(reduced_tx.clone().into())
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+            .map(Transaction::from)
+    }
 }
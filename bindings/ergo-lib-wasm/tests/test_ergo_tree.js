@@ -45,3 +45,11 @@ it('set_constant out of bounds', async () => {
   let constant = Constant.from_i32(99);
   assert(tree.set_constant(3, constant) == null);
 });
+
+it('to_ergoscript', async () => {
+  let tree_bytes_base16_str = "100204a00b08cd021dde34603426402615658f1d970cfa7c7bd92ac81a8b16eeebff264d59ce4604ea02d192a39a8cc7a70173007301";
+  let tree = ErgoTree.from_base16_bytes(tree_bytes_base16_str);
+  expect(tree.to_ergoscript()).to.equal(
+    'sigmaProp(HEIGHT >= (SELF.creationInfo._1 + 720)) && proveDlog(decodePoint(fromBase16("021dde34603426402615658f1d970cfa7c7bd92ac81a8b16eeebff264d59ce4604")))'
+  );
+});
@@ -4,10 +4,10 @@ import {
   Address, Wallet, ErgoBox, ErgoBoxCandidateBuilder, Contract,
   ErgoBoxes, ErgoBoxCandidates,
   ErgoStateContext, TxBuilder, BoxValue, BoxSelector, I64, SecretKey, SecretKeys, TxId, DataInputs,
-  SimpleBoxSelector, Tokens, Token, TokenAmount, TokenId, BlockHeaders, PreHeader,
+  SimpleBoxSelector, Tokens, Token, TokenAmount, TokenId, BlockHeaders, PreHeader, ReducedTransaction,
 } from '../pkg/ergo_lib_wasm';
 
-const block_headers = BlockHeaders.from_json([{
+const block_header_json = {
       "extensionId": "d16f25b14457186df4c5f6355579cc769261ce1aebc8209949ca6feadbac5a3f",
       "difficulty": "626412390187008",
       "votes": "040000",
@@ -30,7 +30,10 @@ const block_headers = BlockHeaders.from_json([{
       "adProofsId": "86eaa41f328bee598e33e52c9e515952ad3b7874102f762847f17318a776a7ae",
       "transactionsId": "ac80245714f25aa2fafe5494ad02a26d46e7955b8f5709f3659f1b9440797b3e",
       "parentId": "6481752bace5fa5acba5d5ef7124d48826664742d46c974c98a2d60ace229a34"
-}]);
+};
+
+// last 10 block headers (the same header is used for the test)
+const block_headers = BlockHeaders.from_json(Array(10).fill(block_header_json));
 
 it('TxBuilder test', async () => {
   const recipient = Address.from_testnet_str('3WvsT2Gm4EpsM9Pg18PdY6XyhNNMqXDsvJTbbf6ihLvAmSb7u5RN');
@@ -85,7 +88,7 @@ it('sign transaction', async () => {
   const tx = tx_builder.build();
   const tx_data_inputs = ErgoBoxes.from_boxes_json([]);
   const pre_header = PreHeader.from_block_header(block_headers.get(0));
-  const ctx = new ErgoStateContext(pre_header);
+  const ctx = new ErgoStateContext(pre_header, block_headers);
   const sks = new SecretKeys();
   sks.add(sk);
   const wallet = Wallet.from_secrets(sks);
@@ -93,6 +96,39 @@ it('sign transaction', async () => {
   assert(signed_tx != null);
 });
 
+it('sign reduced transaction', async () => {
+  const sk = SecretKey.random_dlog();
+  // simulate existing box guarded by the sk key
+  const input_contract = Contract.pay_to_address(sk.get_address());
+  const input_box = new ErgoBox(BoxValue.from_i64(I64.from_str('1000000000')), 0, input_contract, TxId.zero(), 0, new Tokens());
+  // create a transaction that spends the "simulated" box
+  const recipient = Address.from_testnet_str('3WvsT2Gm4EpsM9Pg18PdY6XyhNNMqXDsvJTbbf6ihLvAmSb7u5RN');
+  const unspent_boxes = new ErgoBoxes(input_box);
+  const contract = Contract.pay_to_address(recipient);
+  const outbox_value = BoxValue.SAFE_USER_MIN();
+  const outbox = new ErgoBoxCandidateBuilder(outbox_value, contract, 0).build();
+  const tx_outputs = new ErgoBoxCandidates(outbox);
+  const fee = TxBuilder.SUGGESTED_TX_FEE();
+  const change_address = Address.from_testnet_str('3WvsT2Gm4EpsM9Pg18PdY6XyhNNMqXDsvJTbbf6ihLvAmSb7u5RN');
+  const min_change_value = BoxValue.SAFE_USER_MIN();
+  const box_selector = new SimpleBoxSelector();
+  const target_balance = BoxValue.from_i64(outbox_value.as_i64().checked_add(fee.as_i64()));
+  const box_selection = box_selector.select(unspent_boxes, target_balance, new Tokens());
+  const tx_builder = TxBuilder.new(box_selection, tx_outputs, 0, fee, change_address, min_change_value);
+  const tx = tx_builder.build();
+  const tx_data_inputs = ErgoBoxes.from_boxes_json([]);
+  const pre_header = PreHeader.from_block_header(block_headers.get(0));
+  const ctx = new ErgoStateContext(pre_header, block_headers);
+  const reduced_tx = ReducedTransaction.from_unsigned_tx(tx, unspent_boxes, tx_data_inputs, ctx);
+  // transfer the reduced tx to the offline signer
+  const reduced_tx_parsed = ReducedTransaction.sigma_parse_bytes(reduced_tx.sigma_serialize_bytes());
+  const sks = new SecretKeys();
+  sks.add(sk);
+  const wallet = Wallet.from_secrets(sks);
+  const signed_tx = wallet.sign_reduced_transaction(reduced_tx_parsed);
+  assert(signed_tx != null);
+});
+
 it('TxBuilder mint token test', async () => {
   const recipient = Address.from_testnet_str('3WvsT2Gm4EpsM9Pg18PdY6XyhNNMqXDsvJTbbf6ihLvAmSb7u5RN');
   const unspent_boxes = ErgoBoxes.from_boxes_json([
@@ -197,7 +233,7 @@ it('use signed tx outputs as inputs in a new tx', async () => {
   const tx = tx_builder.build();
   const tx_data_inputs = ErgoBoxes.from_boxes_json([]);
   const pre_header = PreHeader.from_block_header(block_headers.get(0));
-  const ctx = new ErgoStateContext(pre_header);
+  const ctx = new ErgoStateContext(pre_header, block_headers);
   const sks = new SecretKeys();
   sks.add(sk);
   const wallet = Wallet.from_secrets(sks);
@@ -209,3 +245,8 @@ it('use signed tx outputs as inputs in a new tx', async () => {
   const new_tx = tx_builder.build();
   assert(new_tx != null);
 });
+
+it('ErgoStateContext requires 10 block headers', async () => {
+  const pre_header = PreHeader.from_block_header(block_headers.get(0));
+  expect(() => new ErgoStateContext(pre_header, BlockHeaders.from_json([block_header_json]))).to.throw();
+});
@@ -3,11 +3,16 @@
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
+use ergotree_ir::mir::avl_tree_data::ADDigest as IrADDigest;
+use ergotree_ir::mir::header::Header;
 use ergotree_ir::mir::header::PreHeader;
-use ergotree_ir::sigma_protocol::dlog_group;
+use ergotree_ir::serialization::SerializationError;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use num_bigint::BigInt;
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
 
+use super::ADDigest;
 use super::Base16DecodedBytes;
 use super::Base16EncodedBytes;
 use super::Digest32;
@@ -67,15 +72,59 @@ impl From<Votes> for Vec<u8> {
     }
 }
 
+/// Solution of the Autolykos PoW puzzle
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(
+        into = "crate::chain::json::block_header::AutolykosSolutionJson",
+        try_from = "crate::chain::json::block_header::AutolykosSolutionJson"
+    )
+)]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AutolykosSolution {
+    /// Public key of miner
+    pub miner_pk: Box<EcPoint>,
+    /// One-time public key. Prevents revealing of miners secret.
+    pub pow_onetime_pk: Box<EcPoint>,
+    /// Nonce
+    pub nonce: Vec<u8>,
+    /// Distance between pseudo-random number, corresponding to nonce `nonce` and a secret,
+    /// corresponding to `miner_pk` (always 0 in Autolykos v2)
+    pub pow_distance: BigInt,
+}
+
+/// Autolykos solution errors
+#[derive(Error, Debug)]
+pub enum AutolykosSolutionError {
+    /// Invalid public key
+    #[error("AutolykosSolution: invalid public key ({0})")]
+    InvalidPublicKey(#[from] SerializationError),
+    /// Invalid distance
+    #[error("AutolykosSolution: invalid distance ({0})")]
+    InvalidDistance(String),
+}
+
 /// Block header
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct BlockHeader {
+    /// Block id
+    pub id: BlockId,
     /// Block version, to be increased on every soft and hardfork
     pub version: u8,
     /// Id of a parent block
     #[cfg_attr(feature = "json", serde(rename = "parentId"))]
     pub parent_id: BlockId,
+    /// Hash of ADProofs for transactions in a block
+    #[cfg_attr(feature = "json", serde(rename = "adProofsRoot"))]
+    pub ad_proofs_root: Digest32,
+    /// AvlTree digest of a state after transactions application
+    #[cfg_attr(feature = "json", serde(rename = "stateRoot"))]
+    pub state_root: ADDigest,
+    /// Root hash (for a Merkle tree) of transactions in a block
+    #[cfg_attr(feature = "json", serde(rename = "transactionsRoot"))]
+    pub transaction_root: Digest32,
     /// Timestamp of a block in ms from UNIX epoch
     pub timestamp: u64,
     /// Current difficulty in a compressed view.
@@ -83,6 +132,12 @@ pub struct BlockHeader {
     pub n_bits: u64,
     /// Block height
     pub height: u32,
+    /// Root hash of extension section
+    #[cfg_attr(feature = "json", serde(rename = "extensionHash"))]
+    pub extension_root: Digest32,
+    /// Solution of the PoW puzzle
+    #[cfg_attr(feature = "json", serde(rename = "powSolutions"))]
+    pub autolykos_solution: AutolykosSolution,
     /// Votes
     pub votes: Votes,
 }
@@ -95,7 +150,29 @@ impl From<BlockHeader> for PreHeader {
             timestamp: bh.timestamp,
             n_bits: bh.n_bits,
             height: bh.height,
-            miner_pk: dlog_group::identity().into(), // TODO: get from bh.powSolution when its implemented
+            miner_pk: bh.autolykos_solution.miner_pk,
+            votes: bh.votes.into(),
+        }
+    }
+}
+
+impl From<BlockHeader> for Header {
+    fn from(bh: BlockHeader) -> Self {
+        Header {
+            version: bh.version,
+            id: bh.id.0.into(),
+            parent_id: bh.parent_id.0.into(),
+            ad_proofs_root: bh.ad_proofs_root.into(),
+            state_root: IrADDigest(bh.state_root.into()),
+            transaction_root: bh.transaction_root.into(),
+            timestamp: bh.timestamp,
+            n_bits: bh.n_bits,
+            height: bh.height,
+            extension_root: bh.extension_root.into(),
+            miner_pk: bh.autolykos_solution.miner_pk,
+            pow_onetime_pk: bh.autolykos_solution.pow_onetime_pk,
+            pow_nonce: bh.autolykos_solution.nonce,
+            pow_distance: bh.autolykos_solution.pow_distance,
             votes: bh.votes.into(),
         }
     }
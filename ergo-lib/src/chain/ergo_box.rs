@@ -90,6 +90,8 @@ pub struct ErgoBox {
 impl ErgoBox {
     /// Maximum number of tokens in the box
     pub const MAX_TOKENS_COUNT: usize = u8::MAX as usize;
+    /// Maximum size of the serialized box (in bytes)
+    pub const MAX_BOX_SIZE: usize = 4096;
 
     /// Crate new box
     pub fn new(
@@ -198,7 +200,7 @@ impl IrErgoBox for ErgoBox {
     }
 
     fn script_bytes(&self) -> Vec<i8> {
-        self.sigma_serialize_bytes().as_vec_i8()
+        self.ergo_tree.sigma_serialize_bytes().as_vec_i8()
     }
 
     /// Tuple of height when block got included into the blockchain and transaction identifier with
@@ -658,5 +660,15 @@ mod tests {
         fn ergo_box_ser_roundtrip(v in any::<ErgoBox>()) {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
+
+        #[test]
+        fn ir_ergo_box_bytes(b in any::<ErgoBox>()) {
+            prop_assert_eq!(IrErgoBox::bytes(&b), b.sigma_serialize_bytes().as_vec_i8());
+            prop_assert_eq!(
+                b.bytes_without_ref(),
+                ErgoBoxCandidate::from(b.clone()).sigma_serialize_bytes().as_vec_i8()
+            );
+            prop_assert_eq!(b.script_bytes(), b.ergo_tree.sigma_serialize_bytes().as_vec_i8());
+        }
     }
 }
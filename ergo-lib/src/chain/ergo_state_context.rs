@@ -1,4 +1,5 @@
 //! Blockchain state
+use ergotree_ir::mir::header::Header;
 use ergotree_ir::mir::header::PreHeader;
 
 /// Blockchain state (last headers, etc.)
@@ -7,13 +8,61 @@ pub struct ErgoStateContext {
     /// Block header with the current `spendingTransaction`, that can be predicted
     /// by a miner before it's formation
     pub pre_header: PreHeader,
+    /// Fixed number of last block headers in descending order (first header is the newest one)
+    pub headers: [Header; 10],
+    /// Blockchain parameters (set by miners voting)
+    pub parameters: Parameters,
 }
 
 impl ErgoStateContext {
+    /// Create new context with default (launch) blockchain parameters
+    pub fn new(pre_header: PreHeader, headers: [Header; 10]) -> ErgoStateContext {
+        ErgoStateContext {
+            pre_header,
+            headers,
+            parameters: Parameters::default(),
+        }
+    }
+
     /// Dummy instance intended for tests where actual values are not used
     pub fn dummy() -> ErgoStateContext {
-        ErgoStateContext {
-            pre_header: PreHeader::dummy(),
+        let headers = [
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+            Header::dummy(),
+        ];
+        ErgoStateContext::new(PreHeader::dummy(), headers)
+    }
+}
+
+/// Blockchain parameters used in transaction validation
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Parameters {
+    /// Maximum cost of the transactions in a block (in block cost units)
+    pub max_block_cost: u64,
+    /// Validation cost of accessing a single transaction input
+    pub input_cost: u64,
+    /// Validation cost of accessing a single data input
+    pub data_input_cost: u64,
+    /// Validation cost of a single transaction output
+    pub output_cost: u64,
+}
+
+impl Default for Parameters {
+    /// Parameters at the network launch
+    fn default() -> Self {
+        Parameters {
+            max_block_cost: 1000000,
+            input_cost: 2000,
+            data_input_cost: 100,
+            output_cost: 100,
         }
     }
 }
@@ -1,7 +1,12 @@
-use serde::Deserialize;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
+use crate::chain::block_header::AutolykosSolution;
+use crate::chain::block_header::AutolykosSolutionError;
 use crate::chain::block_header::Votes;
 use crate::chain::block_header::VotesError;
 use crate::chain::Base16DecodedBytes;
@@ -24,9 +29,61 @@ impl TryFrom<VotesEncodingVariants> for Votes {
     }
 }
 
+/// PoW distance is a number in the node API, the values that do not fit into u64 (Autolykos v1)
+/// are expected as strings
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum PowDistanceEncodingVariants {
+    AsNumber(u64),
+    AsStr(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AutolykosSolutionJson {
+    pk: Base16DecodedBytes,
+    w: Base16DecodedBytes,
+    n: Base16DecodedBytes,
+    d: PowDistanceEncodingVariants,
+}
+
+impl TryFrom<AutolykosSolutionJson> for AutolykosSolution {
+    type Error = AutolykosSolutionError;
+
+    fn try_from(s: AutolykosSolutionJson) -> Result<Self, Self::Error> {
+        let pow_distance = match s.d {
+            PowDistanceEncodingVariants::AsNumber(d) => BigInt::from(d),
+            PowDistanceEncodingVariants::AsStr(d) => d
+                .parse()
+                .map_err(|_| AutolykosSolutionError::InvalidDistance(d))?,
+        };
+        Ok(AutolykosSolution {
+            miner_pk: EcPoint::sigma_parse_bytes(&s.pk.0)?.into(),
+            pow_onetime_pk: EcPoint::sigma_parse_bytes(&s.w.0)?.into(),
+            nonce: s.n.0,
+            pow_distance,
+        })
+    }
+}
+
+impl From<AutolykosSolution> for AutolykosSolutionJson {
+    fn from(s: AutolykosSolution) -> Self {
+        let d = match u64::try_from(&s.pow_distance) {
+            Ok(d) => PowDistanceEncodingVariants::AsNumber(d),
+            Err(_) => PowDistanceEncodingVariants::AsStr(s.pow_distance.to_string()),
+        };
+        AutolykosSolutionJson {
+            pk: Base16DecodedBytes(s.miner_pk.sigma_serialize_bytes()),
+            w: Base16DecodedBytes(s.pow_onetime_pk.sigma_serialize_bytes()),
+            n: Base16DecodedBytes(s.nonce),
+            d,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::chain::block_header::BlockHeader;
+    use ergotree_ir::mir::header::Header;
 
     #[test]
     fn parse_block_header() {
@@ -56,6 +113,21 @@ mod tests {
         }"#;
         let b: BlockHeader = serde_json::from_str(json).unwrap();
         assert_eq!(b.height, 471746);
+        assert_eq!(
+            serde_json::from_str::<BlockHeader>(&serde_json::to_string(&b).unwrap()).unwrap(),
+            b
+        );
+        let h: Header = b.into();
+        assert_eq!(
+            base16::encode_lower(&h.id),
+            "4caa17e62fe66ba7bd69597afdc996ae35b1ff12e0ba90c22ff288a4de10e91b"
+        );
+        assert_eq!(
+            base16::encode_lower(&h.state_root.0),
+            "8ad868627ea4f7de6e2a2fe3f98fafe57f914e0f2ef3331c006def36c697f92713"
+        );
+        assert_eq!(base16::encode_lower(&h.pow_nonce), "5939ecfee6b0d7f4");
+        assert_eq!(h.pow_distance, 0.into());
     }
 
     #[test]
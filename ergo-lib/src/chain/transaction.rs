@@ -2,7 +2,9 @@
 
 mod data_input;
 pub mod input;
+pub mod reduced;
 pub mod unsigned;
+pub mod validation;
 
 pub use data_input::*;
 use ergotree_ir::serialization::sigma_byte_reader::SigmaByteRead;
@@ -0,0 +1,205 @@
+//! Represent `reduced` transaction, i.e. unsigned transaction where each unsigned input
+//! is augmented with ReducedInput which contains a script reduction result.
+
+use std::convert::TryInto;
+use std::io;
+use std::io::Read;
+use std::rc::Rc;
+
+use ergotree_interpreter::eval::env::Env;
+use ergotree_interpreter::eval::Evaluator;
+use ergotree_interpreter::eval::ReductionResult;
+use ergotree_interpreter::sigma_protocol::prover::ProverError;
+use ergotree_interpreter::sigma_protocol::verifier::TestVerifier;
+use ergotree_ir::serialization::sigma_byte_reader::SigmaByteRead;
+use ergotree_ir::serialization::sigma_byte_writer::SigmaByteWrite;
+use ergotree_ir::serialization::SerializationError;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+
+use crate::chain::ergo_state_context::ErgoStateContext;
+use crate::wallet::signing::make_context;
+use crate::wallet::signing::TransactionContext;
+use crate::wallet::signing::TxSigningError;
+
+use super::unsigned::UnsignedTransaction;
+use super::Transaction;
+use super::UnsignedInput;
+
+/// Input box script reduced to SigmaBoolean
+/// see EIP-19 for more details - https://github.com/ergoplatform/eips/blob/f280890a4163f2f2e988a0091c078e36912fc531/eip-0019.md
+#[derive(PartialEq, Debug, Clone)]
+pub struct ReducedInput {
+    /// result of the ErgoTree reduction
+    pub reduction_result: ReductionResult,
+}
+
+/// Represent `reduced` transaction, i.e. unsigned transaction where each unsigned input
+/// is augmented with ReducedInput which contains a script reduction result.
+/// After an unsigned transaction is reduced it can be signed without context.
+/// Thus, it can be serialized and transferred for example to Cold Wallet and signed
+/// in an environment where secrets are known.
+/// see EIP-19 for more details - https://github.com/ergoplatform/eips/blob/f280890a4163f2f2e988a0091c078e36912fc531/eip-0019.md
+#[derive(PartialEq, Debug, Clone)]
+pub struct ReducedTransaction {
+    /// Unsigned transaction
+    pub unsigned_tx: UnsignedTransaction,
+    /// Reduction result for each unsigned tx input (in the same order)
+    pub reduced_inputs: Vec<ReducedInput>,
+    /// Total cost of the inputs' scripts reduction
+    pub tx_cost: u32,
+}
+
+/// Reduce each input of unsigned transaction to sigma proposition
+pub fn reduce_transaction(
+    tx_context: TransactionContext,
+    state_context: &ErgoStateContext,
+) -> Result<ReducedTransaction, TxSigningError> {
+    let tx = &tx_context.spending_tx;
+    let reduced_inputs = tx
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(idx, input)| {
+            let input_box = tx_context
+                .boxes_to_spend
+                .iter()
+                .find(|b| b.box_id() == input.box_id)
+                .ok_or(TxSigningError::InputBoxNotFound(idx))?;
+            let ctx = Rc::new(make_context(state_context, &tx_context, idx)?);
+            input_box
+                .ergo_tree
+                .proposition()
+                .map_err(ProverError::ErgoTreeError)
+                .and_then(|expr| {
+                    TestVerifier
+                        .reduce_to_crypto(expr.as_ref(), &Env::empty(), ctx)
+                        .map_err(ProverError::EvalError)
+                })
+                .map(|reduction_result| ReducedInput { reduction_result })
+                .map_err(|e| TxSigningError::ProverError(e, idx))
+        })
+        .collect::<Result<Vec<ReducedInput>, TxSigningError>>()?;
+    let tx_cost = reduced_inputs
+        .iter()
+        .map(|i| i.reduction_result.cost)
+        .sum::<u64>()
+        .try_into()
+        .map_err(|_| TxSigningError::ContextError("tx cost overflow".to_string()))?;
+    Ok(ReducedTransaction {
+        unsigned_tx: tx_context.spending_tx,
+        reduced_inputs,
+        tx_cost,
+    })
+}
+
+impl SigmaSerializable for ReducedTransaction {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        // reference implementation - ReducedErgoLikeTransactionSerializer in sigmastate
+        let msg = self.unsigned_tx.bytes_to_sign();
+        w.put_usize_as_u32(msg.len())?;
+        w.write_all(&msg)?;
+        self.reduced_inputs.iter().try_for_each(|red_in| {
+            red_in.reduction_result.sigma_prop.sigma_serialize(w)?;
+            w.put_u64(red_in.reduction_result.cost)
+        })?;
+        w.put_u32(self.tx_cost)?;
+        Ok(())
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let msg_len = r.get_u32()?;
+        // do not allocate the buffer of the (untrusted) declared length upfront
+        let mut msg = Vec::new();
+        r.by_ref().take(msg_len as u64).read_to_end(&mut msg)?;
+        if msg.len() != msg_len as usize {
+            return Err(SerializationError::Io(format!(
+                "expected {} bytes of the message to sign, got {}",
+                msg_len,
+                msg.len()
+            )));
+        }
+        // the message to sign is a transaction with empty proofs
+        let tx = Transaction::sigma_parse_bytes(&msg)?;
+        let inputs = tx
+            .inputs
+            .iter()
+            .map(|i| UnsignedInput::new(i.box_id.clone(), i.spending_proof.extension.clone()))
+            .collect();
+        let unsigned_tx = UnsignedTransaction::new(inputs, tx.data_inputs, tx.output_candidates);
+        let mut reduced_inputs = Vec::with_capacity(unsigned_tx.inputs.len());
+        for _ in 0..unsigned_tx.inputs.len() {
+            let sigma_prop = SigmaBoolean::sigma_parse(r)?;
+            let cost = r.get_u64()?;
+            reduced_inputs.push(ReducedInput {
+                reduction_result: ReductionResult { sigma_prop, cost },
+            });
+        }
+        let tx_cost = r.get_u32()?;
+        Ok(ReducedTransaction {
+            unsigned_tx,
+            reduced_inputs,
+            tx_cost,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
+    use ergotree_ir::serialization::sigma_serialize_roundtrip;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ReducedTransaction {
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (any::<UnsignedTransaction>(), any::<u64>(), any::<u32>())
+                .prop_flat_map(|(unsigned_tx, cost, tx_cost)| {
+                    let inputs_len = unsigned_tx.inputs.len();
+                    (
+                        Just(unsigned_tx),
+                        vec(any::<DlogProverInput>(), inputs_len),
+                        Just(cost),
+                        Just(tx_cost),
+                    )
+                })
+                .prop_map(|(unsigned_tx, secrets, cost, tx_cost)| ReducedTransaction {
+                    unsigned_tx,
+                    reduced_inputs: secrets
+                        .into_iter()
+                        .map(|secret| ReducedInput {
+                            reduction_result: ReductionResult {
+                                sigma_prop: secret.public_image().into(),
+                                cost,
+                            },
+                        })
+                        .collect(),
+                    tx_cost,
+                })
+                .boxed()
+        }
+        type Strategy = BoxedStrategy<Self>;
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<ReducedTransaction>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+    }
+
+    #[test]
+    fn parse_huge_msg_len() {
+        // VLQ-encoded u32::MAX message length followed by a few bytes of the message
+        let bytes = [0xff, 0xff, 0xff, 0xff, 0x0f, 1, 2, 3];
+        assert!(ReducedTransaction::sigma_parse_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,683 @@
+//! Transaction validation (stateless and stateful checks)
+//! reference implementation - `ErgoTransaction.validateStateless/validateStateful` in Ergo node
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use ergotree_interpreter::eval::env::Env;
+use ergotree_interpreter::eval::EvalError;
+use ergotree_interpreter::sigma_protocol::verifier::TestVerifier;
+use ergotree_interpreter::sigma_protocol::verifier::Verifier;
+use ergotree_interpreter::sigma_protocol::verifier::VerifierError;
+use ergotree_ir::serialization::SigmaSerializable;
+use thiserror::Error;
+
+use crate::chain::ergo_box::BoxId;
+use crate::chain::ergo_box::BoxValue;
+use crate::chain::ergo_box::ErgoBox;
+use crate::chain::ergo_state_context::ErgoStateContext;
+use crate::chain::token::Token;
+use crate::chain::token::TokenId;
+use crate::wallet::signing::make_context;
+use crate::wallet::signing::TransactionContext;
+
+use super::unsigned::UnsignedTransaction;
+use super::Transaction;
+use super::UnsignedInput;
+
+/// Errors on transaction validation
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum TxValidationError {
+    /// Transaction has no inputs
+    #[error("Transaction has no inputs")]
+    NoInputs,
+    /// Transaction has no outputs
+    #[error("Transaction has no outputs")]
+    NoOutputs,
+    /// Too many inputs
+    #[error("Too many inputs: {0}")]
+    TooManyInputs(usize),
+    /// Too many data inputs
+    #[error("Too many data inputs: {0}")]
+    TooManyDataInputs(usize),
+    /// Too many outputs
+    #[error("Too many outputs: {0}")]
+    TooManyOutputs(usize),
+    /// The same box is spent more than once
+    #[error("Box is spent more than once: {0:?}")]
+    DoubleSpending(BoxId),
+    /// Output box is too large
+    #[error("Output box (index {index}) size {size} exceeds the maximum box size")]
+    OutputBoxTooLarge {
+        /// output index
+        index: usize,
+        /// serialized box size in bytes
+        size: usize,
+    },
+    /// Output box value is less than the minimum value for the box size
+    #[error("Output box (index {index}) value {value} is less than the minimum {min_value}")]
+    OutputBoxValueTooLow {
+        /// output index
+        index: usize,
+        /// box value
+        value: u64,
+        /// minimum value for the box size
+        min_value: u64,
+    },
+    /// Output box has too many tokens
+    #[error("Output box (index {0}) has too many tokens")]
+    TooManyTokens(usize),
+    /// Total value of the inputs or outputs overflows
+    #[error("Total value overflow")]
+    ValueOverflow,
+    /// Output box creation height is greater than the current height
+    #[error("Output box (index {index}) creation height {creation_height} is greater than the current height {height}")]
+    InvalidCreationHeight {
+        /// output index
+        index: usize,
+        /// output box creation height
+        creation_height: u32,
+        /// current height
+        height: u32,
+    },
+    /// Output box creation height is less than the maximum creation height of the inputs
+    #[error("Output box (index {index}) creation height {creation_height} is less than the maximum inputs creation height {max_inputs_creation_height}")]
+    NonMonotonicCreationHeight {
+        /// output index
+        index: usize,
+        /// output box creation height
+        creation_height: u32,
+        /// maximum creation height of the input boxes
+        max_inputs_creation_height: u32,
+    },
+    /// Provided boxes to spend do not correspond to the transaction inputs
+    #[error("Input box for the input (index {0}) is not found")]
+    InputBoxNotFound(usize),
+    /// Provided data boxes do not correspond to the transaction data inputs
+    #[error("Data input box for the data input (index {0}) is not found")]
+    DataInputBoxNotFound(usize),
+    /// Total value of the inputs differs from the total value of the outputs
+    #[error("ERG is not preserved: inputs {inputs}, outputs {outputs}")]
+    ErgPreservation {
+        /// total value of the inputs
+        inputs: u64,
+        /// total value of the outputs
+        outputs: u64,
+    },
+    /// Outputs contain more tokens than the inputs
+    #[error("Token is not preserved: {0:?}")]
+    TokenPreservation(TokenId),
+    /// New token is minted with an id other than the id of the first input box
+    #[error("Minted token id {0:?} differs from the first input box id")]
+    InvalidMintedToken(TokenId),
+    /// Input script verification failed
+    #[error("Input (index {0}) script verification failed")]
+    InputScriptFailed(usize),
+    /// Error on input script verification
+    #[error("Verifier error (input index {1}): {0}")]
+    VerifierError(VerifierError, usize),
+    /// Total transaction cost exceeds the limit
+    #[error("Transaction cost exceeds the limit {0}")]
+    CostLimitExceeded(u64),
+    /// Context creation error
+    #[error("Context error: {0}")]
+    ContextError(String),
+}
+
+impl Transaction {
+    /// Maximum number of inputs (and data inputs)
+    pub const MAX_INPUTS_COUNT: usize = i16::MAX as usize;
+
+    /// Checks that do not require the blockchain state: input/output counts, double spending,
+    /// outputs size, value and tokens count
+    pub fn validate_stateless(&self) -> Result<(), TxValidationError> {
+        if self.inputs.is_empty() {
+            return Err(TxValidationError::NoInputs);
+        }
+        if self.inputs.len() > Transaction::MAX_INPUTS_COUNT {
+            return Err(TxValidationError::TooManyInputs(self.inputs.len()));
+        }
+        if self.data_inputs.len() > Transaction::MAX_INPUTS_COUNT {
+            return Err(TxValidationError::TooManyDataInputs(self.data_inputs.len()));
+        }
+        if self.output_candidates.is_empty() {
+            return Err(TxValidationError::NoOutputs);
+        }
+        if self.output_candidates.len() > Transaction::MAX_INPUTS_COUNT {
+            return Err(TxValidationError::TooManyOutputs(
+                self.output_candidates.len(),
+            ));
+        }
+        let mut spent = HashSet::new();
+        if let Some(dup) = self.inputs.iter().find(|i| !spent.insert(&i.box_id)) {
+            return Err(TxValidationError::DoubleSpending(dup.box_id.clone()));
+        }
+        self.outputs()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, b)| {
+                if b.tokens.len() > ErgoBox::MAX_TOKENS_COUNT {
+                    return Err(TxValidationError::TooManyTokens(index));
+                }
+                // as in the node, the size of the box with the tx id and index is checked
+                let size = b.sigma_serialize_bytes().len();
+                if size > ErgoBox::MAX_BOX_SIZE {
+                    return Err(TxValidationError::OutputBoxTooLarge { index, size });
+                }
+                let min_value = size as u64 * BoxValue::MIN_VALUE_PER_BOX_BYTE as u64;
+                let value = *b.value.as_u64();
+                if value < min_value {
+                    return Err(TxValidationError::OutputBoxValueTooLow {
+                        index,
+                        value,
+                        min_value,
+                    });
+                }
+                Ok(())
+            })?;
+        self.output_candidates
+            .iter()
+            .try_fold(0u64, |acc, b| acc.checked_add(*b.value.as_u64()))
+            .ok_or(TxValidationError::ValueOverflow)?;
+        Ok(())
+    }
+
+    /// Checks that require the boxes being spent and the blockchain state: ERG and tokens
+    /// preservation, token minting, outputs creation height (not greater than the current height
+    /// and, since block version 2, not less than the inputs creation height) and inputs scripts
+    /// verification (with the total cost checked against the max block cost).
+    /// `boxes_to_spend` and `data_boxes` should be in the same order as the transaction inputs
+    /// and data inputs. Returns the total transaction cost.
+    pub fn validate_stateful(
+        &self,
+        boxes_to_spend: Vec<ErgoBox>,
+        data_boxes: Vec<ErgoBox>,
+        state_context: &ErgoStateContext,
+    ) -> Result<u64, TxValidationError> {
+        self.validate_stateless()?;
+        if boxes_to_spend.len() != self.inputs.len() {
+            return Err(TxValidationError::InputBoxNotFound(
+                boxes_to_spend.len().min(self.inputs.len()),
+            ));
+        }
+        self.inputs
+            .iter()
+            .zip(boxes_to_spend.iter())
+            .enumerate()
+            .try_for_each(|(idx, (input, b))| {
+                if input.box_id == b.box_id() {
+                    Ok(())
+                } else {
+                    Err(TxValidationError::InputBoxNotFound(idx))
+                }
+            })?;
+        if data_boxes.len() != self.data_inputs.len() {
+            return Err(TxValidationError::DataInputBoxNotFound(
+                data_boxes.len().min(self.data_inputs.len()),
+            ));
+        }
+        self.data_inputs
+            .iter()
+            .zip(data_boxes.iter())
+            .enumerate()
+            .try_for_each(|(idx, (data_input, b))| {
+                if data_input.box_id == b.box_id() {
+                    Ok(())
+                } else {
+                    Err(TxValidationError::DataInputBoxNotFound(idx))
+                }
+            })?;
+
+        let height = state_context.pre_header.height;
+        // monotonic creation height rule is enforced since block version 2
+        let max_inputs_creation_height = if state_context.pre_header.version > 1 {
+            boxes_to_spend
+                .iter()
+                .map(|b| b.creation_height)
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        self.output_candidates
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, b)| {
+                if b.creation_height > height {
+                    Err(TxValidationError::InvalidCreationHeight {
+                        index,
+                        creation_height: b.creation_height,
+                        height,
+                    })
+                } else if b.creation_height < max_inputs_creation_height {
+                    Err(TxValidationError::NonMonotonicCreationHeight {
+                        index,
+                        creation_height: b.creation_height,
+                        max_inputs_creation_height,
+                    })
+                } else {
+                    Ok(())
+                }
+            })?;
+
+        self.check_assets_preservation(&boxes_to_spend)?;
+        self.verify_inputs(boxes_to_spend, data_boxes, state_context)
+    }
+
+    fn check_assets_preservation(
+        &self,
+        boxes_to_spend: &[ErgoBox],
+    ) -> Result<(), TxValidationError> {
+        let inputs_value = boxes_to_spend
+            .iter()
+            .try_fold(0u64, |acc, b| acc.checked_add(*b.value.as_u64()))
+            .ok_or(TxValidationError::ValueOverflow)?;
+        let outputs_value = self
+            .output_candidates
+            .iter()
+            .try_fold(0u64, |acc, b| acc.checked_add(*b.value.as_u64()))
+            .ok_or(TxValidationError::ValueOverflow)?;
+        if inputs_value != outputs_value {
+            return Err(TxValidationError::ErgPreservation {
+                inputs: inputs_value,
+                outputs: outputs_value,
+            });
+        }
+
+        let input_tokens = sum_token_amounts(boxes_to_spend.iter().flat_map(|b| b.tokens.iter()));
+        let output_tokens =
+            sum_token_amounts(self.output_candidates.iter().flat_map(|b| b.tokens.iter()));
+        // only a token with the id of the first input box can be minted
+        let new_token_id: Option<TokenId> = boxes_to_spend.first().map(|b| b.box_id().into());
+        output_tokens.iter().try_for_each(|(token_id, out_amount)| {
+            match (input_tokens.get(token_id), out_amount) {
+                (Some(Some(in_amount)), Some(out_amount)) if out_amount <= in_amount => Ok(()),
+                (None, Some(_)) if Some(token_id) == new_token_id.as_ref() => Ok(()),
+                (None, _) => Err(TxValidationError::InvalidMintedToken(token_id.clone())),
+                _ => Err(TxValidationError::TokenPreservation(token_id.clone())),
+            }
+        })
+    }
+
+    fn verify_inputs(
+        &self,
+        boxes_to_spend: Vec<ErgoBox>,
+        data_boxes: Vec<ErgoBox>,
+        state_context: &ErgoStateContext,
+    ) -> Result<u64, TxValidationError> {
+        let params = &state_context.parameters;
+        let max_cost = params.max_block_cost;
+        let initial_cost = (self.inputs.len() as u64)
+            .saturating_mul(params.input_cost)
+            .saturating_add((self.data_inputs.len() as u64).saturating_mul(params.data_input_cost))
+            .saturating_add(
+                (self.output_candidates.len() as u64).saturating_mul(params.output_cost),
+            );
+        if initial_cost > max_cost {
+            return Err(TxValidationError::CostLimitExceeded(max_cost));
+        }
+        let unsigned_tx = UnsignedTransaction::new(
+            self.inputs
+                .iter()
+                .map(|i| UnsignedInput::new(i.box_id.clone(), i.spending_proof.extension.clone()))
+                .collect(),
+            self.data_inputs.clone(),
+            self.output_candidates.clone(),
+        );
+        let message = self.bytes_to_sign();
+        let tx_context = TransactionContext {
+            spending_tx: unsigned_tx,
+            boxes_to_spend,
+            data_boxes,
+        };
+        self.inputs
+            .iter()
+            .enumerate()
+            .try_fold(initial_cost, |total_cost, (idx, input)| {
+                let mut ctx = make_context(state_context, &tx_context, idx)
+                    .map_err(|e| TxValidationError::ContextError(e.to_string()))?;
+                ctx.cost_limit = Some(max_cost - total_cost);
+                let input_box = tx_context
+                    .boxes_to_spend
+                    .get(idx)
+                    .ok_or(TxValidationError::InputBoxNotFound(idx))?;
+                let res = TestVerifier
+                    .verify(
+                        &input_box.ergo_tree,
+                        &Env::empty(),
+                        Rc::new(ctx),
+                        input.spending_proof.proof.clone(),
+                        &message,
+                    )
+                    .map_err(|e| match e {
+                        VerifierError::EvalError(EvalError::CostError(_)) => {
+                            TxValidationError::CostLimitExceeded(max_cost)
+                        }
+                        e => TxValidationError::VerifierError(e, idx),
+                    })?;
+                if !res.result {
+                    return Err(TxValidationError::InputScriptFailed(idx));
+                }
+                let total_cost = total_cost.saturating_add(res.cost);
+                if total_cost > max_cost {
+                    return Err(TxValidationError::CostLimitExceeded(max_cost));
+                }
+                Ok(total_cost)
+            })
+    }
+}
+
+/// Sums token amounts by token id, `None` on overflow
+fn sum_token_amounts<'a, I>(tokens: I) -> HashMap<TokenId, Option<u64>>
+where
+    I: Iterator<Item = &'a Token>,
+{
+    let mut res: HashMap<TokenId, Option<u64>> = HashMap::new();
+    tokens.for_each(|t| {
+        let amount: u64 = t.amount.into();
+        let entry = res.entry(t.token_id.clone()).or_insert(Some(0));
+        *entry = entry.and_then(|a| a.checked_add(amount));
+    });
+    res
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
+    use ergotree_interpreter::sigma_protocol::private_input::PrivateInput;
+    use ergotree_interpreter::sigma_protocol::prover::TestProver;
+    use ergotree_ir::ergo_tree::ErgoTree;
+    use ergotree_ir::mir::expr::Expr;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+
+    use crate::chain::ergo_box::box_builder::ErgoBoxCandidateBuilder;
+    use crate::chain::ergo_box::ErgoBoxCandidate;
+    use crate::chain::ergo_box::NonMandatoryRegisters;
+    use crate::chain::token::TokenAmount;
+    use crate::chain::transaction::Input;
+    use crate::chain::transaction::TxId;
+    use crate::wallet::signing::sign_transaction;
+
+    fn p2pk_box(secret: &DlogProverInput, value: BoxValue, index: u16) -> ErgoBox {
+        let tree = ErgoTree::from(Expr::Const(secret.public_image().into()));
+        ErgoBox::new(
+            value,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            index,
+        )
+    }
+
+    fn p2pk_candidate(
+        secret: &DlogProverInput,
+        value: u64,
+        tokens: Vec<Token>,
+    ) -> ErgoBoxCandidate {
+        let tree = ErgoTree::from(Expr::Const(secret.public_image().into()));
+        let mut builder = ErgoBoxCandidateBuilder::new(BoxValue::new(value).unwrap(), tree, 0);
+        tokens.into_iter().for_each(|t| builder.add_token(t));
+        builder.build().unwrap()
+    }
+
+    /// Signs a tx spending the boxes protected by the given secrets
+    fn signed_tx(
+        secrets: &[DlogProverInput],
+        boxes_to_spend: &[ErgoBox],
+        output_candidates: Vec<ErgoBoxCandidate>,
+    ) -> Transaction {
+        let prover = TestProver {
+            secrets: secrets
+                .iter()
+                .cloned()
+                .map(PrivateInput::DlogProverInput)
+                .collect(),
+        };
+        let inputs: Vec<UnsignedInput> = boxes_to_spend
+            .iter()
+            .cloned()
+            .map(UnsignedInput::from)
+            .collect();
+        let tx_context = TransactionContext {
+            spending_tx: UnsignedTransaction::new(inputs, vec![], output_candidates),
+            boxes_to_spend: boxes_to_spend.to_vec(),
+            data_boxes: vec![],
+        };
+        sign_transaction(&prover, tx_context, &ErgoStateContext::dummy()).unwrap()
+    }
+
+    fn total_value(boxes: &[ErgoBox]) -> u64 {
+        boxes.iter().map(|b| *b.value.as_u64()).sum()
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(8))]
+
+        #[test]
+        fn test_valid_tx(secrets in vec(any::<DlogProverInput>(), 1..4)) {
+            let boxes: Vec<ErgoBox> = secrets.iter().enumerate().map(|(i, s)| p2pk_box(s, BoxValue::SAFE_USER_MIN, i as u16)).collect();
+            let tx = signed_tx(&secrets, &boxes, vec![p2pk_candidate(&secrets[0], total_value(&boxes), vec![])]);
+            prop_assert_eq!(tx.validate_stateless(), Ok(()));
+            let cost = tx.validate_stateful(boxes, vec![], &ErgoStateContext::dummy()).unwrap();
+            prop_assert!(cost > 0);
+        }
+
+        #[test]
+        fn test_erg_preservation(secret in any::<DlogProverInput>()) {
+            let boxes = vec![p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0)];
+            let outputs_value = total_value(&boxes) - 1;
+            let tx = signed_tx(std::slice::from_ref(&secret), &boxes, vec![p2pk_candidate(&secret, outputs_value, vec![])]);
+            prop_assert_eq!(tx.validate_stateful(boxes.clone(), vec![], &ErgoStateContext::dummy()),
+                            Err(TxValidationError::ErgPreservation {
+                                inputs: total_value(&boxes),
+                                outputs: outputs_value
+                            }));
+        }
+
+        #[test]
+        fn test_invalid_proof(secret in any::<DlogProverInput>(), other_secret in any::<DlogProverInput>()) {
+            let boxes = vec![p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0)];
+            let outputs = vec![p2pk_candidate(&secret, total_value(&boxes), vec![])];
+            let tx = signed_tx(std::slice::from_ref(&secret), &boxes, outputs.clone());
+            // proof from a tx spending the box with the same id, but signed with another secret
+            let other_boxes = vec![p2pk_box(&other_secret, BoxValue::SAFE_USER_MIN, 0)];
+            let other_tx = signed_tx(&[other_secret], &other_boxes, outputs);
+            let forged_tx = Transaction::new(
+                vec![Input::new(tx.inputs[0].box_id.clone(), other_tx.inputs[0].spending_proof.clone())],
+                vec![],
+                tx.output_candidates.clone(),
+            );
+            prop_assert!(forged_tx.validate_stateful(boxes, vec![], &ErgoStateContext::dummy()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_double_spending() {
+        let secret = DlogProverInput::random();
+        let b = p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0);
+        let tx = signed_tx(
+            std::slice::from_ref(&secret),
+            std::slice::from_ref(&b),
+            vec![p2pk_candidate(&secret, *b.value.as_u64(), vec![])],
+        );
+        let double_spending_tx = Transaction::new(
+            vec![tx.inputs[0].clone(), tx.inputs[0].clone()],
+            vec![],
+            tx.output_candidates,
+        );
+        assert_eq!(
+            double_spending_tx.validate_stateless(),
+            Err(TxValidationError::DoubleSpending(b.box_id()))
+        );
+    }
+
+    #[test]
+    fn test_no_outputs() {
+        let tx = Transaction::new(vec![force_any_val::<Input>()], vec![], vec![]);
+        assert_eq!(tx.validate_stateless(), Err(TxValidationError::NoOutputs));
+    }
+
+    #[test]
+    fn test_output_box_value_too_low() {
+        let secret = DlogProverInput::random();
+        let mut candidate = p2pk_candidate(&secret, BoxValue::SAFE_USER_MIN.into(), vec![]);
+        candidate.value = BoxValue::MIN;
+        let tx = Transaction::new(vec![force_any_val::<Input>()], vec![], vec![candidate]);
+        assert!(matches!(
+            tx.validate_stateless(),
+            Err(TxValidationError::OutputBoxValueTooLow { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_output_box_min_value_boundary() {
+        let secret = DlogProverInput::random();
+        let mut candidate = p2pk_candidate(&secret, BoxValue::SAFE_USER_MIN.into(), vec![]);
+        // box size includes the tx id and the index
+        let box_size = ErgoBox::from_box_candidate(&candidate, TxId::zero(), 0)
+            .sigma_serialize_bytes()
+            .len();
+        assert!(box_size > candidate.sigma_serialize_bytes().len());
+        let min_value = box_size as u64 * BoxValue::MIN_VALUE_PER_BOX_BYTE as u64;
+        let input = force_any_val::<Input>();
+        candidate.value = BoxValue::new(min_value).unwrap();
+        let tx = Transaction::new(vec![input.clone()], vec![], vec![candidate.clone()]);
+        assert_eq!(tx.validate_stateless(), Ok(()));
+        candidate.value = BoxValue::new(min_value - 1).unwrap();
+        let tx = Transaction::new(vec![input], vec![], vec![candidate]);
+        assert_eq!(
+            tx.validate_stateless(),
+            Err(TxValidationError::OutputBoxValueTooLow {
+                index: 0,
+                value: min_value - 1,
+                min_value
+            })
+        );
+    }
+
+    #[test]
+    fn test_minting() {
+        let secret = DlogProverInput::random();
+        let boxes = vec![
+            p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0),
+            p2pk_box(
+                &secret,
+                BoxValue::SAFE_USER_MIN.checked_mul_u32(2).unwrap(),
+                1,
+            ),
+        ];
+        let amount = TokenAmount::try_from(100u64).unwrap();
+        let minted = Token {
+            token_id: boxes[0].box_id().into(),
+            amount,
+        };
+        let tx = signed_tx(
+            std::slice::from_ref(&secret),
+            &boxes,
+            vec![p2pk_candidate(&secret, total_value(&boxes), vec![minted])],
+        );
+        assert!(tx
+            .validate_stateful(boxes.clone(), vec![], &ErgoStateContext::dummy())
+            .is_ok());
+
+        let not_first_input_token = Token {
+            token_id: boxes[1].box_id().into(),
+            amount,
+        };
+        let tx = signed_tx(
+            std::slice::from_ref(&secret),
+            &boxes,
+            vec![p2pk_candidate(
+                &secret,
+                total_value(&boxes),
+                vec![not_first_input_token.clone()],
+            )],
+        );
+        assert_eq!(
+            tx.validate_stateful(boxes, vec![], &ErgoStateContext::dummy()),
+            Err(TxValidationError::InvalidMintedToken(
+                not_first_input_token.token_id
+            ))
+        );
+    }
+
+    #[test]
+    fn test_creation_height() {
+        let secret = DlogProverInput::random();
+        let boxes = vec![p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0)];
+        let mut candidate = p2pk_candidate(&secret, total_value(&boxes), vec![]);
+        candidate.creation_height = 1;
+        let tx = signed_tx(&[secret], &boxes, vec![candidate]);
+        assert_eq!(
+            tx.validate_stateful(boxes, vec![], &ErgoStateContext::dummy()),
+            Err(TxValidationError::InvalidCreationHeight {
+                index: 0,
+                creation_height: 1,
+                height: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_monotonic_creation_height() {
+        let secret = DlogProverInput::random();
+        let tree = ErgoTree::from(Expr::Const(secret.public_image().into()));
+        let boxes = vec![ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            1,
+            TxId::zero(),
+            0,
+        )];
+        let candidate = p2pk_candidate(&secret, total_value(&boxes), vec![]);
+        assert_eq!(candidate.creation_height, 0);
+        let tx = signed_tx(&[secret], &boxes, vec![candidate]);
+        let mut state_context = ErgoStateContext::dummy();
+        state_context.pre_header.height = 1;
+        assert!(tx
+            .validate_stateful(boxes.clone(), vec![], &state_context)
+            .is_ok());
+        state_context.pre_header.version = 2;
+        assert_eq!(
+            tx.validate_stateful(boxes, vec![], &state_context),
+            Err(TxValidationError::NonMonotonicCreationHeight {
+                index: 0,
+                creation_height: 0,
+                max_inputs_creation_height: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_cost_limit() {
+        let secret = DlogProverInput::random();
+        let boxes = vec![p2pk_box(&secret, BoxValue::SAFE_USER_MIN, 0)];
+        let tx = signed_tx(
+            std::slice::from_ref(&secret),
+            &boxes,
+            vec![p2pk_candidate(&secret, total_value(&boxes), vec![])],
+        );
+        let mut state_context = ErgoStateContext::dummy();
+        let cost = tx
+            .validate_stateful(boxes.clone(), vec![], &state_context)
+            .unwrap();
+        state_context.parameters.max_block_cost = cost - 1;
+        assert_eq!(
+            tx.validate_stateful(boxes, vec![], &state_context),
+            Err(TxValidationError::CostLimitExceeded(cost - 1))
+        );
+    }
+}
@@ -1,18 +1,28 @@
 //! Wallet-related features for Ergo
 
 pub mod box_selector;
+pub mod multi_sig;
 pub mod secret_key;
 pub mod signing;
 pub mod tx_builder;
 
 use ergotree_interpreter::sigma_protocol::private_input::PrivateInput;
+use ergotree_interpreter::sigma_protocol::prover::hint::HintsBag;
 use ergotree_interpreter::sigma_protocol::prover::Prover;
+use ergotree_interpreter::sigma_protocol::prover::ProverError;
 use ergotree_interpreter::sigma_protocol::prover::TestProver;
+use ergotree_interpreter::sigma_protocol::verifier::VerifierError;
+use ergotree_ir::address::Address;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use multi_sig::TransactionHintsBag;
 use secret_key::SecretKey;
-use signing::{sign_transaction, TxSigningError};
+use signing::{
+    sign_reduced_transaction, sign_transaction, sign_transaction_with_hints, TxSigningError,
+};
 use thiserror::Error;
 
 use crate::chain::ergo_state_context::ErgoStateContext;
+use crate::chain::transaction::reduced::ReducedTransaction;
 use crate::chain::transaction::Transaction;
 
 use self::signing::TransactionContext;
@@ -28,6 +38,15 @@ pub enum WalletError {
     /// Error on tx signing
     #[error("Transaction signing error: {0}")]
     TxSigningError(TxSigningError),
+    /// Error on message signing
+    #[error("Prover error: {0}")]
+    ProverError(ProverError),
+    /// Error on signature verification
+    #[error("Verifier error: {0}")]
+    VerifierError(VerifierError),
+    /// Only P2PK addresses are supported
+    #[error("Expected P2PK address, got: {0:?}")]
+    NotP2PkAddress(Address),
 }
 
 impl From<TxSigningError> for WalletError {
@@ -36,6 +55,18 @@ impl From<TxSigningError> for WalletError {
     }
 }
 
+impl From<ProverError> for WalletError {
+    fn from(e: ProverError) -> Self {
+        WalletError::ProverError(e)
+    }
+}
+
+impl From<VerifierError> for WalletError {
+    fn from(e: VerifierError) -> Self {
+        WalletError::VerifierError(e)
+    }
+}
+
 impl Wallet {
     /// Create Wallet from secrets
     pub fn from_secrets(secrets: Vec<SecretKey>) -> Wallet {
@@ -55,4 +86,115 @@ impl Wallet {
     ) -> Result<Transaction, WalletError> {
         sign_transaction(self.prover.as_ref(), tx_context, state_context).map_err(WalletError::from)
     }
+
+    /// Signs a reduced transaction (generating proofs for inputs)
+    pub fn sign_reduced_transaction(
+        &self,
+        reduced_tx: ReducedTransaction,
+    ) -> Result<Transaction, WalletError> {
+        sign_reduced_transaction(self.prover.as_ref(), reduced_tx, None).map_err(WalletError::from)
+    }
+
+    /// Generates commitments for the transaction inputs (first step of the multi-signature
+    /// workflow, see [`multi_sig`]).
+    /// Only public hints ([`TransactionHintsBag::public_hints`]) should be shared with the co-signers.
+    pub fn generate_commitments(
+        &self,
+        tx_context: TransactionContext,
+        state_context: &ErgoStateContext,
+    ) -> Result<TransactionHintsBag, WalletError> {
+        multi_sig::generate_commitments(self.prover.as_ref(), &tx_context, state_context)
+            .map_err(WalletError::from)
+    }
+
+    /// Signs a transaction using the given hints (commitments and proofs of the co-signers).
+    /// If the wallet lacks some of the secrets the resulting transaction is partially signed and
+    /// the hints for the next co-signer can be extracted with [`multi_sig::extract_hints`]
+    pub fn sign_transaction_with_hints(
+        &self,
+        tx_context: TransactionContext,
+        state_context: &ErgoStateContext,
+        tx_hints: &TransactionHintsBag,
+    ) -> Result<Transaction, WalletError> {
+        sign_transaction_with_hints(self.prover.as_ref(), tx_context, state_context, tx_hints)
+            .map_err(WalletError::from)
+    }
+
+    /// Signs an arbitrary message using the secret key of the given P2PK address.
+    /// The signature can be checked with [`verify_signature`]
+    pub fn sign_message_using_p2pk(
+        &self,
+        address: &Address,
+        message: &[u8],
+    ) -> Result<Vec<u8>, WalletError> {
+        let sigma_tree = p2pk_sigma_tree(address)?;
+        self.prover
+            .sign_message(sigma_tree, message, &HintsBag::empty())
+            .map_err(WalletError::from)
+    }
+}
+
+/// Verifies the signature of an arbitrary message made with [`Wallet::sign_message_using_p2pk`]
+pub fn verify_signature(
+    address: &Address,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, WalletError> {
+    let sigma_tree = p2pk_sigma_tree(address)?;
+    ergotree_interpreter::sigma_protocol::verifier::verify_signature(sigma_tree, message, signature)
+        .map_err(WalletError::from)
+}
+
+fn p2pk_sigma_tree(address: &Address) -> Result<SigmaBoolean, WalletError> {
+    match address {
+        Address::P2Pk(pk) => Ok(pk.clone().into()),
+        _ => Err(WalletError::NotP2PkAddress(address.clone())),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn test_sign_message_using_p2pk(secret in any::<DlogProverInput>(), message in vec(any::<u8>(), 0..200)) {
+            let secret = SecretKey::DlogSecretKey(secret);
+            let address = secret.get_address_from_public_image();
+            let wallet = Wallet::from_secrets(vec![secret]);
+            let signature = wallet.sign_message_using_p2pk(&address, &message).unwrap();
+            prop_assert!(verify_signature(&address, &message, &signature).unwrap());
+            let mut other_message = message.clone();
+            other_message.push(1);
+            prop_assert!(!verify_signature(&address, &other_message, &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sign_message_unknown_key() {
+        let wallet = Wallet::from_secrets(vec![SecretKey::random_dlog()]);
+        let address = SecretKey::random_dlog().get_address_from_public_image();
+        let res = wallet.sign_message_using_p2pk(&address, &[1, 2, 3]);
+        assert_eq!(
+            res,
+            Err(WalletError::ProverError(ProverError::TreeRootIsNotReal))
+        );
+    }
+
+    #[test]
+    fn test_sign_message_p2s_address() {
+        let secret = SecretKey::random_dht();
+        let address = secret.get_address_from_public_image();
+        let wallet = Wallet::from_secrets(vec![secret]);
+        let res = wallet.sign_message_using_p2pk(&address, &[1, 2, 3]);
+        assert_eq!(res, Err(WalletError::NotP2PkAddress(address)));
+    }
 }
@@ -0,0 +1,355 @@
+//! Multi-signature (distributed signing) workflow
+//!
+//! Mirrors the node's `/wallet/generateCommitments` and `/wallet/extractHints` flows:
+//! 1. every co-signer generates commitments for the inputs ([`generate_commitments`]) and shares
+//!    the public part of them;
+//! 2. a co-signer signs the transaction with the hints of the others, producing a partially signed
+//!    transaction ([`crate::wallet::Wallet::sign_transaction_with_hints`]);
+//! 3. the next co-signer extracts the hints from the partially signed transaction
+//!    ([`extract_hints`]), adds own secret hints and signs the transaction again.
+
+use std::collections::HashMap;
+
+use ergotree_interpreter::sigma_protocol::prover::bag_for_multisig;
+use ergotree_interpreter::sigma_protocol::prover::hint::CommitmentHint;
+use ergotree_interpreter::sigma_protocol::prover::hint::Hint;
+use ergotree_interpreter::sigma_protocol::prover::hint::HintsBag;
+use ergotree_interpreter::sigma_protocol::prover::Prover;
+use ergotree_interpreter::sigma_protocol::prover::ProverError;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::chain::ergo_box::ErgoBox;
+use crate::chain::ergo_state_context::ErgoStateContext;
+use crate::chain::transaction::reduced::reduce_transaction;
+use crate::chain::transaction::unsigned::UnsignedTransaction;
+use crate::chain::transaction::Transaction;
+use crate::chain::transaction::UnsignedInput;
+
+use super::signing::TransactionContext;
+use super::signing::TxSigningError;
+
+/// Hints for the transaction inputs (keyed by input index), split into secret hints
+/// (own commitments, not to be shared) and public hints
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(
+        from = "json::TransactionHintsBagJson",
+        into = "json::TransactionHintsBagJson"
+    )
+)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct TransactionHintsBag {
+    secret_hints: HashMap<usize, HintsBag>,
+    public_hints: HashMap<usize, HintsBag>,
+}
+
+impl TransactionHintsBag {
+    /// Bag without hints
+    pub fn empty() -> Self {
+        TransactionHintsBag {
+            secret_hints: HashMap::new(),
+            public_hints: HashMap::new(),
+        }
+    }
+
+    /// Replaces the hints for the given input (by index) with the given hints
+    pub fn replace_hints_for_input(&mut self, index: usize, hints_bag: HintsBag) {
+        let (secret, public) = split_hints(hints_bag);
+        self.secret_hints.insert(index, secret);
+        self.public_hints.insert(index, public);
+    }
+
+    /// Adds the given hints to the hints for the given input (by index)
+    pub fn add_hints_for_input(&mut self, index: usize, hints_bag: HintsBag) {
+        let (secret, public) = split_hints(hints_bag);
+        self.secret_hints
+            .entry(index)
+            .or_insert_with(HintsBag::empty)
+            .add_hints_from_bag(secret);
+        self.public_hints
+            .entry(index)
+            .or_insert_with(HintsBag::empty)
+            .add_hints_from_bag(public);
+    }
+
+    /// Adds all the hints from another bag
+    pub fn add_hints_from_bag(&mut self, other: TransactionHintsBag) {
+        other
+            .secret_hints
+            .into_iter()
+            .chain(other.public_hints)
+            .for_each(|(idx, bag)| self.add_hints_for_input(idx, bag));
+    }
+
+    /// All (secret and public) hints for the given input (by index)
+    pub fn all_hints_for_input(&self, index: usize) -> HintsBag {
+        let mut hints = HintsBag::empty();
+        if let Some(bag) = self.secret_hints.get(&index) {
+            hints.add_hints_from_bag(bag.clone());
+        }
+        if let Some(bag) = self.public_hints.get(&index) {
+            hints.add_hints_from_bag(bag.clone());
+        }
+        hints
+    }
+
+    /// Public hints only (safe to share with the co-signers)
+    pub fn public_hints(&self) -> TransactionHintsBag {
+        TransactionHintsBag {
+            secret_hints: HashMap::new(),
+            public_hints: self.public_hints.clone(),
+        }
+    }
+}
+
+/// Splits hints into secret ones (own commitments) and public ones
+fn split_hints(hints_bag: HintsBag) -> (HintsBag, HintsBag) {
+    let (secret, public): (Vec<Hint>, Vec<Hint>) = hints_bag
+        .hints
+        .into_iter()
+        .partition(|h| matches!(h, Hint::CommitmentHint(CommitmentHint::OwnCommitment(_))));
+    (HintsBag { hints: secret }, HintsBag { hints: public })
+}
+
+/// Generates commitments for all the transaction inputs for the secrets of the given prover.
+/// Secret hints (own commitments) should be kept by the prover, public ones are sent to the
+/// co-signers.
+pub fn generate_commitments(
+    prover: &dyn Prover,
+    tx_context: &TransactionContext,
+    state_context: &ErgoStateContext,
+) -> Result<TransactionHintsBag, TxSigningError> {
+    let public_keys: Vec<SigmaBoolean> = prover
+        .secrets()
+        .iter()
+        .map(|secret| secret.public_image())
+        .collect();
+    let mut bag = TransactionHintsBag::empty();
+    reduce_transaction(tx_context.clone(), state_context)?
+        .reduced_inputs
+        .iter()
+        .enumerate()
+        .for_each(|(idx, reduced_input)| {
+            bag.add_hints_for_input(
+                idx,
+                ergotree_interpreter::sigma_protocol::prover::generate_commitments(
+                    &reduced_input.reduction_result.sigma_prop,
+                    &public_keys,
+                ),
+            )
+        });
+    Ok(bag)
+}
+
+/// Extracts hints from the (partially) signed transaction.
+/// `real_propositions` - public keys of the co-signers who provided real proofs
+/// `simulated_propositions` - public keys for which the proofs were simulated
+pub fn extract_hints(
+    signed_tx: &Transaction,
+    boxes_to_spend: Vec<ErgoBox>,
+    data_boxes: Vec<ErgoBox>,
+    state_context: &ErgoStateContext,
+    real_propositions: &[SigmaBoolean],
+    simulated_propositions: &[SigmaBoolean],
+) -> Result<TransactionHintsBag, TxSigningError> {
+    let unsigned_inputs = signed_tx
+        .inputs
+        .iter()
+        .map(|i| UnsignedInput::new(i.box_id.clone(), i.spending_proof.extension.clone()))
+        .collect();
+    let tx_context = TransactionContext {
+        spending_tx: UnsignedTransaction::new(
+            unsigned_inputs,
+            signed_tx.data_inputs.clone(),
+            signed_tx.output_candidates.clone(),
+        ),
+        boxes_to_spend,
+        data_boxes,
+    };
+    let mut bag = TransactionHintsBag::empty();
+    for (idx, (input, reduced_input)) in signed_tx
+        .inputs
+        .iter()
+        .zip(reduce_transaction(tx_context, state_context)?.reduced_inputs)
+        .enumerate()
+    {
+        let proof: Vec<u8> = input.spending_proof.proof.clone().into();
+        let hints = bag_for_multisig(
+            &reduced_input.reduction_result.sigma_prop,
+            &proof,
+            real_propositions,
+            simulated_propositions,
+        )
+        .map_err(|e| TxSigningError::ProverError(ProverError::Unexpected(e.to_string()), idx))?;
+        bag.add_hints_for_input(idx, hints);
+    }
+    Ok(bag)
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use std::collections::HashMap;
+
+    use ergotree_interpreter::sigma_protocol::prover::hint::Hint;
+    use ergotree_interpreter::sigma_protocol::prover::hint::HintsBag;
+    use serde::{Deserialize, Serialize};
+
+    use super::TransactionHintsBag;
+
+    /// Encoded as in the node's API: hints for every input index
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+    pub(crate) struct TransactionHintsBagJson {
+        #[serde(rename = "secretHints")]
+        secret_hints: HashMap<usize, Vec<Hint>>,
+        #[serde(rename = "publicHints")]
+        public_hints: HashMap<usize, Vec<Hint>>,
+    }
+
+    impl From<TransactionHintsBag> for TransactionHintsBagJson {
+        fn from(bag: TransactionHintsBag) -> Self {
+            let to_json = |m: HashMap<usize, HintsBag>| {
+                m.into_iter()
+                    .map(|(idx, bag)| (idx, bag.hints))
+                    .collect::<HashMap<usize, Vec<Hint>>>()
+            };
+            TransactionHintsBagJson {
+                secret_hints: to_json(bag.secret_hints),
+                public_hints: to_json(bag.public_hints),
+            }
+        }
+    }
+
+    impl From<TransactionHintsBagJson> for TransactionHintsBag {
+        fn from(bag: TransactionHintsBagJson) -> Self {
+            let from_json = |m: HashMap<usize, Vec<Hint>>| {
+                m.into_iter()
+                    .map(|(idx, hints)| (idx, HintsBag { hints }))
+                    .collect::<HashMap<usize, HintsBag>>()
+            };
+            TransactionHintsBag {
+                secret_hints: from_json(bag.secret_hints),
+                public_hints: from_json(bag.public_hints),
+            }
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::ergo_box::box_builder::ErgoBoxCandidateBuilder;
+    use crate::chain::ergo_box::BoxValue;
+    use crate::chain::ergo_box::NonMandatoryRegisters;
+    use crate::chain::transaction::TxId;
+    use crate::wallet::secret_key::SecretKey;
+    use crate::wallet::Wallet;
+    use ergotree_interpreter::eval::context::Context;
+    use ergotree_interpreter::eval::env::Env;
+    use ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
+    use ergotree_interpreter::sigma_protocol::verifier::TestVerifier;
+    use ergotree_interpreter::sigma_protocol::verifier::Verifier;
+    use ergotree_ir::ergo_tree::ErgoTree;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::sigma_and::SigmaAnd;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_multi_sig_tx() {
+        let alice_secret = DlogProverInput::random();
+        let bob_secret = DlogProverInput::random();
+        let pk_alice = alice_secret.public_image();
+        let pk_bob = bob_secret.public_image();
+        let tree: ErgoTree = Expr::from(
+            SigmaAnd::new(vec![
+                Expr::Const(pk_alice.clone().into()),
+                Expr::Const(pk_bob.clone().into()),
+            ])
+            .unwrap(),
+        )
+        .into();
+        let input_box = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        );
+        let candidate = ErgoBoxCandidateBuilder::new(
+            BoxValue::SAFE_USER_MIN,
+            ErgoTree::from(Expr::Const(pk_alice.clone().into())),
+            0,
+        )
+        .build()
+        .unwrap();
+        let tx_context = TransactionContext {
+            spending_tx: UnsignedTransaction::new(
+                vec![UnsignedInput::from(input_box.clone())],
+                vec![],
+                vec![candidate],
+            ),
+            boxes_to_spend: vec![input_box.clone()],
+            data_boxes: vec![],
+        };
+        let state_context = ErgoStateContext::dummy();
+        let alice = Wallet::from_secrets(vec![SecretKey::from(alice_secret)]);
+        let bob = Wallet::from_secrets(vec![SecretKey::from(bob_secret)]);
+
+        // signing fails without the co-signer
+        assert!(alice
+            .sign_transaction(tx_context.clone(), &state_context)
+            .is_err());
+
+        let alice_hints = alice
+            .generate_commitments(tx_context.clone(), &state_context)
+            .unwrap();
+        // pass the public hints around as JSON
+        let alice_public_hints: TransactionHintsBag =
+            serde_json::from_str(&serde_json::to_string(&alice_hints.public_hints()).unwrap())
+                .unwrap();
+        assert!(alice_public_hints
+            .all_hints_for_input(0)
+            .own_commitments()
+            .is_empty());
+
+        let partial_tx = bob
+            .sign_transaction_with_hints(tx_context.clone(), &state_context, &alice_public_hints)
+            .unwrap();
+        let mut bob_hints = extract_hints(
+            &partial_tx,
+            vec![input_box.clone()],
+            vec![],
+            &state_context,
+            &[pk_bob.into()],
+            &[],
+        )
+        .unwrap();
+        bob_hints.add_hints_from_bag(alice_hints);
+        let signed_tx = alice
+            .sign_transaction_with_hints(tx_context, &state_context, &bob_hints)
+            .unwrap();
+
+        let res = TestVerifier
+            .verify(
+                &input_box.ergo_tree,
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>()),
+                signed_tx
+                    .inputs
+                    .first()
+                    .unwrap()
+                    .spending_proof
+                    .proof
+                    .clone(),
+                signed_tx.bytes_to_sign().as_slice(),
+            )
+            .unwrap();
+        assert!(res.result);
+    }
+}
@@ -1,13 +1,20 @@
 //! Secret types
+use ergotree_interpreter::sigma_protocol::private_input::DhTupleProverInput;
 use ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
 use ergotree_interpreter::sigma_protocol::private_input::PrivateInput;
 use ergotree_ir::address::Address;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
 
 /// Types of secrets
 #[derive(PartialEq, Debug, Clone)]
 pub enum SecretKey {
     /// Secret exponent of a group element, i.e. secret w such as h = g^^w, where g is group generator, h is a public key.
     DlogSecretKey(DlogProverInput),
+    /// Diffie-Hellman tuple and secret
+    /// Used in a proof that of equality of discrete logarithms (i.e., a proof of a Diffie-Hellman tuple):
+    /// given group elements g, h, u, v, the proof convinces a verifier that the prover knows `w` such
+    /// that `u = g^w` and `v = h^w`, without revealing `w`
+    DhtSecretKey(DhTupleProverInput),
 }
 
 impl SecretKey {
@@ -16,15 +23,28 @@ impl SecretKey {
         SecretKey::DlogSecretKey(DlogProverInput::random())
     }
 
+    /// Generates random DhTupleProverInput
+    pub fn random_dht() -> SecretKey {
+        SecretKey::DhtSecretKey(DhTupleProverInput::random())
+    }
+
     /// Parse DlogSecretKey from bytes (SEC-1-encoded scalar)
     pub fn dlog_from_bytes(bytes: &[u8; DlogProverInput::SIZE_BYTES]) -> Option<SecretKey> {
         DlogProverInput::from_bytes(bytes).map(SecretKey::DlogSecretKey)
     }
 
+    /// Parse DhtSecretKey from bytes (SEC-1-encoded scalar followed by the tuple points)
+    pub fn dht_from_bytes(bytes: &[u8; DhTupleProverInput::SIZE_BYTES]) -> Option<SecretKey> {
+        DhTupleProverInput::from_bytes(bytes).map(SecretKey::DhtSecretKey)
+    }
+
     /// Address (encoded public image)
     pub fn get_address_from_public_image(&self) -> Address {
         match self {
             SecretKey::DlogSecretKey(dpi) => Address::P2Pk(dpi.public_image()),
+            SecretKey::DhtSecretKey(dht) => {
+                Address::P2S(SigmaProp::from(dht.public_image().clone()).prop_bytes())
+            }
         }
     }
 
@@ -32,6 +52,7 @@ impl SecretKey {
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             SecretKey::DlogSecretKey(key) => key.to_bytes().to_vec(),
+            SecretKey::DhtSecretKey(key) => key.to_bytes().to_vec(),
         }
     }
 }
@@ -40,6 +61,7 @@ impl From<SecretKey> for PrivateInput {
     fn from(s: SecretKey) -> Self {
         match s {
             SecretKey::DlogSecretKey(dpi) => PrivateInput::DlogProverInput(dpi),
+            SecretKey::DhtSecretKey(dht) => PrivateInput::DhTupleProverInput(dht),
         }
     }
 }
@@ -50,6 +72,12 @@ impl From<DlogProverInput> for SecretKey {
     }
 }
 
+impl From<DhTupleProverInput> for SecretKey {
+    fn from(pi: DhTupleProverInput) -> Self {
+        SecretKey::DhtSecretKey(pi)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,4 +90,12 @@ mod tests {
             SecretKey::dlog_from_bytes(&sk.to_bytes().as_slice().try_into().unwrap()).unwrap();
         assert_eq!(sk, sk_copy);
     }
+
+    #[test]
+    fn dht_roundtrip() {
+        let sk = SecretKey::random_dht();
+        let sk_copy =
+            SecretKey::dht_from_bytes(&sk.to_bytes().as_slice().try_into().unwrap()).unwrap();
+        assert_eq!(sk, sk_copy);
+    }
 }
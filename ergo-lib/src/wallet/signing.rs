@@ -5,17 +5,20 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::chain::ergo_box::BoxId;
+use crate::chain::transaction::input::prover_result::ProverResult;
+use crate::chain::transaction::reduced::ReducedTransaction;
 use crate::chain::transaction::Input;
 use crate::chain::{
     ergo_box::ErgoBox,
     ergo_state_context::ErgoStateContext,
     transaction::{unsigned::UnsignedTransaction, Transaction},
 };
+use crate::wallet::multi_sig::TransactionHintsBag;
 
 use ergotree_interpreter::eval::context::Context;
 use ergotree_interpreter::eval::env::Env;
+use ergotree_interpreter::sigma_protocol::prover::Prover;
 use ergotree_interpreter::sigma_protocol::prover::ProverError;
-use ergotree_interpreter::sigma_protocol::prover::{ContextExtension, Prover};
 use ergotree_ir::ir_ergo_box::IrBoxId;
 use ergotree_ir::ir_ergo_box::IrErgoBox;
 use ergotree_ir::ir_ergo_box::IrErgoBoxArena;
@@ -34,6 +37,9 @@ pub enum TxSigningError {
     /// Context creation error
     #[error("Context error: {0}")]
     ContextError(String),
+    /// reduced inputs count does not match the transaction inputs count
+    #[error("Reduced inputs count {1} does not match tx inputs count {0}")]
+    ReducedInputsCountMismatch(usize, usize),
 }
 
 /// Transaction and an additional info required for signing
@@ -84,18 +90,25 @@ impl IrErgoBoxArena for ErgoBoxArena {
     }
 }
 
-/// `self_index` - index of the SELF box in the tx_ctx.boxes_to_spend
+/// `self_index` - index of the SELF box input in the tx_ctx.spending_tx.inputs
+/// (the box itself is looked up in tx_ctx.boxes_to_spend by its id). The context extension is
+/// taken from the corresponding unsigned input.
 pub fn make_context(
     state_ctx: &ErgoStateContext,
     tx_ctx: &TransactionContext,
     self_index: usize,
 ) -> Result<Context, TxSigningError> {
     let height = state_ctx.pre_header.height;
+    let self_input =
+        tx_ctx.spending_tx.inputs.get(self_index).ok_or_else(|| {
+            TxSigningError::ContextError("self_index is out of bounds".to_string())
+        })?;
     let self_box = tx_ctx
         .boxes_to_spend
-        .get(self_index)
+        .iter()
+        .find(|b| b.box_id() == self_input.box_id)
         .cloned()
-        .ok_or_else(|| TxSigningError::ContextError("self_index is out of bounds".to_string()))?;
+        .ok_or(TxSigningError::InputBoxNotFound(self_index))?;
     let outputs: Vec<ErgoBox> = tx_ctx
         .spending_tx
         .output_candidates
@@ -107,9 +120,10 @@ pub fn make_context(
     let self_box_ir = self_box.box_id().into();
     let outputs_ir = outputs.iter().map(|b| b.box_id().into()).collect();
     let inputs_ir = tx_ctx
-        .boxes_to_spend
+        .spending_tx
+        .inputs
         .iter()
-        .map(|b| b.box_id().into())
+        .map(|i| i.box_id.clone().into())
         .collect();
     let data_inputs_ir = data_inputs.iter().map(|b| b.box_id().into()).collect();
     let box_arena = Rc::new(ErgoBoxArena::new(
@@ -126,7 +140,9 @@ pub fn make_context(
         data_inputs: data_inputs_ir,
         inputs: inputs_ir,
         pre_header: state_ctx.pre_header.clone(),
-        extension: ContextExtension::empty(),
+        headers: state_ctx.headers.clone(),
+        extension: self_input.extension.clone(),
+        cost_limit: None,
     })
 }
 
@@ -135,6 +151,23 @@ pub fn sign_transaction(
     prover: &dyn Prover,
     tx_context: TransactionContext,
     state_context: &ErgoStateContext,
+) -> Result<Transaction, TxSigningError> {
+    sign_transaction_with_hints(
+        prover,
+        tx_context,
+        state_context,
+        &TransactionHintsBag::empty(),
+    )
+}
+
+/// Signs a transaction (generating proofs for inputs) using the given hints for the inputs
+/// (e.g. commitments and partial proofs of the co-signers in the multi-signature workflow, see
+/// [`crate::wallet::multi_sig`])
+pub fn sign_transaction_with_hints(
+    prover: &dyn Prover,
+    tx_context: TransactionContext,
+    state_context: &ErgoStateContext,
+    tx_hints: &TransactionHintsBag,
 ) -> Result<Transaction, TxSigningError> {
     let tx = tx_context.spending_tx.clone();
     let message_to_sign = tx.bytes_to_sign();
@@ -146,17 +179,21 @@ pub fn sign_transaction(
             .find(|b| b.box_id() == input.box_id)
         {
             let ctx = Rc::new(make_context(state_context, &tx_context, idx)?);
+            let hints_bag = tx_hints.all_hints_for_input(idx);
             prover
                 .prove(
                     &input_box.ergo_tree,
                     &Env::empty(),
                     ctx,
                     message_to_sign.as_slice(),
-                    &HintsBag::empty(),
+                    &hints_bag,
                 )
                 .map(|proof| {
-                    let input = Input::new(input.box_id.clone(), proof.into());
-                    signed_inputs.push(input);
+                    let spending_proof = ProverResult {
+                        proof: proof.proof,
+                        extension: input.extension.clone(),
+                    };
+                    signed_inputs.push(Input::new(input.box_id.clone(), spending_proof));
                 })
                 .map_err(|e| TxSigningError::ProverError(e, idx))
         } else {
@@ -170,6 +207,53 @@ pub fn sign_transaction(
     ))
 }
 
+/// Signs a reduced transaction (generating proofs for inputs).
+/// Does not need the boxes or the chain context, only the secrets (see [`ReducedTransaction`])
+pub fn sign_reduced_transaction(
+    prover: &dyn Prover,
+    reduced_tx: ReducedTransaction,
+    tx_hints: Option<&TransactionHintsBag>,
+) -> Result<Transaction, TxSigningError> {
+    let tx = reduced_tx.unsigned_tx;
+    if tx.inputs.len() != reduced_tx.reduced_inputs.len() {
+        return Err(TxSigningError::ReducedInputsCountMismatch(
+            tx.inputs.len(),
+            reduced_tx.reduced_inputs.len(),
+        ));
+    }
+    let message_to_sign = tx.bytes_to_sign();
+    let signed_inputs = tx
+        .inputs
+        .iter()
+        .zip(reduced_tx.reduced_inputs)
+        .enumerate()
+        .map(|(idx, (input, reduced_input))| {
+            let hints_bag = tx_hints
+                .map(|h| h.all_hints_for_input(idx))
+                .unwrap_or_else(HintsBag::empty);
+            prover
+                .generate_proof(
+                    reduced_input.reduction_result.sigma_prop,
+                    message_to_sign.as_slice(),
+                    &hints_bag,
+                )
+                .map(|proof| {
+                    let spending_proof = ProverResult {
+                        proof: proof.proof,
+                        extension: input.extension.clone(),
+                    };
+                    Input::new(input.box_id.clone(), spending_proof)
+                })
+                .map_err(|e| TxSigningError::ProverError(e, idx))
+        })
+        .collect::<Result<Vec<Input>, TxSigningError>>()?;
+    Ok(Transaction::new(
+        signed_inputs,
+        tx.data_inputs,
+        tx.output_candidates,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,12 +271,14 @@ mod tests {
     use rand::thread_rng;
     use sigma_test_util::force_any_val;
 
+    use crate::chain::transaction::reduced::reduce_transaction;
     use crate::chain::{
         ergo_box::{box_builder::ErgoBoxCandidateBuilder, BoxValue, NonMandatoryRegisters},
         transaction::{TxId, UnsignedInput},
     };
     use ergotree_ir::ergo_tree::ErgoTree;
     use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::serialization::SigmaSerializable;
     use std::rc::Rc;
 
     fn verify_tx_proofs(
@@ -253,6 +339,117 @@ mod tests {
             let signed_tx = res.unwrap();
             prop_assert!(verify_tx_proofs(&signed_tx, &boxes_to_spend).unwrap());
         }
+
+        #[test]
+        fn test_reduced_tx_signing(secrets in vec(any::<DlogProverInput>(), 1..5)) {
+            let boxes_to_spend: Vec<ErgoBox> = secrets.iter().map(|secret|{
+                let tree = ErgoTree::from(Expr::Const(secret.public_image().into()));
+                ErgoBox::new(BoxValue::SAFE_USER_MIN,
+                             tree,
+                             vec![],
+                             NonMandatoryRegisters::empty(),
+                             0,
+                             TxId::zero(),
+                             0)
+            }).collect();
+            let prover = TestProver {
+                secrets: secrets.clone().into_iter().map(PrivateInput::DlogProverInput).collect(),
+            };
+            let inputs: Vec<UnsignedInput> = boxes_to_spend.clone().into_iter().map(UnsignedInput::from).collect();
+            let ergo_tree = ErgoTree::from(Expr::Const(secrets.first().unwrap().public_image().into()));
+            let candidate = ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, ergo_tree, 0)
+                .build().unwrap();
+            let tx = UnsignedTransaction::new(inputs, vec![], vec![candidate]);
+            let tx_context = TransactionContext { spending_tx: tx,
+                                                  boxes_to_spend: boxes_to_spend.clone(), data_boxes: vec![] };
+            let reduced_tx = reduce_transaction(tx_context, &ErgoStateContext::dummy()).unwrap();
+            // transfer to the "offline" signer
+            let reduced_tx_parsed = ReducedTransaction::sigma_parse_bytes(&reduced_tx.sigma_serialize_bytes()).unwrap();
+            prop_assert_eq!(&reduced_tx_parsed, &reduced_tx);
+            let mut reduced_tx_missing_input = reduced_tx.clone();
+            reduced_tx_missing_input.reduced_inputs.pop();
+            prop_assert_eq!(
+                sign_reduced_transaction(&prover, reduced_tx_missing_input, None),
+                Err(TxSigningError::ReducedInputsCountMismatch(secrets.len(), secrets.len() - 1))
+            );
+            let signed_tx = sign_reduced_transaction(&prover, reduced_tx_parsed, None).unwrap();
+            prop_assert!(verify_tx_proofs(&signed_tx, &boxes_to_spend).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_tx_signing_with_context_extension() {
+        use ergotree_interpreter::sigma_protocol::prover::ContextExtension;
+        use ergotree_ir::mir::get_var::GetVar;
+        use ergotree_ir::mir::option_get::OptionGet;
+        use ergotree_ir::mir::unary_op::UnaryOpTryBuild;
+        use ergotree_ir::types::stype::SType;
+
+        let secret = force_any_val::<DlogProverInput>();
+        // spendable only with a public key (of the signer) provided in the context extension
+        let tree = ErgoTree::from(Expr::from(
+            OptionGet::try_build(
+                GetVar {
+                    var_id: 0,
+                    var_tpe: SType::SSigmaProp,
+                }
+                .into(),
+            )
+            .unwrap(),
+        ));
+        let input_box = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        );
+        let mut extension = ContextExtension::empty();
+        extension.values.insert(0, secret.public_image().into());
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        let candidate =
+            ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, force_any_val::<ErgoTree>(), 0)
+                .build()
+                .unwrap();
+        let make_tx_context = |extension: ContextExtension| TransactionContext {
+            spending_tx: UnsignedTransaction::new(
+                vec![UnsignedInput::new(input_box.box_id(), extension)],
+                vec![],
+                vec![candidate.clone()],
+            ),
+            boxes_to_spend: vec![input_box.clone()],
+            data_boxes: vec![],
+        };
+        assert!(sign_transaction(
+            &prover,
+            make_tx_context(ContextExtension::empty()),
+            &ErgoStateContext::dummy()
+        )
+        .is_err());
+        let signed_tx = sign_transaction(
+            &prover,
+            make_tx_context(extension.clone()),
+            &ErgoStateContext::dummy(),
+        )
+        .unwrap();
+        let spending_proof = &signed_tx.inputs.first().unwrap().spending_proof;
+        assert_eq!(spending_proof.extension, extension);
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension = spending_proof.extension.clone();
+        let res = TestVerifier
+            .verify(
+                &input_box.ergo_tree,
+                &Env::empty(),
+                Rc::new(ctx),
+                spending_proof.proof.clone(),
+                &signed_tx.bytes_to_sign(),
+            )
+            .unwrap();
+        assert!(res.result);
     }
 
     #[test]
@@ -1,8 +1,10 @@
 //! Builder for an UnsignedTransaction
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use ergotree_interpreter::sigma_protocol;
+use ergotree_interpreter::sigma_protocol::prover::ContextExtension;
 use ergotree_interpreter::sigma_protocol::prover::ProofBytes;
 use ergotree_ir::address::{Address, AddressEncoder, NetworkPrefix};
 use ergotree_ir::serialization::SerializationError;
@@ -35,6 +37,7 @@ pub struct TxBuilder<S: ErgoBoxAssets> {
     fee_amount: BoxValue,
     change_address: Address,
     min_change_value: BoxValue,
+    context_extensions: HashMap<BoxId, ContextExtension>,
 }
 
 impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
@@ -62,6 +65,7 @@ impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
             fee_amount,
             change_address,
             min_change_value,
+            context_extensions: HashMap::new(),
         }
     }
 
@@ -105,6 +109,17 @@ impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
         self.data_inputs = data_inputs;
     }
 
+    /// Get context extension for the given input box (if set)
+    pub fn context_extension(&self, input_id: &BoxId) -> Option<ContextExtension> {
+        self.context_extensions.get(input_id).cloned()
+    }
+
+    /// Set context extension (user-defined variables available via `getVar` in the box's
+    /// script) for the input box with the given id
+    pub fn set_context_extension(&mut self, input_id: BoxId, context_extension: ContextExtension) {
+        self.context_extensions.insert(input_id, context_extension);
+    }
+
     /// Estimated serialized transaction size in bytes after signing (assuming P2PK box spending)
     pub fn estimate_tx_size_bytes(&self) -> Result<usize, TxBuilderError> {
         let tx = self.build_tx()?;
@@ -149,6 +164,16 @@ impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
                 "duplicate inputs found".to_string(),
             ));
         }
+        if let Some(id) = self
+            .context_extensions
+            .keys()
+            .find(|id| !self.box_selection.boxes.iter().any(|b| &b.box_id() == *id))
+        {
+            return Err(TxBuilderError::InvalidArgs(format!(
+                "context extension is set for the box {:?} which is not an input",
+                id
+            )));
+        }
         if self.data_inputs.len() > u16::MAX as usize {
             return Err(TxBuilderError::InvalidArgs(
                 "too many data inputs".to_string(),
@@ -226,7 +251,15 @@ impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
                 .boxes
                 .clone()
                 .into_iter()
-                .map(UnsignedInput::from)
+                .map(|b| {
+                    let box_id = b.box_id();
+                    let extension = self
+                        .context_extensions
+                        .get(&box_id)
+                        .cloned()
+                        .unwrap_or_else(ContextExtension::empty);
+                    UnsignedInput::new(box_id, extension)
+                })
                 .collect(),
             self.data_inputs.clone(),
             output_candidates,
@@ -516,6 +549,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_context_extension() {
+        let inputs = vec![
+            force_any_val_with::<ErgoBox>(
+                (BoxValue::MIN_RAW * 5000..BoxValue::MIN_RAW * 10000).into(),
+            ),
+            force_any_val_with::<ErgoBox>(
+                (BoxValue::MIN_RAW * 5000..BoxValue::MIN_RAW * 10000).into(),
+            ),
+        ];
+        let box_selection = BoxSelection {
+            boxes: inputs.clone(),
+            change_boxes: vec![],
+        };
+        let out_box =
+            ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, force_any_val::<ErgoTree>(), 0)
+                .build()
+                .unwrap();
+        let mut tx_builder = TxBuilder::new(
+            box_selection,
+            vec![out_box],
+            0,
+            BoxValue::SAFE_USER_MIN,
+            force_any_val::<Address>(),
+            BoxValue::SAFE_USER_MIN,
+        );
+        let extension = force_any_val::<ContextExtension>();
+        tx_builder.set_context_extension(inputs[1].box_id(), extension.clone());
+        assert_eq!(
+            tx_builder.context_extension(&inputs[1].box_id()),
+            Some(extension.clone())
+        );
+        let tx = tx_builder.clone().build().unwrap();
+        assert_eq!(tx.inputs[0].extension, ContextExtension::empty());
+        assert_eq!(tx.inputs[1].extension, extension);
+
+        // extension for the box which is not an input
+        tx_builder.set_context_extension(force_any_val::<ErgoBox>().box_id(), extension);
+        assert!(tx_builder.build().is_err());
+    }
+
     #[test]
     fn test_est_tx_size() {
         let input = ErgoBox::new(
@@ -0,0 +1,94 @@
+//! Authenticated AVL+ tree (authenticated dictionary), see https://eprint.iacr.org/2016/994
+//! The tree is committed by its digest (root label and height) and the operations on it are
+//! checked with [`batch_verifier::BatchAvlVerifier`] against the proof generated by the holder of
+//! the full tree (reference implementation is `BatchAVLProver` from scorex-util)
+
+use thiserror::Error;
+
+#[cfg(test)]
+pub(crate) mod batch_prover;
+pub mod batch_verifier;
+mod node;
+mod tree_ops;
+
+/// Key in the tree
+pub type ADKey = Vec<u8>;
+/// Value in the tree
+pub type ADValue = Vec<u8>;
+
+/// Label of the node (Blake2b256 hash)
+pub(crate) const LABEL_LENGTH: usize = 32;
+
+/// Packaged proof markers
+pub(crate) const LABEL_IN_PACKAGED_PROOF: u8 = 2;
+pub(crate) const LEAF_IN_PACKAGED_PROOF: u8 = 3;
+pub(crate) const END_OF_TREE_IN_PACKAGED_PROOF: u8 = 4;
+
+/// Errors of the AVL+ tree operations
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum AvlTreeError {
+    /// Proof is malformed or does not match the tree digest
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+    /// Operation cannot be performed on the tree (key not found, already exists, etc.)
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+}
+
+/// Operation on the tree
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Operation {
+    /// Look up the value of the key
+    Lookup(ADKey),
+    /// Insert a new key (fails if the key already exists)
+    Insert {
+        /// key to insert
+        key: ADKey,
+        /// value of the key
+        value: ADValue,
+    },
+    /// Update the value of the existing key (fails if the key does not exist)
+    Update {
+        /// key to update
+        key: ADKey,
+        /// new value of the key
+        value: ADValue,
+    },
+    /// Remove the existing key (fails if the key does not exist)
+    Remove(ADKey),
+}
+
+impl Operation {
+    /// Key of the operation
+    pub fn key(&self) -> &[u8] {
+        match self {
+            Operation::Lookup(key) => key,
+            Operation::Insert { key, .. } => key,
+            Operation::Update { key, .. } => key,
+            Operation::Remove(key) => key,
+        }
+    }
+
+    /// New value for the key (`None` to remove it) given the current value of the key (if any)
+    /// Should not be called for [`Operation::Lookup`], which does not modify the tree
+    pub(crate) fn update_fn(
+        &self,
+        old_value: Option<&ADValue>,
+    ) -> Result<Option<ADValue>, AvlTreeError> {
+        match (self, old_value) {
+            (Operation::Insert { value, .. }, None) => Ok(Some(value.clone())),
+            (Operation::Insert { key, .. }, Some(_)) => Err(AvlTreeError::InvalidOperation(
+                format!("key {} already exists", base16::encode_lower(key)),
+            )),
+            (Operation::Update { value, .. }, Some(_)) => Ok(Some(value.clone())),
+            (Operation::Remove(_), Some(_)) => Ok(None),
+            (Operation::Update { key, .. }, None) | (Operation::Remove(key), None) => {
+                Err(AvlTreeError::InvalidOperation(format!(
+                    "key {} not found",
+                    base16::encode_lower(key)
+                )))
+            }
+            (Operation::Lookup(_), old_value) => Ok(old_value.cloned()),
+        }
+    }
+}
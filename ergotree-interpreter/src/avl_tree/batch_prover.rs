@@ -0,0 +1,217 @@
+//! Prover for the batch of operations on the AVL+ tree (holds the full tree).
+//! Generates the proofs in the format of `BatchAVLProver` of scorex-util.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use super::node::InternalNode;
+use super::node::LeafNode;
+use super::node::Node;
+use super::tree_ops::AuthenticatedTreeOps;
+use super::ADValue;
+use super::AvlTreeError;
+use super::Operation;
+use super::END_OF_TREE_IN_PACKAGED_PROOF;
+use super::LABEL_IN_PACKAGED_PROOF;
+use super::LEAF_IN_PACKAGED_PROOF;
+
+pub(crate) struct BatchAvlProver {
+    key_length: usize,
+    value_length: Option<usize>,
+    top_node: Node,
+    root_node_height: usize,
+    /// Tree at the beginning of the batch (packaged into the proof)
+    old_top_node: Node,
+    /// Nodes of the old tree visited by the operations in the batch
+    visited: HashSet<*const ()>,
+    directions: Vec<u8>,
+    directions_bit_length: usize,
+    found: bool,
+    last_right_step: usize,
+    replay_index: usize,
+}
+
+impl BatchAvlProver {
+    /// Creates the empty tree (with the single -inf leaf)
+    pub fn new(key_length: usize, value_length: Option<usize>) -> Self {
+        let root = Node::leaf(
+            vec![0; key_length],
+            vec![0; value_length.unwrap_or(0)],
+            vec![0xff; key_length],
+        );
+        BatchAvlProver {
+            key_length,
+            value_length,
+            top_node: root.clone(),
+            root_node_height: 0,
+            old_top_node: root,
+            visited: HashSet::new(),
+            directions: Vec::new(),
+            directions_bit_length: 0,
+            found: false,
+            last_right_step: 0,
+            replay_index: 0,
+        }
+    }
+
+    pub fn perform_one_operation(
+        &mut self,
+        operation: &Operation,
+    ) -> Result<Option<ADValue>, AvlTreeError> {
+        self.replay_index = self.directions_bit_length;
+        self.found = false;
+        let directions_bit_length = self.directions_bit_length;
+        let top_node = self.top_node.clone();
+        match self.return_result_of_one_operation(operation, &top_node, self.root_node_height) {
+            Ok((new_top_node, new_height, old_value)) => {
+                self.top_node = new_top_node;
+                self.root_node_height = new_height;
+                Ok(old_value)
+            }
+            Err(e) => {
+                // revert the directions of the failed operation
+                self.directions_bit_length = directions_bit_length;
+                self.directions.truncate((directions_bit_length + 7) >> 3);
+                if directions_bit_length & 7 != 0 {
+                    if let Some(last) = self.directions.last_mut() {
+                        *last &= (1u8 << (directions_bit_length & 7)) - 1;
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+
+    pub fn digest(&self) -> Vec<u8> {
+        let mut digest = self.top_node.label().to_vec();
+        digest.push(self.root_node_height as u8);
+        digest
+    }
+
+    /// Proof for the operations performed since the previous proof generation
+    pub fn generate_proof(&mut self) -> Vec<u8> {
+        let mut packaged_tree = Vec::new();
+        let mut previous_leaf_available = false;
+        self.pack_tree(
+            &self.old_top_node,
+            &mut packaged_tree,
+            &mut previous_leaf_available,
+        );
+        packaged_tree.push(END_OF_TREE_IN_PACKAGED_PROOF);
+        packaged_tree.extend_from_slice(&self.directions);
+        self.old_top_node = self.top_node.clone();
+        self.visited.clear();
+        self.directions.clear();
+        self.directions_bit_length = 0;
+        packaged_tree
+    }
+
+    fn pack_tree(&self, r_node: &Node, packaged_tree: &mut Vec<u8>, previous_leaf: &mut bool) {
+        let visited = r_node
+            .ptr()
+            .map(|p| self.visited.contains(&p))
+            .unwrap_or(false);
+        match r_node {
+            Node::Leaf(r) if visited => {
+                packaged_tree.push(LEAF_IN_PACKAGED_PROOF);
+                if !*previous_leaf {
+                    packaged_tree.extend_from_slice(&r.key);
+                }
+                packaged_tree.extend_from_slice(&r.next_leaf_key);
+                if self.value_length.is_none() {
+                    packaged_tree.extend_from_slice(&(r.value.len() as i32).to_be_bytes());
+                }
+                packaged_tree.extend_from_slice(&r.value);
+                *previous_leaf = true;
+            }
+            Node::Internal(r) if visited => {
+                self.pack_tree(&r.left, packaged_tree, previous_leaf);
+                self.pack_tree(&r.right, packaged_tree, previous_leaf);
+                packaged_tree.push(r.balance as u8);
+            }
+            _ => {
+                packaged_tree.push(LABEL_IN_PACKAGED_PROOF);
+                packaged_tree.extend_from_slice(&r_node.label());
+                *previous_leaf = false;
+            }
+        }
+    }
+
+    fn add_direction(&mut self, is_left: bool) {
+        if self.directions_bit_length & 7 == 0 {
+            self.directions.push(0);
+        }
+        if is_left {
+            if let Some(last) = self.directions.last_mut() {
+                *last |= 1 << (self.directions_bit_length & 7);
+            }
+        }
+        self.directions_bit_length += 1;
+    }
+}
+
+impl AuthenticatedTreeOps for BatchAvlProver {
+    fn key_length(&self) -> usize {
+        self.key_length
+    }
+
+    fn value_length(&self) -> Option<usize> {
+        self.value_length
+    }
+
+    fn next_direction_is_left(
+        &mut self,
+        key: &[u8],
+        r: &InternalNode,
+    ) -> Result<bool, AvlTreeError> {
+        let is_left = if self.found {
+            // the key is the leftmost leaf of the right subtree of the node where it was found
+            true
+        } else {
+            let r_key = r.key.as_ref().ok_or_else(|| {
+                AvlTreeError::InvalidProof("internal node key is unknown".to_string())
+            })?;
+            match key.cmp(r_key) {
+                Ordering::Equal => {
+                    self.found = true;
+                    self.last_right_step = self.directions_bit_length;
+                    false
+                }
+                Ordering::Less => true,
+                Ordering::Greater => false,
+            }
+        };
+        self.add_direction(is_left);
+        Ok(is_left)
+    }
+
+    fn key_matches_leaf(&mut self, _key: &[u8], _r: &LeafNode) -> Result<bool, AvlTreeError> {
+        // the key was seen on the way down to the leaf iff the leaf matches it
+        let found = self.found;
+        self.found = false;
+        Ok(found)
+    }
+
+    fn replay_comparison(&mut self) -> Result<Ordering, AvlTreeError> {
+        let bit = self
+            .directions
+            .get(self.replay_index >> 3)
+            .map(|byte| byte & (1 << (self.replay_index & 7)) != 0)
+            .unwrap_or(false);
+        let ret = if self.replay_index == self.last_right_step {
+            Ordering::Equal
+        } else if !bit {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        self.replay_index += 1;
+        Ok(ret)
+    }
+
+    fn on_node_visit(&mut self, node: &Node) {
+        if let Some(p) = node.ptr() {
+            self.visited.insert(p);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! Verifier of the batch of operations on the AVL+ tree
+
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+use super::node::InternalNode;
+use super::node::Label;
+use super::node::LeafNode;
+use super::node::Node;
+use super::tree_ops::AuthenticatedTreeOps;
+use super::ADValue;
+use super::AvlTreeError;
+use super::Operation;
+use super::END_OF_TREE_IN_PACKAGED_PROOF;
+use super::LABEL_IN_PACKAGED_PROOF;
+use super::LABEL_LENGTH;
+use super::LEAF_IN_PACKAGED_PROOF;
+
+/// Verifies the operations on the AVL+ tree (given by its digest) against the proof generated
+/// by the prover for the same operations in the same order. Reconstructs the part of the tree
+/// included in the proof, so that the digest of the modified tree can be obtained.
+#[derive(Debug)]
+pub struct BatchAvlVerifier {
+    proof: Vec<u8>,
+    key_length: usize,
+    value_length: Option<usize>,
+    /// `None` after the failed operation
+    top_node: Option<Node>,
+    root_node_height: usize,
+    directions_index: usize,
+    last_right_step: usize,
+    replay_index: usize,
+}
+
+impl BatchAvlVerifier {
+    /// Digest size (root label followed by the tree height)
+    pub const DIGEST_SIZE: usize = LABEL_LENGTH + 1;
+
+    /// Reconstructs the tree from the proof and checks it against the `starting_digest`
+    /// `key_length` - length of the keys in the tree,
+    /// `value_length` - length of the values in the tree (if all of them are of the same length)
+    pub fn new(
+        starting_digest: &[u8],
+        proof: &[u8],
+        key_length: usize,
+        value_length: Option<usize>,
+    ) -> Result<BatchAvlVerifier, AvlTreeError> {
+        if starting_digest.len() != Self::DIGEST_SIZE {
+            return Err(AvlTreeError::InvalidProof(format!(
+                "expected digest of {0} bytes, got {1} bytes",
+                Self::DIGEST_SIZE,
+                starting_digest.len()
+            )));
+        }
+        if key_length == 0 {
+            return Err(AvlTreeError::InvalidOperation(
+                "key length should be positive".to_string(),
+            ));
+        }
+        let mut verifier = BatchAvlVerifier {
+            proof: proof.to_vec(),
+            key_length,
+            value_length,
+            top_node: None,
+            root_node_height: starting_digest[LABEL_LENGTH] as usize,
+            directions_index: 0,
+            last_right_step: 0,
+            replay_index: 0,
+        };
+        let (root, tree_end) = verifier.reconstruct_tree()?;
+        if root.label()[..] != starting_digest[..LABEL_LENGTH] {
+            return Err(AvlTreeError::InvalidProof(
+                "proof does not match the digest".to_string(),
+            ));
+        }
+        // directions start right after the packed tree
+        verifier.directions_index = (tree_end + 1) * 8;
+        verifier.top_node = Some(root);
+        Ok(verifier)
+    }
+
+    /// Performs the operation (all the operations in the proof in the same order they were
+    /// performed by the prover), returns the value of the key before the operation.
+    /// After the failed operation the verifier cannot be used anymore.
+    pub fn perform_one_operation(
+        &mut self,
+        operation: &Operation,
+    ) -> Result<Option<ADValue>, AvlTreeError> {
+        self.replay_index = self.directions_index;
+        let top_node = self.top_node.take().ok_or_else(|| {
+            AvlTreeError::InvalidProof("previous operation has failed".to_string())
+        })?;
+        let (new_top_node, new_height, old_value) =
+            self.return_result_of_one_operation(operation, &top_node, self.root_node_height)?;
+        self.top_node = Some(new_top_node);
+        self.root_node_height = new_height;
+        Ok(old_value)
+    }
+
+    /// Digest of the current tree (`None` if any operation has failed)
+    pub fn digest(&self) -> Option<Vec<u8>> {
+        self.top_node.as_ref().map(|root| {
+            let mut digest = root.label().to_vec();
+            digest.push(self.root_node_height as u8);
+            digest
+        })
+    }
+
+    /// Current tree height
+    pub fn tree_height(&self) -> usize {
+        self.root_node_height
+    }
+
+    fn reconstruct_tree(&self) -> Result<(Node, usize), AvlTreeError> {
+        let mut stack: Vec<Node> = Vec::new();
+        let mut i = 0;
+        let mut previous_leaf_next_key: Option<Vec<u8>> = None;
+        loop {
+            let n = *self.proof.get(i).ok_or_else(proof_too_short)?;
+            if n == END_OF_TREE_IN_PACKAGED_PROOF {
+                break;
+            }
+            i += 1;
+            match n {
+                LABEL_IN_PACKAGED_PROOF => {
+                    let label: Label =
+                        self.proof_slice(i, LABEL_LENGTH)?.try_into().map_err(|_| {
+                            AvlTreeError::InvalidProof("invalid label length".to_string())
+                        })?;
+                    i += LABEL_LENGTH;
+                    stack.push(Node::LabelOnly(label));
+                    previous_leaf_next_key = None;
+                }
+                LEAF_IN_PACKAGED_PROOF => {
+                    let key = match previous_leaf_next_key.take() {
+                        Some(key) => key,
+                        None => {
+                            let key = self.proof_slice(i, self.key_length)?.to_vec();
+                            i += self.key_length;
+                            key
+                        }
+                    };
+                    let next_leaf_key = self.proof_slice(i, self.key_length)?.to_vec();
+                    i += self.key_length;
+                    let value_length = match self.value_length {
+                        Some(value_length) => value_length,
+                        None => {
+                            let bytes: [u8; 4] =
+                                self.proof_slice(i, 4)?.try_into().map_err(|_| {
+                                    AvlTreeError::InvalidProof("invalid value length".to_string())
+                                })?;
+                            i += 4;
+                            let value_length = i32::from_be_bytes(bytes);
+                            if value_length < 0 {
+                                return Err(AvlTreeError::InvalidProof(
+                                    "negative value length".to_string(),
+                                ));
+                            }
+                            value_length as usize
+                        }
+                    };
+                    let value = self.proof_slice(i, value_length)?.to_vec();
+                    i += value_length;
+                    previous_leaf_next_key = Some(next_leaf_key.clone());
+                    stack.push(Node::leaf(key, value, next_leaf_key));
+                }
+                balance => {
+                    let balance = balance as i8;
+                    if !(-1..=1).contains(&balance) {
+                        return Err(AvlTreeError::InvalidProof(format!(
+                            "invalid node balance {}",
+                            balance
+                        )));
+                    }
+                    let right = stack.pop().ok_or_else(malformed_tree)?;
+                    let left = stack.pop().ok_or_else(malformed_tree)?;
+                    stack.push(Node::internal(None, left, right, balance));
+                }
+            }
+        }
+        let root = stack.pop().ok_or_else(malformed_tree)?;
+        if !stack.is_empty() {
+            return Err(malformed_tree());
+        }
+        Ok((root, i))
+    }
+
+    fn proof_slice(&self, start: usize, len: usize) -> Result<&[u8], AvlTreeError> {
+        start
+            .checked_add(len)
+            .and_then(|end| self.proof.get(start..end))
+            .ok_or_else(proof_too_short)
+    }
+
+    fn direction_bit(&self, index: usize) -> Result<bool, AvlTreeError> {
+        let byte = self.proof.get(index >> 3).ok_or_else(proof_too_short)?;
+        Ok(byte & (1 << (index & 7)) != 0)
+    }
+}
+
+fn proof_too_short() -> AvlTreeError {
+    AvlTreeError::InvalidProof("proof is too short".to_string())
+}
+
+fn malformed_tree() -> AvlTreeError {
+    AvlTreeError::InvalidProof("malformed tree in the proof".to_string())
+}
+
+impl AuthenticatedTreeOps for BatchAvlVerifier {
+    fn key_length(&self) -> usize {
+        self.key_length
+    }
+
+    fn value_length(&self) -> Option<usize> {
+        self.value_length
+    }
+
+    fn next_direction_is_left(
+        &mut self,
+        _key: &[u8],
+        _r: &InternalNode,
+    ) -> Result<bool, AvlTreeError> {
+        let is_left = self.direction_bit(self.directions_index)?;
+        if !is_left {
+            self.last_right_step = self.directions_index;
+        }
+        self.directions_index += 1;
+        Ok(is_left)
+    }
+
+    fn key_matches_leaf(&mut self, key: &[u8], r: &LeafNode) -> Result<bool, AvlTreeError> {
+        // the verifier doesn't have the keys in the internal nodes, so it checks that the key is
+        // either equal to the leaf's key or is between the leaf's key and its next leaf key
+        match key.cmp(&r.key) {
+            Ordering::Equal => Ok(true),
+            Ordering::Greater if key < &r.next_leaf_key[..] => Ok(false),
+            _ => Err(AvlTreeError::InvalidProof(
+                "key is out of the range of the leaf".to_string(),
+            )),
+        }
+    }
+
+    fn replay_comparison(&mut self) -> Result<Ordering, AvlTreeError> {
+        let ret = if self.replay_index == self.last_right_step {
+            Ordering::Equal
+        } else if !self.direction_bit(self.replay_index)?
+            && self.replay_index < self.last_right_step
+        {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        self.replay_index += 1;
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::avl_tree::batch_prover::BatchAvlProver;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const KEY_LENGTH: usize = 8;
+
+    fn key_strategy() -> impl Strategy<Value = Vec<u8>> {
+        // small alphabet for the collisions between the operations keys
+        vec(1u8..5, KEY_LENGTH)
+    }
+
+    fn operation_strategy() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            key_strategy().prop_map(Operation::Lookup),
+            (key_strategy(), vec(any::<u8>(), 0..10))
+                .prop_map(|(key, value)| Operation::Insert { key, value }),
+            (key_strategy(), vec(any::<u8>(), 0..10))
+                .prop_map(|(key, value)| Operation::Update { key, value }),
+            key_strategy().prop_map(Operation::Remove),
+        ]
+    }
+
+    /// Performs operations with the prover (skipping the failed ones), returns the succeeded
+    /// operations along with their results
+    fn prove(
+        prover: &mut BatchAvlProver,
+        operations: Vec<Operation>,
+    ) -> Vec<(Operation, Option<ADValue>)> {
+        operations
+            .into_iter()
+            .filter_map(|op| prover.perform_one_operation(&op).ok().map(|res| (op, res)))
+            .collect()
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn verify_batch(
+            initial in vec(operation_strategy(), 0..50),
+            batch in vec(operation_strategy(), 1..50)
+        ) {
+            let mut prover = BatchAvlProver::new(KEY_LENGTH, None);
+            prove(&mut prover, initial);
+            prover.generate_proof();
+            let starting_digest = prover.digest();
+            let performed = prove(&mut prover, batch);
+            let proof = prover.generate_proof();
+            let mut verifier =
+                BatchAvlVerifier::new(&starting_digest, &proof, KEY_LENGTH, None).unwrap();
+            for (op, res) in performed {
+                prop_assert_eq!(verifier.perform_one_operation(&op).unwrap(), res);
+            }
+            prop_assert_eq!(verifier.digest(), Some(prover.digest()));
+        }
+
+        #[test]
+        fn verify_tampered_proof(
+            batch in vec(operation_strategy(), 1..50),
+            idx in any::<prop::sample::Index>(),
+            mask in any::<u8>()
+        ) {
+            let mut prover = BatchAvlProver::new(KEY_LENGTH, None);
+            let starting_digest = prover.digest();
+            let performed = prove(&mut prover, batch);
+            let mut proof = prover.generate_proof();
+            // tamper the packaged tree or the directions, except the unused bits of the last
+            // directions byte (ignored by the verifier)
+            let mut verifier =
+                BatchAvlVerifier::new(&starting_digest, &proof, KEY_LENGTH, None).unwrap();
+            for (op, _) in &performed {
+                verifier.perform_one_operation(op).unwrap();
+            }
+            let used_bits = verifier.directions_index;
+            let i = idx.index((used_bits + 7) >> 3);
+            let mask = if i == used_bits >> 3 {
+                mask & ((1 << (used_bits & 7)) - 1)
+            } else {
+                mask
+            };
+            prop_assume!(mask != 0);
+            proof[i] ^= mask;
+            let verified = BatchAvlVerifier::new(&starting_digest, &proof, KEY_LENGTH, None)
+                .and_then(|mut verifier| {
+                    performed
+                        .iter()
+                        .try_for_each(|(op, res)| {
+                            if verifier.perform_one_operation(op)? == *res {
+                                Ok(())
+                            } else {
+                                Err(AvlTreeError::InvalidProof("unexpected result".to_string()))
+                            }
+                        })
+                        .map(|_| verifier.digest())
+                });
+            prop_assert_ne!(verified, Ok(Some(prover.digest())));
+        }
+    }
+
+    #[test]
+    fn fixed_value_length() {
+        let mut prover = BatchAvlProver::new(KEY_LENGTH, Some(4));
+        let starting_digest = prover.digest();
+        let insert = Operation::Insert {
+            key: vec![1; KEY_LENGTH],
+            value: vec![2; 4],
+        };
+        prover.perform_one_operation(&insert).unwrap();
+        assert!(prover
+            .perform_one_operation(&Operation::Update {
+                key: vec![1; KEY_LENGTH],
+                value: vec![3; 5],
+            })
+            .is_err());
+        let proof = prover.generate_proof();
+        let mut verifier =
+            BatchAvlVerifier::new(&starting_digest, &proof, KEY_LENGTH, Some(4)).unwrap();
+        assert_eq!(verifier.perform_one_operation(&insert), Ok(None));
+        assert_eq!(verifier.digest(), Some(prover.digest()));
+    }
+
+    #[test]
+    fn failed_operation() {
+        let mut prover = BatchAvlProver::new(KEY_LENGTH, None);
+        let starting_digest = prover.digest();
+        let proof = prover.generate_proof();
+        let mut verifier =
+            BatchAvlVerifier::new(&starting_digest, &proof, KEY_LENGTH, None).unwrap();
+        // key is not in the tree
+        assert!(verifier
+            .perform_one_operation(&Operation::Remove(vec![1; KEY_LENGTH]))
+            .is_err());
+        assert_eq!(verifier.digest(), None);
+        assert!(verifier
+            .perform_one_operation(&Operation::Lookup(vec![1; KEY_LENGTH]))
+            .is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Nodes of the AVL+ tree
+
+use std::rc::Rc;
+
+use sigma_util::hash::blake2b256_hash;
+
+use super::ADKey;
+use super::ADValue;
+use super::AvlTreeError;
+use super::LABEL_LENGTH;
+
+/// Node label (hash)
+pub(crate) type Label = [u8; LABEL_LENGTH];
+
+/// Tree node. Nodes are immutable, modifications of the tree produce the new nodes.
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    /// Node known only by its label (the subtree is not included in the proof)
+    LabelOnly(Label),
+    Leaf(Rc<LeafNode>),
+    Internal(Rc<InternalNode>),
+}
+
+#[derive(Debug)]
+pub(crate) struct LeafNode {
+    pub key: ADKey,
+    pub value: ADValue,
+    /// Key of the next leaf in the sorted order
+    pub next_leaf_key: ADKey,
+    label: Label,
+}
+
+#[derive(Debug)]
+pub(crate) struct InternalNode {
+    /// Smallest key in the right subtree (known to the prover only)
+    pub key: Option<ADKey>,
+    pub left: Node,
+    pub right: Node,
+    /// Height of the right subtree minus height of the left subtree (-1, 0 or 1)
+    pub balance: i8,
+    label: Label,
+}
+
+impl Node {
+    pub fn leaf(key: ADKey, value: ADValue, next_leaf_key: ADKey) -> Node {
+        let label = *blake2b256_hash(&[&[0u8][..], &key, &value, &next_leaf_key].concat());
+        Node::Leaf(Rc::new(LeafNode {
+            key,
+            value,
+            next_leaf_key,
+            label,
+        }))
+    }
+
+    pub fn internal(key: Option<ADKey>, left: Node, right: Node, balance: i8) -> Node {
+        let label = *blake2b256_hash(
+            &[
+                &[1u8, balance as u8][..],
+                &left.label()[..],
+                &right.label()[..],
+            ]
+            .concat(),
+        );
+        Node::Internal(Rc::new(InternalNode {
+            key,
+            left,
+            right,
+            balance,
+            label,
+        }))
+    }
+
+    pub fn label(&self) -> Label {
+        match self {
+            Node::LabelOnly(label) => *label,
+            Node::Leaf(leaf) => leaf.label,
+            Node::Internal(internal) => internal.label,
+        }
+    }
+
+    /// Node identity (used by the prover to track the visited nodes)
+    #[cfg(test)]
+    pub fn ptr(&self) -> Option<*const ()> {
+        match self {
+            Node::LabelOnly(_) => None,
+            Node::Leaf(leaf) => Some(Rc::as_ptr(leaf) as *const ()),
+            Node::Internal(internal) => Some(Rc::as_ptr(internal) as *const ()),
+        }
+    }
+
+    pub fn as_internal(&self) -> Result<&Rc<InternalNode>, AvlTreeError> {
+        match self {
+            Node::Internal(internal) => Ok(internal),
+            _ => Err(AvlTreeError::InvalidProof(
+                "expected internal node".to_string(),
+            )),
+        }
+    }
+}
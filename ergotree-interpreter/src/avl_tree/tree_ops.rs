@@ -0,0 +1,533 @@
+//! Operations on the AVL+ tree shared by the prover and the verifier
+//! (follows `AuthenticatedTreeOps` of scorex-util, so that both produce the same tree)
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use super::node::InternalNode;
+use super::node::LeafNode;
+use super::node::Node;
+use super::ADKey;
+use super::ADValue;
+use super::AvlTreeError;
+use super::Operation;
+
+pub(crate) struct ModifyResult {
+    node: Node,
+    change_happened: bool,
+    height_increased: bool,
+    to_delete: bool,
+    old_value: Option<ADValue>,
+}
+
+impl ModifyResult {
+    fn unchanged(node: Node, to_delete: bool, old_value: Option<ADValue>) -> Self {
+        ModifyResult {
+            node,
+            change_happened: false,
+            height_increased: false,
+            to_delete,
+            old_value,
+        }
+    }
+
+    fn changed(node: Node, height_increased: bool, old_value: Option<ADValue>) -> Self {
+        ModifyResult {
+            node,
+            change_happened: true,
+            height_increased,
+            to_delete: false,
+            old_value,
+        }
+    }
+}
+
+pub(crate) trait AuthenticatedTreeOps {
+    fn key_length(&self) -> usize;
+
+    fn value_length(&self) -> Option<usize>;
+
+    /// Whether to go left from the internal node `r` when looking for the `key`
+    fn next_direction_is_left(
+        &mut self,
+        key: &[u8],
+        r: &InternalNode,
+    ) -> Result<bool, AvlTreeError>;
+
+    /// Whether the `key` is the key of the leaf `r` (otherwise it is not in the tree and should be
+    /// inserted right after the leaf)
+    fn key_matches_leaf(&mut self, key: &[u8], r: &LeafNode) -> Result<bool, AvlTreeError>;
+
+    /// Replays the comparison of the key with the internal nodes keys made by
+    /// [`AuthenticatedTreeOps::next_direction_is_left`] (used in the second pass of the deletion)
+    fn replay_comparison(&mut self) -> Result<Ordering, AvlTreeError>;
+
+    /// Called on every node the operation looks into
+    fn on_node_visit(&mut self, _node: &Node) {}
+
+    /// Performs the operation on the tree, returns the new root, the new tree height and the
+    /// value of the key before the operation
+    fn return_result_of_one_operation(
+        &mut self,
+        operation: &Operation,
+        root: &Node,
+        root_height: usize,
+    ) -> Result<(Node, usize, Option<ADValue>), AvlTreeError> {
+        let key = operation.key();
+        if key.len() != self.key_length() {
+            return Err(AvlTreeError::InvalidOperation(format!(
+                "key length {0} does not match the tree key length {1}",
+                key.len(),
+                self.key_length()
+            )));
+        }
+        if key.iter().all(|b| *b == 0) || key.iter().all(|b| *b == 0xff) {
+            return Err(AvlTreeError::InvalidOperation(
+                "key should be greater than -inf and less than +inf key".to_string(),
+            ));
+        }
+        let res = self.modify_helper(root, key, operation)?;
+        if res.to_delete {
+            let mut saved_node = None;
+            let (new_root, height_decreased) =
+                self.delete_helper(res.node.as_internal()?, false, &mut saved_node)?;
+            let new_height = if height_decreased {
+                root_height.saturating_sub(1)
+            } else {
+                root_height
+            };
+            Ok((new_root, new_height, res.old_value))
+        } else if res.change_happened {
+            let new_height = if res.height_increased {
+                root_height + 1
+            } else {
+                root_height
+            };
+            Ok((res.node, new_height, res.old_value))
+        } else {
+            Ok((root.clone(), root_height, res.old_value))
+        }
+    }
+
+    /// Finds the leaf for the key and modifies the tree (insert, update), rebalancing it.
+    /// Deletion is only marked (`to_delete`) to be done by [`AuthenticatedTreeOps::delete_helper`].
+    fn modify_helper(
+        &mut self,
+        r_node: &Node,
+        key: &[u8],
+        operation: &Operation,
+    ) -> Result<ModifyResult, AvlTreeError> {
+        self.on_node_visit(r_node);
+        match r_node {
+            Node::Leaf(r) => {
+                if self.key_matches_leaf(key, r)? {
+                    let old_value = Some(r.value.clone());
+                    if let Operation::Lookup(_) = operation {
+                        return Ok(ModifyResult::unchanged(r_node.clone(), false, old_value));
+                    }
+                    match operation.update_fn(Some(&r.value))? {
+                        None => Ok(ModifyResult::unchanged(r_node.clone(), true, old_value)),
+                        Some(v) => {
+                            self.check_value_length(&v)?;
+                            let new_leaf = Node::leaf(r.key.clone(), v, r.next_leaf_key.clone());
+                            Ok(ModifyResult::changed(new_leaf, false, old_value))
+                        }
+                    }
+                } else {
+                    if let Operation::Lookup(_) = operation {
+                        return Ok(ModifyResult::unchanged(r_node.clone(), false, None));
+                    }
+                    match operation.update_fn(None)? {
+                        None => Ok(ModifyResult::unchanged(r_node.clone(), false, None)),
+                        Some(v) => {
+                            self.check_value_length(&v)?;
+                            let new_node = add_node(r, key.to_vec(), v);
+                            Ok(ModifyResult::changed(new_node, true, None))
+                        }
+                    }
+                }
+            }
+            Node::Internal(r) => {
+                if self.next_direction_is_left(key, r)? {
+                    let res = self.modify_helper(&r.left, key, operation)?;
+                    if !res.change_happened {
+                        return Ok(ModifyResult::unchanged(
+                            r_node.clone(),
+                            res.to_delete,
+                            res.old_value,
+                        ));
+                    }
+                    if res.height_increased && r.balance < 0 {
+                        // need to rotate (the new left child is higher, so it is an internal node)
+                        let new_left = res.node.as_internal()?;
+                        let new_node = if new_left.balance < 0 {
+                            // single right rotate
+                            let new_r = Node::internal(
+                                r.key.clone(),
+                                new_left.right.clone(),
+                                r.right.clone(),
+                                0,
+                            );
+                            Node::internal(new_left.key.clone(), new_left.left.clone(), new_r, 0)
+                        } else {
+                            double_right_rotate(r.key.clone(), new_left, r.right.clone())?
+                        };
+                        Ok(ModifyResult::changed(new_node, false, res.old_value))
+                    } else {
+                        // no need to rotate
+                        let my_height_increased = res.height_increased && r.balance == 0;
+                        let balance = if res.height_increased {
+                            r.balance - 1
+                        } else {
+                            r.balance
+                        };
+                        let new_node =
+                            Node::internal(r.key.clone(), res.node, r.right.clone(), balance);
+                        Ok(ModifyResult::changed(
+                            new_node,
+                            my_height_increased,
+                            res.old_value,
+                        ))
+                    }
+                } else {
+                    let res = self.modify_helper(&r.right, key, operation)?;
+                    if !res.change_happened {
+                        return Ok(ModifyResult::unchanged(
+                            r_node.clone(),
+                            res.to_delete,
+                            res.old_value,
+                        ));
+                    }
+                    if res.height_increased && r.balance > 0 {
+                        // need to rotate (the new right child is higher, so it is an internal node)
+                        let new_right = res.node.as_internal()?;
+                        let new_node = if new_right.balance > 0 {
+                            // single left rotate
+                            let new_r = Node::internal(
+                                r.key.clone(),
+                                r.left.clone(),
+                                new_right.left.clone(),
+                                0,
+                            );
+                            Node::internal(new_right.key.clone(), new_r, new_right.right.clone(), 0)
+                        } else {
+                            double_left_rotate(r.key.clone(), r.left.clone(), new_right)?
+                        };
+                        Ok(ModifyResult::changed(new_node, false, res.old_value))
+                    } else {
+                        // no need to rotate
+                        let my_height_increased = res.height_increased && r.balance == 0;
+                        let balance = if res.height_increased {
+                            r.balance + 1
+                        } else {
+                            r.balance
+                        };
+                        let new_node =
+                            Node::internal(r.key.clone(), r.left.clone(), res.node, balance);
+                        Ok(ModifyResult::changed(
+                            new_node,
+                            my_height_increased,
+                            res.old_value,
+                        ))
+                    }
+                }
+            }
+            Node::LabelOnly(_) => Err(AvlTreeError::InvalidProof(
+                "reached the node which is not in the proof".to_string(),
+            )),
+        }
+    }
+
+    /// Deletes the leaf with the key in the subtree `r` (replaying the path found by
+    /// [`AuthenticatedTreeOps::modify_helper`]), or the rightmost leaf if `delete_max` is set
+    /// (saving it into `saved_node`). Returns the new subtree and whether its height decreased.
+    fn delete_helper(
+        &mut self,
+        r: &Rc<InternalNode>,
+        delete_max: bool,
+        saved_node: &mut Option<Rc<LeafNode>>,
+    ) -> Result<(Node, bool), AvlTreeError> {
+        let direction = if delete_max {
+            Ordering::Greater
+        } else {
+            self.replay_comparison()?
+        };
+        if direction == Ordering::Less {
+            if let Node::Leaf(_) = r.left {
+                return Err(AvlTreeError::InvalidProof(
+                    "deletion path leads to the leaf without the key".to_string(),
+                ));
+            }
+        }
+        if direction != Ordering::Less {
+            if let Node::Leaf(right_child) = &r.right {
+                self.on_node_visit(&r.right);
+                return if delete_max {
+                    *saved_node = Some(right_child.clone());
+                    Ok((r.left.clone(), true))
+                } else if direction == Ordering::Equal {
+                    Ok((
+                        self.change_next_leaf_key_of_max_node(
+                            &r.left,
+                            right_child.next_leaf_key.clone(),
+                        )?,
+                        true,
+                    ))
+                } else {
+                    Err(AvlTreeError::InvalidProof(
+                        "deletion path leads to the leaf without the key".to_string(),
+                    ))
+                };
+            }
+        }
+        if direction == Ordering::Equal {
+            if let Node::Leaf(left_child) = &r.left {
+                self.on_node_visit(&r.left);
+                return Ok((
+                    self.change_key_and_value_of_min_node(
+                        &r.right,
+                        left_child.key.clone(),
+                        left_child.value.clone(),
+                    )?,
+                    true,
+                ));
+            }
+        }
+        if direction != Ordering::Greater {
+            // going left (deleting the max of the left subtree if the key is in this node)
+            self.on_node_visit(&r.left);
+            let (new_left, child_height_decreased) = self.delete_helper(
+                r.left.as_internal()?,
+                direction == Ordering::Equal,
+                saved_node,
+            )?;
+            let (new_root_key, new_root_right) = if direction == Ordering::Equal {
+                // the predecessor of the deleted key takes its place
+                let s = saved_node.take().ok_or_else(|| {
+                    AvlTreeError::InvalidProof(
+                        "missing the max node of the left subtree".to_string(),
+                    )
+                })?;
+                let new_right = self.change_key_and_value_of_min_node(
+                    &r.right,
+                    s.key.clone(),
+                    s.value.clone(),
+                )?;
+                (Some(s.key.clone()), new_right)
+            } else {
+                (r.key.clone(), r.right.clone())
+            };
+            if child_height_decreased && r.balance > 0 {
+                // need to rotate because the left subtree is shorter than the right one
+                self.on_node_visit(&new_root_right);
+                let right_child = new_root_right.as_internal()?;
+                if right_child.balance < 0 {
+                    self.on_node_visit(&right_child.left);
+                    Ok((
+                        double_left_rotate(new_root_key, new_left, right_child)?,
+                        true,
+                    ))
+                } else {
+                    // single left rotate
+                    let new_left_child = Node::internal(
+                        new_root_key,
+                        new_left,
+                        right_child.left.clone(),
+                        1 - right_child.balance,
+                    );
+                    let balance = right_child.balance - 1;
+                    let new_r = Node::internal(
+                        right_child.key.clone(),
+                        new_left_child,
+                        right_child.right.clone(),
+                        balance,
+                    );
+                    Ok((new_r, balance == 0))
+                }
+            } else {
+                let balance = if child_height_decreased {
+                    r.balance + 1
+                } else {
+                    r.balance
+                };
+                Ok((
+                    Node::internal(new_root_key, new_left, new_root_right, balance),
+                    child_height_decreased && balance == 0,
+                ))
+            }
+        } else {
+            // going right
+            self.on_node_visit(&r.right);
+            let (new_right, child_height_decreased) =
+                self.delete_helper(r.right.as_internal()?, delete_max, saved_node)?;
+            if child_height_decreased && r.balance < 0 {
+                // need to rotate because the right subtree is shorter than the left one
+                self.on_node_visit(&r.left);
+                let left_child = r.left.as_internal()?;
+                if left_child.balance > 0 {
+                    self.on_node_visit(&left_child.right);
+                    Ok((
+                        double_right_rotate(r.key.clone(), left_child, new_right)?,
+                        true,
+                    ))
+                } else {
+                    // single right rotate
+                    let new_right_child = Node::internal(
+                        r.key.clone(),
+                        left_child.right.clone(),
+                        new_right,
+                        -left_child.balance - 1,
+                    );
+                    let balance = 1 + left_child.balance;
+                    let new_r = Node::internal(
+                        left_child.key.clone(),
+                        left_child.left.clone(),
+                        new_right_child,
+                        balance,
+                    );
+                    Ok((new_r, balance == 0))
+                }
+            } else {
+                let balance = if child_height_decreased {
+                    r.balance - 1
+                } else {
+                    r.balance
+                };
+                Ok((
+                    Node::internal(r.key.clone(), r.left.clone(), new_right, balance),
+                    child_height_decreased && balance == 0,
+                ))
+            }
+        }
+    }
+
+    fn change_next_leaf_key_of_max_node(
+        &mut self,
+        r_node: &Node,
+        next_leaf_key: ADKey,
+    ) -> Result<Node, AvlTreeError> {
+        self.on_node_visit(r_node);
+        match r_node {
+            Node::Leaf(leaf) => Ok(Node::leaf(
+                leaf.key.clone(),
+                leaf.value.clone(),
+                next_leaf_key,
+            )),
+            Node::Internal(r) => Ok(Node::internal(
+                r.key.clone(),
+                r.left.clone(),
+                self.change_next_leaf_key_of_max_node(&r.right, next_leaf_key)?,
+                r.balance,
+            )),
+            Node::LabelOnly(_) => Err(AvlTreeError::InvalidProof(
+                "reached the node which is not in the proof".to_string(),
+            )),
+        }
+    }
+
+    fn change_key_and_value_of_min_node(
+        &mut self,
+        r_node: &Node,
+        new_key: ADKey,
+        new_value: ADValue,
+    ) -> Result<Node, AvlTreeError> {
+        self.on_node_visit(r_node);
+        match r_node {
+            Node::Leaf(leaf) => Ok(Node::leaf(new_key, new_value, leaf.next_leaf_key.clone())),
+            Node::Internal(r) => Ok(Node::internal(
+                r.key.clone(),
+                self.change_key_and_value_of_min_node(&r.left, new_key, new_value)?,
+                r.right.clone(),
+                r.balance,
+            )),
+            Node::LabelOnly(_) => Err(AvlTreeError::InvalidProof(
+                "reached the node which is not in the proof".to_string(),
+            )),
+        }
+    }
+
+    fn check_value_length(&self, value: &[u8]) -> Result<(), AvlTreeError> {
+        match self.value_length() {
+            Some(value_length) if value_length != value.len() => {
+                Err(AvlTreeError::InvalidOperation(format!(
+                    "value length {0} does not match the tree value length {1}",
+                    value.len(),
+                    value_length
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Replaces the leaf `r` with the internal node having `r` (now followed by the `key`) as the left
+/// child and the new leaf as the right child
+fn add_node(r: &LeafNode, key: ADKey, value: ADValue) -> Node {
+    let new_leaf = Node::leaf(key.clone(), value, r.next_leaf_key.clone());
+    let old_leaf = Node::leaf(r.key.clone(), r.value.clone(), key.clone());
+    Node::internal(Some(key), old_leaf, new_leaf, 0)
+}
+
+fn double_left_rotate(
+    current_root_key: Option<ADKey>,
+    left_child: Node,
+    right_child: &InternalNode,
+) -> Result<Node, AvlTreeError> {
+    let new_root = right_child.left.as_internal()?;
+    let (new_left_balance, new_right_balance) = rotated_children_balances(new_root.balance);
+    let new_left_child = Node::internal(
+        current_root_key,
+        left_child,
+        new_root.left.clone(),
+        new_left_balance,
+    );
+    let new_right_child = Node::internal(
+        right_child.key.clone(),
+        new_root.right.clone(),
+        right_child.right.clone(),
+        new_right_balance,
+    );
+    Ok(Node::internal(
+        new_root.key.clone(),
+        new_left_child,
+        new_right_child,
+        0,
+    ))
+}
+
+fn double_right_rotate(
+    current_root_key: Option<ADKey>,
+    left_child: &InternalNode,
+    right_child: Node,
+) -> Result<Node, AvlTreeError> {
+    let new_root = left_child.right.as_internal()?;
+    let (new_left_balance, new_right_balance) = rotated_children_balances(new_root.balance);
+    let new_right_child = Node::internal(
+        current_root_key,
+        new_root.right.clone(),
+        right_child,
+        new_right_balance,
+    );
+    let new_left_child = Node::internal(
+        left_child.key.clone(),
+        left_child.left.clone(),
+        new_root.left.clone(),
+        new_left_balance,
+    );
+    Ok(Node::internal(
+        new_root.key.clone(),
+        new_left_child,
+        new_right_child,
+        0,
+    ))
+}
+
+/// Balances of the left and right children after the double rotation around the node with the
+/// given balance
+fn rotated_children_balances(balance: i8) -> (i8, i8) {
+    match balance.cmp(&0) {
+        Ordering::Equal => (0, 0),
+        Ordering::Less => (0, 1),
+        Ordering::Greater => (-1, 0),
+    }
+}
@@ -5,6 +5,7 @@ use ergotree_ir::ir_ergo_box::IrErgoBoxArenaError;
 use ergotree_ir::mir::constant::TryExtractFromError;
 use ergotree_ir::mir::expr::Expr;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::SerializationError;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 
 use cost_accum::CostAccumulator;
@@ -14,6 +15,7 @@ use thiserror::Error;
 use self::context::Context;
 use self::cost_accum::CostError;
 use self::env::Env;
+use crate::avl_tree::AvlTreeError;
 
 /// Context(blockchain) for the interpreter
 pub mod context;
@@ -22,6 +24,7 @@ pub mod env;
 
 pub(crate) mod and;
 pub(crate) mod apply;
+pub(crate) mod atleast;
 pub(crate) mod bigint;
 pub(crate) mod bin_op;
 pub(crate) mod block;
@@ -30,6 +33,7 @@ pub(crate) mod byte_array_to_bigint;
 pub(crate) mod byte_array_to_long;
 pub(crate) mod calc_blake2b256;
 pub(crate) mod calc_sha256;
+pub(crate) mod coll_append;
 pub(crate) mod coll_by_index;
 pub(crate) mod coll_exists;
 pub(crate) mod coll_filter;
@@ -37,16 +41,21 @@ pub(crate) mod coll_fold;
 pub(crate) mod coll_forall;
 pub(crate) mod coll_map;
 pub(crate) mod coll_size;
+pub(crate) mod coll_slice;
 pub(crate) mod collection;
 pub(crate) mod cost_accum;
-pub(crate) mod costs;
+pub mod costs;
 pub(crate) mod create_prove_dh_tuple;
 pub(crate) mod create_provedlog;
 pub(crate) mod decode_point;
 mod deserialize_context;
 mod deserialize_register;
+pub(crate) mod downcast;
+pub(crate) mod exponentiate;
 pub(crate) mod expr;
 pub(crate) mod extract_amount;
+pub(crate) mod extract_bytes;
+pub(crate) mod extract_bytes_with_no_ref;
 pub(crate) mod extract_creation_info;
 pub(crate) mod extract_id;
 pub(crate) mod extract_reg_as;
@@ -58,19 +67,33 @@ pub(crate) mod if_op;
 pub(crate) mod logical_not;
 pub(crate) mod long_to_byte_array;
 pub(crate) mod method_call;
+pub(crate) mod minus_mod_q;
+pub(crate) mod mod_q;
+pub(crate) mod multiply_group;
 pub(crate) mod negation;
+pub(crate) mod none_value;
 pub(crate) mod option_get;
 pub(crate) mod option_get_or_else;
 pub(crate) mod option_is_defined;
 pub(crate) mod or;
+pub(crate) mod plus_mod_q;
 pub(crate) mod property_call;
+pub(crate) mod savltree;
 pub(crate) mod sbox;
 pub(crate) mod scoll;
 pub(crate) mod scontext;
 pub(crate) mod select_field;
+pub(crate) mod sglobal;
+pub(crate) mod sgroup_elem;
+pub(crate) mod sheader;
 pub(crate) mod sigma_and;
 pub(crate) mod sigma_or;
 pub(crate) mod sigma_prop_bytes;
+pub(crate) mod snumeric;
+pub(crate) mod some_value;
+pub(crate) mod soption;
+pub(crate) mod spreheader;
+pub(crate) mod subst_const;
 pub(crate) mod tuple;
 pub(crate) mod upcast;
 pub(crate) mod val_use;
@@ -106,16 +129,23 @@ pub enum EvalError {
     /// Cannot find ErgoBox in Context
     #[error("Cannot find ErgoBox in Context: {0:?}")]
     ErgoBoxNotFound(#[from] IrErgoBoxArenaError),
+    /// Serialization error (e.g. on parsing the script of deserializeContext/Register)
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] SerializationError),
+    /// AVL tree operation error
+    #[error("AVL tree error: {0}")]
+    AvlTree(#[from] AvlTreeError),
     /// Misc error
     #[error("error: {0}")]
     Misc(String),
 }
 
 /// Result of expression reduction procedure (see `reduce_to_crypto`).
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ReductionResult {
     /// value of SigmaProp type which represents a statement verifiable via sigma protocol.
     pub sigma_prop: SigmaBoolean,
-    /// estimated cost of expression evaluation
+    /// estimated cost of expression evaluation (in block cost units, see [`costs`])
     pub cost: u64,
 }
 
@@ -128,22 +158,17 @@ pub trait Evaluator {
         env: &Env,
         ctx: Rc<Context>,
     ) -> Result<ReductionResult, EvalError> {
-        let cost_accum = CostAccumulator::new(0, None);
+        let cost_accum = CostAccumulator::new(0, ctx.cost_limit);
         let mut ectx = EvalContext::new(ctx, cost_accum);
-        expr.eval(env, &mut ectx)
-            .and_then(|v| -> Result<ReductionResult, EvalError> {
-                match v {
-                    Value::Boolean(b) => Ok(ReductionResult {
-                        sigma_prop: SigmaBoolean::TrivialProp(b),
-                        cost: 0,
-                    }),
-                    Value::SigmaProp(sp) => Ok(ReductionResult {
-                        sigma_prop: sp.value().clone(),
-                        cost: 0,
-                    }),
-                    _ => Err(EvalError::InvalidResultType),
-                }
-            })
+        let sigma_prop = match expr.eval(env, &mut ectx)? {
+            Value::Boolean(b) => SigmaBoolean::TrivialProp(b),
+            Value::SigmaProp(sp) => sp.value().clone(),
+            _ => return Err(EvalError::InvalidResultType),
+        };
+        Ok(ReductionResult {
+            sigma_prop,
+            cost: ectx.cost_accum.total_cost(),
+        })
     }
 }
 
@@ -166,27 +191,197 @@ pub(crate) trait Evaluable {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError>;
 }
 
-type EvalFn = fn(env: &Env, ctx: &mut EvalContext, Value, Vec<Value>) -> Result<Value, EvalError>;
+type EvalFn = fn(
+    env: &Env,
+    ctx: &mut EvalContext,
+    method: &SMethod,
+    Value,
+    Vec<Value>,
+) -> Result<Value, EvalError>;
 
-fn smethod_eval_fn(method: &SMethod) -> EvalFn {
+fn smethod_eval_fn(method: &SMethod) -> Result<EvalFn, EvalError> {
     use ergotree_ir::types::*;
-    match method.obj_type.type_id() {
-        scontext::TYPE_ID if method.method_id() == scontext::DATA_INPUTS_PROPERTY_METHOD_ID => {
-            self::scontext::DATA_INPUTS_EVAL_FN
-        }
+    Ok(match method.obj_type.type_id() {
+        scontext::TYPE_ID => match method.method_id() {
+            scontext::DATA_INPUTS_PROPERTY_METHOD_ID => self::scontext::DATA_INPUTS_EVAL_FN,
+            scontext::HEADERS_PROPERTY_METHOD_ID => self::scontext::HEADERS_EVAL_FN,
+            scontext::PRE_HEADERS_PROPERTY_METHOD_ID => self::scontext::PRE_HEADER_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SContext: {:?}",
+                    method_id
+                )))
+            }
+        },
+        sheader::TYPE_ID => match method.method_id() {
+            sheader::ID_METHOD_ID => self::sheader::ID_EVAL_FN,
+            sheader::VERSION_METHOD_ID => self::sheader::VERSION_EVAL_FN,
+            sheader::PARENT_ID_METHOD_ID => self::sheader::PARENT_ID_EVAL_FN,
+            sheader::AD_PROOFS_ROOT_METHOD_ID => self::sheader::AD_PROOFS_ROOT_EVAL_FN,
+            sheader::STATE_ROOT_METHOD_ID => self::sheader::STATE_ROOT_EVAL_FN,
+            sheader::TRANSACTIONS_ROOT_METHOD_ID => self::sheader::TRANSACTIONS_ROOT_EVAL_FN,
+            sheader::TIMESTAMP_METHOD_ID => self::sheader::TIMESTAMP_EVAL_FN,
+            sheader::N_BITS_METHOD_ID => self::sheader::N_BITS_EVAL_FN,
+            sheader::HEIGHT_METHOD_ID => self::sheader::HEIGHT_EVAL_FN,
+            sheader::EXTENSION_ROOT_METHOD_ID => self::sheader::EXTENSION_ROOT_EVAL_FN,
+            sheader::MINER_PK_METHOD_ID => self::sheader::MINER_PK_EVAL_FN,
+            sheader::POW_ONETIME_PK_METHOD_ID => self::sheader::POW_ONETIME_PK_EVAL_FN,
+            sheader::POW_NONCE_METHOD_ID => self::sheader::POW_NONCE_EVAL_FN,
+            sheader::POW_DISTANCE_METHOD_ID => self::sheader::POW_DISTANCE_EVAL_FN,
+            sheader::VOTES_METHOD_ID => self::sheader::VOTES_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SHeader: {:?}",
+                    method_id
+                )))
+            }
+        },
+        spreheader::TYPE_ID => match method.method_id() {
+            spreheader::VERSION_METHOD_ID => self::spreheader::VERSION_EVAL_FN,
+            spreheader::PARENT_ID_METHOD_ID => self::spreheader::PARENT_ID_EVAL_FN,
+            spreheader::TIMESTAMP_METHOD_ID => self::spreheader::TIMESTAMP_EVAL_FN,
+            spreheader::N_BITS_METHOD_ID => self::spreheader::N_BITS_EVAL_FN,
+            spreheader::HEIGHT_METHOD_ID => self::spreheader::HEIGHT_EVAL_FN,
+            spreheader::MINER_PK_METHOD_ID => self::spreheader::MINER_PK_EVAL_FN,
+            spreheader::VOTES_METHOD_ID => self::spreheader::VOTES_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SPreHeader: {:?}",
+                    method_id
+                )))
+            }
+        },
         sbox::TYPE_ID => match method.method_id() {
             sbox::VALUE_METHOD_ID => self::sbox::VALUE_EVAL_FN,
             sbox::GET_REG_METHOD_ID => self::sbox::GET_REG_EVAL_FN,
             sbox::TOKENS_METHOD_ID => self::sbox::TOKENS_EVAL_FN,
-            method_id => panic!("Eval fn: unknown method id in SBox: {:?}", method_id),
+            sbox::PROPOSITION_BYTES_METHOD_ID => self::sbox::PROPOSITION_BYTES_EVAL_FN,
+            sbox::BYTES_METHOD_ID => self::sbox::BYTES_EVAL_FN,
+            sbox::BYTES_WITHOUT_REF_METHOD_ID => self::sbox::BYTES_WITHOUT_REF_EVAL_FN,
+            sbox::ID_METHOD_ID => self::sbox::ID_EVAL_FN,
+            sbox::CREATION_INFO_METHOD_ID => self::sbox::CREATION_INFO_EVAL_FN,
+            sbox::R0_METHOD_ID => self::sbox::R0_EVAL_FN,
+            sbox::R1_METHOD_ID => self::sbox::R1_EVAL_FN,
+            sbox::R2_METHOD_ID => self::sbox::R2_EVAL_FN,
+            sbox::R3_METHOD_ID => self::sbox::R3_EVAL_FN,
+            sbox::R4_METHOD_ID => self::sbox::R4_EVAL_FN,
+            sbox::R5_METHOD_ID => self::sbox::R5_EVAL_FN,
+            sbox::R6_METHOD_ID => self::sbox::R6_EVAL_FN,
+            sbox::R7_METHOD_ID => self::sbox::R7_EVAL_FN,
+            sbox::R8_METHOD_ID => self::sbox::R8_EVAL_FN,
+            sbox::R9_METHOD_ID => self::sbox::R9_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SBox: {:?}",
+                    method_id
+                )))
+            }
+        },
+        sglobal::TYPE_ID => match method.method_id() {
+            sglobal::GROUP_GENERATOR_METHOD_ID => self::sglobal::GROUP_GENERATOR_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SGlobal: {:?}",
+                    method_id
+                )))
+            }
+        },
+        sgroup_elem::TYPE_ID => match method.method_id() {
+            sgroup_elem::GET_ENCODED_METHOD_ID => self::sgroup_elem::GET_ENCODED_EVAL_FN,
+            sgroup_elem::EXP_METHOD_ID => self::sgroup_elem::EXP_EVAL_FN,
+            sgroup_elem::MULTIPLY_METHOD_ID => self::sgroup_elem::MULTIPLY_EVAL_FN,
+            sgroup_elem::NEGATE_METHOD_ID => self::sgroup_elem::NEGATE_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SGroupElement: {:?}",
+                    method_id
+                )))
+            }
+        },
+        savltree::TYPE_ID => match method.method_id() {
+            savltree::DIGEST_METHOD_ID => self::savltree::DIGEST_EVAL_FN,
+            savltree::ENABLED_OPERATIONS_METHOD_ID => self::savltree::ENABLED_OPERATIONS_EVAL_FN,
+            savltree::KEY_LENGTH_METHOD_ID => self::savltree::KEY_LENGTH_EVAL_FN,
+            savltree::VALUE_LENGTH_OPT_METHOD_ID => self::savltree::VALUE_LENGTH_OPT_EVAL_FN,
+            savltree::IS_INSERT_ALLOWED_METHOD_ID => self::savltree::IS_INSERT_ALLOWED_EVAL_FN,
+            savltree::IS_UPDATE_ALLOWED_METHOD_ID => self::savltree::IS_UPDATE_ALLOWED_EVAL_FN,
+            savltree::IS_REMOVE_ALLOWED_METHOD_ID => self::savltree::IS_REMOVE_ALLOWED_EVAL_FN,
+            savltree::UPDATE_OPERATIONS_METHOD_ID => self::savltree::UPDATE_OPERATIONS_EVAL_FN,
+            savltree::CONTAINS_METHOD_ID => self::savltree::CONTAINS_EVAL_FN,
+            savltree::GET_METHOD_ID => self::savltree::GET_EVAL_FN,
+            savltree::GET_MANY_METHOD_ID => self::savltree::GET_MANY_EVAL_FN,
+            savltree::INSERT_METHOD_ID => self::savltree::INSERT_EVAL_FN,
+            savltree::UPDATE_METHOD_ID => self::savltree::UPDATE_EVAL_FN,
+            savltree::REMOVE_METHOD_ID => self::savltree::REMOVE_EVAL_FN,
+            savltree::UPDATE_DIGEST_METHOD_ID => self::savltree::UPDATE_DIGEST_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SAvlTree: {:?}",
+                    method_id
+                )))
+            }
         },
         scoll::TYPE_ID => match method.method_id() {
-            scoll::INDEX_OF_METHOD_ID => self::scoll::INDEX_OF_EVAL_FN,
+            scoll::SIZE_METHOD_ID => self::scoll::SIZE_EVAL_FN,
+            scoll::GET_OR_ELSE_METHOD_ID => self::scoll::GET_OR_ELSE_EVAL_FN,
+            scoll::MAP_METHOD_ID => self::scoll::MAP_EVAL_FN,
+            scoll::EXISTS_METHOD_ID => self::scoll::EXISTS_EVAL_FN,
+            scoll::FOLD_METHOD_ID => self::scoll::FOLD_EVAL_FN,
+            scoll::FORALL_METHOD_ID => self::scoll::FORALL_EVAL_FN,
+            scoll::SLICE_METHOD_ID => self::scoll::SLICE_EVAL_FN,
+            scoll::FILTER_METHOD_ID => self::scoll::FILTER_EVAL_FN,
+            scoll::APPEND_METHOD_ID => self::scoll::APPEND_EVAL_FN,
+            scoll::APPLY_METHOD_ID => self::scoll::APPLY_EVAL_FN,
+            scoll::INDICES_METHOD_ID => self::scoll::INDICES_EVAL_FN,
             scoll::FLATMAP_METHOD_ID => self::scoll::FLATMAP_EVAL_FN,
-            method_id => panic!("Eval fn: unknown method id in SCollection: {:?}", method_id),
+            scoll::PATCH_METHOD_ID => self::scoll::PATCH_EVAL_FN,
+            scoll::UPDATED_METHOD_ID => self::scoll::UPDATED_EVAL_FN,
+            scoll::UPDATE_MANY_METHOD_ID => self::scoll::UPDATE_MANY_EVAL_FN,
+            scoll::INDEX_OF_METHOD_ID => self::scoll::INDEX_OF_EVAL_FN,
+            scoll::ZIP_METHOD_ID => self::scoll::ZIP_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SCollection: {:?}",
+                    method_id
+                )))
+            }
         },
-        type_id => todo!("Eval fn: unknown type id {:?}", type_id),
-    }
+        soption::TYPE_ID => match method.method_id() {
+            soption::IS_EMPTY_METHOD_ID => self::soption::IS_EMPTY_EVAL_FN,
+            soption::IS_DEFINED_METHOD_ID => self::soption::IS_DEFINED_EVAL_FN,
+            soption::GET_METHOD_ID => self::soption::GET_EVAL_FN,
+            soption::GET_OR_ELSE_METHOD_ID => self::soption::GET_OR_ELSE_EVAL_FN,
+            soption::MAP_METHOD_ID => self::soption::MAP_EVAL_FN,
+            soption::FILTER_METHOD_ID => self::soption::FILTER_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SOption: {:?}",
+                    method_id
+                )))
+            }
+        },
+        type_id if snumeric::is_numeric_type_id(type_id) => match method.method_id() {
+            snumeric::TO_BYTE_METHOD_ID => self::snumeric::TO_BYTE_EVAL_FN,
+            snumeric::TO_SHORT_METHOD_ID => self::snumeric::TO_SHORT_EVAL_FN,
+            snumeric::TO_INT_METHOD_ID => self::snumeric::TO_INT_EVAL_FN,
+            snumeric::TO_LONG_METHOD_ID => self::snumeric::TO_LONG_EVAL_FN,
+            snumeric::TO_BIGINT_METHOD_ID => self::snumeric::TO_BIGINT_EVAL_FN,
+            snumeric::TO_BYTES_METHOD_ID => self::snumeric::TO_BYTES_EVAL_FN,
+            snumeric::TO_BITS_METHOD_ID => self::snumeric::TO_BITS_EVAL_FN,
+            method_id => {
+                return Err(EvalError::NotFound(format!(
+                    "Eval fn: unknown method id in SNumeric: {:?}",
+                    method_id
+                )))
+            }
+        },
+        type_id => {
+            return Err(EvalError::NotFound(format!(
+                "Eval fn: unknown type id {:?}",
+                type_id
+            )))
+        }
+    })
 }
 
 #[cfg(test)]
@@ -198,6 +393,8 @@ pub(crate) mod tests {
 
     use super::env::Env;
     use super::*;
+    use ergotree_ir::mir::and::And;
+    use ergotree_ir::mir::collection::Collection;
     use ergotree_ir::mir::constant::TryExtractFrom;
     use ergotree_ir::mir::constant::TryExtractInto;
     use sigma_test_util::force_any_val;
@@ -230,4 +427,48 @@ pub(crate) mod tests {
         let ctx = Rc::new(force_any_val::<Context>());
         try_eval_out(expr, ctx)
     }
+
+    /// Evaluates the expression and returns the accumulated cost (in block cost units)
+    pub fn eval_cost_wo_ctx(expr: &Expr) -> u64 {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let cost_accum = CostAccumulator::new(0, None);
+        let mut ectx = EvalContext::new(ctx, cost_accum);
+        expr.eval(&Env::empty(), &mut ectx).unwrap();
+        ectx.cost_accum.total_cost()
+    }
+
+    fn and_of_bools(n: usize) -> Expr {
+        And {
+            input: Box::new(Collection::BoolConstants(vec![true; n]).into()),
+        }
+        .into()
+    }
+
+    struct TestEvaluator;
+    impl Evaluator for TestEvaluator {}
+
+    #[test]
+    fn reduction_cost_depends_on_items_count() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let small = TestEvaluator
+            .reduce_to_crypto(&and_of_bools(1), &Env::empty(), ctx.clone())
+            .unwrap();
+        let large = TestEvaluator
+            .reduce_to_crypto(&and_of_bools(10000), &Env::empty(), ctx)
+            .unwrap();
+        assert_eq!(small.sigma_prop, SigmaBoolean::TrivialProp(true));
+        assert!(small.cost > 0);
+        assert!(large.cost > small.cost);
+    }
+
+    #[test]
+    fn reduction_cost_limit() {
+        let expr = and_of_bools(10000);
+        let mut ctx = force_any_val::<Context>();
+        ctx.cost_limit = Some(10);
+        assert_eq!(
+            TestEvaluator.reduce_to_crypto(&expr, &Env::empty(), Rc::new(ctx)),
+            Err(EvalError::CostError(CostError::LimitExceeded(10)))
+        );
+    }
 }
@@ -1,34 +1,139 @@
 use ergotree_ir::mir::and::And;
 use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::expr::Expr;
 use ergotree_ir::mir::value::Value;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
 use crate::eval::Evaluable;
 
+/// Evaluates the `input` collection of booleans and looks for the first item equal to
+/// `stop_on`, which is returned then. If there is no such item `!stop_on` is returned. As in
+/// sigmastate, the whole collection is evaluated but only the processed items are added to
+/// the cost.
+pub(crate) fn eval_short_circuit(
+    input: &Expr,
+    stop_on: bool,
+    env: &Env,
+    ctx: &mut EvalContext,
+) -> Result<bool, EvalError> {
+    let input_v_bools = input.eval(env, ctx)?.try_extract_into::<Vec<bool>>()?;
+    let mut n_processed = 0;
+    let mut stopped = false;
+    for b in input_v_bools {
+        n_processed += 1;
+        if b == stop_on {
+            stopped = true;
+            break;
+        }
+    }
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.and_or, n_processed)?;
+    Ok(if stopped { stop_on } else { !stop_on })
+}
+
 impl Evaluable for And {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        let input_v = self.input.eval(env, ctx)?;
-        let input_v_bools = input_v.try_extract_into::<Vec<bool>>()?;
-        Ok(input_v_bools.iter().all(|b| *b).into())
+        Ok(eval_short_circuit(&self.input, false, env, ctx)?.into())
     }
 }
 
+#[allow(clippy::unwrap_used)]
 #[cfg(test)]
 mod tests {
     use std::rc::Rc;
 
     use crate::eval::context::Context;
+    use crate::eval::costs::Cost;
+    use crate::eval::tests::eval_cost_wo_ctx;
     use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out_wo_ctx;
 
     use super::*;
 
-    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::collection::Collection;
+    use ergotree_ir::mir::logical_not::LogicalNot;
+    use ergotree_ir::types::stype::SType;
     use proptest::collection;
     use proptest::prelude::*;
     use sigma_test_util::force_any_val;
 
+    // (1 / 0) == 1, fails if evaluated
+    fn div_by_zero_eq_one() -> Expr {
+        BinOp {
+            kind: RelationOp::Eq.into(),
+            left: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(Expr::Const(1i32.into())),
+                    right: Box::new(Expr::Const(0i32.into())),
+                }
+                .into(),
+            ),
+            right: Box::new(Expr::Const(1i32.into())),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_whole_input() {
+        let expr: Expr = And {
+            input: Box::new(
+                Collection::new(
+                    SType::SBoolean,
+                    vec![Expr::Const(false.into()), div_by_zero_eq_one()],
+                )
+                .unwrap()
+                .into(),
+            ),
+        }
+        .into();
+        assert!(try_eval_out_wo_ctx::<bool>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_cost_of_processed_items_only() {
+        let and_of = |bools: Vec<bool>| -> Expr {
+            And {
+                input: Box::new(Collection::BoolConstants(bools).into()),
+            }
+            .into()
+        };
+        assert!(
+            eval_cost_wo_ctx(&and_of(vec![false; 10000]))
+                < eval_cost_wo_ctx(&and_of(vec![true; 10000]))
+        );
+    }
+
+    #[test]
+    fn eval_cost_of_exprs_collection() {
+        let item: Expr = LogicalNot {
+            input: Box::new(Expr::Const(false.into())),
+        }
+        .into();
+        let coll: Expr = Collection::new(SType::SBoolean, vec![item.clone(); 3])
+            .unwrap()
+            .into();
+        let expr: Expr = And {
+            input: Box::new(coll.clone()),
+        }
+        .into();
+        let costs = Costs::DEFAULT;
+        let jit = |c: Cost| u32::from(c) as u64;
+        // same as evaluating the collection node and then And over its value
+        let expected = jit(costs.cost_of(&coll))
+            + jit(costs.collection.items_cost(3))
+            + 3 * (jit(costs.cost_of(&item)) + jit(costs.constant))
+            + jit(costs.cost_of(&expr))
+            + jit(costs.and_or.items_cost(3));
+        assert_eq!(eval_cost_wo_ctx(&expr), Cost::to_block_cost(expected));
+    }
+
     proptest! {
 
         #[test]
@@ -0,0 +1,134 @@
+use std::convert::TryInto;
+
+use ergotree_ir::mir::atleast::Atleast;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjectureItems;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::costs::Costs;
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Atleast {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bound_v = self.n_required.eval(env, ctx)?;
+        let input_v = self.expressions.eval(env, ctx)?;
+
+        let bound = bound_v.try_extract_into::<i32>()?;
+        let input = input_v.try_extract_into::<Vec<SigmaProp>>()?;
+
+        if input.len() > Cthreshold::MAX_CHILDREN_COUNT {
+            return Err(EvalError::Misc(format!(
+                "Atleast: expected input elements count to be no more than {}, got {}",
+                Cthreshold::MAX_CHILDREN_COUNT,
+                input.len()
+            )));
+        }
+
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.atleast, input.len())?;
+        let mut sigma_booleans: Vec<SigmaBoolean> = input.into_iter().map(|p| p.into()).collect();
+        let sb = if bound <= 0 {
+            true.into()
+        } else if bound as usize > sigma_booleans.len() {
+            false.into()
+        } else if sigma_booleans.len() == 1 {
+            // bound is 1 here, so it's an OR with a single item
+            sigma_booleans.remove(0)
+        } else {
+            let items: SigmaConjectureItems<SigmaBoolean> = sigma_booleans
+                .try_into()
+                .map_err(|e| EvalError::Misc(format!("Atleast: invalid input items: {:?}", e)))?;
+            Cthreshold::reduce(bound as usize, items)
+        };
+        Ok(Value::SigmaProp(Box::new(SigmaProp::new(sb))))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+
+    fn eval_atleast(bound: i32, props: Vec<SigmaProp>) -> SigmaBoolean {
+        let expr: Expr = Atleast::new(
+            Expr::Const(bound.into()),
+            Expr::Const(Constant::from(props)),
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        eval_out::<SigmaProp>(&expr, ctx).into()
+    }
+
+    fn pks(count: usize) -> Vec<SigmaProp> {
+        (0..count)
+            .map(|_| force_any_val::<ProveDlog>().into())
+            .collect()
+    }
+
+    #[test]
+    fn eval_threshold() {
+        let props = pks(3);
+        let res = eval_atleast(2, props.clone());
+        let expected_children: Vec<SigmaBoolean> = props.into_iter().map(|p| p.into()).collect();
+        assert_eq!(
+            res,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(Cthreshold {
+                k: 2,
+                children: expected_children.try_into().unwrap()
+            }))
+        );
+    }
+
+    #[test]
+    fn eval_bound_zero() {
+        assert_eq!(eval_atleast(0, pks(2)), SigmaBoolean::TrivialProp(true));
+        assert_eq!(eval_atleast(-1, pks(2)), SigmaBoolean::TrivialProp(true));
+    }
+
+    #[test]
+    fn eval_bound_too_big() {
+        assert_eq!(eval_atleast(3, pks(2)), SigmaBoolean::TrivialProp(false));
+    }
+
+    #[test]
+    fn eval_single_item() {
+        let props = pks(1);
+        assert_eq!(eval_atleast(1, props.clone()), props[0].clone().into());
+    }
+
+    #[test]
+    fn eval_empty() {
+        assert_eq!(eval_atleast(1, vec![]), SigmaBoolean::TrivialProp(false));
+    }
+
+    #[test]
+    fn eval_too_many_items() {
+        let expr: Expr = Atleast::new(
+            Expr::Const(2i32.into()),
+            Expr::Const(Constant::from(pks(Cthreshold::MAX_CHILDREN_COUNT + 1))),
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<SigmaProp>(&expr, ctx).is_err());
+    }
+}
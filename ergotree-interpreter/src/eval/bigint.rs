@@ -1,7 +1,10 @@
+use ergotree_ir::sigma_protocol::dlog_group;
 use lazy_static::lazy_static;
 use num_bigint::BigInt;
 use num_traits::pow::Pow;
 
+use crate::eval::EvalError;
+
 lazy_static! {
     pub static ref MAX_BOUND: BigInt = Pow::pow(BigInt::from(2), 255u32) - 1;
     pub static ref MIN_BOUND: BigInt = -Pow::pow(BigInt::from(2), 255u32);
@@ -10,3 +13,17 @@ lazy_static! {
 pub fn fits_in_256_bits(b: &BigInt) -> bool {
     *b >= *MIN_BOUND && *b <= *MAX_BOUND
 }
+
+/// Non-negative remainder of the division by the secp256k1 group order, i.e. a value in [0, q).
+/// Since q > 2^255, remainders in [2^255, q) do not fit into 256-bit BigInt and are reported as
+/// an overflow, same as the results of other BigInt operations.
+pub fn mod_q(b: &BigInt) -> Result<BigInt, EvalError> {
+    let res = dlog_group::mod_order(b);
+    if fits_in_256_bits(&res) {
+        Ok(res)
+    } else {
+        Err(EvalError::ArithmeticException(
+            "Arithmetic Overflow on BigInt modQ operation".to_string(),
+        ))
+    }
+}
@@ -12,8 +12,10 @@ use num_bigint::BigInt;
 use num_traits::CheckedAdd;
 use num_traits::CheckedDiv;
 use num_traits::CheckedMul;
+use num_traits::CheckedRem;
 use num_traits::CheckedSub;
 use num_traits::Num;
+use num_traits::Signed;
 
 use crate::eval;
 use crate::eval::bigint::fits_in_256_bits;
@@ -89,6 +91,30 @@ where
         .map(|t| t.into()) // convert T to Value
 }
 
+fn eval_mod<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedRem + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    if rv_raw.is_zero() {
+        Err(arithmetic_err("%", lv_raw, rv_raw, "exception"))
+    } else {
+        // the only other case checked_rem fails on is MIN % -1, which is 0 on JVM
+        Ok(lv_raw.checked_rem(&rv_raw).unwrap_or_else(T::zero).into())
+    }
+}
+
+// Same as java.math.BigInteger.mod (used in sigmastate), the result is always non-negative
+fn eval_bigint_mod(lv_raw: BigInt, rv: Value) -> Result<Value, EvalError> {
+    let rv_raw = rv.try_extract_into::<BigInt>()?;
+    if !rv_raw.is_positive() {
+        Err(arithmetic_err("%", lv_raw, rv_raw, "non-positive modulus"))
+    } else {
+        let rem = lv_raw % &rv_raw;
+        Ok(if rem.is_negative() { rem + rv_raw } else { rem }.into())
+    }
+}
+
 fn eval_bit_op<T, F>(lv_raw: T, rv: Value, op: F) -> Result<Value, EvalError>
 where
     T: Num + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
@@ -172,8 +198,13 @@ where
 
 impl Evaluable for BinOp {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        ctx.cost_accum.add(Costs::DEFAULT.eq_const_size)?;
         let lv = self.left.eval(env, ctx)?;
+        if let (BinOpKind::Relation(RelationOp::Eq | RelationOp::NEq), Value::Coll(coll)) =
+            (self.kind, &lv)
+        {
+            ctx.cost_accum
+                .add_items_cost(Costs::DEFAULT.eq_coll, coll.len())?;
+        }
         // using closure to keep right value from evaluation (for lazy AND, OR)
         let mut rv = || self.right.eval(env, ctx);
         match self.kind {
@@ -241,12 +272,23 @@ impl Evaluable for BinOp {
                         lv
                     ))),
                 },
+                ArithOp::Modulo => match lv {
+                    Value::Byte(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => check_bigint_overflow(eval_bigint_mod(lv_raw, rv()?)?),
+                    _ => Err(EvalError::UnexpectedValue(format!(
+                        "expected BinOp::left to be numeric value, got {0:?}",
+                        lv
+                    ))),
+                },
                 ArithOp::Max => match lv {
                     Value::Byte(lv_raw) => eval_max(lv_raw, rv()?),
                     Value::Short(lv_raw) => eval_max(lv_raw, rv()?),
                     Value::Int(lv_raw) => eval_max(lv_raw, rv()?),
                     Value::Long(lv_raw) => eval_max(lv_raw, rv()?),
-                    Value::BigInt(lv_raw) => eval_max(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => check_bigint_overflow(eval_max(lv_raw, rv()?)?),
                     _ => Err(EvalError::UnexpectedValue(format!(
                         "expected BinOp::left to be numeric value, got {0:?}",
                         lv
@@ -257,7 +299,7 @@ impl Evaluable for BinOp {
                     Value::Short(lv_raw) => eval_min(lv_raw, rv()?),
                     Value::Int(lv_raw) => eval_min(lv_raw, rv()?),
                     Value::Long(lv_raw) => eval_min(lv_raw, rv()?),
-                    Value::BigInt(lv_raw) => eval_min(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => check_bigint_overflow(eval_min(lv_raw, rv()?)?),
                     _ => Err(EvalError::UnexpectedValue(format!(
                         "expected BinOp::left to be numeric value, got {0:?}",
                         lv
@@ -268,7 +310,9 @@ impl Evaluable for BinOp {
                     Value::Short(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l & r),
                     Value::Int(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l & r),
                     Value::Long(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l & r),
-                    Value::BigInt(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l & r),
+                    Value::BigInt(lv_raw) => {
+                        check_bigint_overflow(eval_bit_op(lv_raw, rv()?, |l, r| l & r)?)
+                    }
                     _ => Err(EvalError::UnexpectedValue(format!(
                         "expected BinOp::left to be numeric value, got {0:?}",
                         lv
@@ -279,7 +323,9 @@ impl Evaluable for BinOp {
                     Value::Short(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l | r),
                     Value::Int(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l | r),
                     Value::Long(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l | r),
-                    Value::BigInt(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l | r),
+                    Value::BigInt(lv_raw) => {
+                        check_bigint_overflow(eval_bit_op(lv_raw, rv()?, |l, r| l | r)?)
+                    }
                     _ => Err(EvalError::UnexpectedValue(format!(
                         "expected BinOp::left to be numeric value, got {0:?}",
                         lv
@@ -290,7 +336,9 @@ impl Evaluable for BinOp {
                     Value::Short(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l ^ r),
                     Value::Int(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l ^ r),
                     Value::Long(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l ^ r),
-                    Value::BigInt(lv_raw) => eval_bit_op(lv_raw, rv()?, |l, r| l ^ r),
+                    Value::BigInt(lv_raw) => {
+                        check_bigint_overflow(eval_bit_op(lv_raw, rv()?, |l, r| l ^ r)?)
+                    }
                     _ => Err(EvalError::UnexpectedValue(format!(
                         "expected BinOp::left to be numeric value, got {0:?}",
                         lv
@@ -308,8 +356,15 @@ mod tests {
     use crate::eval::context::Context;
     use crate::eval::tests::eval_out;
     use crate::eval::tests::try_eval_out;
+    use ergotree_ir::ir_ergo_box::IrErgoBox;
+    use ergotree_ir::ir_ergo_box::ParsedErgoBox;
     use ergotree_ir::mir::constant::Constant;
     use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::get_var::GetVar;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::option_get::OptionGet;
+    use ergotree_ir::mir::unary_op::UnaryOpTryBuild;
+    use ergotree_ir::types::stype::SType;
     use num_bigint::ToBigInt;
     use proptest::prelude::*;
     use sigma_test_util::force_any_val;
@@ -437,6 +492,35 @@ mod tests {
         try_eval_out::<T>(&expr, ctx)
     }
 
+    #[test]
+    fn eval_box_eq_by_id() {
+        let b = force_any_val::<ParsedErgoBox>();
+        // SELF is a box from the box arena, the same box from context var is a parsed one
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box = b.id();
+        ctx.extension.values.insert(1, b.into());
+        let ctx = Rc::new(ctx);
+        let var: Expr = OptionGet::try_build(
+            GetVar {
+                var_id: 1,
+                var_tpe: SType::SBox,
+            }
+            .into(),
+        )
+        .unwrap()
+        .into();
+        let eq_op = |kind: RelationOp| -> Expr {
+            BinOp {
+                kind: kind.into(),
+                left: Box::new(GlobalVars::SelfBox.into()),
+                right: Box::new(var.clone()),
+            }
+            .into()
+        };
+        assert!(eval_out::<bool>(&eq_op(RelationOp::Eq), ctx.clone()));
+        assert!(!eval_out::<bool>(&eq_op(RelationOp::NEq), ctx));
+    }
+
     fn eval_relation_op<T: Into<Constant>>(op: RelationOp, left: T, right: T) -> bool {
         let expr: Expr = BinOp {
             kind: BinOpKind::Relation(op),
@@ -467,6 +551,16 @@ mod tests {
         assert_eq!(eval_num_op(ArithOp::Divide, min() + 1, b(-1)), Ok(max()));
         assert!(eval_num_op(ArithOp::Divide, b(20), b(0)).is_err());
 
+        assert!(eval_num_op(ArithOp::Modulo, b(20), b(0)).is_err());
+        assert!(eval_num_op(ArithOp::Modulo, min(), b(-1)).is_err());
+        assert!(eval_num_op(ArithOp::Modulo, b(7), b(-3)).is_err());
+        assert!(eval_num_op(ArithOp::Modulo, b(-7), b(-3)).is_err());
+        assert_eq!(eval_num_op(ArithOp::Modulo, b(7), b(3)), Ok(b(1)));
+        assert_eq!(eval_num_op(ArithOp::Modulo, b(-7), b(3)), Ok(b(2)));
+        assert_eq!(eval_num_op(ArithOp::Modulo, b(-6), b(3)), Ok(b(0)));
+        assert_eq!(eval_num_op(ArithOp::Modulo, min(), max()), Ok(max() - 1));
+        assert_eq!(eval_num_op(ArithOp::Modulo, max(), max()), Ok(b(0)));
+
         assert!(eval_num_op(ArithOp::Plus, max(), b(1)).is_err());
         assert_eq!(eval_num_op(ArithOp::Plus, max(), b(0)), Ok(max()));
         assert!(eval_num_op(ArithOp::Plus, min(), b(-1)).is_err());
@@ -480,6 +574,45 @@ mod tests {
         assert_eq!(eval_num_op(ArithOp::BitAnd, max(), min()), Ok(b(0)));
         assert_eq!(eval_num_op(ArithOp::BitOr, max(), min()), Ok(b(-1)));
         assert_eq!(eval_num_op(ArithOp::BitXor, max(), min()), Ok(b(-1)));
+
+        // every operation checks that the result fits into 256 bits
+        let over = || max() + 1;
+        for (op, right) in [
+            (ArithOp::Plus, b(0)),
+            (ArithOp::Minus, b(0)),
+            (ArithOp::Multiply, b(1)),
+            (ArithOp::Divide, b(1)),
+            (ArithOp::Max, b(0)),
+            (ArithOp::Min, over()),
+            (ArithOp::BitOr, b(0)),
+            (ArithOp::BitXor, b(0)),
+            (ArithOp::BitAnd, over()),
+        ]
+        .iter()
+        {
+            assert!(
+                matches!(
+                    eval_num_op::<BigInt>(*op, over(), right.clone()),
+                    Err(EvalError::ArithmeticException(_))
+                ),
+                "{:?}",
+                op
+            );
+        }
+    }
+
+    #[test]
+    fn test_mod_jvm_semantics() {
+        // sign follows the dividend
+        assert_eq!(eval_num_op(ArithOp::Modulo, -7i32, 3), Ok(-1));
+        assert_eq!(eval_num_op(ArithOp::Modulo, 7i32, -3), Ok(1));
+        assert_eq!(eval_num_op(ArithOp::Modulo, -7i32, -3), Ok(-1));
+        // MIN % -1 does not overflow on JVM
+        assert_eq!(eval_num_op(ArithOp::Modulo, i8::MIN, -1), Ok(0));
+        assert_eq!(eval_num_op(ArithOp::Modulo, i16::MIN, -1), Ok(0));
+        assert_eq!(eval_num_op(ArithOp::Modulo, i32::MIN, -1), Ok(0));
+        assert_eq!(eval_num_op(ArithOp::Modulo, i64::MIN, -1), Ok(0));
+        assert!(eval_num_op(ArithOp::Modulo, i64::MIN, 0).is_err());
     }
 
     proptest! {
@@ -497,6 +630,8 @@ mod tests {
             prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
             prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
             prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(),
+                    if r == 0 { None } else { Some(l.wrapping_rem(r)) });
             prop_assert_eq!(eval_num_op::<i64>(ArithOp::Max, l, r).unwrap(), l.max(r));
             prop_assert_eq!(eval_num_op::<i64>(ArithOp::Min, l, r).unwrap(), l.min(r));
 
@@ -516,6 +651,8 @@ mod tests {
             prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
             prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
             prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(),
+                    if r == 0 { None } else { Some(l.wrapping_rem(r)) });
             prop_assert_eq!(eval_num_op::<i32>(ArithOp::Max, l, r).unwrap(), l.max(r));
             prop_assert_eq!(eval_num_op::<i32>(ArithOp::Min, l, r).unwrap(), l.min(r));
 
@@ -535,6 +672,8 @@ mod tests {
             prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
             prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
             prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(),
+                    if r == 0 { None } else { Some(l.wrapping_rem(r)) });
             prop_assert_eq!(eval_num_op::<i16>(ArithOp::Max, l, r).unwrap(), l.max(r));
             prop_assert_eq!(eval_num_op::<i16>(ArithOp::Min, l, r).unwrap(), l.min(r));
 
@@ -554,6 +693,8 @@ mod tests {
             prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
             prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
             prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(),
+                    if r == 0 { None } else { Some(l.wrapping_rem(r)) });
             prop_assert_eq!(eval_num_op::<i8>(ArithOp::Max, l, r).unwrap(), l.max(r));
             prop_assert_eq!(eval_num_op::<i8>(ArithOp::Min, l, r).unwrap(), l.min(r));
 
@@ -575,6 +716,8 @@ mod tests {
             prop_assert_eq!(eval_num_op(ArithOp::Minus, l.clone(), r.clone()).ok(), l.checked_sub(&r));
             prop_assert_eq!(eval_num_op(ArithOp::Multiply, l.clone(), r.clone()).ok(), l.checked_mul(&r));
             prop_assert_eq!(eval_num_op(ArithOp::Divide, l.clone(), r.clone()).ok(), l.checked_div(&r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l.clone(), r.clone()).ok(),
+                    if r.is_positive() { Some(((&l % &r) + &r) % &r) } else { None });
             prop_assert_eq!(eval_num_op::<BigInt>(ArithOp::Max, l.clone(),
                     r.clone()).unwrap(), l.clone().max(r.clone()));
             prop_assert_eq!(eval_num_op::<BigInt>(ArithOp::Min, l.clone(),
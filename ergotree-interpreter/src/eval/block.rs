@@ -3,6 +3,7 @@ use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::val_def::ValDef;
 use ergotree_ir::mir::value::Value;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -10,6 +11,8 @@ use crate::eval::Evaluable;
 
 impl Evaluable for BlockValue {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.block_value, self.items.len())?;
         let mut cur_env = env.clone();
         for i in &self.items {
             let val_def = i.clone().try_extract_into::<ValDef>()?;
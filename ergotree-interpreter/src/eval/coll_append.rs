@@ -0,0 +1,53 @@
+use ergotree_ir::mir::coll_append::Append;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::env::Env;
+use crate::eval::scoll;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Append {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(env, ctx)?;
+        let col_2_v = self.col_2.eval(env, ctx)?;
+        match (input_v, col_2_v) {
+            (Value::Coll(coll), Value::Coll(coll_2)) => scoll::append(ctx, coll, coll_2),
+            (input_v, col_2_v) => Err(EvalError::UnexpectedValue(format!(
+                "Append: expected both inputs to be Value::Coll, got: {0:?}",
+                (input_v, col_2_v)
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_byte_colls() {
+        let expr: Expr = Append::new(
+            Expr::Const(vec![1i8, 2i8].into()),
+            Expr::Const(vec![3i8].into()),
+        )
+        .unwrap()
+        .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![1i8, 2, 3]);
+    }
+
+    #[test]
+    fn eval_wrapped_colls() {
+        let expr: Expr = Append::new(
+            Expr::Const(Vec::<i64>::new().into()),
+            Expr::Const(vec![3i64, 4i64].into()),
+        )
+        .unwrap()
+        .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![3i64, 4]);
+    }
+}
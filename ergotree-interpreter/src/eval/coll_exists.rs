@@ -2,6 +2,7 @@ use ergotree_ir::mir::coll_exists::Exists;
 use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::value::Value;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -44,13 +45,20 @@ impl Evaluable for Exists {
             ))),
         }?;
 
+        // stop on the first item satisfying the condition, only the processed items are added
+        // to the cost
+        let mut n_processed = 0;
+        let mut res = false;
         for item in normalized_input_vals {
-            let res = condition_call(item)?.try_extract_into::<bool>()?;
-            if res {
-                return Ok(true.into());
+            n_processed += 1;
+            if condition_call(item)?.try_extract_into::<bool>()? {
+                res = true;
+                break;
             }
         }
-        Ok(false.into())
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.exists_forall, n_processed)?;
+        Ok(res.into())
     }
 }
 
@@ -62,6 +70,7 @@ mod tests {
 
     use super::*;
 
+    use ergotree_ir::mir::bin_op::ArithOp;
     use ergotree_ir::mir::bin_op::BinOp;
     use ergotree_ir::mir::bin_op::RelationOp;
     use ergotree_ir::mir::expr::Expr;
@@ -116,4 +125,42 @@ mod tests {
     fn eval_false() {
         check(vec![2, 2]);
     }
+
+    #[test]
+    fn eval_laziness() {
+        // { (x: Int) => 0 < 1 / x } fails on 0, which should not be reached
+        let body: Expr = BinOp {
+            kind: RelationOp::Lt.into(),
+            left: Box::new(Expr::Const(0i32.into())),
+            right: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(Expr::Const(1i32.into())),
+                    right: Box::new(
+                        ValUse {
+                            val_id: 1.into(),
+                            tpe: SType::SInt,
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let expr: Expr = Exists::new(
+            vec![1i32, 0].into(),
+            FuncValue::new(
+                vec![FuncArg {
+                    idx: 1.into(),
+                    tpe: SType::SInt,
+                }],
+                body,
+            )
+            .into(),
+        )
+        .unwrap()
+        .into();
+        assert!(eval_out_wo_ctx::<bool>(&expr));
+    }
 }
@@ -0,0 +1,59 @@
+use ergotree_ir::mir::coll_slice::Slice;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::env::Env;
+use crate::eval::scoll;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Slice {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(env, ctx)?;
+        let from = self.from.eval(env, ctx)?.try_extract_into::<i32>()?;
+        let until = self.until.eval(env, ctx)?.try_extract_into::<i32>()?;
+        match input_v {
+            Value::Coll(coll) => scoll::slice(ctx, coll, from, until),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Slice: expected input to be Value::Coll, got: {0:?}",
+                input_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    fn slice_expr(input: Expr, from: i32, until: i32) -> Expr {
+        Slice::new(input, Expr::Const(from.into()), Expr::Const(until.into()))
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_byte_coll() {
+        let expr = slice_expr(Expr::Const(vec![1i8, 2, 3, 4].into()), 1, 3);
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![2i8, 3]);
+    }
+
+    #[test]
+    fn eval_wrapped_coll() {
+        let expr = slice_expr(Expr::Const(vec![1i64, 2, 3, 4].into()), 2, 10);
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![3i64, 4]);
+    }
+
+    #[test]
+    fn eval_out_of_range_bounds() {
+        let expr = slice_expr(Expr::Const(vec![1i64, 2, 3].into()), -1, 2);
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![1i64, 2]);
+        let expr = slice_expr(Expr::Const(vec![1i8, 2, 3].into()), 2, 1);
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), Vec::<i8>::new());
+    }
+}
@@ -6,6 +6,7 @@ use ergotree_ir::mir::value::NativeColl;
 use ergotree_ir::mir::value::Value;
 use ergotree_ir::types::stype::SType;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -13,6 +14,12 @@ use crate::eval::Evaluable;
 
 impl Evaluable for Collection {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let n_items = match self {
+            Collection::BoolConstants(bools) => bools.len(),
+            Collection::Exprs { items, .. } => items.len(),
+        };
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.collection, n_items)?;
         Ok(match self {
             Collection::BoolConstants(bools) => bools.clone().into(),
             Collection::Exprs { elem_tpe, items } => {
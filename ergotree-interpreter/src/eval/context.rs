@@ -1,10 +1,11 @@
-mod ir_ergo_box_dummy;
+pub(crate) mod ir_ergo_box_dummy;
 
 use std::rc::Rc;
 
 use crate::sigma_protocol::prover::ContextExtension;
 use ergotree_ir::ir_ergo_box::IrBoxId;
 use ergotree_ir::ir_ergo_box::IrErgoBoxArena;
+use ergotree_ir::mir::header::Header;
 use ergotree_ir::mir::header::PreHeader;
 
 /// Interpreter's context (blockchain state)
@@ -24,8 +25,12 @@ pub struct Context {
     pub inputs: Vec<IrBoxId>,
     /// Pre header of current block
     pub pre_header: PreHeader,
+    /// Fixed number of last block headers in descending order (first header is the newest one)
+    pub headers: [Header; 10],
     /// prover-defined key-value pairs, that may be used inside a script
     pub extension: ContextExtension,
+    /// Maximum cost of the script evaluation (in block cost units), `None` for no limit
+    pub cost_limit: Option<u64>,
 }
 
 #[cfg(feature = "arbitrary")]
@@ -35,6 +40,7 @@ mod arbitrary {
     use super::ir_ergo_box_dummy::*;
     use super::*;
     use ergotree_ir::ir_ergo_box::IrErgoBox;
+    use proptest::array::uniform10;
     use proptest::collection::vec;
     use proptest::prelude::*;
 
@@ -50,9 +56,19 @@ mod arbitrary {
                 vec(any::<IrErgoBoxDummy>(), 0..3),
                 any::<PreHeader>(),
                 any::<ContextExtension>(),
+                uniform10(any::<Header>()),
             )
                 .prop_map(
-                    |(height, self_box, outputs, inputs, data_inputs, pre_header, extension)| {
+                    |(
+                        height,
+                        self_box,
+                        outputs,
+                        inputs,
+                        data_inputs,
+                        pre_header,
+                        extension,
+                        headers,
+                    )| {
                         let self_box_id = self_box.id();
                         let outputs_ids = outputs.iter().map(|b| b.id()).collect();
                         let inputs_ids = inputs.iter().map(|b| b.id()).collect();
@@ -77,7 +93,9 @@ mod arbitrary {
                             data_inputs: data_inputs_ids,
                             inputs: inputs_ids,
                             pre_header,
+                            headers,
                             extension,
+                            cost_limit: None,
                         }
                     },
                 )
@@ -31,6 +31,17 @@ pub(crate) struct IrErgoBoxDummy {
     creation_info: (i32, Vec<i8>),
 }
 
+impl IrErgoBoxDummy {
+    /// Replace the additional registers (R4..R9)
+    #[cfg(test)]
+    pub(crate) fn with_additional_registers(self, additional_registers: Vec<Constant>) -> Self {
+        IrErgoBoxDummy {
+            additional_registers,
+            ..self
+        }
+    }
+}
+
 impl IrErgoBox for IrErgoBoxDummy {
     fn id(&self) -> IrBoxId {
         self.id.clone()
@@ -54,7 +65,10 @@ impl IrErgoBox for IrErgoBoxDummy {
             1 => Some(self.script_bytes.clone().into()),
             2 => Some(self.tokens.clone().into()),
             3 => Some(self.creation_info.clone().into()),
-            _ => self.additional_registers.get(id as usize).cloned(),
+            _ => (id as usize)
+                .checked_sub(4)
+                .and_then(|idx| self.additional_registers.get(idx))
+                .cloned(),
         }
     }
 
@@ -1,7 +1,8 @@
-use super::costs::{Cost, Costs};
+use super::costs::{Cost, Costs, PerItemCost};
 use ergotree_ir::mir::expr::Expr;
 use thiserror::Error;
 
+/// Accumulates the cost of the evaluation (in JIT cost units) and checks it against the limit
 #[derive(Debug)]
 pub struct CostAccumulator {
     costs: Costs,
@@ -9,17 +10,20 @@ pub struct CostAccumulator {
     limit: Option<u64>,
 }
 
+/// Cost accumulation errors
 #[derive(Error, PartialEq, Eq, Debug, Clone)]
 pub enum CostError {
+    /// Accumulated cost exceeded the limit (in block cost units)
     #[error("Limit ({0}) exceeded")]
     LimitExceeded(u64),
 }
 
 impl CostAccumulator {
+    /// `initial_cost` and `cost_limit` are in block cost units
     pub fn new(initial_cost: u64, cost_limit: Option<u64>) -> CostAccumulator {
         CostAccumulator {
             costs: Costs::DEFAULT,
-            accum: initial_cost,
+            accum: Cost::from_block_cost(initial_cost),
             limit: cost_limit,
         }
     }
@@ -29,13 +33,41 @@ impl CostAccumulator {
         self.add(cost)
     }
 
+    /// Adds the cost of processing the given number of items (without the base cost of the
+    /// operation, which is added in [`CostAccumulator::add_cost_of`])
+    pub fn add_items_cost(&mut self, cost: PerItemCost, n_items: usize) -> Result<(), CostError> {
+        self.add(cost.items_cost(n_items))
+    }
+
     pub fn add(&mut self, cost: Cost) -> Result<(), CostError> {
-        self.accum += u32::from(cost) as u64;
+        self.add_jit_cost(u32::from(cost) as u64)
+    }
+
+    pub fn add_jit_cost(&mut self, jit_cost: u64) -> Result<(), CostError> {
+        self.accum = self.accum.saturating_add(jit_cost);
         if let Some(limit) = self.limit {
-            if self.accum > limit {
+            if self.accum > Cost::from_block_cost(limit) {
                 return Err(CostError::LimitExceeded(limit));
             }
         }
         Ok(())
     }
+
+    /// Accumulated cost in block cost units
+    pub fn total_cost(&self) -> u64 {
+        Cost::to_block_cost(self.accum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_exceeded() {
+        let mut acc = CostAccumulator::new(0, Some(1));
+        assert_eq!(acc.add(Cost::from(10)), Ok(()));
+        assert_eq!(acc.total_cost(), 1);
+        assert_eq!(acc.add(Cost::from(1)), Err(CostError::LimitExceeded(1)));
+    }
 }
@@ -1,22 +1,475 @@
+//! Costs of the ErgoTree operations
+//! The values (and units) follow the JIT cost model of the reference implementation
+//! (`sigmastate` v5, see `ErgoTreeEvaluator` and the `costKind` of each operation).
+//! One block cost unit is 10 JIT cost units (see [`Cost::to_block_cost`]).
+
+use ergotree_ir::mir::bin_op::ArithOp;
+use ergotree_ir::mir::bin_op::BinOpKind;
+use ergotree_ir::mir::bin_op::RelationOp;
 use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::global_vars::GlobalVars;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
 
 extern crate derive_more;
 use derive_more::{From, Into};
 
-#[derive(PartialEq, Eq, Debug, Clone, From, Into)]
+/// Cost in JIT cost units
+#[derive(PartialEq, Eq, Debug, Clone, Copy, From, Into)]
 pub struct Cost(u32);
 
+impl Cost {
+    /// Convert to block cost units (used in the block cost limit)
+    pub fn to_block_cost(jit_cost: u64) -> u64 {
+        jit_cost / 10
+    }
+
+    /// Convert from block cost units
+    pub fn from_block_cost(block_cost: u64) -> u64 {
+        block_cost.saturating_mul(10)
+    }
+}
+
+/// Cost of the operation which depends on the number of the processed items (collection
+/// elements, bytes, etc.). The items are processed in chunks of `chunk_size` items, with
+/// `per_chunk` cost for each chunk.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct PerItemCost {
+    /// cost of the operation itself (regardless of the number of items)
+    pub base: Cost,
+    /// cost of processing each chunk of items
+    pub per_chunk: Cost,
+    /// number of items in a chunk
+    pub chunk_size: usize,
+}
+
+impl PerItemCost {
+    const fn new(base: u32, per_chunk: u32, chunk_size: usize) -> PerItemCost {
+        PerItemCost {
+            base: Cost(base),
+            per_chunk: Cost(per_chunk),
+            chunk_size,
+        }
+    }
+
+    /// Number of chunks for the given number of items (at least 1)
+    pub fn chunks(&self, n_items: usize) -> usize {
+        n_items.saturating_sub(1) / self.chunk_size + 1
+    }
+
+    /// Cost of processing the given number of items (without the base cost)
+    pub fn items_cost(&self, n_items: usize) -> Cost {
+        let cost = (self.per_chunk.0 as u64).saturating_mul(self.chunks(n_items) as u64);
+        Cost(cost.min(u32::MAX as u64) as u32)
+    }
+
+    /// Total cost of the operation processing the given number of items
+    pub fn cost(&self, n_items: usize) -> Cost {
+        Cost(self.base.0.saturating_add(self.items_cost(n_items).0))
+    }
+}
+
+/// Cost table
 #[derive(Debug)]
 pub struct Costs {
-    pub eq_const_size: Cost,
+    /// Constant
+    pub constant: Cost,
+    /// ValUse
+    pub val_use: Cost,
+    /// FuncValue
+    pub func_value: Cost,
+    /// Apply
+    pub apply: Cost,
+    /// BlockValue (per ValDef item)
+    pub block_value: PerItemCost,
+    /// Arithmetic operations (+, -, *, /, %, min, max)
+    pub arith_op: Cost,
+    /// Bitwise operations (|, &, ^)
+    pub bit_op: Cost,
+    /// Comparison (<, <=, >, >=)
+    pub comparison: Cost,
+    /// Equality of primitive values
+    pub eq: Cost,
+    /// Equality of collections (per element)
+    pub eq_coll: PerItemCost,
+    /// Lazy && and || for booleans
+    pub bin_logic: Cost,
+    /// Logical not
+    pub logical_not: Cost,
+    /// Numeric negation
+    pub negation: Cost,
+    /// Numeric upcast
+    pub upcast: Cost,
+    /// Numeric downcast
+    pub downcast: Cost,
+    /// Numeric toBytes/toBits (per byte of the numeric value)
+    pub numeric_to_bytes: PerItemCost,
+    /// If-then-else
+    pub if_op: Cost,
+    /// Global
+    pub global: Cost,
+    /// Context
+    pub context: Cost,
+    /// HEIGHT
+    pub height: Cost,
+    /// INPUTS, OUTPUTS
+    pub inputs_outputs: Cost,
+    /// SELF
+    pub self_box: Cost,
+    /// minerPubKey
+    pub miner_pub_key: Cost,
+    /// ConcreteCollection (per element)
+    pub collection: PerItemCost,
+    /// Tuple
+    pub tuple: Cost,
+    /// SelectField
+    pub select_field: Cost,
+    /// Collection size
+    pub size_of: Cost,
+    /// Collection element access
+    pub by_index: Cost,
+    /// Map (per element)
+    pub map: PerItemCost,
+    /// Filter (per element)
+    pub filter: PerItemCost,
+    /// Fold (per element)
+    pub fold: PerItemCost,
+    /// Exists, ForAll (per element)
+    pub exists_forall: PerItemCost,
+    /// Collection methods producing a new collection (flatMap, indexOf, etc.) (per element)
+    pub coll_method: PerItemCost,
+    /// Concatenation of two collections (per element of the result)
+    pub append: PerItemCost,
+    /// Collection slice (per element of the result)
+    pub slice: PerItemCost,
+    /// &&, || on collection of booleans (per element)
+    pub and_or: PerItemCost,
+    /// Xor of byte arrays (per byte)
+    pub xor: PerItemCost,
+    /// atLeast (per child)
+    pub atleast: PerItemCost,
+    /// SigmaAnd, SigmaOr (per child)
+    pub sigma_and_or: PerItemCost,
+    /// BoolToSigmaProp
+    pub bool_to_sigma: Cost,
+    /// SigmaPropBytes (per node in the proposition tree)
+    pub sigma_prop_bytes: PerItemCost,
+    /// blake2b256 (per input byte)
+    pub blake2b256: PerItemCost,
+    /// sha256 (per input byte)
+    pub sha256: PerItemCost,
+    /// Box.value
+    pub extract_amount: Cost,
+    /// Box.propositionBytes
+    pub extract_script_bytes: Cost,
+    /// Box.bytes, Box.bytesWithoutRef
+    pub extract_bytes: Cost,
+    /// Box.id
+    pub extract_id: Cost,
+    /// Box.creationInfo
+    pub extract_creation_info: Cost,
+    /// Box.R0..R9
+    pub extract_register_as: Cost,
+    /// Option.get
+    pub option_get: Cost,
+    /// Option.getOrElse
+    pub option_get_or_else: Cost,
+    /// Option constructors (SomeValue, NoneValue)
+    pub option_value: Cost,
+    /// Option.isEmpty
+    pub option_is_empty: Cost,
+    /// Option.map
+    pub option_map: Cost,
+    /// Option.filter
+    pub option_filter: Cost,
+    /// Option.isDefined
+    pub option_is_defined: Cost,
+    /// proveDlog
+    pub create_prove_dlog: Cost,
+    /// proveDHTuple
+    pub create_prove_dh_tuple: Cost,
+    /// decodePoint
+    pub decode_point: Cost,
+    /// groupGenerator
+    pub group_generator: Cost,
+    /// GroupElement exponentiation (exp)
+    pub exponentiate: Cost,
+    /// GroupElement multiplication (multiply)
+    pub multiply_group: Cost,
+    /// GroupElement.getEncoded
+    pub get_encoded: Cost,
+    /// GroupElement.negate
+    pub negate_group: Cost,
+    /// BigInt modulo the group order (modQ, plusModQ, minusModQ)
+    pub mod_q: Cost,
+    /// longToByteArray
+    pub long_to_byte_array: Cost,
+    /// byteArrayToLong
+    pub byte_array_to_long: Cost,
+    /// byteArrayToBigInt
+    pub byte_array_to_bigint: Cost,
+    /// getVar
+    pub get_var: Cost,
+    /// deserializeContext, deserializeRegister
+    pub deserialize: Cost,
+    /// substConstants (per substituted constant)
+    pub subst_constants: PerItemCost,
+    /// Method and property calls (without the cost of the method-specific processing)
+    pub method_call: Cost,
+    /// AvlTree: creating the verifier from the proof (per proof byte)
+    pub avl_create_verifier: PerItemCost,
+    /// AvlTree: lookup operation (per tree level)
+    pub avl_lookup: PerItemCost,
+    /// AvlTree: insert operation (per tree level)
+    pub avl_insert: PerItemCost,
+    /// AvlTree: update operation (per tree level)
+    pub avl_update: PerItemCost,
+    /// AvlTree: remove operation (per tree level)
+    pub avl_remove: PerItemCost,
+    /// Parsing of the ProveDlog/ProveDhTuple leaf of the proposition from the signature
+    pub parse_sig_prop: Cost,
+    /// Computing commitments (verifier step 4) for ProveDlog
+    pub compute_commitments_schnorr: Cost,
+    /// Computing commitments (verifier step 4) for ProveDhTuple
+    pub compute_commitments_dht: Cost,
+    /// Serialization of ProveDlog leaf for the Fiat-Shamir hash
+    pub to_bytes_schnorr: Cost,
+    /// Serialization of ProveDhTuple leaf for the Fiat-Shamir hash
+    pub to_bytes_dht: Cost,
+    /// Serialization of CAND/COR/CTHRESHOLD node for the Fiat-Shamir hash
+    pub to_bytes_proof_node: Cost,
+    /// Computing the challenges of the CTHRESHOLD children (per child)
+    pub compute_challenges_threshold: PerItemCost,
+    /// Fiat-Shamir hash (per byte of the serialized tree)
+    pub fiat_shamir_hash: PerItemCost,
 }
 
 impl Costs {
+    /// Default cost table
     pub const DEFAULT: Costs = Costs {
-        eq_const_size: Cost(1),
+        constant: Cost(5),
+        val_use: Cost(5),
+        func_value: Cost(5),
+        apply: Cost(30),
+        block_value: PerItemCost::new(1, 1, 10),
+        arith_op: Cost(15),
+        bit_op: Cost(20),
+        comparison: Cost(20),
+        eq: Cost(3),
+        eq_coll: PerItemCost::new(10, 2, 8),
+        bin_logic: Cost(20),
+        logical_not: Cost(15),
+        negation: Cost(30),
+        upcast: Cost(10),
+        downcast: Cost(10),
+        numeric_to_bytes: PerItemCost::new(10, 1, 1),
+        if_op: Cost(10),
+        global: Cost(10),
+        context: Cost(1),
+        height: Cost(26),
+        inputs_outputs: Cost(10),
+        self_box: Cost(10),
+        miner_pub_key: Cost(20),
+        collection: PerItemCost::new(20, 1, 10),
+        tuple: Cost(15),
+        select_field: Cost(10),
+        size_of: Cost(14),
+        by_index: Cost(30),
+        map: PerItemCost::new(20, 1, 10),
+        filter: PerItemCost::new(20, 1, 10),
+        fold: PerItemCost::new(3, 1, 10),
+        exists_forall: PerItemCost::new(3, 1, 10),
+        coll_method: PerItemCost::new(20, 1, 10),
+        append: PerItemCost::new(20, 2, 100),
+        slice: PerItemCost::new(10, 2, 100),
+        and_or: PerItemCost::new(10, 5, 32),
+        xor: PerItemCost::new(10, 2, 128),
+        atleast: PerItemCost::new(20, 3, 5),
+        sigma_and_or: PerItemCost::new(10, 2, 1),
+        bool_to_sigma: Cost(15),
+        sigma_prop_bytes: PerItemCost::new(35, 6, 1),
+        blake2b256: PerItemCost::new(20, 7, 128),
+        sha256: PerItemCost::new(80, 8, 64),
+        extract_amount: Cost(8),
+        extract_script_bytes: Cost(12),
+        extract_bytes: Cost(12),
+        extract_id: Cost(12),
+        extract_creation_info: Cost(16),
+        extract_register_as: Cost(50),
+        option_get: Cost(15),
+        option_get_or_else: Cost(20),
+        option_value: Cost(10),
+        option_is_empty: Cost(15),
+        option_map: Cost(20),
+        option_filter: Cost(20),
+        option_is_defined: Cost(10),
+        create_prove_dlog: Cost(10),
+        create_prove_dh_tuple: Cost(20),
+        decode_point: Cost(300),
+        group_generator: Cost(10),
+        exponentiate: Cost(900),
+        multiply_group: Cost(40),
+        get_encoded: Cost(250),
+        negate_group: Cost(45),
+        mod_q: Cost(20),
+        long_to_byte_array: Cost(17),
+        byte_array_to_long: Cost(16),
+        byte_array_to_bigint: Cost(30),
+        get_var: Cost(100),
+        deserialize: Cost(1000),
+        subst_constants: PerItemCost::new(100, 100, 1),
+        method_call: Cost(10),
+        avl_create_verifier: PerItemCost::new(110, 20, 64),
+        avl_lookup: PerItemCost::new(40, 10, 1),
+        avl_insert: PerItemCost::new(40, 10, 1),
+        avl_update: PerItemCost::new(120, 20, 1),
+        avl_remove: PerItemCost::new(100, 15, 1),
+        parse_sig_prop: Cost(10),
+        compute_commitments_schnorr: Cost(3400),
+        compute_commitments_dht: Cost(6450),
+        to_bytes_schnorr: Cost(570),
+        to_bytes_dht: Cost(680),
+        to_bytes_proof_node: Cost(15),
+        compute_challenges_threshold: PerItemCost::new(10, 30, 1),
+        fiat_shamir_hash: PerItemCost::new(12, 2, 128),
     };
 
-    pub fn cost_of(&self, _: &Expr) -> Cost {
-        Cost(1)
+    /// Cost of evaluating the given node (without the cost of evaluating its children).
+    /// For the operations with [`PerItemCost`] only the base cost is returned, the cost of the
+    /// processed items is added in the evaluation of the operation.
+    pub fn cost_of(&self, expr: &Expr) -> Cost {
+        match expr {
+            Expr::Const(_) => self.constant,
+            Expr::ConstPlaceholder(_) => self.constant,
+            Expr::ValUse(_) => self.val_use,
+            Expr::ValDef(_) => Cost(0),
+            Expr::FuncValue(_) => self.func_value,
+            Expr::Apply(_) => self.apply,
+            Expr::BlockValue(_) => self.block_value.base,
+            Expr::BinOp(op) => match op.kind {
+                BinOpKind::Arith(ArithOp::BitOr)
+                | BinOpKind::Arith(ArithOp::BitAnd)
+                | BinOpKind::Arith(ArithOp::BitXor) => self.bit_op,
+                BinOpKind::Arith(_) => self.arith_op,
+                BinOpKind::Relation(RelationOp::Eq) | BinOpKind::Relation(RelationOp::NEq) => {
+                    self.eq
+                }
+                BinOpKind::Relation(RelationOp::And) | BinOpKind::Relation(RelationOp::Or) => {
+                    self.bin_logic
+                }
+                BinOpKind::Relation(_) => self.comparison,
+            },
+            Expr::LogicalNot(_) => self.logical_not,
+            Expr::Negation(_) => self.negation,
+            Expr::Upcast(_) => self.upcast,
+            Expr::Downcast(_) => self.downcast,
+            Expr::If(_) => self.if_op,
+            Expr::Global => self.global,
+            Expr::Context => self.context,
+            Expr::GlobalVars(v) => match v {
+                GlobalVars::Height => self.height,
+                GlobalVars::Inputs | GlobalVars::Outputs => self.inputs_outputs,
+                GlobalVars::SelfBox => self.self_box,
+                GlobalVars::MinerPubKey => self.miner_pub_key,
+            },
+            Expr::Collection(_) => self.collection.base,
+            Expr::Tuple(_) => self.tuple,
+            Expr::SelectField(_) => self.select_field,
+            Expr::SizeOf(_) => self.size_of,
+            Expr::ByIndex(_) => self.by_index,
+            Expr::Append(_) => self.append.base,
+            Expr::Slice(_) => self.slice.base,
+            Expr::Map(_) => self.map.base,
+            Expr::Filter(_) => self.filter.base,
+            Expr::Fold(_) => self.fold.base,
+            Expr::Exists(_) | Expr::ForAll(_) => self.exists_forall.base,
+            Expr::And(_) | Expr::Or(_) => self.and_or.base,
+            Expr::Xor(_) => self.xor.base,
+            Expr::Atleast(_) => self.atleast.base,
+            Expr::SigmaAnd(_) | Expr::SigmaOr(_) => self.sigma_and_or.base,
+            Expr::BoolToSigmaProp(_) => self.bool_to_sigma,
+            Expr::SigmaPropBytes(_) => self.sigma_prop_bytes.base,
+            Expr::CalcBlake2b256(_) => self.blake2b256.base,
+            Expr::CalcSha256(_) => self.sha256.base,
+            Expr::ExtractAmount(_) => self.extract_amount,
+            Expr::ExtractScriptBytes(_) => self.extract_script_bytes,
+            Expr::ExtractBytes(_) | Expr::ExtractBytesWithNoRef(_) => self.extract_bytes,
+            Expr::ExtractId(_) => self.extract_id,
+            Expr::ExtractCreationInfo(_) => self.extract_creation_info,
+            Expr::ExtractRegisterAs(_) => self.extract_register_as,
+            Expr::OptionGet(_) => self.option_get,
+            Expr::OptionGetOrElse(_) => self.option_get_or_else,
+            Expr::SomeValue(_) | Expr::NoneValue(_) => self.option_value,
+            Expr::OptionIsDefined(_) => self.option_is_defined,
+            Expr::CreateProveDlog(_) => self.create_prove_dlog,
+            Expr::CreateProveDhTuple(_) => self.create_prove_dh_tuple,
+            Expr::DecodePoint(_) => self.decode_point,
+            Expr::GroupGenerator => self.group_generator,
+            Expr::Exponentiate(_) => self.exponentiate,
+            Expr::MultiplyGroup(_) => self.multiply_group,
+            Expr::ModQ(_) | Expr::PlusModQ(_) | Expr::MinusModQ(_) => self.mod_q,
+            Expr::LongToByteArray(_) => self.long_to_byte_array,
+            Expr::ByteArrayToLong(_) => self.byte_array_to_long,
+            Expr::ByteArrayToBigInt(_) => self.byte_array_to_bigint,
+            Expr::GetVar(_) => self.get_var,
+            Expr::DeserializeContext(_) | Expr::DeserializeRegister(_) => self.deserialize,
+            Expr::SubstConstants(_) => self.subst_constants.base,
+            Expr::MethodCall(_) | Expr::ProperyCall(_) => self.method_call,
+        }
+    }
+
+    /// Estimated cost of the proof verification (verifier steps 1-6) for the given proposition
+    /// (see `Interpreter.verifySignature` in the reference implementation).
+    /// `fiat_shamir_input_len` - estimated length of the Fiat-Shamir hash input (proof and message)
+    pub fn sigma_verification_cost(&self, sb: &SigmaBoolean, fiat_shamir_input_len: usize) -> u64 {
+        self.sigma_tree_cost(sb)
+            + u32::from(self.fiat_shamir_hash.cost(fiat_shamir_input_len)) as u64
+    }
+
+    fn sigma_tree_cost(&self, sb: &SigmaBoolean) -> u64 {
+        match sb {
+            SigmaBoolean::TrivialProp(_) => 0,
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(_)) => {
+                (self.parse_sig_prop.0
+                    + self.compute_commitments_schnorr.0
+                    + self.to_bytes_schnorr.0) as u64
+            }
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(_)) => {
+                (self.parse_sig_prop.0 + self.compute_commitments_dht.0 + self.to_bytes_dht.0)
+                    as u64
+            }
+            SigmaBoolean::SigmaConjecture(conj) => {
+                let children = conj.children();
+                let node_cost = match conj {
+                    SigmaConjecture::Cthreshold(_) => {
+                        self.to_bytes_proof_node.0
+                            + self.compute_challenges_threshold.cost(children.len()).0
+                    }
+                    _ => self.to_bytes_proof_node.0,
+                };
+                node_cost as u64
+                    + children
+                        .iter()
+                        .map(|c| self.sigma_tree_cost(c))
+                        .sum::<u64>()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_item_cost() {
+        let c = PerItemCost::new(10, 2, 8);
+        assert_eq!(c.chunks(0), 1);
+        assert_eq!(c.chunks(1), 1);
+        assert_eq!(c.chunks(8), 1);
+        assert_eq!(c.chunks(9), 2);
+        assert_eq!(c.cost(0), Cost(12));
+        assert_eq!(c.cost(17), Cost(16));
     }
 }
@@ -1,15 +1,167 @@
+use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::deserialize_context::DeserializeContext;
+use ergotree_ir::mir::expr::Expr;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::sigma_byte_reader;
+use ergotree_ir::serialization::sigma_byte_reader::SigmaByteRead;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::types::stype::SType;
 
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
 use crate::eval::Evaluable;
-// use ergotree_ir::mir::constant::TryExtractInto;
-// use ergotree_ir::serialization::SigmaSerializable;
 
 impl Evaluable for DeserializeContext {
-    fn eval(&self, _env: &Env, _ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        todo!()
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bytes = match ctx.ctx.extension.values.get(&self.id) {
+            Some(c) if c.tpe == SType::SColl(SType::SByte.into()) => c.v.clone(),
+            Some(c) => {
+                return Err(EvalError::UnexpectedValue(format!(
+                "DeserializeContext: expected extension value {} to have type Coll[Byte], got {:?}",
+                self.id, c.tpe
+            )))
+            }
+            None => {
+                return Err(EvalError::NotFound(format!(
+                    "DeserializeContext: no value with id {} in context extension",
+                    self.id
+                )))
+            }
+        };
+        eval_deserialized(bytes, &self.tpe, env, ctx)
+    }
+}
+
+/// Parses the script from the given bytes, checks its type and evaluates it in the given
+/// environment. As in sigmastate, the deserialized script may not contain
+/// DeserializeContext/DeserializeRegister nodes (otherwise a script could deserialize itself
+/// without bound).
+pub(crate) fn eval_deserialized(
+    bytes: Value,
+    tpe: &SType,
+    env: &Env,
+    ctx: &mut EvalContext,
+) -> Result<Value, EvalError> {
+    let mut r = sigma_byte_reader::from_bytes(bytes.try_extract_into::<Vec<u8>>()?);
+    let expr = Expr::sigma_parse(&mut r)?;
+    if r.was_deserialize() {
+        return Err(EvalError::UnexpectedExpr(
+            "deserialized script contains DeserializeContext/DeserializeRegister".to_string(),
+        ));
+    }
+    if expr.tpe() != *tpe {
+        return Err(EvalError::UnexpectedExpr(format!(
+            "deserialized script has type {:?}, expected {:?}",
+            expr.tpe(),
+            tpe
+        )));
+    }
+    expr.eval(env, ctx)
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::constant::Constant;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    const VAR_IDX: u8 = 3;
+
+    /// Prepare context with single extension variable
+    fn prepare_context(var: Constant) -> Rc<Context> {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.clear();
+        ctx.extension.values.insert(VAR_IDX, var);
+        Rc::new(ctx)
+    }
+
+    fn script() -> Expr {
+        BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(Expr::Const(1i32.into())),
+            right: Box::new(Expr::Const(2i32.into())),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_success() {
+        let ctx = prepare_context(script().sigma_serialize_bytes().into());
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SInt,
+            id: VAR_IDX,
+        }
+        .into();
+        assert_eq!(eval_out::<i32>(&expr, ctx), 3);
+    }
+
+    #[test]
+    fn eval_missing_var() {
+        let ctx = prepare_context(script().sigma_serialize_bytes().into());
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SInt,
+            id: VAR_IDX + 1,
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_wrong_var_type() {
+        let ctx = prepare_context(1i32.into());
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SInt,
+            id: VAR_IDX,
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_wrong_script_type() {
+        let ctx = prepare_context(script().sigma_serialize_bytes().into());
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SLong,
+            id: VAR_IDX,
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_malformed_script() {
+        let mut bytes = script().sigma_serialize_bytes();
+        bytes.truncate(bytes.len() - 1);
+        let ctx = prepare_context(bytes.into());
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SInt,
+            id: VAR_IDX,
+        }
+        .into();
+        assert!(matches!(
+            try_eval_out::<Value>(&expr, ctx),
+            Err(EvalError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn eval_self_referencing_script() {
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SInt,
+            id: VAR_IDX,
+        }
+        .into();
+        let ctx = prepare_context(expr.sigma_serialize_bytes().into());
+        assert!(matches!(
+            try_eval_out::<Value>(&expr, ctx),
+            Err(EvalError::UnexpectedExpr(_))
+        ));
     }
 }
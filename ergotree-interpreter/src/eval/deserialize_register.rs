@@ -1,15 +1,138 @@
 use ergotree_ir::mir::deserialize_register::DeserializeRegister;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
 
+use crate::eval::deserialize_context::eval_deserialized;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
 use crate::eval::Evaluable;
-// use ergotree_ir::mir::constant::TryExtractInto;
-// use ergotree_ir::serialization::SigmaSerializable;
 
 impl Evaluable for DeserializeRegister {
-    fn eval(&self, _env: &Env, _ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        todo!()
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let reg_value = ctx
+            .ctx
+            .self_box
+            .get_box(&ctx.ctx.box_arena)?
+            .get_register(self.reg as i8);
+        match reg_value {
+            Some(c) if c.tpe == SType::SColl(SType::SByte.into()) => {
+                eval_deserialized(c.v, &self.tpe, env, ctx)
+            }
+            Some(c) => Err(EvalError::UnexpectedValue(format!(
+                "DeserializeRegister: expected register R{} to have type Coll[Byte], got {:?}",
+                self.reg, c.tpe
+            ))),
+            None => match &self.default {
+                Some(default) => default.eval(env, ctx),
+                None => Err(EvalError::NotFound(format!(
+                    "DeserializeRegister: register R{} is empty and no default is provided",
+                    self.reg
+                ))),
+            },
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::context::ir_ergo_box_dummy::IrErgoBoxDummy;
+    use crate::eval::context::ir_ergo_box_dummy::IrErgoBoxDummyArena;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use ergotree_ir::ir_ergo_box::IrErgoBox;
+    use ergotree_ir::ir_ergo_box::IrErgoBoxArena;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::serialization::SigmaSerializable;
+    use sigma_test_util::force_any_val;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    /// Prepare context with SELF box having the given R4
+    fn prepare_context(r4: Constant) -> Rc<Context> {
+        let self_box = force_any_val::<IrErgoBoxDummy>().with_additional_registers(vec![r4]);
+        let mut boxes = HashMap::new();
+        boxes.insert(self_box.id(), self_box.clone());
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box = self_box.id();
+        ctx.box_arena = Rc::new(IrErgoBoxDummyArena(boxes)) as Rc<dyn IrErgoBoxArena>;
+        Rc::new(ctx)
+    }
+
+    fn script_bytes() -> Constant {
+        Expr::Const(true.into()).sigma_serialize_bytes().into()
+    }
+
+    #[test]
+    fn eval_success() {
+        let ctx = prepare_context(script_bytes());
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SBoolean,
+            default: None,
+        }
+        .into();
+        assert!(eval_out::<bool>(&expr, ctx));
+    }
+
+    #[test]
+    fn eval_empty_register() {
+        let ctx = prepare_context(script_bytes());
+        let expr: Expr = DeserializeRegister {
+            reg: 5,
+            tpe: SType::SBoolean,
+            default: None,
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx.clone()).is_err());
+        let expr: Expr = DeserializeRegister {
+            reg: 5,
+            tpe: SType::SBoolean,
+            default: Some(Box::new(Expr::Const(false.into()))),
+        }
+        .into();
+        assert!(!eval_out::<bool>(&expr, ctx));
+    }
+
+    #[test]
+    fn eval_wrong_register_type() {
+        let ctx = prepare_context(1i32.into());
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SBoolean,
+            default: Some(Box::new(Expr::Const(false.into()))),
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_wrong_script_type() {
+        let ctx = prepare_context(script_bytes());
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SInt,
+            default: None,
+        }
+        .into();
+        assert!(try_eval_out::<Value>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_self_referencing_script() {
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SBoolean,
+            default: None,
+        }
+        .into();
+        let ctx = prepare_context(expr.sigma_serialize_bytes().into());
+        assert!(matches!(
+            try_eval_out::<Value>(&expr, ctx),
+            Err(EvalError::UnexpectedExpr(_))
+        ));
     }
 }
@@ -0,0 +1,188 @@
+use std::convert::TryFrom;
+
+use ergotree_ir::mir::downcast::Downcast;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+fn overflow_err(tpe_name: &str, in_v: &Value) -> EvalError {
+    EvalError::ArithmeticException(format!("{0} overflow: {1:?}", tpe_name, in_v))
+}
+
+fn not_numeric_err(tpe_name: &str, in_v: &Value) -> EvalError {
+    EvalError::UnexpectedValue(format!(
+        "Downcast: cannot downcast {0:?} to {1}",
+        in_v, tpe_name
+    ))
+}
+
+// The conversions below accept the values of the narrower types as well, so that they can be
+// reused by the numeric `toByte`, `toShort`, etc. methods
+
+pub(crate) fn downcast_to_bigint(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok(BigInt::from(v).into()),
+        Value::Short(v) => Ok(BigInt::from(v).into()),
+        Value::Int(v) => Ok(BigInt::from(v).into()),
+        Value::Long(v) => Ok(BigInt::from(v).into()),
+        Value::BigInt(_) => Ok(in_v),
+        _ => Err(not_numeric_err("BigInt", &in_v)),
+    }
+}
+
+pub(crate) fn downcast_to_long(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i64).into()),
+        Value::Short(v) => Ok((v as i64).into()),
+        Value::Int(v) => Ok((v as i64).into()),
+        Value::Long(_) => Ok(in_v),
+        Value::BigInt(ref v) => v
+            .to_i64()
+            .map(Value::from)
+            .ok_or_else(|| overflow_err("Long", &in_v)),
+        _ => Err(not_numeric_err("Long", &in_v)),
+    }
+}
+
+pub(crate) fn downcast_to_int(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i32).into()),
+        Value::Short(v) => Ok((v as i32).into()),
+        Value::Int(_) => Ok(in_v),
+        Value::Long(v) => i32::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Int", &in_v)),
+        Value::BigInt(ref v) => v
+            .to_i32()
+            .map(Value::from)
+            .ok_or_else(|| overflow_err("Int", &in_v)),
+        _ => Err(not_numeric_err("Int", &in_v)),
+    }
+}
+
+pub(crate) fn downcast_to_short(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i16).into()),
+        Value::Short(_) => Ok(in_v),
+        Value::Int(v) => i16::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Short", &in_v)),
+        Value::Long(v) => i16::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Short", &in_v)),
+        Value::BigInt(ref v) => v
+            .to_i16()
+            .map(Value::from)
+            .ok_or_else(|| overflow_err("Short", &in_v)),
+        _ => Err(not_numeric_err("Short", &in_v)),
+    }
+}
+
+pub(crate) fn downcast_to_byte(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(_) => Ok(in_v),
+        Value::Short(v) => i8::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Byte", &in_v)),
+        Value::Int(v) => i8::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Byte", &in_v)),
+        Value::Long(v) => i8::try_from(v)
+            .map(Value::from)
+            .map_err(|_| overflow_err("Byte", &in_v)),
+        Value::BigInt(ref v) => v
+            .to_i8()
+            .map(Value::from)
+            .ok_or_else(|| overflow_err("Byte", &in_v)),
+        _ => Err(not_numeric_err("Byte", &in_v)),
+    }
+}
+
+impl Evaluable for Downcast {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(env, ctx)?;
+        match self.tpe {
+            SType::SBigInt => downcast_to_bigint(input_v),
+            SType::SLong => downcast_to_long(input_v),
+            SType::SInt => downcast_to_int(input_v),
+            SType::SShort => downcast_to_short(input_v),
+            SType::SByte => downcast_to_byte(input_v),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Downcast: expected numeric value, got {0:?}",
+                input_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use num_bigint::ToBigInt;
+    use proptest::prelude::*;
+
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    use super::*;
+
+    fn downcast(c: Constant, tpe: SType) -> Expr {
+        Downcast::new(c.into(), tpe).unwrap().into()
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(try_eval_out_wo_ctx::<i8>(&downcast(128i16.into(), SType::SByte)).is_err());
+        assert!(try_eval_out_wo_ctx::<i8>(&downcast((-129i32).into(), SType::SByte)).is_err());
+        assert!(try_eval_out_wo_ctx::<i16>(&downcast(32768i32.into(), SType::SShort)).is_err());
+        assert!(try_eval_out_wo_ctx::<i32>(&downcast(i64::MAX.into(), SType::SInt)).is_err());
+        let big: BigInt = BigInt::from(i64::MAX) + 1;
+        assert!(matches!(
+            try_eval_out_wo_ctx::<i64>(&downcast(big.into(), SType::SLong)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    proptest! {
+
+        #[test]
+        fn from_long(v in any::<i64>()) {
+            let c: Constant = v.into();
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i8>(&downcast(c.clone(), SType::SByte)).ok(),
+                i8::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i16>(&downcast(c.clone(), SType::SShort)).ok(),
+                i16::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i32>(&downcast(c.clone(), SType::SInt)).ok(),
+                i32::try_from(v).ok()
+            );
+            prop_assert_eq!(eval_out_wo_ctx::<i64>(&downcast(c, SType::SLong)), v);
+        }
+
+        #[test]
+        fn from_bigint(v in any::<i64>()) {
+            let c: Constant = v.to_bigint().unwrap().into();
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i8>(&downcast(c.clone(), SType::SByte)).ok(),
+                i8::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i32>(&downcast(c.clone(), SType::SInt)).ok(),
+                i32::try_from(v).ok()
+            );
+            prop_assert_eq!(eval_out_wo_ctx::<i64>(&downcast(c, SType::SLong)), v);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::exponentiate::Exponentiate;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::dlog_group;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use num_bigint::BigInt;
+
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Exponentiate {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let base = self.left.eval(env, ctx)?.try_extract_into::<EcPoint>()?;
+        let exponent = self.right.eval(env, ctx)?.try_extract_into::<BigInt>()?;
+        Ok(dlog_group::exponentiate(&base, &dlog_group::bigint_to_scalar(&exponent)).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+    use k256::Scalar;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn eval_any(base in any::<EcPoint>(), exponent in any::<i64>()) {
+            let expr: Expr = Exponentiate::new(
+                Expr::Const(base.clone().into()),
+                Expr::Const(BigInt::from(exponent).into()),
+            )
+            .unwrap()
+            .into();
+            let scalar = if exponent >= 0 {
+                Scalar::from(exponent as u64)
+            } else {
+                -Scalar::from(exponent.unsigned_abs())
+            };
+            prop_assert_eq!(
+                eval_out_wo_ctx::<EcPoint>(&expr),
+                dlog_group::exponentiate(&base, &scalar)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_generator_exp_one() {
+        let expr: Expr =
+            Exponentiate::new(Expr::GroupGenerator, Expr::Const(BigInt::from(1).into()))
+                .unwrap()
+                .into();
+        assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), dlog_group::generator());
+    }
+}
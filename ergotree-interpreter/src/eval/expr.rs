@@ -1,5 +1,6 @@
 use ergotree_ir::mir::expr::Expr;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::dlog_group;
 
 use super::Env;
 use super::EvalContext;
@@ -25,28 +26,39 @@ impl Evaluable for Expr {
             Expr::Global => Ok(Value::Global),
             Expr::Context => Ok(Value::Context),
             Expr::OptionGet(v) => v.eval(env, ctx),
+            Expr::SomeValue(v) => v.eval(env, ctx),
+            Expr::NoneValue(v) => v.eval(env, ctx),
             Expr::Apply(op) => op.eval(env, ctx),
             Expr::FuncValue(op) => op.eval(env, ctx),
             Expr::ValUse(op) => op.eval(env, ctx),
             Expr::BlockValue(op) => op.eval(env, ctx),
             Expr::SelectField(op) => op.eval(env, ctx),
             Expr::ExtractAmount(op) => op.eval(env, ctx),
-            Expr::ConstPlaceholder(_) => panic!("ConstPlaceholder cannot be evaluated"),
+            Expr::ConstPlaceholder(_) => Err(EvalError::UnexpectedExpr(
+                "ConstPlaceholder cannot be evaluated".to_string(),
+            )),
             Expr::Collection(op) => op.eval(env, ctx),
-            Expr::ValDef(_) => panic!("ValDef is evaluated in BlockValue"),
+            Expr::ValDef(_) => Err(EvalError::UnexpectedExpr(
+                "ValDef is evaluated in BlockValue".to_string(),
+            )),
             Expr::And(op) => op.eval(env, ctx),
             Expr::Or(op) => op.eval(env, ctx),
             Expr::Xor(op) => op.eval(env, ctx),
-            Expr::Atleast(_) => todo!(),
+            Expr::Atleast(op) => op.eval(env, ctx),
             Expr::LogicalNot(op) => op.eval(env, ctx),
             Expr::Map(op) => op.eval(env, ctx),
             Expr::Filter(op) => op.eval(env, ctx),
             Expr::BoolToSigmaProp(op) => op.eval(env, ctx),
             Expr::Upcast(op) => op.eval(env, ctx),
+            Expr::Downcast(op) => op.eval(env, ctx),
             Expr::If(op) => op.eval(env, ctx),
             Expr::ByIndex(op) => op.eval(env, ctx),
             Expr::ExtractScriptBytes(op) => op.eval(env, ctx),
+            Expr::ExtractBytes(op) => op.eval(env, ctx),
+            Expr::ExtractBytesWithNoRef(op) => op.eval(env, ctx),
             Expr::SizeOf(op) => op.eval(env, ctx),
+            Expr::Slice(op) => op.eval(env, ctx),
+            Expr::Append(op) => op.eval(env, ctx),
             Expr::CreateProveDlog(op) => op.eval(env, ctx),
             Expr::CreateProveDhTuple(op) => op.eval(env, ctx),
             Expr::ExtractCreationInfo(op) => op.eval(env, ctx),
@@ -59,10 +71,17 @@ impl Evaluable for Expr {
             Expr::ForAll(op) => op.eval(env, ctx),
             Expr::Tuple(op) => op.eval(env, ctx),
             Expr::DecodePoint(op) => op.eval(env, ctx),
+            Expr::GroupGenerator => Ok(dlog_group::generator().into()),
+            Expr::Exponentiate(op) => op.eval(env, ctx),
+            Expr::MultiplyGroup(op) => op.eval(env, ctx),
+            Expr::ModQ(op) => op.eval(env, ctx),
+            Expr::PlusModQ(op) => op.eval(env, ctx),
+            Expr::MinusModQ(op) => op.eval(env, ctx),
             Expr::SigmaAnd(op) => op.eval(env, ctx),
             Expr::SigmaOr(op) => op.eval(env, ctx),
             Expr::DeserializeRegister(op) => op.eval(env, ctx),
             Expr::DeserializeContext(op) => op.eval(env, ctx),
+            Expr::SubstConstants(op) => op.eval(env, ctx),
             Expr::GetVar(op) => op.eval(env, ctx),
         }
     }
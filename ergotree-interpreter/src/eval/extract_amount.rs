@@ -10,7 +10,7 @@ impl Evaluable for ExtractAmount {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
         let input_v = self.input.eval(env, ctx)?;
         match input_v {
-            Value::CBox(b) => Ok(Value::Long(ctx.ctx.box_arena.get(&b)?.value())),
+            Value::CBox(b) => Ok(Value::Long(b.get_box(&ctx.ctx.box_arena)?.value())),
             _ => Err(EvalError::UnexpectedValue(format!(
                 "Expected ExtractAmount input to be Value::CBox, got {0:?}",
                 input_v
@@ -25,8 +25,16 @@ mod tests {
     use super::*;
     use crate::eval::context::Context;
     use crate::eval::tests::eval_out;
+    use ergotree_ir::ir_ergo_box::IrErgoBox;
+    use ergotree_ir::ir_ergo_box::ParsedErgoBox;
+    use ergotree_ir::mir::constant::Constant;
     use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::get_var::GetVar;
     use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::option_get::OptionGet;
+    use ergotree_ir::mir::unary_op::UnaryOpTryBuild;
+    use ergotree_ir::serialization::SigmaSerializable;
+    use ergotree_ir::types::stype::SType;
     use sigma_test_util::force_any_val;
     use std::rc::Rc;
 
@@ -42,4 +50,29 @@ mod tests {
             ctx.self_box.get_box(&ctx.box_arena).unwrap().value()
         );
     }
+
+    #[test]
+    fn eval_box_from_context_var() {
+        let b = force_any_val::<ParsedErgoBox>();
+        // box is not in the box arena, only in the context extension
+        let c = Constant::sigma_parse_bytes(&Constant::from(b.clone()).sigma_serialize_bytes())
+            .unwrap();
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.insert(1, c);
+        let e: Expr = ExtractAmount {
+            input: Box::new(
+                OptionGet::try_build(
+                    GetVar {
+                        var_id: 1,
+                        var_tpe: SType::SBox,
+                    }
+                    .into(),
+                )
+                .unwrap()
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq!(eval_out::<i64>(&e, Rc::new(ctx)), b.value());
+    }
 }
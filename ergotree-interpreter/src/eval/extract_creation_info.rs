@@ -10,7 +10,7 @@ impl Evaluable for ExtractCreationInfo {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
         let input_v = self.input.eval(env, ctx)?;
         match input_v {
-            Value::CBox(b) => Ok(ctx.ctx.box_arena.get(&b)?.creation_info().into()),
+            Value::CBox(b) => Ok(b.get_box(&ctx.ctx.box_arena)?.creation_info().into()),
             _ => Err(EvalError::UnexpectedValue(format!(
                 "Expected ExtractCreationInfo input to be Value::CBox, got {0:?}",
                 input_v
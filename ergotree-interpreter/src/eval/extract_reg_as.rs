@@ -1,4 +1,4 @@
-use ergotree_ir::ir_ergo_box::IrBoxId;
+use ergotree_ir::ir_ergo_box::IrBox;
 use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::extract_reg_as::ExtractRegisterAs;
 use ergotree_ir::mir::value::Value;
@@ -10,14 +10,17 @@ use crate::eval::Evaluable;
 
 impl Evaluable for ExtractRegisterAs {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        let ir_box_id = self.input.eval(env, ctx)?.try_extract_into::<IrBoxId>()?;
-        Ok(Value::Opt(Box::new(
-            ctx.ctx
-                .box_arena
-                .get(&ir_box_id)?
-                .get_register(self.register_id)
-                .map(|c| c.v),
-        )))
+        let ir_box = self.input.eval(env, ctx)?.try_extract_into::<IrBox>()?;
+        let reg = ir_box
+            .get_box(&ctx.ctx.box_arena)?
+            .get_register(self.register_id);
+        match reg {
+            Some(c) if c.tpe != self.elem_tpe => Err(EvalError::UnexpectedValue(format!(
+                "register R{0} holds the value of type {1:?}, expected {2:?}",
+                self.register_id, c.tpe, self.elem_tpe
+            ))),
+            reg => Ok(Value::Opt(Box::new(reg.map(|c| c.v)))),
+        }
     }
 }
 
@@ -27,6 +30,7 @@ mod tests {
     use super::*;
     use crate::eval::context::Context;
     use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
     use ergotree_ir::mir::expr::Expr;
     use ergotree_ir::mir::global_vars::GlobalVars;
     use ergotree_ir::mir::option_get::OptionGet;
@@ -49,4 +53,18 @@ mod tests {
         let v = eval_out::<i64>(&option_get_expr, ctx.clone());
         assert_eq!(v, ctx.self_box.get_box(&ctx.box_arena).unwrap().value());
     }
+
+    #[test]
+    fn eval_box_get_reg_r0_wrong_type() {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            0,
+            SType::SOption(SType::SInt.into()),
+        )
+        .unwrap()
+        .into();
+        let option_get_expr: Expr = OptionGet::try_build(get_reg_expr).unwrap().into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<i32>(&option_get_expr, ctx).is_err());
+    }
 }
@@ -21,9 +21,11 @@ impl Evaluable for If {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::eval::tests::eval_cost_wo_ctx;
     use crate::eval::tests::eval_out_wo_ctx;
     use ergotree_ir::mir::bin_op::ArithOp;
     use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::calc_blake2b256::CalcBlake2b256;
     use ergotree_ir::mir::expr::Expr;
 
     #[test]
@@ -75,4 +77,29 @@ mod tests {
         let res = eval_out_wo_ctx::<i64>(&expr);
         assert_eq!(res, 1);
     }
+
+    #[test]
+    fn eval_cost_of_taken_branch_only() {
+        let cheap: Expr = Expr::Const(vec![1u8].into());
+        let expensive: Expr = CalcBlake2b256 {
+            input: Box::new(Expr::Const(vec![0u8; 10000].into())),
+        }
+        .into();
+        let if_op = |condition: bool, false_branch: Expr| -> Expr {
+            If {
+                condition: Expr::Const(condition.into()).into(),
+                true_branch: cheap.clone().into(),
+                false_branch: false_branch.into(),
+            }
+            .into()
+        };
+        assert_eq!(
+            eval_cost_wo_ctx(&if_op(true, expensive.clone())),
+            eval_cost_wo_ctx(&if_op(true, cheap.clone()))
+        );
+        assert!(
+            eval_cost_wo_ctx(&if_op(false, expensive))
+                > eval_cost_wo_ctx(&if_op(false, cheap.clone()))
+        );
+    }
 }
@@ -0,0 +1,74 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::minus_mod_q::MinusModQ;
+use ergotree_ir::mir::value::Value;
+use num_bigint::BigInt;
+
+use crate::eval::bigint::mod_q;
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for MinusModQ {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(env, ctx)?.try_extract_into::<BigInt>()?;
+        let right = self.right.eval(env, ctx)?.try_extract_into::<BigInt>()?;
+        Ok(mod_q(&(left - right))?.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::bigint::fits_in_256_bits;
+    use crate::eval::bigint::MAX_BOUND;
+    use crate::eval::bigint::MIN_BOUND;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::dlog_group;
+    use proptest::prelude::*;
+
+    fn eval_minus_mod_q(left: BigInt, right: BigInt) -> Result<BigInt, EvalError> {
+        let expr: Expr = MinusModQ::new(Expr::Const(left.into()), Expr::Const(right.into()))
+            .unwrap()
+            .into();
+        try_eval_out_wo_ctx::<BigInt>(&expr)
+    }
+
+    #[test]
+    fn eval_wraps_around_order() {
+        let q = dlog_group::order_bigint();
+        assert_eq!(
+            eval_minus_mod_q(MAX_BOUND.clone(), MIN_BOUND.clone()).unwrap(),
+            MAX_BOUND.clone() - MIN_BOUND.clone() - q
+        );
+        assert_eq!(
+            eval_minus_mod_q(BigInt::from(7), BigInt::from(2)).unwrap(),
+            BigInt::from(5)
+        );
+    }
+
+    #[test]
+    fn eval_result_overflow() {
+        // q - 1 does not fit into 256-bit BigInt
+        assert!(matches!(
+            eval_minus_mod_q(BigInt::from(1), BigInt::from(2)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_any(l in any::<i64>(), r in any::<i64>()) {
+            let expected = dlog_group::mod_order(&(BigInt::from(l) - BigInt::from(r)));
+            let res = eval_minus_mod_q(BigInt::from(l), BigInt::from(r));
+            if fits_in_256_bits(&expected) {
+                prop_assert_eq!(res.unwrap(), expected);
+            } else {
+                prop_assert!(matches!(res, Err(EvalError::ArithmeticException(_))));
+            }
+        }
+    }
+}
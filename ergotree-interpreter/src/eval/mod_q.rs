@@ -0,0 +1,50 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::mod_q::ModQ;
+use ergotree_ir::mir::value::Value;
+use num_bigint::BigInt;
+
+use crate::eval::bigint::mod_q;
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ModQ {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ctx)?.try_extract_into::<BigInt>()?;
+        Ok(mod_q(&input)?.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::bigint::MAX_BOUND;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+
+    fn eval_mod_q(v: BigInt) -> Result<BigInt, EvalError> {
+        let expr: Expr = ModQ {
+            input: Box::new(Expr::Const(v.into())),
+        }
+        .into();
+        try_eval_out_wo_ctx::<BigInt>(&expr)
+    }
+
+    #[test]
+    fn eval() {
+        assert_eq!(eval_mod_q(BigInt::from(0)).unwrap(), BigInt::from(0));
+        assert_eq!(eval_mod_q(BigInt::from(5)).unwrap(), BigInt::from(5));
+        assert_eq!(eval_mod_q(MAX_BOUND.clone()).unwrap(), MAX_BOUND.clone());
+    }
+
+    #[test]
+    fn eval_result_overflow() {
+        // q - 1 does not fit into 256-bit BigInt
+        assert!(matches!(
+            eval_mod_q(BigInt::from(-1)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::multiply_group::MultiplyGroup;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for MultiplyGroup {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(env, ctx)?.try_extract_into::<EcPoint>()?;
+        let right = self.right.eval(env, ctx)?.try_extract_into::<EcPoint>()?;
+        Ok((left * &right).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::dlog_group;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn eval_any(left in any::<EcPoint>(), right in any::<EcPoint>()) {
+            let expr: Expr = MultiplyGroup::new(
+                Expr::Const(left.clone().into()),
+                Expr::Const(right.clone().into()),
+            )
+            .unwrap()
+            .into();
+            prop_assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), left * &right);
+        }
+
+        #[test]
+        fn eval_inverse(p in any::<EcPoint>()) {
+            let expr: Expr = MultiplyGroup::new(
+                Expr::Const(p.clone().into()),
+                Expr::Const(dlog_group::inverse(&p).into()),
+            )
+            .unwrap()
+            .into();
+            prop_assert!(dlog_group::is_identity(&eval_out_wo_ctx::<EcPoint>(&expr)));
+        }
+    }
+}
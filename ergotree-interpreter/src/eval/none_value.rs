@@ -0,0 +1,31 @@
+use ergotree_ir::mir::none_value::NoneValue;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for NoneValue {
+    fn eval(&self, _env: &Env, _ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        Ok(Value::Opt(Box::new(None)))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::types::stype::SType;
+
+    #[test]
+    fn eval() {
+        let expr: Expr = NoneValue {
+            elem_tpe: SType::SInt,
+        }
+        .into();
+        assert_eq!(eval_out_wo_ctx::<Option<i32>>(&expr), None);
+    }
+}
@@ -1,7 +1,7 @@
-use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::or::Or;
 use ergotree_ir::mir::value::Value;
 
+use crate::eval::and::eval_short_circuit;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -9,23 +9,76 @@ use crate::eval::Evaluable;
 
 impl Evaluable for Or {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
-        let input_v = self.input.eval(env, ctx)?;
-        let input_v_bools = input_v.try_extract_into::<Vec<bool>>()?;
-        Ok(input_v_bools.iter().any(|b| *b).into())
+        Ok(eval_short_circuit(&self.input, true, env, ctx)?.into())
     }
 }
 
+#[allow(clippy::unwrap_used)]
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eval::context::Context;
+    use crate::eval::tests::eval_cost_wo_ctx;
     use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::collection::Collection;
     use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::types::stype::SType;
     use proptest::collection;
     use proptest::prelude::*;
     use sigma_test_util::force_any_val;
     use std::rc::Rc;
 
+    // (1 / 0) == 1, fails if evaluated
+    fn div_by_zero_eq_one() -> Expr {
+        BinOp {
+            kind: RelationOp::Eq.into(),
+            left: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(Expr::Const(1i32.into())),
+                    right: Box::new(Expr::Const(0i32.into())),
+                }
+                .into(),
+            ),
+            right: Box::new(Expr::Const(1i32.into())),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_whole_input() {
+        let expr: Expr = Or {
+            input: Box::new(
+                Collection::new(
+                    SType::SBoolean,
+                    vec![Expr::Const(true.into()), div_by_zero_eq_one()],
+                )
+                .unwrap()
+                .into(),
+            ),
+        }
+        .into();
+        assert!(try_eval_out_wo_ctx::<bool>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_cost_of_processed_items_only() {
+        let or_of = |bools: Vec<bool>| -> Expr {
+            Or {
+                input: Box::new(Collection::BoolConstants(bools).into()),
+            }
+            .into()
+        };
+        assert!(
+            eval_cost_wo_ctx(&or_of(vec![true; 10000]))
+                < eval_cost_wo_ctx(&or_of(vec![false; 10000]))
+        );
+    }
+
     proptest! {
 
         #[test]
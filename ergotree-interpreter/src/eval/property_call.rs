@@ -10,7 +10,7 @@ use super::Evaluable;
 impl Evaluable for PropertyCall {
     fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
         let ov = self.obj.eval(env, ectx)?;
-        smethod_eval_fn(&self.method)(env, ectx, ov, vec![])
+        smethod_eval_fn(&self.method)?(env, ectx, &self.method, ov, vec![])
     }
 }
 
@@ -0,0 +1,498 @@
+use std::convert::TryFrom;
+
+use crate::avl_tree::batch_verifier::BatchAvlVerifier;
+use crate::avl_tree::ADValue;
+use crate::avl_tree::Operation;
+use crate::eval::costs::Costs;
+use crate::eval::costs::PerItemCost;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+
+use ergotree_ir::mir::avl_tree_data::ADDigest;
+use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+use ergotree_ir::mir::constant::TryExtractFrom;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::util::AsVecI8;
+
+use super::EvalFn;
+
+pub(crate) static DIGEST_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    let digest: Vec<u8> = obj.try_extract_into::<AvlTreeData>()?.digest.into();
+    Ok(digest.into())
+};
+
+pub(crate) static ENABLED_OPERATIONS_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Byte(
+        obj.try_extract_into::<AvlTreeData>()?
+            .tree_flags
+            .serialize() as i8,
+    ))
+};
+
+pub(crate) static KEY_LENGTH_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Int(
+        obj.try_extract_into::<AvlTreeData>()?.key_length as i32,
+    ))
+};
+
+pub(crate) static VALUE_LENGTH_OPT_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Opt(Box::new(
+        obj.try_extract_into::<AvlTreeData>()?
+            .value_length_opt
+            .map(|l| Value::Int(l as i32)),
+    )))
+};
+
+pub(crate) static IS_INSERT_ALLOWED_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Boolean(
+        obj.try_extract_into::<AvlTreeData>()?
+            .tree_flags
+            .insert_allowed(),
+    ))
+};
+
+pub(crate) static IS_UPDATE_ALLOWED_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Boolean(
+        obj.try_extract_into::<AvlTreeData>()?
+            .tree_flags
+            .update_allowed(),
+    ))
+};
+
+pub(crate) static IS_REMOVE_ALLOWED_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(Value::Boolean(
+        obj.try_extract_into::<AvlTreeData>()?
+            .tree_flags
+            .remove_allowed(),
+    ))
+};
+
+pub(crate) static UPDATE_OPERATIONS_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, args| {
+    let mut tree = obj.try_extract_into::<AvlTreeData>()?;
+    let flags = arg::<i8>(&args, 0, "updateOperations: new flags")?;
+    tree.tree_flags = AvlTreeFlags::parse(flags as u8);
+    Ok(tree.into())
+};
+
+pub(crate) static UPDATE_DIGEST_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, args| {
+    let mut tree = obj.try_extract_into::<AvlTreeData>()?;
+    let digest = arg::<Vec<u8>>(&args, 0, "updateDigest: new digest")?;
+    tree.digest =
+        ADDigest::try_from(digest).map_err(|e| EvalError::UnexpectedValue(e.to_string()))?;
+    Ok(tree.into())
+};
+
+pub(crate) static CONTAINS_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let key = arg::<Vec<u8>>(&args, 0, "contains: key")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "contains: proof")?;
+    // any failure (invalid proof, wrong key length, etc.) means the key is not in the tree
+    let lookup = create_verifier(ctx, &tree, &proof)
+        .and_then(|mut verifier| perform_operation(ctx, &mut verifier, Operation::Lookup(key)));
+    let contains = match lookup {
+        Ok(value) => value.is_some(),
+        Err(EvalError::CostError(e)) => return Err(e.into()),
+        Err(_) => false,
+    };
+    Ok(Value::Boolean(contains))
+};
+
+pub(crate) static GET_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let key = arg::<Vec<u8>>(&args, 0, "get: key")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "get: proof")?;
+    let mut verifier = create_verifier(ctx, &tree, &proof)?;
+    let value = perform_operation(ctx, &mut verifier, Operation::Lookup(key))?;
+    Ok(value.map(|v| v.as_vec_i8()).into())
+};
+
+pub(crate) static GET_MANY_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let keys = arg::<Vec<Vec<u8>>>(&args, 0, "getMany: keys")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "getMany: proof")?;
+    let mut verifier = create_verifier(ctx, &tree, &proof)?;
+    let values = keys
+        .into_iter()
+        .map(|key| {
+            perform_operation(ctx, &mut verifier, Operation::Lookup(key))
+                .map(|value| value.map(|v| v.as_vec_i8()))
+        })
+        .collect::<Result<Vec<Option<Vec<i8>>>, EvalError>>()?;
+    Ok(values.into())
+};
+
+pub(crate) static INSERT_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let entries = arg::<Vec<(Vec<u8>, Vec<u8>)>>(&args, 0, "insert: entries")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "insert: proof")?;
+    if !tree.tree_flags.insert_allowed() {
+        return Ok(None::<AvlTreeData>.into());
+    }
+    let mut verifier = create_verifier(ctx, &tree, &proof)?;
+    for (key, value) in entries {
+        perform_operation(ctx, &mut verifier, Operation::Insert { key, value })?;
+    }
+    Ok(with_digest_of(tree, &verifier).into())
+};
+
+pub(crate) static UPDATE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let entries = arg::<Vec<(Vec<u8>, Vec<u8>)>>(&args, 0, "update: entries")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "update: proof")?;
+    if !tree.tree_flags.update_allowed() {
+        return Ok(None::<AvlTreeData>.into());
+    }
+    let mut verifier = create_verifier(ctx, &tree, &proof)?;
+    for (key, value) in entries {
+        // failed operation leaves the verifier without a digest, the result is None
+        if let Err(e) = perform_operation(ctx, &mut verifier, Operation::Update { key, value }) {
+            if let EvalError::CostError(_) = e {
+                return Err(e);
+            }
+            break;
+        }
+    }
+    Ok(with_digest_of(tree, &verifier).into())
+};
+
+pub(crate) static REMOVE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let tree = obj.try_extract_into::<AvlTreeData>()?;
+    let keys = arg::<Vec<Vec<u8>>>(&args, 0, "remove: keys")?;
+    let proof = arg::<Vec<u8>>(&args, 1, "remove: proof")?;
+    if !tree.tree_flags.remove_allowed() {
+        return Ok(None::<AvlTreeData>.into());
+    }
+    let mut verifier = create_verifier(ctx, &tree, &proof)?;
+    for key in keys {
+        // failed operation leaves the verifier without a digest, the result is None
+        if let Err(e) = perform_operation(ctx, &mut verifier, Operation::Remove(key)) {
+            if let EvalError::CostError(_) = e {
+                return Err(e);
+            }
+            break;
+        }
+    }
+    Ok(with_digest_of(tree, &verifier).into())
+};
+
+fn arg<T: TryExtractFrom<Value>>(args: &[Value], idx: usize, name: &str) -> Result<T, EvalError> {
+    Ok(args
+        .get(idx)
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound(format!("{} is missing", name)))?
+        .try_extract_into::<T>()?)
+}
+
+fn create_verifier(
+    ctx: &mut EvalContext,
+    tree: &AvlTreeData,
+    proof: &[u8],
+) -> Result<BatchAvlVerifier, EvalError> {
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.avl_create_verifier, proof.len())?;
+    Ok(BatchAvlVerifier::new(
+        &tree.digest.0,
+        proof,
+        tree.key_length as usize,
+        tree.value_length_opt.map(|l| l as usize),
+    )?)
+}
+
+fn perform_operation(
+    ctx: &mut EvalContext,
+    verifier: &mut BatchAvlVerifier,
+    operation: Operation,
+) -> Result<Option<ADValue>, EvalError> {
+    let cost: PerItemCost = match operation {
+        Operation::Lookup(_) => Costs::DEFAULT.avl_lookup,
+        Operation::Insert { .. } => Costs::DEFAULT.avl_insert,
+        Operation::Update { .. } => Costs::DEFAULT.avl_update,
+        Operation::Remove(_) => Costs::DEFAULT.avl_remove,
+    };
+    ctx.cost_accum.add(cost.cost(verifier.tree_height()))?;
+    Ok(verifier.perform_one_operation(&operation)?)
+}
+
+/// Tree with the digest of the verifier after the performed operations (`None` if any of them
+/// has failed)
+fn with_digest_of(tree: AvlTreeData, verifier: &BatchAvlVerifier) -> Option<AvlTreeData> {
+    verifier
+        .digest()
+        .and_then(|d| ADDigest::try_from(d).ok())
+        .map(|digest| AvlTreeData { digest, ..tree })
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ergotree_ir::mir::avl_tree_data::ADDigest;
+    use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+    use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::savltree;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::util::AsVecI8;
+    use sigma_test_util::force_any_val;
+
+    use crate::avl_tree::batch_prover::BatchAvlProver;
+    use crate::avl_tree::Operation;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    const KEY_LENGTH: usize = 4;
+
+    fn key(i: u8) -> Vec<i8> {
+        vec![i as i8; KEY_LENGTH]
+    }
+
+    fn value(i: u8) -> Vec<i8> {
+        vec![i as i8; 3]
+    }
+
+    fn tree_of(prover: &BatchAvlProver, flags: AvlTreeFlags) -> AvlTreeData {
+        AvlTreeData {
+            digest: ADDigest::try_from(prover.digest()).unwrap(),
+            tree_flags: flags,
+            key_length: KEY_LENGTH as u32,
+            value_length_opt: None,
+        }
+    }
+
+    /// Prover with the keys 1..=n inserted
+    fn prover_with_keys(n: u8) -> BatchAvlProver {
+        let mut prover = BatchAvlProver::new(KEY_LENGTH, None);
+        for i in 1..=n {
+            prover
+                .perform_one_operation(&Operation::Insert {
+                    key: key(i).into_iter().map(|b| b as u8).collect(),
+                    value: value(i).into_iter().map(|b| b as u8).collect(),
+                })
+                .unwrap();
+        }
+        prover.generate_proof();
+        prover
+    }
+
+    fn method_call(tree: AvlTreeData, method: &SMethod, args: Vec<Constant>) -> Expr {
+        MethodCall::new(
+            Constant::from(tree).into(),
+            method.clone(),
+            args.into_iter().map(Expr::from).collect(),
+        )
+        .unwrap()
+        .into()
+    }
+
+    fn property_call(tree: AvlTreeData, method: &SMethod) -> Expr {
+        PropertyCall::new(Constant::from(tree).into(), method.clone())
+            .unwrap()
+            .into()
+    }
+
+    fn all_ops() -> AvlTreeFlags {
+        AvlTreeFlags::new(true, true, true)
+    }
+
+    #[test]
+    fn eval_properties() {
+        let tree = force_any_val::<AvlTreeData>();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&property_call(tree.clone(), &savltree::DIGEST_METHOD)),
+            tree.digest.0.to_vec()
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i8>(&property_call(
+                tree.clone(),
+                &savltree::ENABLED_OPERATIONS_METHOD
+            )),
+            tree.tree_flags.serialize() as i8
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&property_call(tree.clone(), &savltree::KEY_LENGTH_METHOD)),
+            tree.key_length as i32
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&property_call(
+                tree.clone(),
+                &savltree::VALUE_LENGTH_OPT_METHOD
+            )),
+            tree.value_length_opt.map(|l| l as i32)
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<bool>(&property_call(
+                tree.clone(),
+                &savltree::IS_INSERT_ALLOWED_METHOD
+            )),
+            tree.tree_flags.insert_allowed()
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<bool>(&property_call(
+                tree.clone(),
+                &savltree::IS_UPDATE_ALLOWED_METHOD
+            )),
+            tree.tree_flags.update_allowed()
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<bool>(&property_call(
+                tree.clone(),
+                &savltree::IS_REMOVE_ALLOWED_METHOD
+            )),
+            tree.tree_flags.remove_allowed()
+        );
+    }
+
+    #[test]
+    fn eval_update_operations_and_digest() {
+        let tree = force_any_val::<AvlTreeData>();
+        let flags = AvlTreeFlags::new(false, true, false);
+        let res = eval_out_wo_ctx::<AvlTreeData>(&method_call(
+            tree.clone(),
+            &savltree::UPDATE_OPERATIONS_METHOD,
+            vec![(flags.serialize() as i8).into()],
+        ));
+        assert_eq!(
+            res,
+            AvlTreeData {
+                tree_flags: flags,
+                ..tree.clone()
+            }
+        );
+        let digest = vec![7u8; ADDigest::SIZE];
+        let res = eval_out_wo_ctx::<AvlTreeData>(&method_call(
+            tree.clone(),
+            &savltree::UPDATE_DIGEST_METHOD,
+            vec![digest.clone().into()],
+        ));
+        assert_eq!(res.digest.0.to_vec(), digest);
+        assert!(try_eval_out_wo_ctx::<AvlTreeData>(&method_call(
+            tree,
+            &savltree::UPDATE_DIGEST_METHOD,
+            vec![vec![7u8; 3].into()],
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn eval_lookups() {
+        let mut prover = prover_with_keys(5);
+        let tree = tree_of(&prover, all_ops());
+        prover
+            .perform_one_operation(&Operation::Lookup(vec![2; KEY_LENGTH]))
+            .unwrap();
+        prover
+            .perform_one_operation(&Operation::Lookup(vec![9; KEY_LENGTH]))
+            .unwrap();
+        let proof = prover.generate_proof().as_vec_i8();
+        let res = eval_out_wo_ctx::<Vec<Option<Vec<i8>>>>(&method_call(
+            tree.clone(),
+            &savltree::GET_MANY_METHOD,
+            vec![vec![key(2), key(9)].into(), proof.into()],
+        ));
+        assert_eq!(res, vec![Some(value(2)), None]);
+
+        prover
+            .perform_one_operation(&Operation::Lookup(vec![3; KEY_LENGTH]))
+            .unwrap();
+        let proof = prover.generate_proof().as_vec_i8();
+        assert_eq!(
+            eval_out_wo_ctx::<Option<Vec<i8>>>(&method_call(
+                tree.clone(),
+                &savltree::GET_METHOD,
+                vec![key(3).into(), proof.clone().into()],
+            )),
+            Some(value(3))
+        );
+        assert!(eval_out_wo_ctx::<bool>(&method_call(
+            tree.clone(),
+            &savltree::CONTAINS_METHOD,
+            vec![key(3).into(), proof.clone().into()],
+        )));
+        // the proof is for the different key
+        assert!(!eval_out_wo_ctx::<bool>(&method_call(
+            tree.clone(),
+            &savltree::CONTAINS_METHOD,
+            vec![key(4).into(), proof.clone().into()],
+        )));
+        // invalid proof
+        assert!(!eval_out_wo_ctx::<bool>(&method_call(
+            tree.clone(),
+            &savltree::CONTAINS_METHOD,
+            vec![key(3).into(), proof[1..].to_vec().into()],
+        )));
+        assert!(try_eval_out_wo_ctx::<Option<Vec<i8>>>(&method_call(
+            tree,
+            &savltree::GET_METHOD,
+            vec![key(3).into(), proof[1..].to_vec().into()],
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn eval_modifications() {
+        let mut prover = prover_with_keys(5);
+        let tree = tree_of(&prover, all_ops());
+
+        let op = Operation::Insert {
+            key: vec![7; KEY_LENGTH],
+            value: vec![7; 3],
+        };
+        prover.perform_one_operation(&op).unwrap();
+        let proof = prover.generate_proof().as_vec_i8();
+        let insert_args: Vec<Constant> = vec![vec![(key(7), value(7))].into(), proof.into()];
+        let res = eval_out_wo_ctx::<Option<AvlTreeData>>(&method_call(
+            tree.clone(),
+            &savltree::INSERT_METHOD,
+            insert_args.clone(),
+        ));
+        assert_eq!(res, Some(tree_of(&prover, all_ops())));
+        // insert is not allowed
+        let res = eval_out_wo_ctx::<Option<AvlTreeData>>(&method_call(
+            tree_of(&prover_with_keys(5), AvlTreeFlags::new(false, true, true)),
+            &savltree::INSERT_METHOD,
+            insert_args,
+        ));
+        assert_eq!(res, None);
+
+        let tree = tree_of(&prover, all_ops());
+        prover
+            .perform_one_operation(&Operation::Update {
+                key: vec![7; KEY_LENGTH],
+                value: vec![8; 3],
+            })
+            .unwrap();
+        let proof = prover.generate_proof().as_vec_i8();
+        let res = eval_out_wo_ctx::<Option<AvlTreeData>>(&method_call(
+            tree,
+            &savltree::UPDATE_METHOD,
+            vec![vec![(key(7), value(8))].into(), proof.into()],
+        ));
+        assert_eq!(res, Some(tree_of(&prover, all_ops())));
+
+        let tree = tree_of(&prover, all_ops());
+        prover
+            .perform_one_operation(&Operation::Remove(vec![2; KEY_LENGTH]))
+            .unwrap();
+        let proof = prover.generate_proof().as_vec_i8();
+        let res = eval_out_wo_ctx::<Option<AvlTreeData>>(&method_call(
+            tree.clone(),
+            &savltree::REMOVE_METHOD,
+            vec![vec![key(2)].into(), proof.clone().into()],
+        ));
+        assert_eq!(res, Some(tree_of(&prover, all_ops())));
+        // the proof does not contain the removal of the key 3
+        let res = eval_out_wo_ctx::<Option<AvlTreeData>>(&method_call(
+            tree,
+            &savltree::REMOVE_METHOD,
+            vec![vec![key(2), key(3)].into(), proof.into()],
+        ));
+        assert_eq!(res, None);
+    }
+}
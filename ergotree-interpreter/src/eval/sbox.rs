@@ -1,42 +1,117 @@
+use crate::eval::EvalContext;
 use crate::eval::EvalError;
 
-use ergotree_ir::ir_ergo_box::IrBoxId;
+use ergotree_ir::ir_ergo_box::IrBox;
 use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::smethod::SMethod;
+use ergotree_ir::types::stype::SType;
 
 use super::EvalFn;
 
-pub(crate) static VALUE_EVAL_FN: EvalFn = |_env, ctx, obj, _args| {
+pub(crate) static VALUE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
     Ok(Value::Long(
-        obj.try_extract_into::<IrBoxId>()?
+        obj.try_extract_into::<IrBox>()?
             .get_box(&ctx.ctx.box_arena)?
             .value(),
     ))
 };
 
-pub(crate) static GET_REG_EVAL_FN: EvalFn = |_env, ctx, obj, args| {
-    Ok(Value::Opt(Box::new(
-        obj.try_extract_into::<IrBoxId>()?
-            .get_box(&ctx.ctx.box_arena)?
-            .get_register(
-                args.get(0)
-                    .cloned()
-                    .ok_or_else(|| EvalError::NotFound("register index is missing".to_string()))?
-                    .try_extract_into::<i8>()?,
-            )
-            .map(|c| c.v),
-    )))
+pub(crate) static GET_REG_EVAL_FN: EvalFn = |_env, ctx, method, obj, args| {
+    let reg_id = args
+        .get(0)
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound("register index is missing".to_string()))?
+        .try_extract_into::<i8>()?;
+    get_reg(ctx, method, obj, reg_id)
 };
 
-pub(crate) static TOKENS_EVAL_FN: EvalFn = |_env, ctx, obj, _args| {
+pub(crate) static TOKENS_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
     let res: Value = obj
-        .try_extract_into::<IrBoxId>()?
+        .try_extract_into::<IrBox>()?
         .get_box(&ctx.ctx.box_arena)?
         .tokens_raw()
         .into();
     Ok(res)
 };
 
+pub(crate) static PROPOSITION_BYTES_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    Ok(obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .script_bytes()
+        .into())
+};
+
+pub(crate) static BYTES_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    Ok(obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .bytes()
+        .into())
+};
+
+pub(crate) static BYTES_WITHOUT_REF_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    Ok(obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .bytes_without_ref()
+        .into())
+};
+
+pub(crate) static ID_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    Ok(obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .id()
+        .to_bytes()
+        .into())
+};
+
+pub(crate) static CREATION_INFO_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    Ok(obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .creation_info()
+        .into())
+};
+
+/// Returns the register value as `Option[T]`, fails if the type of the stored value is not `T`
+/// (the method's type argument, unless it's left unresolved)
+fn get_reg(
+    ctx: &EvalContext,
+    method: &SMethod,
+    obj: Value,
+    reg_id: i8,
+) -> Result<Value, EvalError> {
+    let reg = obj
+        .try_extract_into::<IrBox>()?
+        .get_box(&ctx.ctx.box_arena)?
+        .get_register(reg_id);
+    match (&*method.tpe().t_range, reg) {
+        (SType::SOption(tpe), Some(c))
+            if !matches!(**tpe, SType::STypeVar(_)) && c.tpe != **tpe =>
+        {
+            Err(EvalError::UnexpectedValue(format!(
+                "register R{0} holds the value of type {1:?}, expected {2:?}",
+                reg_id, c.tpe, tpe
+            )))
+        }
+        (_, reg) => Ok(Value::Opt(Box::new(reg.map(|c| c.v)))),
+    }
+}
+
+pub(crate) static R0_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 0);
+pub(crate) static R1_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 1);
+pub(crate) static R2_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 2);
+pub(crate) static R3_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 3);
+pub(crate) static R4_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 4);
+pub(crate) static R5_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 5);
+pub(crate) static R6_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 6);
+pub(crate) static R7_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 7);
+pub(crate) static R8_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 8);
+pub(crate) static R9_EVAL_FN: EvalFn = |_env, ctx, method, obj, _args| get_reg(ctx, method, obj, 9);
+
 #[allow(clippy::unwrap_used)]
 #[cfg(test)]
 #[cfg(feature = "arbitrary")]
@@ -44,11 +119,21 @@ mod tests {
     use ergotree_ir::mir::expr::Expr;
     use ergotree_ir::mir::global_vars::GlobalVars;
     use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::mir::value::Value;
     use ergotree_ir::types::sbox;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::stype::SType;
+    use ergotree_ir::types::stype_param::STypeVar;
     use sigma_test_util::force_any_val;
 
+    use crate::eval::context::ir_ergo_box_dummy::IrErgoBoxDummy;
+    use crate::eval::context::ir_ergo_box_dummy::IrErgoBoxDummyArena;
     use crate::eval::context::Context;
     use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use ergotree_ir::ir_ergo_box::IrErgoBox;
+    use ergotree_ir::ir_ergo_box::IrErgoBoxArena;
+    use std::collections::HashMap;
     use std::rc::Rc;
 
     #[test]
@@ -74,4 +159,92 @@ mod tests {
             ctx.self_box.get_box(&ctx.box_arena).unwrap().tokens_raw()
         );
     }
+
+    fn box_property(method: &SMethod) -> Expr {
+        PropertyCall::new(GlobalVars::SelfBox.into(), method.clone())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_box_bytes_properties() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let self_box = ctx.self_box.get_box(&ctx.box_arena).unwrap();
+        assert_eq!(
+            eval_out::<Vec<i8>>(&box_property(&sbox::PROPOSITION_BYTES_METHOD), ctx.clone()),
+            self_box.script_bytes()
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&box_property(&sbox::BYTES_METHOD), ctx.clone()),
+            self_box.bytes()
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&box_property(&sbox::BYTES_WITHOUT_REF_METHOD), ctx.clone()),
+            self_box.bytes_without_ref()
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&box_property(&sbox::ID_METHOD), ctx.clone()),
+            self_box.id().to_bytes()
+        );
+    }
+
+    #[test]
+    fn eval_box_creation_info() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let creation_info: Value = ctx
+            .self_box
+            .get_box(&ctx.box_arena)
+            .unwrap()
+            .creation_info()
+            .into();
+        assert_eq!(
+            eval_out::<Value>(&box_property(&sbox::CREATION_INFO_METHOD), ctx),
+            creation_info
+        );
+    }
+
+    #[test]
+    fn eval_box_registers() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let self_box = ctx.self_box.get_box(&ctx.box_arena).unwrap();
+        let r0 = sbox::R0_METHOD
+            .clone()
+            .with_concrete_types(&[(STypeVar::t(), SType::SLong)].iter().cloned().collect());
+        assert_eq!(box_property(&r0).tpe(), SType::SOption(SType::SLong.into()));
+        assert_eq!(
+            eval_out::<Option<i64>>(&box_property(&r0), ctx.clone()),
+            Some(self_box.value())
+        );
+        let r9 = sbox::R9_METHOD
+            .clone()
+            .with_concrete_types(&[(STypeVar::t(), SType::SInt)].iter().cloned().collect());
+        assert_eq!(
+            eval_out::<Option<Value>>(&box_property(&r9), ctx.clone()),
+            self_box.get_register(9).map(|c| c.v)
+        );
+    }
+
+    #[test]
+    fn eval_box_register_type_mismatch() {
+        let self_box = force_any_val::<IrErgoBoxDummy>()
+            .with_additional_registers(vec![vec![1u8, 2, 3].into()]);
+        let mut boxes = HashMap::new();
+        boxes.insert(self_box.id(), self_box.clone());
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box = self_box.id();
+        ctx.box_arena = Rc::new(IrErgoBoxDummyArena(boxes)) as Rc<dyn IrErgoBoxArena>;
+        let ctx = Rc::new(ctx);
+        let r4 = |tpe: SType| {
+            box_property(
+                &sbox::R4_METHOD
+                    .clone()
+                    .with_concrete_types(&[(STypeVar::t(), tpe)].iter().cloned().collect()),
+            )
+        };
+        assert!(try_eval_out::<Value>(&r4(SType::SInt), ctx.clone()).is_err());
+        assert_eq!(
+            eval_out::<Option<Vec<u8>>>(&r4(SType::SColl(SType::SByte.into())), ctx),
+            Some(vec![1u8, 2, 3])
+        );
+    }
 }
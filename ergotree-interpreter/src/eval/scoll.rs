@@ -1,16 +1,343 @@
+use crate::eval::costs::Costs;
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
 use crate::eval::EvalError;
 use crate::eval::Evaluable;
 
+use ergotree_ir::mir::constant::TryExtractFrom;
 use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::NativeColl;
 use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stuple::STuple;
+use ergotree_ir::types::stype::SType;
 
 use super::EvalFn;
 
-pub(crate) static INDEX_OF_EVAL_FN: EvalFn = |_env, _ctx, obj, args| {
+fn coll_obj(obj: Value, method_name: &str) -> Result<CollKind, EvalError> {
+    match obj {
+        Value::Coll(coll) => Ok(coll),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "{0}: expected obj to be Value::Coll, got: {1:?}",
+            method_name, obj
+        ))),
+    }
+}
+
+fn arg<T: TryExtractFrom<Value>>(args: &[Value], idx: usize, name: &str) -> Result<T, EvalError> {
+    Ok(args
+        .get(idx)
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound(format!("{} is missing", name)))?
+        .try_extract_into::<T>()?)
+}
+
+/// Calls the evaluated one-argument lambda `lambda_v` with `arg`
+pub(crate) fn call_lambda(
+    env: &Env,
+    ctx: &mut EvalContext,
+    lambda_v: &Value,
+    arg: Value,
+) -> Result<Value, EvalError> {
+    match lambda_v {
+        Value::Lambda(lambda) => {
+            let func_arg = lambda.args.first().ok_or_else(|| {
+                EvalError::NotFound("lambda has empty arguments list".to_string())
+            })?;
+            let env1 = env.clone().extend(func_arg.idx, arg);
+            lambda.body.eval(&env1, ctx)
+        }
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "expected Value::Lambda, got: {0:?}",
+            lambda_v
+        ))),
+    }
+}
+
+fn lambda_result_tpe(lambda_v: &Value) -> Result<SType, EvalError> {
+    match lambda_v {
+        Value::Lambda(lambda) => Ok(lambda.body.tpe()),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "expected Value::Lambda, got: {0:?}",
+            lambda_v
+        ))),
+    }
+}
+
+fn build_coll(elem_tpe: SType, items: Vec<Value>) -> Result<Value, EvalError> {
+    Ok(Value::Coll(CollKind::from_vec(elem_tpe, items)?))
+}
+
+fn check_index(index: i32, len: usize) -> Result<usize, EvalError> {
+    if index >= 0 && (index as usize) < len {
+        Ok(index as usize)
+    } else {
+        Err(EvalError::Misc(format!(
+            "index {0} out of bounds for collection size {1}",
+            index, len
+        )))
+    }
+}
+
+/// Concatenation of two collections of the same element type
+pub(crate) fn append(
+    ctx: &mut EvalContext,
+    coll: CollKind,
+    coll_2: CollKind,
+) -> Result<Value, EvalError> {
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.append, coll.len() + coll_2.len())?;
+    match (coll, coll_2) {
+        (
+            CollKind::NativeColl(NativeColl::CollByte(mut bytes)),
+            CollKind::NativeColl(NativeColl::CollByte(bytes_2)),
+        ) => {
+            bytes.extend(bytes_2);
+            Ok(Value::Coll(CollKind::NativeColl(NativeColl::CollByte(
+                bytes,
+            ))))
+        }
+        (coll, coll_2) => {
+            if coll.elem_tpe() != coll_2.elem_tpe() {
+                return Err(EvalError::UnexpectedValue(format!(
+                    "append: collection element types differ: {0:?} and {1:?}",
+                    coll.elem_tpe(),
+                    coll_2.elem_tpe()
+                )));
+            }
+            let elem_tpe = coll.elem_tpe().clone();
+            let mut items = coll.as_vec();
+            items.extend(coll_2.as_vec());
+            build_coll(elem_tpe, items)
+        }
+    }
+}
+
+/// Elements in `[from, until)`, where out of range bounds are clamped to the collection
+/// (an empty collection is returned if `from >= until`)
+pub(crate) fn slice(
+    ctx: &mut EvalContext,
+    coll: CollKind,
+    from: i32,
+    until: i32,
+) -> Result<Value, EvalError> {
+    let len = coll.len();
+    let from = from.max(0) as usize;
+    let until = (until.max(0) as usize).min(len);
+    let range = if from < until { from..until } else { 0..0 };
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.slice, range.len())?;
+    Ok(Value::Coll(match coll {
+        CollKind::NativeColl(NativeColl::CollByte(bytes)) => {
+            CollKind::NativeColl(NativeColl::CollByte(bytes[range].to_vec()))
+        }
+        CollKind::WrappedColl { elem_tpe, items } => CollKind::WrappedColl {
+            elem_tpe,
+            items: items[range].to_vec(),
+        },
+    }))
+}
+
+pub(crate) static SIZE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.size_of)?;
+    Ok(Value::Int(coll_obj(obj, "size")?.len() as i32))
+};
+
+pub(crate) static GET_OR_ELSE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.by_index)?;
+    let coll = coll_obj(obj, "getOrElse")?;
+    let index = arg::<i32>(&args, 0, "getOrElse: index")?;
+    let default = arg::<Value>(&args, 1, "getOrElse: default")?;
+    Ok(match check_index(index, coll.len()) {
+        Ok(i) => coll.as_vec().swap_remove(i),
+        Err(_) => default,
+    })
+};
+
+pub(crate) static APPLY_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.by_index)?;
+    let coll = coll_obj(obj, "apply")?;
+    let index = check_index(arg::<i32>(&args, 0, "apply: index")?, coll.len())?;
+    Ok(coll.as_vec().swap_remove(index))
+};
+
+pub(crate) static MAP_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "map")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.map, coll.len())?;
+    let mapper = arg::<Value>(&args, 0, "map: mapper")?;
+    let items = coll
+        .as_vec()
+        .into_iter()
+        .map(|item| call_lambda(env, ctx, &mapper, item))
+        .collect::<Result<Vec<Value>, EvalError>>()?;
+    build_coll(lambda_result_tpe(&mapper)?, items)
+};
+
+pub(crate) static FILTER_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "filter")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.filter, coll.len())?;
+    let condition = arg::<Value>(&args, 0, "filter: condition")?;
+    let elem_tpe = coll.elem_tpe().clone();
+    let mut items = Vec::new();
+    for item in coll.as_vec() {
+        if call_lambda(env, ctx, &condition, item.clone())?.try_extract_into::<bool>()? {
+            items.push(item);
+        }
+    }
+    build_coll(elem_tpe, items)
+};
+
+pub(crate) static EXISTS_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "exists")?;
+    let condition = arg::<Value>(&args, 0, "exists: condition")?;
+    // stop on the first item satisfying the condition, only the processed items are added
+    // to the cost
+    let mut n_processed = 0;
+    let mut res = false;
+    for item in coll.as_vec() {
+        n_processed += 1;
+        if call_lambda(env, ctx, &condition, item)?.try_extract_into::<bool>()? {
+            res = true;
+            break;
+        }
+    }
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.exists_forall, n_processed)?;
+    Ok(Value::Boolean(res))
+};
+
+pub(crate) static FORALL_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "forall")?;
+    let condition = arg::<Value>(&args, 0, "forall: condition")?;
+    // stop on the first item not satisfying the condition, only the processed items are added
+    // to the cost
+    let mut n_processed = 0;
+    let mut res = true;
+    for item in coll.as_vec() {
+        n_processed += 1;
+        if !call_lambda(env, ctx, &condition, item)?.try_extract_into::<bool>()? {
+            res = false;
+            break;
+        }
+    }
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.exists_forall, n_processed)?;
+    Ok(Value::Boolean(res))
+};
+
+pub(crate) static FOLD_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "fold")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.fold, coll.len())?;
+    let zero = arg::<Value>(&args, 0, "fold: zero")?;
+    let fold_op = arg::<Value>(&args, 1, "fold: op")?;
+    coll.as_vec().into_iter().try_fold(zero, |acc, item| {
+        call_lambda(env, ctx, &fold_op, Value::Tup([acc, item].into()))
+    })
+};
+
+pub(crate) static SLICE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "slice")?;
+    let from = arg::<i32>(&args, 0, "slice: from")?;
+    let until = arg::<i32>(&args, 1, "slice: until")?;
+    slice(ctx, coll, from, until)
+};
+
+pub(crate) static APPEND_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "append")?;
+    let coll_2 = coll_obj(arg::<Value>(&args, 0, "append: other")?, "append")?;
+    append(ctx, coll, coll_2)
+};
+
+pub(crate) static INDICES_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    let coll = coll_obj(obj, "indices")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.coll_method, coll.len())?;
+    let indices = (0..coll.len() as i32).map(Value::Int).collect();
+    build_coll(SType::SInt, indices)
+};
+
+pub(crate) static PATCH_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "patch")?;
+    let from = arg::<i32>(&args, 0, "patch: from")?;
+    let patch = coll_obj(arg::<Value>(&args, 1, "patch: patch")?, "patch")?;
+    let replaced = arg::<i32>(&args, 2, "patch: replaced")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.coll_method, coll.len() + patch.len())?;
+    let elem_tpe = coll.elem_tpe().clone();
+    let items = coll.as_vec();
+    let from = (from.max(0) as usize).min(items.len());
+    let rest = from
+        .saturating_add(replaced.max(0) as usize)
+        .min(items.len());
+    let mut res = items[..from].to_vec();
+    res.extend(patch.as_vec());
+    res.extend_from_slice(&items[rest..]);
+    build_coll(elem_tpe, res)
+};
+
+pub(crate) static UPDATED_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "updated")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.coll_method, coll.len())?;
+    let index = check_index(arg::<i32>(&args, 0, "updated: index")?, coll.len())?;
+    let elem = arg::<Value>(&args, 1, "updated: elem")?;
+    let elem_tpe = coll.elem_tpe().clone();
+    let mut items = coll.as_vec();
+    items[index] = elem;
+    build_coll(elem_tpe, items)
+};
+
+pub(crate) static UPDATE_MANY_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "updateMany")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.coll_method, coll.len())?;
+    let indexes = arg::<Vec<i32>>(&args, 0, "updateMany: indexes")?;
+    let values = coll_obj(arg::<Value>(&args, 1, "updateMany: values")?, "updateMany")?;
+    if indexes.len() != values.len() {
+        return Err(EvalError::Misc(format!(
+            "updateMany: indexes and values have different sizes: {0} and {1}",
+            indexes.len(),
+            values.len()
+        )));
+    }
+    let elem_tpe = coll.elem_tpe().clone();
+    let mut items = coll.as_vec();
+    for (index, value) in indexes.into_iter().zip(values.as_vec()) {
+        let i = check_index(index, items.len())?;
+        items[i] = value;
+    }
+    build_coll(elem_tpe, items)
+};
+
+pub(crate) static ZIP_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    let coll = coll_obj(obj, "zip")?;
+    let coll_2 = coll_obj(arg::<Value>(&args, 0, "zip: other")?, "zip")?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.coll_method, coll.len().min(coll_2.len()))?;
+    let elem_tpe = SType::STuple(STuple::pair(
+        coll.elem_tpe().clone(),
+        coll_2.elem_tpe().clone(),
+    ));
+    let items = coll
+        .as_vec()
+        .into_iter()
+        .zip(coll_2.as_vec())
+        .map(|(a, b)| Value::Tup([a, b].into()))
+        .collect();
+    build_coll(elem_tpe, items)
+};
+
+pub(crate) static INDEX_OF_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
     Ok(Value::Int({
         let normalized_input_vals: Vec<Value> = match obj {
-            Value::Coll(coll) => Ok(coll.as_vec()),
+            Value::Coll(coll) => {
+                ctx.cost_accum
+                    .add_items_cost(Costs::DEFAULT.coll_method, coll.len())?;
+                Ok(coll.as_vec())
+            }
             _ => Err(EvalError::UnexpectedValue(format!(
                 "expected obj to be Value::Coll, got: {0:?}",
                 obj
@@ -32,8 +359,12 @@ pub(crate) static INDEX_OF_EVAL_FN: EvalFn = |_env, _ctx, obj, args| {
     }))
 };
 
-pub(crate) static FLATMAP_EVAL_FN: EvalFn = |env, ctx, obj, args| {
+pub(crate) static FLATMAP_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
     let input_v = obj;
+    if let Value::Coll(coll) = &input_v {
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.coll_method, coll.len())?;
+    }
     let lambda_v = args
         .get(0)
         .cloned()
@@ -107,8 +438,18 @@ mod tests {
     use ergotree_ir::types::stype::SType;
     use ergotree_ir::types::stype_param::STypeVar;
 
+    use crate::eval::tests::eval_cost_wo_ctx;
     use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::mir::select_field::SelectField;
+    use ergotree_ir::mir::value::Value;
+    use ergotree_ir::types::sfunc::SFunc;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::stuple::STuple;
     use ergotree_ir::types::stype::SType::SBoolean;
+    use std::convert::TryInto;
 
     #[test]
     fn eval_index_of() {
@@ -186,4 +527,309 @@ mod tests {
         let res = eval_out_wo_ctx::<Vec<bool>>(&expr);
         assert_eq!(res, vec![true, false]);
     }
+
+    fn call(obj: Constant, method: &SMethod, args: Vec<Expr>) -> Expr {
+        let obj: Expr = obj.into();
+        let arg_types = args.iter().map(|a| a.tpe()).collect();
+        let method = method.clone().specialize_for(obj.tpe(), arg_types).unwrap();
+        MethodCall::new(obj, method, args).unwrap().into()
+    }
+
+    fn property(obj: Constant, method: &SMethod) -> Expr {
+        let obj: Expr = obj.into();
+        let method = method.clone().specialize_for(obj.tpe(), vec![]).unwrap();
+        PropertyCall::new(obj, method).unwrap().into()
+    }
+
+    fn lambda(arg_tpe: SType, body: Expr) -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: 1.into(),
+                tpe: arg_tpe,
+            }],
+            body,
+        )
+        .into()
+    }
+
+    fn arg_use(tpe: SType) -> Expr {
+        ValUse {
+            val_id: 1.into(),
+            tpe,
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_size_and_indices() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let longs: Constant = vec![1i64, 2].into();
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&property(bytes.clone(), &scoll::SIZE_METHOD)),
+            3
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&property(longs.clone(), &scoll::SIZE_METHOD)),
+            2
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i32>>(&property(bytes, &scoll::INDICES_METHOD)),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i32>>(&property(longs, &scoll::INDICES_METHOD)),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn eval_get_or_else_and_apply() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let longs: Constant = vec![1i64, 2].into();
+        let get_or_else = |obj: Constant, idx: i32, default: Constant| {
+            call(
+                obj,
+                &scoll::GET_OR_ELSE_METHOD,
+                vec![idx.into(), default.into()],
+            )
+        };
+        assert_eq!(
+            eval_out_wo_ctx::<i8>(&get_or_else(bytes.clone(), 2, 9i8.into())),
+            3
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i8>(&get_or_else(bytes.clone(), 3, 9i8.into())),
+            9
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i64>(&get_or_else(longs.clone(), -1, 9i64.into())),
+            9
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i8>(&call(bytes, &scoll::APPLY_METHOD, vec![1i32.into()])),
+            2
+        );
+        assert!(
+            try_eval_out_wo_ctx::<i64>(&call(longs, &scoll::APPLY_METHOD, vec![2i32.into()]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn eval_map_filter_exists_forall() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let inc: Expr = lambda(
+            SType::SByte,
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: arg_use(SType::SByte).into(),
+                right: Expr::Const(1i8.into()).into(),
+            }
+            .into(),
+        );
+        let gt_one: Expr = lambda(
+            SType::SByte,
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: arg_use(SType::SByte).into(),
+                right: Expr::Const(1i8.into()).into(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&call(bytes.clone(), &scoll::MAP_METHOD, vec![inc])),
+            vec![2i8, 3, 4]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&call(
+                bytes.clone(),
+                &scoll::FILTER_METHOD,
+                vec![gt_one.clone()]
+            )),
+            vec![2i8, 3]
+        );
+        assert!(eval_out_wo_ctx::<bool>(&call(
+            bytes.clone(),
+            &scoll::EXISTS_METHOD,
+            vec![gt_one.clone()]
+        )));
+        assert!(!eval_out_wo_ctx::<bool>(&call(
+            bytes,
+            &scoll::FORALL_METHOD,
+            vec![gt_one]
+        )));
+    }
+
+    #[test]
+    fn eval_exists_forall_cost_of_processed_items_only() {
+        let gt_one: Expr = lambda(
+            SType::SByte,
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: arg_use(SType::SByte).into(),
+                right: Expr::Const(1i8.into()).into(),
+            }
+            .into(),
+        );
+        let cost_of = |bytes: Vec<i8>, method: &SMethod| -> u64 {
+            eval_cost_wo_ctx(&call(bytes.into(), method, vec![gt_one.clone()]))
+        };
+        // both stop on the first item
+        assert_eq!(
+            cost_of(vec![2; 1], &scoll::EXISTS_METHOD),
+            cost_of(vec![2; 10000], &scoll::EXISTS_METHOD)
+        );
+        assert_eq!(
+            cost_of(vec![0; 1], &scoll::FORALL_METHOD),
+            cost_of(vec![0; 10000], &scoll::FORALL_METHOD)
+        );
+    }
+
+    #[test]
+    fn eval_fold() {
+        let longs: Constant = vec![1i64, 2, 3].into();
+        let tuple_tpe: SType = STuple::pair(SType::SLong, SType::SLong).into();
+        let field = |idx: u8| -> Expr {
+            SelectField::new(arg_use(tuple_tpe.clone()), idx.try_into().unwrap())
+                .unwrap()
+                .into()
+        };
+        let sum: Expr = lambda(
+            tuple_tpe.clone(),
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: field(1).into(),
+                right: field(2).into(),
+            }
+            .into(),
+        );
+        assert_eq!(sum.tpe(), SFunc::new(vec![tuple_tpe], SType::SLong).into());
+        let expr = call(
+            longs,
+            &scoll::FOLD_METHOD,
+            vec![Expr::Const(10i64.into()), sum],
+        );
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 16);
+    }
+
+    #[test]
+    fn eval_slice_and_append() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let longs: Constant = vec![1i64, 2, 3].into();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&call(
+                bytes.clone(),
+                &scoll::SLICE_METHOD,
+                vec![1i32.into(), 5i32.into()]
+            )),
+            vec![2i8, 3]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i64>>(&call(
+                longs.clone(),
+                &scoll::SLICE_METHOD,
+                vec![0i32.into(), 1i32.into()]
+            )),
+            vec![1i64]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&call(
+                bytes.clone(),
+                &scoll::APPEND_METHOD,
+                vec![bytes.into()]
+            )),
+            vec![1i8, 2, 3, 1, 2, 3]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i64>>(&call(
+                longs,
+                &scoll::APPEND_METHOD,
+                vec![Constant::from(vec![4i64]).into()]
+            )),
+            vec![1i64, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn eval_patch() {
+        let bytes: Constant = vec![1i8, 2, 3, 4].into();
+        let patch = |from: i32, replaced: i32| {
+            call(
+                bytes.clone(),
+                &scoll::PATCH_METHOD,
+                vec![
+                    from.into(),
+                    Constant::from(vec![7i8, 8]).into(),
+                    replaced.into(),
+                ],
+            )
+        };
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&patch(1, 2)), vec![1i8, 7, 8, 4]);
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&patch(3, 10)),
+            vec![1i8, 2, 3, 7, 8]
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&patch(-1, 0)),
+            vec![7i8, 8, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn eval_updated_and_update_many() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let longs: Constant = vec![1i64, 2, 3].into();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&call(
+                bytes.clone(),
+                &scoll::UPDATED_METHOD,
+                vec![0i32.into(), 5i8.into()]
+            )),
+            vec![5i8, 2, 3]
+        );
+        assert!(try_eval_out_wo_ctx::<Vec<i8>>(&call(
+            bytes,
+            &scoll::UPDATED_METHOD,
+            vec![3i32.into(), 5i8.into()]
+        ))
+        .is_err());
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i64>>(&call(
+                longs.clone(),
+                &scoll::UPDATE_MANY_METHOD,
+                vec![
+                    Constant::from(vec![0i32, 2]).into(),
+                    Constant::from(vec![7i64, 9]).into()
+                ]
+            )),
+            vec![7i64, 2, 9]
+        );
+        assert!(try_eval_out_wo_ctx::<Vec<i64>>(&call(
+            longs,
+            &scoll::UPDATE_MANY_METHOD,
+            vec![
+                Constant::from(vec![0i32]).into(),
+                Constant::from(vec![7i64, 9]).into()
+            ]
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn eval_zip() {
+        let bytes: Constant = vec![1i8, 2, 3].into();
+        let longs: Constant = vec![4i64, 5].into();
+        let expr = call(bytes, &scoll::ZIP_METHOD, vec![longs.into()]);
+        assert_eq!(
+            expr.tpe(),
+            SType::SColl(SType::STuple(STuple::pair(SType::SByte, SType::SLong)).into())
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<Value>>(&expr),
+            vec![
+                Value::Tup([Value::Byte(1), Value::Long(4)].into()),
+                Value::Tup([Value::Byte(2), Value::Long(5)].into()),
+            ]
+        );
+    }
 }
@@ -5,7 +5,7 @@ use ergotree_ir::types::stype::SType;
 use super::EvalError;
 use super::EvalFn;
 
-pub(crate) static DATA_INPUTS_EVAL_FN: EvalFn = |_env, ctx, obj, _args| {
+pub(crate) static DATA_INPUTS_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
     if obj != Value::Context {
         return Err(EvalError::UnexpectedValue(format!(
             "Context.dataInputs: expected object of Value::Context, got {:?}",
@@ -18,8 +18,68 @@ pub(crate) static DATA_INPUTS_EVAL_FN: EvalFn = |_env, ctx, obj, _args| {
             .data_inputs
             .clone()
             .into_iter()
-            .map(Value::CBox)
+            .map(Value::from)
             .collect(),
         elem_tpe: SType::SBox,
     }))
 };
+
+pub(crate) static HEADERS_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    if obj != Value::Context {
+        return Err(EvalError::UnexpectedValue(format!(
+            "Context.headers: expected object of Value::Context, got {:?}",
+            obj
+        )));
+    }
+    Ok(Value::Coll(CollKind::WrappedColl {
+        items: ctx.ctx.headers.iter().cloned().map(Value::from).collect(),
+        elem_tpe: SType::SHeader,
+    }))
+};
+
+pub(crate) static PRE_HEADER_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    if obj != Value::Context {
+        return Err(EvalError::UnexpectedValue(format!(
+            "Context.preHeader: expected object of Value::Context, got {:?}",
+            obj
+        )));
+    }
+    Ok(ctx.ctx.pre_header.clone().into())
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::header::Header;
+    use ergotree_ir::mir::header::PreHeader;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::scontext;
+    use sigma_test_util::force_any_val;
+
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use std::rc::Rc;
+
+    #[test]
+    fn eval_headers() {
+        let expr: Expr = PropertyCall::new(Expr::Context, scontext::HEADERS_PROPERTY.clone())
+            .unwrap()
+            .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<Vec<Header>>(&expr, ctx.clone()),
+            ctx.headers.to_vec()
+        );
+    }
+
+    #[test]
+    fn eval_pre_header() {
+        let expr: Expr = PropertyCall::new(Expr::Context, scontext::PRE_HEADERS_PROPERTY.clone())
+            .unwrap()
+            .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<PreHeader>(&expr, ctx.clone()), ctx.pre_header);
+    }
+}
@@ -0,0 +1,31 @@
+use ergotree_ir::sigma_protocol::dlog_group;
+
+use crate::eval::costs::Costs;
+
+use super::EvalFn;
+
+pub(crate) static GROUP_GENERATOR_EVAL_FN: EvalFn = |_env, ctx, _method, _obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.group_generator)?;
+    Ok(dlog_group::generator().into())
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::sigma_protocol::dlog_group;
+    use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+    use ergotree_ir::types::sglobal;
+
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_group_generator() {
+        let expr: Expr = PropertyCall::new(Expr::Global, sglobal::GROUP_GENERATOR_METHOD.clone())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), dlog_group::generator());
+    }
+}
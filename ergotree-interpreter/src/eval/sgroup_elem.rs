@@ -0,0 +1,117 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::dlog_group;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use num_bigint::BigInt;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalError;
+
+use super::EvalFn;
+
+pub(crate) static GET_ENCODED_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.get_encoded)?;
+    Ok(obj
+        .try_extract_into::<EcPoint>()?
+        .sigma_serialize_bytes()
+        .into())
+};
+
+pub(crate) static EXP_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.exponentiate)?;
+    let base = obj.try_extract_into::<EcPoint>()?;
+    let exponent = args
+        .first()
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound("exp: missing exponent arg".to_string()))?
+        .try_extract_into::<BigInt>()?;
+    Ok(dlog_group::exponentiate(&base, &dlog_group::bigint_to_scalar(&exponent)).into())
+};
+
+pub(crate) static MULTIPLY_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.multiply_group)?;
+    let left = obj.try_extract_into::<EcPoint>()?;
+    let right = args
+        .first()
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound("multiply: missing other arg".to_string()))?
+        .try_extract_into::<EcPoint>()?;
+    Ok((left * &right).into())
+};
+
+pub(crate) static NEGATE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.negate_group)?;
+    Ok(dlog_group::inverse(&obj.try_extract_into::<EcPoint>()?).into())
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::serialization::SigmaSerializable;
+    use ergotree_ir::sigma_protocol::dlog_group;
+    use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+    use ergotree_ir::types::sgroup_elem;
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn eval_get_encoded(p in any::<EcPoint>()) {
+            let expr: Expr = PropertyCall::new(
+                Expr::Const(p.clone().into()),
+                sgroup_elem::GET_ENCODED_METHOD.clone(),
+            )
+            .unwrap()
+            .into();
+            prop_assert_eq!(eval_out_wo_ctx::<Vec<u8>>(&expr), p.sigma_serialize_bytes());
+        }
+
+        #[test]
+        fn eval_negate(p in any::<EcPoint>()) {
+            let expr: Expr = PropertyCall::new(
+                Expr::Const(p.clone().into()),
+                sgroup_elem::NEGATE_METHOD.clone(),
+            )
+            .unwrap()
+            .into();
+            prop_assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), dlog_group::inverse(&p));
+        }
+
+        #[test]
+        fn eval_multiply(left in any::<EcPoint>(), right in any::<EcPoint>()) {
+            let expr: Expr = MethodCall::new(
+                Expr::Const(left.clone().into()),
+                sgroup_elem::MULTIPLY_METHOD.clone(),
+                vec![Expr::Const(right.clone().into())],
+            )
+            .unwrap()
+            .into();
+            prop_assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), left * &right);
+        }
+    }
+
+    #[test]
+    fn eval_exp() {
+        // g^2 == g * g
+        let expr: Expr = MethodCall::new(
+            Expr::GroupGenerator,
+            sgroup_elem::EXP_METHOD.clone(),
+            vec![Expr::Const(BigInt::from(2).into())],
+        )
+        .unwrap()
+        .into();
+        assert_eq!(
+            eval_out_wo_ctx::<EcPoint>(&expr),
+            dlog_group::generator() * &dlog_group::generator()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::header::Header;
+use ergotree_ir::mir::value::Value;
+
+use super::EvalError;
+use super::EvalFn;
+
+fn header(obj: Value) -> Result<Header, EvalError> {
+    Ok(obj.try_extract_into::<Header>()?)
+}
+
+pub(crate) static ID_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| Ok(header(obj)?.id.into());
+
+pub(crate) static VERSION_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Byte(header(obj)?.version as i8));
+
+pub(crate) static PARENT_ID_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.parent_id.into());
+
+pub(crate) static AD_PROOFS_ROOT_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.ad_proofs_root.into());
+
+/// State root is exposed as an AVL tree with all modifications allowed and 32 byte keys
+/// (same as in the reference implementation)
+pub(crate) static STATE_ROOT_EVAL_FN: EvalFn = |_env, _ctx, _method, obj, _args| {
+    Ok(AvlTreeData {
+        digest: header(obj)?.state_root,
+        tree_flags: AvlTreeFlags::new(true, true, true),
+        key_length: 32,
+        value_length_opt: None,
+    }
+    .into())
+};
+
+pub(crate) static TRANSACTIONS_ROOT_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.transaction_root.into());
+
+pub(crate) static TIMESTAMP_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Long(header(obj)?.timestamp as i64));
+
+pub(crate) static N_BITS_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Long(header(obj)?.n_bits as i64));
+
+pub(crate) static HEIGHT_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Int(header(obj)?.height as i32));
+
+pub(crate) static EXTENSION_ROOT_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.extension_root.into());
+
+pub(crate) static MINER_PK_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::GroupElement(header(obj)?.miner_pk));
+
+pub(crate) static POW_ONETIME_PK_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::GroupElement(header(obj)?.pow_onetime_pk));
+
+pub(crate) static POW_NONCE_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.pow_nonce.into());
+
+pub(crate) static POW_DISTANCE_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::BigInt(header(obj)?.pow_distance));
+
+pub(crate) static VOTES_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(header(obj)?.votes.into());
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::coll_by_index::ByIndex;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+    use ergotree_ir::types::scontext;
+    use ergotree_ir::types::sheader;
+    use ergotree_ir::types::smethod::SMethod;
+    use num_bigint::BigInt;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use std::rc::Rc;
+
+    fn last_header_property(method: &SMethod) -> Expr {
+        let headers: Expr = PropertyCall::new(Expr::Context, scontext::HEADERS_PROPERTY.clone())
+            .unwrap()
+            .into();
+        let last_header: Expr = ByIndex::new(headers, Expr::Const(0i32.into()), None)
+            .unwrap()
+            .into();
+        PropertyCall::new(last_header, method.clone())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_header_properties() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let h = ctx.headers[0].clone();
+        let eval = |method: &SMethod| eval_out::<Value>(&last_header_property(method), ctx.clone());
+        assert_eq!(eval(&sheader::ID_METHOD), h.id.clone().into());
+        assert_eq!(eval(&sheader::VERSION_METHOD), Value::Byte(h.version as i8));
+        assert_eq!(eval(&sheader::PARENT_ID_METHOD), h.parent_id.clone().into());
+        assert_eq!(
+            eval(&sheader::AD_PROOFS_ROOT_METHOD),
+            h.ad_proofs_root.clone().into()
+        );
+        assert_eq!(
+            eval_out::<AvlTreeData>(
+                &last_header_property(&sheader::STATE_ROOT_METHOD),
+                ctx.clone()
+            )
+            .digest,
+            h.state_root
+        );
+        assert_eq!(
+            eval(&sheader::TRANSACTIONS_ROOT_METHOD),
+            h.transaction_root.clone().into()
+        );
+        assert_eq!(
+            eval(&sheader::TIMESTAMP_METHOD),
+            Value::Long(h.timestamp as i64)
+        );
+        assert_eq!(eval(&sheader::N_BITS_METHOD), Value::Long(h.n_bits as i64));
+        assert_eq!(eval(&sheader::HEIGHT_METHOD), Value::Int(h.height as i32));
+        assert_eq!(
+            eval(&sheader::EXTENSION_ROOT_METHOD),
+            h.extension_root.clone().into()
+        );
+        assert_eq!(
+            eval_out::<EcPoint>(
+                &last_header_property(&sheader::MINER_PK_METHOD),
+                ctx.clone()
+            ),
+            *h.miner_pk
+        );
+        assert_eq!(
+            eval_out::<EcPoint>(
+                &last_header_property(&sheader::POW_ONETIME_PK_METHOD),
+                ctx.clone()
+            ),
+            *h.pow_onetime_pk
+        );
+        assert_eq!(eval(&sheader::POW_NONCE_METHOD), h.pow_nonce.clone().into());
+        assert_eq!(
+            eval_out::<BigInt>(
+                &last_header_property(&sheader::POW_DISTANCE_METHOD),
+                ctx.clone()
+            ),
+            h.pow_distance
+        );
+        assert_eq!(eval(&sheader::VOTES_METHOD), h.votes.into());
+    }
+}
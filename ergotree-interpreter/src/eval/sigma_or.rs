@@ -4,6 +4,7 @@ use ergotree_ir::mir::value::Value;
 use ergotree_ir::sigma_protocol::sigma_boolean::cor::Cor;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -12,6 +13,8 @@ use crate::eval::Evaluable;
 impl Evaluable for SigmaOr {
     fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
         let items_v_res = self.items.try_mapped_ref(|it| it.eval(env, ctx));
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.sigma_and_or, self.items.len())?;
         let items_sigmabool = items_v_res?
             .try_mapped(|it| it.try_extract_into::<SigmaProp>())?
             .mapped(|it| it.value().clone());
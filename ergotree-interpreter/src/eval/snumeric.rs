@@ -0,0 +1,174 @@
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::costs::Costs;
+use crate::eval::downcast::downcast_to_bigint;
+use crate::eval::downcast::downcast_to_byte;
+use crate::eval::downcast::downcast_to_int;
+use crate::eval::downcast::downcast_to_long;
+use crate::eval::downcast::downcast_to_short;
+use crate::eval::EvalError;
+
+use super::EvalFn;
+
+/// Big-endian two's complement representation of the numeric value
+fn numeric_to_bytes(obj: &Value) -> Result<Vec<u8>, EvalError> {
+    match obj {
+        Value::Byte(v) => Ok(v.to_be_bytes().to_vec()),
+        Value::Short(v) => Ok(v.to_be_bytes().to_vec()),
+        Value::Int(v) => Ok(v.to_be_bytes().to_vec()),
+        Value::Long(v) => Ok(v.to_be_bytes().to_vec()),
+        Value::BigInt(v) => Ok(v.to_signed_bytes_be()),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "expected numeric value, got {0:?}",
+            obj
+        ))),
+    }
+}
+
+pub(crate) static TO_BYTE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.downcast)?;
+    downcast_to_byte(obj)
+};
+
+pub(crate) static TO_SHORT_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.downcast)?;
+    downcast_to_short(obj)
+};
+
+pub(crate) static TO_INT_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.downcast)?;
+    downcast_to_int(obj)
+};
+
+pub(crate) static TO_LONG_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.downcast)?;
+    downcast_to_long(obj)
+};
+
+pub(crate) static TO_BIGINT_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.upcast)?;
+    downcast_to_bigint(obj)
+};
+
+pub(crate) static TO_BYTES_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    let bytes = numeric_to_bytes(&obj)?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.numeric_to_bytes, bytes.len())?;
+    Ok(bytes.into())
+};
+
+pub(crate) static TO_BITS_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    let bytes = numeric_to_bytes(&obj)?;
+    ctx.cost_accum
+        .add_items_cost(Costs::DEFAULT.numeric_to_bytes, bytes.len())?;
+    let bits: Vec<bool> = bytes
+        .iter()
+        .flat_map(|b| (0..8).rev().map(move |i| (b >> i) & 1 == 1))
+        .collect();
+    Ok(bits.into())
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::smethod::MethodId;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::snumeric;
+    use ergotree_ir::types::stype::SType;
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    fn prop_call(obj: Constant, tpe: SType, method_id: MethodId) -> Expr {
+        let type_id = match tpe {
+            SType::SByte => snumeric::BYTE_TYPE_ID,
+            SType::SShort => snumeric::SHORT_TYPE_ID,
+            SType::SInt => snumeric::INT_TYPE_ID,
+            SType::SLong => snumeric::LONG_TYPE_ID,
+            _ => snumeric::BIGINT_TYPE_ID,
+        };
+        PropertyCall::new(obj.into(), SMethod::from_ids(type_id, method_id).unwrap())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_to_bytes() {
+        let expr = prop_call(
+            0x0102i16.into(),
+            SType::SShort,
+            snumeric::TO_BYTES_METHOD_ID,
+        );
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![1i8, 2]);
+        let expr = prop_call(
+            BigInt::from(-1).into(),
+            SType::SBigInt,
+            snumeric::TO_BYTES_METHOD_ID,
+        );
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![-1i8]);
+    }
+
+    #[test]
+    fn eval_to_bits() {
+        let expr = prop_call(5i8.into(), SType::SByte, snumeric::TO_BITS_METHOD_ID);
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<bool>>(&expr),
+            vec![false, false, false, false, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn eval_to_bigint() {
+        let expr = prop_call(i64::MIN.into(), SType::SLong, snumeric::TO_BIGINT_METHOD_ID);
+        assert_eq!(eval_out_wo_ctx::<BigInt>(&expr), BigInt::from(i64::MIN));
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_long_conversions(v in any::<i64>()) {
+            let c: Constant = v.into();
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i8>(&prop_call(c.clone(), SType::SLong, snumeric::TO_BYTE_METHOD_ID)).ok(),
+                i8::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i16>(&prop_call(c.clone(), SType::SLong, snumeric::TO_SHORT_METHOD_ID)).ok(),
+                i16::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                try_eval_out_wo_ctx::<i32>(&prop_call(c.clone(), SType::SLong, snumeric::TO_INT_METHOD_ID)).ok(),
+                i32::try_from(v).ok()
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i64>(&prop_call(c.clone(), SType::SLong, snumeric::TO_LONG_METHOD_ID)),
+                v
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<Vec<i8>>(&prop_call(c, SType::SLong, snumeric::TO_BYTES_METHOD_ID)),
+                v.to_be_bytes().iter().map(|b| *b as i8).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn eval_byte_upcasts(v in any::<i8>()) {
+            let c: Constant = v.into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i32>(&prop_call(c.clone(), SType::SByte, snumeric::TO_INT_METHOD_ID)),
+                v as i32
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<BigInt>(&prop_call(c, SType::SByte, snumeric::TO_BIGINT_METHOD_ID)),
+                BigInt::from(v)
+            );
+        }
+    }
+}
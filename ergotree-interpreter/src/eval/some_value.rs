@@ -0,0 +1,31 @@
+use ergotree_ir::mir::some_value::SomeValue;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SomeValue {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(env, ctx)?;
+        Ok(Value::Opt(Box::new(Some(v))))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+
+    #[test]
+    fn eval() {
+        let expr: Expr = SomeValue {
+            input: Box::new(Expr::Const(42i32.into())),
+        }
+        .into();
+        assert_eq!(eval_out_wo_ctx::<Option<i32>>(&expr), Some(42));
+    }
+}
@@ -0,0 +1,277 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::costs::Costs;
+use crate::eval::scoll::call_lambda;
+use crate::eval::EvalError;
+
+use super::EvalFn;
+
+fn opt_obj(obj: Value, method_name: &str) -> Result<Option<Value>, EvalError> {
+    match obj {
+        Value::Opt(opt) => Ok(*opt),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "{0}: expected obj to be Value::Opt, got: {1:?}",
+            method_name, obj
+        ))),
+    }
+}
+
+fn lambda_arg(args: &[Value], method_name: &str) -> Result<Value, EvalError> {
+    args.first()
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound(format!("{0}: lambda arg is missing", method_name)))
+}
+
+pub(crate) static IS_EMPTY_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_is_empty)?;
+    Ok(opt_obj(obj, "isEmpty")?.is_none().into())
+};
+
+pub(crate) static IS_DEFINED_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_is_defined)?;
+    Ok(opt_obj(obj, "isDefined")?.is_some().into())
+};
+
+pub(crate) static GET_EVAL_FN: EvalFn = |_env, ctx, _method, obj, _args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_get)?;
+    opt_obj(obj, "get")?
+        .ok_or_else(|| EvalError::NotFound("calling Option.get on None".to_string()))
+};
+
+pub(crate) static GET_OR_ELSE_EVAL_FN: EvalFn = |_env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_get_or_else)?;
+    let default_v = args
+        .first()
+        .cloned()
+        .ok_or_else(|| EvalError::NotFound("getOrElse: default arg is missing".to_string()))?;
+    Ok(opt_obj(obj, "getOrElse")?.unwrap_or(default_v))
+};
+
+pub(crate) static MAP_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_map)?;
+    let mapper = lambda_arg(&args, "map")?;
+    let res = match opt_obj(obj, "map")? {
+        Some(v) => Some(call_lambda(env, ctx, &mapper, v)?),
+        None => None,
+    };
+    Ok(Value::Opt(Box::new(res)))
+};
+
+pub(crate) static FILTER_EVAL_FN: EvalFn = |env, ctx, _method, obj, args| {
+    ctx.cost_accum.add(Costs::DEFAULT.option_filter)?;
+    let condition = lambda_arg(&args, "filter")?;
+    let res = match opt_obj(obj, "filter")? {
+        Some(v) => {
+            if call_lambda(env, ctx, &condition, v.clone())?.try_extract_into::<bool>()? {
+                Some(v)
+            } else {
+                None
+            }
+        }
+        None => None,
+    };
+    Ok(Value::Opt(Box::new(res)))
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::extract_reg_as::ExtractRegisterAs;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::mir::none_value::NoneValue;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::mir::some_value::SomeValue;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::serialization::sigma_serialize_roundtrip;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::soption;
+    use ergotree_ir::types::stype::SType;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    fn some(v: i32) -> Expr {
+        SomeValue {
+            input: Box::new(Expr::Const(v.into())),
+        }
+        .into()
+    }
+
+    fn none() -> Expr {
+        NoneValue {
+            elem_tpe: SType::SInt,
+        }
+        .into()
+    }
+
+    fn call(obj: Expr, method: &SMethod, args: Vec<Expr>) -> Expr {
+        let arg_types = args.iter().map(|a| a.tpe()).collect();
+        let method = method.clone().specialize_for(obj.tpe(), arg_types).unwrap();
+        MethodCall::new(obj, method, args).unwrap().into()
+    }
+
+    fn property(obj: Expr, method: &SMethod) -> Expr {
+        let method = method.clone().specialize_for(obj.tpe(), vec![]).unwrap();
+        PropertyCall::new(obj, method).unwrap().into()
+    }
+
+    fn lambda(body: Expr) -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: 1.into(),
+                tpe: SType::SInt,
+            }],
+            body,
+        )
+        .into()
+    }
+
+    fn arg_use() -> Expr {
+        ValUse {
+            val_id: 1.into(),
+            tpe: SType::SInt,
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_is_empty_is_defined() {
+        assert!(!eval_out_wo_ctx::<bool>(&property(
+            some(1),
+            &soption::IS_EMPTY_METHOD
+        )));
+        assert!(eval_out_wo_ctx::<bool>(&property(
+            none(),
+            &soption::IS_EMPTY_METHOD
+        )));
+        assert!(eval_out_wo_ctx::<bool>(&property(
+            some(1),
+            &soption::IS_DEFINED_METHOD
+        )));
+        assert!(!eval_out_wo_ctx::<bool>(&property(
+            none(),
+            &soption::IS_DEFINED_METHOD
+        )));
+    }
+
+    #[test]
+    fn eval_get() {
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&property(some(7), &soption::GET_METHOD)),
+            7
+        );
+        assert!(try_eval_out_wo_ctx::<i32>(&property(none(), &soption::GET_METHOD)).is_err());
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&call(
+                none(),
+                &soption::GET_OR_ELSE_METHOD,
+                vec![Expr::Const(3i32.into())]
+            )),
+            3
+        );
+    }
+
+    #[test]
+    fn eval_map() {
+        let plus_one = lambda(
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: Box::new(arg_use()),
+                right: Box::new(Expr::Const(1i32.into())),
+            }
+            .into(),
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(
+                some(1),
+                &soption::MAP_METHOD,
+                vec![plus_one.clone()]
+            )),
+            Some(2)
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(none(), &soption::MAP_METHOD, vec![plus_one])),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_filter() {
+        let gt_one = lambda(
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: Box::new(arg_use()),
+                right: Box::new(Expr::Const(1i32.into())),
+            }
+            .into(),
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(
+                some(2),
+                &soption::FILTER_METHOD,
+                vec![gt_one.clone()]
+            )),
+            Some(2)
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(
+                some(1),
+                &soption::FILTER_METHOD,
+                vec![gt_one.clone()]
+            )),
+            None
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(none(), &soption::FILTER_METHOD, vec![gt_one])),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_register_map_after_roundtrip() {
+        // SELF.R0[Long].map({ (v: Long) => v + 1 })
+        let reg: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            0,
+            SType::SOption(SType::SLong.into()),
+        )
+        .unwrap()
+        .into();
+        let plus_one: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: 1.into(),
+                tpe: SType::SLong,
+            }],
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id: 1.into(),
+                        tpe: SType::SLong,
+                    }
+                    .into(),
+                ),
+                right: Box::new(Expr::Const(1i64.into())),
+            }
+            .into(),
+        )
+        .into();
+        let expr = sigma_serialize_roundtrip(&call(reg, &soption::MAP_METHOD, vec![plus_one]));
+        let ctx = Rc::new(force_any_val::<Context>());
+        let self_value = ctx.self_box.get_box(&ctx.box_arena).unwrap().value();
+        assert_eq!(eval_out::<Option<i64>>(&expr, ctx), Some(self_value + 1));
+    }
+}
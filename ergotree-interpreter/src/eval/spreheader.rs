@@ -0,0 +1,90 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::header::PreHeader;
+use ergotree_ir::mir::value::Value;
+
+use super::EvalError;
+use super::EvalFn;
+
+fn pre_header(obj: Value) -> Result<PreHeader, EvalError> {
+    Ok(obj.try_extract_into::<PreHeader>()?)
+}
+
+pub(crate) static VERSION_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Byte(pre_header(obj)?.version as i8));
+
+pub(crate) static PARENT_ID_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(pre_header(obj)?.parent_id.into());
+
+pub(crate) static TIMESTAMP_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Long(pre_header(obj)?.timestamp as i64));
+
+pub(crate) static N_BITS_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Long(pre_header(obj)?.n_bits as i64));
+
+pub(crate) static HEIGHT_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::Int(pre_header(obj)?.height as i32));
+
+pub(crate) static MINER_PK_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(Value::GroupElement(pre_header(obj)?.miner_pk));
+
+pub(crate) static VOTES_EVAL_FN: EvalFn =
+    |_env, _ctx, _method, obj, _args| Ok(pre_header(obj)?.votes.into());
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::scontext;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::spreheader;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use std::rc::Rc;
+
+    fn pre_header_property(method: &SMethod) -> Expr {
+        let pre_header: Expr =
+            PropertyCall::new(Expr::Context, scontext::PRE_HEADERS_PROPERTY.clone())
+                .unwrap()
+                .into();
+        PropertyCall::new(pre_header, method.clone())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_pre_header_properties() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let ph = ctx.pre_header.clone();
+        let eval = |method: &SMethod| eval_out::<Value>(&pre_header_property(method), ctx.clone());
+        assert_eq!(
+            eval(&spreheader::VERSION_METHOD),
+            Value::Byte(ph.version as i8)
+        );
+        assert_eq!(
+            eval(&spreheader::PARENT_ID_METHOD),
+            ph.parent_id.clone().into()
+        );
+        assert_eq!(
+            eval(&spreheader::TIMESTAMP_METHOD),
+            Value::Long(ph.timestamp as i64)
+        );
+        assert_eq!(
+            eval(&spreheader::N_BITS_METHOD),
+            Value::Long(ph.n_bits as i64)
+        );
+        assert_eq!(
+            eval(&spreheader::HEIGHT_METHOD),
+            Value::Int(ph.height as i32)
+        );
+        assert_eq!(
+            eval(&spreheader::MINER_PK_METHOD),
+            Value::GroupElement(ph.miner_pk.clone())
+        );
+        assert_eq!(eval(&spreheader::VOTES_METHOD), ph.votes.into());
+    }
+}
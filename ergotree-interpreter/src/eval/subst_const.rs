@@ -0,0 +1,240 @@
+use ergotree_ir::ergo_tree::ErgoTree;
+use ergotree_ir::ergo_tree::ErgoTreeHeader;
+use ergotree_ir::mir::constant::Constant;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::subst_const::SubstConstants;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::sigma_byte_reader;
+use ergotree_ir::serialization::sigma_byte_writer::SigmaByteWriter;
+use ergotree_ir::serialization::SerializationError;
+use ergotree_ir::serialization::SigmaSerializable;
+use sigma_ser::vlq_encode::ReadSigmaVlqExt;
+use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+use std::io::Read;
+use std::io::Write;
+
+use crate::eval::costs::Costs;
+use crate::eval::env::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SubstConstants {
+    fn eval(&self, env: &Env, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let script_bytes = self
+            .script_bytes
+            .eval(env, ctx)?
+            .try_extract_into::<Vec<u8>>()?;
+        let positions = self
+            .positions
+            .eval(env, ctx)?
+            .try_extract_into::<Vec<i32>>()?;
+        let new_values_v = self.new_values.eval(env, ctx)?;
+        let (new_values_tpe, new_values) = match new_values_v {
+            Value::Coll(coll) => (coll.elem_tpe().clone(), coll.as_vec()),
+            _ => {
+                return Err(EvalError::UnexpectedValue(format!(
+                    "SubstConstants: expected new_values to be a collection, got {0:?}",
+                    new_values_v
+                )))
+            }
+        };
+        if positions.len() != new_values.len() {
+            return Err(EvalError::Misc(format!(
+                "SubstConstants: expected positions and new_values to have the same length, got {0} and {1}",
+                positions.len(),
+                new_values.len()
+            )));
+        }
+        ctx.cost_accum
+            .add_items_cost(Costs::DEFAULT.subst_constants, positions.len())?;
+        // as in the reference implementation, only the header and the constants are parsed,
+        // the root expression bytes are copied to the result verbatim
+        let mut r = sigma_byte_reader::from_bytes(&script_bytes);
+        let header = ErgoTreeHeader::sigma_parse(&mut r)?;
+        if header.has_size() {
+            // same as the reference implementation (v5), the size is not written to the result
+            r.get_u32().map_err(SerializationError::from)?;
+        }
+        let mut constants = if header.is_constant_segregation() {
+            let constants_len = r.get_u32().map_err(SerializationError::from)?;
+            if constants_len as usize > ErgoTree::MAX_CONSTANTS_COUNT {
+                return Err(
+                    SerializationError::ValueOutOfBounds("too many constants".to_string()).into(),
+                );
+            }
+            (0..constants_len)
+                .map(|_| Constant::sigma_parse(&mut r))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+        let mut root_expr_bytes = Vec::new();
+        r.read_to_end(&mut root_expr_bytes)
+            .map_err(SerializationError::from)?;
+        // the constant at index `i` is replaced with the new value at the first occurrence of `i`
+        // in positions, the positions outside of the constants list (including negative ones)
+        // are ignored
+        for (i, old_constant) in constants.iter_mut().enumerate() {
+            let new_value = match positions.iter().position(|pos| *pos as i64 == i as i64) {
+                Some(j) => new_values[j].clone(),
+                None => continue,
+            };
+            if old_constant.tpe != new_values_tpe {
+                return Err(EvalError::UnexpectedValue(format!(
+                    "SubstConstants: cannot substitute constant of type {0:?} at position {1} with the value of type {2:?}",
+                    old_constant.tpe, i, new_values_tpe
+                )));
+            }
+            old_constant.v = new_value;
+        }
+        Ok(serialize_tree(&header, &constants, &root_expr_bytes)
+            .map_err(SerializationError::from)?
+            .into())
+    }
+}
+
+fn serialize_tree(
+    header: &ErgoTreeHeader,
+    constants: &[Constant],
+    root_expr_bytes: &[u8],
+) -> Result<Vec<u8>, std::io::Error> {
+    let mut res = Vec::new();
+    let mut w = SigmaByteWriter::new(&mut res, None);
+    header.sigma_serialize(&mut w)?;
+    if header.is_constant_segregation() {
+        w.put_usize_as_u32(constants.len())?;
+        constants
+            .iter()
+            .try_for_each(|c| c.sigma_serialize(&mut w))?;
+    }
+    w.write_all(root_expr_bytes)?;
+    Ok(res)
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+    use ergotree_ir::mir::expr::Expr;
+
+    // sigmaProp(1 < 2)
+    fn template() -> ErgoTree {
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Lt.into(),
+                    left: Box::new(Expr::Const(1i32.into())),
+                    right: Box::new(Expr::Const(2i32.into())),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        ErgoTree::new(ErgoTreeHeader::v0(true), &expr)
+    }
+
+    fn subst_constants(tree: &ErgoTree, positions: Vec<i32>, new_values: Constant) -> Expr {
+        SubstConstants::new(
+            Expr::Const(tree.sigma_serialize_bytes().into()),
+            Expr::Const(positions.into()),
+            Expr::Const(new_values),
+        )
+        .unwrap()
+        .into()
+    }
+
+    #[test]
+    fn eval_subst() {
+        let tree = template();
+        assert_eq!(tree.constants_len().unwrap(), 2);
+        let expr = subst_constants(&tree, vec![1], vec![0i32].into());
+        let res = ErgoTree::sigma_parse_bytes(&eval_out_wo_ctx::<Vec<u8>>(&expr)).unwrap();
+        let mut expected = tree;
+        expected.set_constant(1, 0i32.into()).unwrap();
+        assert_eq!(res, expected);
+        assert_eq!(res.get_constant(0).unwrap(), Some(1i32.into()));
+        assert_eq!(res.get_constant(1).unwrap(), Some(0i32.into()));
+    }
+
+    #[test]
+    fn eval_subst_keeps_root_expr_bytes() {
+        // encode the index of the last ConstantPlaceholder with a redundant VLQ byte
+        let non_canonical = |bytes: Vec<u8>| -> Vec<u8> {
+            // ConstantPlaceholder op code followed by the index
+            let ph = [115, 1];
+            let pos = bytes.windows(2).rposition(|w| w == ph).unwrap();
+            [&bytes[..pos + 1], &[0x81, 0x00], &bytes[pos + 2..]].concat()
+        };
+        let tree = template();
+        let script_bytes = non_canonical(tree.sigma_serialize_bytes());
+        assert_eq!(
+            ErgoTree::sigma_parse_bytes(&script_bytes)
+                .unwrap()
+                .proposition()
+                .unwrap(),
+            tree.proposition().unwrap()
+        );
+        let expr: Expr = SubstConstants::new(
+            Expr::Const(script_bytes.into()),
+            Expr::Const(vec![1].into()),
+            Expr::Const(vec![0i32].into()),
+        )
+        .unwrap()
+        .into();
+        let mut expected = tree;
+        expected.set_constant(1, 0i32.into()).unwrap();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&expr),
+            non_canonical(expected.sigma_serialize_bytes())
+        );
+    }
+
+    #[test]
+    fn eval_subst_out_of_bounds_position_ignored() {
+        let tree = template();
+        let expr = subst_constants(&tree, vec![5], vec![0i32].into());
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&expr),
+            tree.sigma_serialize_bytes()
+        );
+    }
+
+    #[test]
+    fn eval_subst_negative_position_ignored() {
+        let tree = template();
+        let expr = subst_constants(&tree, vec![-1], vec![0i32].into());
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&expr),
+            tree.sigma_serialize_bytes()
+        );
+    }
+
+    #[test]
+    fn eval_subst_duplicate_positions_first_wins() {
+        let tree = template();
+        let expr = subst_constants(&tree, vec![1, 0, 1], vec![3i32, 4, 5].into());
+        let res = ErgoTree::sigma_parse_bytes(&eval_out_wo_ctx::<Vec<u8>>(&expr)).unwrap();
+        assert_eq!(res.get_constant(0).unwrap(), Some(4i32.into()));
+        assert_eq!(res.get_constant(1).unwrap(), Some(3i32.into()));
+    }
+
+    #[test]
+    fn eval_subst_type_mismatch() {
+        let tree = template();
+        let expr = subst_constants(&tree, vec![0], vec![0i64].into());
+        assert!(try_eval_out_wo_ctx::<Vec<u8>>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_subst_length_mismatch() {
+        let tree = template();
+        let expr = subst_constants(&tree, vec![0, 1], vec![0i32].into());
+        assert!(try_eval_out_wo_ctx::<Vec<u8>>(&expr).is_err());
+    }
+}
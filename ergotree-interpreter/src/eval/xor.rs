@@ -3,6 +3,7 @@ use ergotree_ir::mir::value::NativeColl;
 use ergotree_ir::mir::value::Value;
 use ergotree_ir::mir::xor::Xor;
 
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::EvalContext;
 use crate::eval::EvalError;
@@ -23,6 +24,8 @@ impl Evaluable for Xor {
                 Value::Coll(CollKind::NativeColl(NativeColl::CollByte(l_byte))),
                 Value::Coll(CollKind::NativeColl(NativeColl::CollByte(r_byte))),
             ) => {
+                ctx.cost_accum
+                    .add_items_cost(Costs::DEFAULT.xor, l_byte.len().min(r_byte.len()))?;
                 let xor = helper_xor(l_byte, r_byte);
                 Ok(xor.into())
             }
@@ -18,6 +18,7 @@
 mod contracts;
 mod util;
 
+pub mod avl_tree;
 pub mod eval;
 // TODO: remove after https://github.com/ergoplatform/sigma-rust/pull/226 is merged
 #[allow(clippy::unwrap_used)]
@@ -8,8 +8,10 @@ pub mod verifier;
 
 mod challenge;
 mod crypto_utils;
+mod dht_protocol;
 mod dlog_protocol;
 mod fiat_shamir;
+mod gf2_192;
 mod proof_tree;
 mod sig_serializer;
 mod unchecked_tree;
@@ -18,6 +20,7 @@ mod unproven_tree;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 use k256::Scalar;
 
+use dht_protocol::FirstDhTupleProverMessage;
 use dlog_protocol::FirstDlogProverMessage;
 use std::convert::TryInto;
 use unchecked_tree::{UncheckedSigmaTree, UncheckedTree};
@@ -40,15 +43,15 @@ pub(crate) trait ProverMessage {
 pub enum FirstProverMessage {
     /// Discrete log
     FirstDlogProverMessage(FirstDlogProverMessage),
-    /// DH tupl
-    FirstDhtProverMessage,
+    /// DH tuple
+    FirstDhtProverMessage(FirstDhTupleProverMessage),
 }
 
 impl ProverMessage for FirstProverMessage {
     fn bytes(&self) -> Vec<u8> {
         match self {
             FirstProverMessage::FirstDlogProverMessage(fdpm) => fdpm.bytes(),
-            FirstProverMessage::FirstDhtProverMessage => todo!(),
+            FirstProverMessage::FirstDhtProverMessage(fdhtpm) => fdhtpm.bytes(),
         }
     }
 }
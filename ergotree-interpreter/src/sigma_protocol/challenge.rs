@@ -55,6 +55,12 @@ impl From<FiatShamirHash> for Challenge {
     }
 }
 
+impl From<[u8; SOUNDNESS_BYTES]> for Challenge {
+    fn from(bytes: [u8; SOUNDNESS_BYTES]) -> Self {
+        Challenge(FiatShamirHash(Box::new(bytes)))
+    }
+}
+
 impl SigmaSerializable for Challenge {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
         w.write_all(self.0 .0.as_ref())
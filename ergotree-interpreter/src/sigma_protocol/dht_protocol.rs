@@ -0,0 +1,164 @@
+//! Diffie-Hellman tuple signature protocol
+
+use super::ProverMessage;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use k256::Scalar;
+
+/// First message from the prover (message `a` of `SigmaProtocol`) for Diffie-Hellman tuple case
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FirstDhTupleProverMessage {
+    /// message `a`
+    pub(crate) a: Box<EcPoint>,
+    /// message `b`
+    pub(crate) b: Box<EcPoint>,
+}
+
+impl FirstDhTupleProverMessage {
+    /// Create new instance
+    pub fn new(a: EcPoint, b: EcPoint) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+        }
+    }
+}
+
+impl ProverMessage for FirstDhTupleProverMessage {
+    fn bytes(&self) -> Vec<u8> {
+        let mut res = self.a.sigma_serialize_bytes();
+        res.append(&mut self.b.sigma_serialize_bytes());
+        res
+    }
+}
+
+/// Second message from the prover (message `z` of `SigmaProtocol`) for Diffie-Hellman tuple case
+#[derive(PartialEq, Debug, Clone)]
+pub struct SecondDhTupleProverMessage {
+    /// message `z`
+    pub z: Scalar,
+}
+
+impl From<Scalar> for SecondDhTupleProverMessage {
+    fn from(z: Scalar) -> Self {
+        SecondDhTupleProverMessage { z }
+    }
+}
+
+/// Interactive prover
+pub(crate) mod interactive_prover {
+    use std::ops::Mul;
+
+    use super::{FirstDhTupleProverMessage, SecondDhTupleProverMessage};
+    use crate::sigma_protocol::private_input::DhTupleProverInput;
+    use crate::sigma_protocol::Challenge;
+    use ergotree_ir::sigma_protocol::dlog_group;
+    use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
+    use k256::Scalar;
+
+    /// Simulate the protocol for the given public input and challenge
+    pub(crate) fn simulate(
+        public_input: &ProveDhTuple,
+        challenge: &Challenge,
+    ) -> (FirstDhTupleProverMessage, SecondDhTupleProverMessage) {
+        //SAMPLE a random z <- Zq
+        let z = dlog_group::random_scalar_in_group_range();
+
+        // COMPUTE a = g^z*u^(-e) and b = h^z*v^{-e}  (where -e here means -e mod q)
+        let e: Scalar = challenge.clone().into();
+        let minus_e = e.negate();
+        let g_to_z = dlog_group::exponentiate(&public_input.gv, &z);
+        let h_to_z = dlog_group::exponentiate(&public_input.hv, &z);
+        let u_to_minus_e = dlog_group::exponentiate(&public_input.uv, &minus_e);
+        let v_to_minus_e = dlog_group::exponentiate(&public_input.vv, &minus_e);
+        let a = g_to_z * &u_to_minus_e;
+        let b = h_to_z * &v_to_minus_e;
+        (
+            FirstDhTupleProverMessage::new(a, b),
+            SecondDhTupleProverMessage { z },
+        )
+    }
+
+    /// Create first message from the prover and a randomness
+    pub(crate) fn first_message(
+        public_input: &ProveDhTuple,
+    ) -> (Scalar, FirstDhTupleProverMessage) {
+        let r = dlog_group::random_scalar_in_group_range();
+        let a = dlog_group::exponentiate(&public_input.gv, &r);
+        let b = dlog_group::exponentiate(&public_input.hv, &r);
+        (r, FirstDhTupleProverMessage::new(a, b))
+    }
+
+    /// Create second message from the prover
+    pub(crate) fn second_message(
+        private_input: &DhTupleProverInput,
+        rnd: Scalar,
+        challenge: &Challenge,
+    ) -> SecondDhTupleProverMessage {
+        let e: Scalar = challenge.clone().into();
+        // modulo multiplication, no need to explicit mod op
+        let ew = e.mul(&private_input.w);
+        // modulo addition, no need to explicit mod op
+        let z = rnd.add(&ew);
+        z.into()
+    }
+
+    /**
+     * The function computes initial prover's commitment to randomness
+     * ("a" message of the sigma-protocol, which in this case has two parts "a" and "b")
+     * based on the verifier's challenge ("e")
+     * and prover's response ("z")
+     *
+     * g^z = a*u^e, h^z = b*v^e  => a = g^z/u^e, b = h^z/v^e
+     */
+    pub(crate) fn compute_commitment(
+        proposition: &ProveDhTuple,
+        challenge: &Challenge,
+        second_message: &SecondDhTupleProverMessage,
+    ) -> (EcPoint, EcPoint) {
+        let e: Scalar = challenge.clone().into();
+        let g_z = dlog_group::exponentiate(&proposition.gv, &second_message.z);
+        let h_z = dlog_group::exponentiate(&proposition.hv, &second_message.z);
+        let u_e = dlog_group::exponentiate(&proposition.uv, &e);
+        let v_e = dlog_group::exponentiate(&proposition.vv, &e);
+        (
+            g_z * &dlog_group::inverse(&u_e),
+            h_z * &dlog_group::inverse(&v_e),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use crate::sigma_protocol::private_input::DhTupleProverInput;
+
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn test_compute_commitment(secret in any::<DhTupleProverInput>(), challenge in any::<Challenge>()) {
+            let pk = secret.public_image();
+            let (r, commitment) = interactive_prover::first_message(pk);
+            let second_message = interactive_prover::second_message(&secret, r, &challenge);
+            let (a, b) = interactive_prover::compute_commitment(pk, &challenge, &second_message);
+            prop_assert_eq!(a, *commitment.a);
+            prop_assert_eq!(b, *commitment.b);
+        }
+
+        #[test]
+        fn test_simulate(secret in any::<DhTupleProverInput>(), challenge in any::<Challenge>()) {
+            let pk = secret.public_image();
+            let (fm, sm) = interactive_prover::simulate(pk, &challenge);
+            let (a, b) = interactive_prover::compute_commitment(pk, &challenge, &sm);
+            prop_assert_eq!(a, *fm.a);
+            prop_assert_eq!(b, *fm.b);
+        }
+    }
+}
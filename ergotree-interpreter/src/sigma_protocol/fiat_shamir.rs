@@ -128,6 +128,9 @@ fn fiat_shamir_write_bytes<W: SigmaByteWrite>(
         ProofTreeKind::Conjecture(c) => {
             w.put_u8(INTERNAL_NODE_PREFIX)?;
             w.put_u8(c.conjecture_type() as u8)?;
+            if let Some(k) = c.threshold_k() {
+                w.put_u8(k)?;
+            }
             w.put_i16_be_bytes(c.children().len() as i16)?;
             for child in &c.children() {
                 fiat_shamir_write_bytes(child, w)?;
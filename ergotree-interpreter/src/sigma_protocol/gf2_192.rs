@@ -0,0 +1,290 @@
+//! Arithmetic in GF(2^192) and polynomials over it (used for THRESHOLD challenges)
+//!
+//! Byte encoding follows the reference implementation (sigmastate `GF2_192`, `GF2_192_Poly`)
+//! so that threshold signatures are interoperable.
+
+use std::convert::TryInto;
+
+use super::challenge::Challenge;
+use super::SOUNDNESS_BYTES;
+
+/// Low bits of the irreducible polynomial x^192 + x^7 + x^2 + x + 1
+const IRRED_PENTANOMIAL: u64 = (1 << 7) | (1 << 2) | (1 << 1) | 1;
+
+/// Element of GF(2^192), stored as three little-endian 64-bit words
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Gf2_192 {
+    word: [u64; 3],
+}
+
+impl Gf2_192 {
+    /// Zero of the field
+    pub(crate) fn zero() -> Self {
+        Gf2_192 { word: [0, 0, 0] }
+    }
+
+    /// One of the field
+    pub(crate) fn one() -> Self {
+        Gf2_192 { word: [1, 0, 0] }
+    }
+
+    /// Parse 24 bytes, least significant byte first
+    pub(crate) fn from_bytes(bytes: &[u8; SOUNDNESS_BYTES]) -> Self {
+        let mut word = [0u64; 3];
+        for (i, w) in word.iter_mut().enumerate() {
+            #[allow(clippy::unwrap_used)] // slice is exactly 8 bytes long
+            let chunk: [u8; 8] = bytes[i * 8..(i + 1) * 8].try_into().unwrap();
+            *w = u64::from_le_bytes(chunk);
+        }
+        Gf2_192 { word }
+    }
+
+    /// Serialize to 24 bytes, least significant byte first
+    pub(crate) fn to_bytes(self) -> [u8; SOUNDNESS_BYTES] {
+        let mut res = [0u8; SOUNDNESS_BYTES];
+        for (i, w) in self.word.iter().enumerate() {
+            res[i * 8..(i + 1) * 8].copy_from_slice(&w.to_le_bytes());
+        }
+        res
+    }
+
+    /// Multiplication by x, reduced by the irreducible polynomial
+    fn mul_by_x(self) -> Self {
+        let [w0, w1, w2] = self.word;
+        let carry = w2 >> 63;
+        Gf2_192 {
+            word: [
+                (w0 << 1) ^ (carry * IRRED_PENTANOMIAL),
+                (w1 << 1) | (w0 >> 63),
+                (w2 << 1) | (w1 >> 63),
+            ],
+        }
+    }
+
+    /// Computes the multiplicative inverse (0 for 0)
+    pub(crate) fn invert(self) -> Self {
+        // a^(2^192 - 2) = a^(-1), where 2^192 - 2 = 2 + 4 + ... + 2^191
+        let mut res = Gf2_192::one();
+        let mut square = self * self;
+        for _ in 1..192 {
+            res = res * square;
+            square = square * square;
+        }
+        res
+    }
+}
+
+impl From<u8> for Gf2_192 {
+    fn from(v: u8) -> Self {
+        Gf2_192 {
+            word: [v as u64, 0, 0],
+        }
+    }
+}
+
+impl std::ops::Add for Gf2_192 {
+    type Output = Gf2_192;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf2_192 {
+            word: [
+                self.word[0] ^ rhs.word[0],
+                self.word[1] ^ rhs.word[1],
+                self.word[2] ^ rhs.word[2],
+            ],
+        }
+    }
+}
+
+impl std::ops::Mul for Gf2_192 {
+    type Output = Gf2_192;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // shift-and-add, starting from the most significant bit of rhs
+        let mut res = Gf2_192::zero();
+        for i in (0..192).rev() {
+            res = res.mul_by_x();
+            if (rhs.word[i / 64] >> (i % 64)) & 1 == 1 {
+                res = res + self;
+            }
+        }
+        res
+    }
+}
+
+impl From<Challenge> for Gf2_192 {
+    fn from(c: Challenge) -> Self {
+        let bytes: Vec<u8> = c.into();
+        #[allow(clippy::unwrap_used)] // challenge is always SOUNDNESS_BYTES long
+        Gf2_192::from_bytes(&bytes.as_slice().try_into().unwrap())
+    }
+}
+
+impl From<Gf2_192> for Challenge {
+    fn from(v: Gf2_192) -> Self {
+        Challenge::from(v.to_bytes())
+    }
+}
+
+/// Polynomial over GF(2^192)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Gf2_192Poly {
+    /// Coefficients, starting from the free term
+    coefficients: Vec<Gf2_192>,
+}
+
+impl Gf2_192Poly {
+    /// Polynomial of the given degree with all coefficients zero
+    fn zero(degree: usize) -> Self {
+        Gf2_192Poly {
+            coefficients: vec![Gf2_192::zero(); degree + 1],
+        }
+    }
+
+    /// Constructs the polynomial with the free term `coeff0` and the rest of the coefficients
+    /// given as concatenated 24-byte encodings in `more_coeffs`
+    pub(crate) fn from_byte_array(coeff0: Gf2_192, more_coeffs: &[u8]) -> Self {
+        let mut coefficients = vec![coeff0];
+        for chunk in more_coeffs.chunks_exact(SOUNDNESS_BYTES) {
+            #[allow(clippy::unwrap_used)] // chunk is exactly SOUNDNESS_BYTES long
+            coefficients.push(Gf2_192::from_bytes(&chunk.try_into().unwrap()));
+        }
+        Gf2_192Poly { coefficients }
+    }
+
+    /// Serializes all coefficients except the free term
+    pub(crate) fn to_bytes_without_coeff0(&self) -> Vec<u8> {
+        self.coefficients
+            .iter()
+            .skip(1)
+            .flat_map(|c| c.to_bytes().to_vec())
+            .collect()
+    }
+
+    /// Degree of the polynomial (number of coefficients - 1)
+    pub(crate) fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Evaluates the polynomial at the given point
+    pub(crate) fn evaluate(&self, x: u8) -> Gf2_192 {
+        let x = Gf2_192::from(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Gf2_192::zero(), |acc, c| acc * x + *c)
+    }
+
+    /// Multiplies the polynomial by (x + r), increasing its degree by one
+    fn mul_by_monic_linear(&self, r: Gf2_192) -> Self {
+        let mut res = Gf2_192Poly::zero(self.degree() + 1);
+        for (i, c) in self.coefficients.iter().enumerate() {
+            res.coefficients[i + 1] = res.coefficients[i + 1] + *c;
+            res.coefficients[i] = res.coefficients[i] + *c * r;
+        }
+        res
+    }
+
+    /// Interpolates the polynomial of degree `points.len()` that passes through (0, `value_at_0`)
+    /// and (`points[i]`, `values[i]`) for every `i`.
+    /// Points are expected to be distinct and non-zero.
+    pub(crate) fn interpolate(points: &[u8], values: &[Gf2_192], value_at_0: Gf2_192) -> Self {
+        let xs: Vec<Gf2_192> = std::iter::once(Gf2_192::zero())
+            .chain(points.iter().map(|p| Gf2_192::from(*p)))
+            .collect();
+        let ys: Vec<Gf2_192> = std::iter::once(value_at_0)
+            .chain(values.iter().copied())
+            .collect();
+        let mut res = Gf2_192Poly::zero(points.len());
+        // Lagrange interpolation (subtraction is addition in characteristic 2)
+        for (j, (xj, yj)) in xs.iter().zip(ys.iter()).enumerate() {
+            let mut basis = Gf2_192Poly {
+                coefficients: vec![Gf2_192::one()],
+            };
+            let mut denominator = Gf2_192::one();
+            for (m, xm) in xs.iter().enumerate() {
+                if m != j {
+                    basis = basis.mul_by_monic_linear(*xm);
+                    denominator = denominator * (*xj + *xm);
+                }
+            }
+            let factor = *yj * denominator.invert();
+            for (r, b) in res.coefficients.iter_mut().zip(basis.coefficients.iter()) {
+                *r = *r + *b * factor;
+            }
+        }
+        res
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::array::uniform24;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn any_gf() -> impl Strategy<Value = Gf2_192> {
+        uniform24(any::<u8>()).prop_map(|b| Gf2_192::from_bytes(&b))
+    }
+
+    #[test]
+    fn x_pow_192_is_reduced() {
+        let x = Gf2_192::from(2u8);
+        let mut res = Gf2_192::one();
+        for _ in 0..192 {
+            res = res * x;
+        }
+        assert_eq!(res, Gf2_192::from(IRRED_PENTANOMIAL as u8));
+    }
+
+    proptest! {
+
+        #[test]
+        fn bytes_roundtrip(b in uniform24(any::<u8>())) {
+            prop_assert_eq![Gf2_192::from_bytes(&b).to_bytes(), b];
+        }
+
+        #[test]
+        fn mul_one(a in any_gf()) {
+            prop_assert_eq![a * Gf2_192::one(), a];
+        }
+
+        #[test]
+        fn mul_commutative(a in any_gf(), b in any_gf()) {
+            prop_assert_eq![a * b, b * a];
+        }
+
+        #[test]
+        fn mul_distributive(a in any_gf(), b in any_gf(), c in any_gf()) {
+            prop_assert_eq![a * (b + c), a * b + a * c];
+        }
+
+        #[test]
+        fn invert(a in any_gf()) {
+            prop_assume!(a != Gf2_192::zero());
+            prop_assert_eq![a * a.invert(), Gf2_192::one()];
+        }
+
+        #[test]
+        fn poly_bytes_roundtrip(coeff0 in any_gf(), more in vec(any_gf(), 0..10)) {
+            let bytes: Vec<u8> = more.iter().flat_map(|c| c.to_bytes().to_vec()).collect();
+            let poly = Gf2_192Poly::from_byte_array(coeff0, &bytes);
+            prop_assert_eq![poly.degree(), more.len()];
+            prop_assert_eq![poly.evaluate(0), coeff0];
+            prop_assert_eq![poly.to_bytes_without_coeff0(), bytes];
+        }
+
+        #[test]
+        fn interpolate(values in vec(any_gf(), 0..10), value_at_0 in any_gf()) {
+            let points: Vec<u8> = (1..=values.len() as u8).map(|i| i * 3).collect();
+            let poly = Gf2_192Poly::interpolate(&points, &values, value_at_0);
+            prop_assert_eq![poly.degree(), points.len()];
+            prop_assert_eq![poly.evaluate(0), value_at_0];
+            for (p, v) in points.iter().zip(values.iter()) {
+                prop_assert_eq![poly.evaluate(*p), *v];
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! Private input types for the prover's secrets
 use std::convert::TryInto;
 
+use ergotree_ir::serialization::SigmaSerializable;
 use ergotree_ir::sigma_protocol::dlog_group;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
 use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 
 use k256::elliptic_curve::ff::PrimeField;
 use k256::Scalar;
@@ -76,13 +80,86 @@ impl From<Scalar> for DlogProverInput {
     }
 }
 
+/// Diffie-Hellman tuple and secret
+/// Used in a proof that of equality of discrete logarithms (i.e., a proof of a Diffie-Hellman tuple):
+/// given group elements g, h, u, v, the proof convinces a verifier that the prover knows `w` such
+/// that `u = g^w` and `v = h^w`, without revealing `w`
+#[derive(PartialEq, Debug, Clone)]
+pub struct DhTupleProverInput {
+    /// Diffie-Hellman tuple's secret
+    pub w: Scalar,
+    /// Diffie-Hellman tuple
+    pub common_input: ProveDhTuple,
+}
+
+impl DhTupleProverInput {
+    /// Size in bytes: 32 bytes for secret + 4*33 bytes for the tuple points
+    pub const SIZE_BYTES: usize = DlogProverInput::SIZE_BYTES + 4 * EcPoint::GROUP_SIZE;
+
+    /// Create random secret and Diffie-Hellman tuple
+    pub fn random() -> DhTupleProverInput {
+        let g = dlog_group::generator();
+        let h = dlog_group::exponentiate(
+            &dlog_group::generator(),
+            &dlog_group::random_scalar_in_group_range(),
+        );
+        let w = dlog_group::random_scalar_in_group_range();
+        let u = dlog_group::exponentiate(&g, &w);
+        let v = dlog_group::exponentiate(&h, &w);
+        let common_input = ProveDhTuple::new(g, h, u, v);
+        DhTupleProverInput { w, common_input }
+    }
+
+    /// Attempts to parse the given byte array as a secret (SEC-1-encoded scalar) followed
+    /// by the serialized points of the tuple (g, h, u, v).
+    /// Returns None if the secret is not in the range [0, modulus) or any point is invalid, or
+    /// the points do not form a Diffie-Hellman tuple with the secret.
+    pub fn from_bytes(bytes: &[u8; DhTupleProverInput::SIZE_BYTES]) -> Option<DhTupleProverInput> {
+        let (w_bytes, points_bytes) = bytes.split_at(DlogProverInput::SIZE_BYTES);
+        let w = DlogProverInput::from_bytes(w_bytes.try_into().ok()?)?.w;
+        let common_input = ProveDhTuple::sigma_parse_bytes(points_bytes).ok()?;
+        let u = dlog_group::exponentiate(&common_input.gv, &w);
+        let v = dlog_group::exponentiate(&common_input.hv, &w);
+        if u == *common_input.uv && v == *common_input.vv {
+            Some(DhTupleProverInput { w, common_input })
+        } else {
+            None
+        }
+    }
+
+    /// Byte representation of the secret followed by the serialized points of the tuple (g, h, u, v)
+    pub fn to_bytes(&self) -> [u8; DhTupleProverInput::SIZE_BYTES] {
+        let mut bytes = [0u8; DhTupleProverInput::SIZE_BYTES];
+        let w_bytes: [u8; DlogProverInput::SIZE_BYTES] = self.w.to_bytes().into();
+        let points_bytes = self.common_input.sigma_serialize_bytes();
+        bytes[..DlogProverInput::SIZE_BYTES].copy_from_slice(&w_bytes);
+        bytes[DlogProverInput::SIZE_BYTES..].copy_from_slice(&points_bytes);
+        bytes
+    }
+
+    /// Public image (Diffie-Hellman tuple)
+    pub fn public_image(&self) -> &ProveDhTuple {
+        &self.common_input
+    }
+}
+
 /// Private inputs (secrets)
 #[derive(PartialEq, Debug, Clone, From)]
 pub enum PrivateInput {
     /// Discrete logarithm prover input
     DlogProverInput(DlogProverInput),
     /// DH tuple prover input
-    DiffieHellmanTupleProverInput,
+    DhTupleProverInput(DhTupleProverInput),
+}
+
+impl PrivateInput {
+    /// Public image of the secret
+    pub fn public_image(&self) -> SigmaBoolean {
+        match self {
+            PrivateInput::DlogProverInput(dl) => dl.public_image().into(),
+            PrivateInput::DhTupleProverInput(dht) => dht.public_image().clone().into(),
+        }
+    }
 }
 
 #[cfg(feature = "arbitrary")]
@@ -105,8 +182,37 @@ mod arbitrary {
             .boxed()
         }
     }
+
+    impl Arbitrary for DhTupleProverInput {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                Just(DhTupleProverInput::random()),
+                Just(DhTupleProverInput::random()),
+                Just(DhTupleProverInput::random()),
+                Just(DhTupleProverInput::random()),
+                Just(DhTupleProverInput::random()),
+            ]
+            .boxed()
+        }
+    }
 }
 
 #[cfg(test)]
 #[cfg(feature = "arbitrary")]
-mod tests {}
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn dht_bytes_roundtrip(secret in any::<DhTupleProverInput>()) {
+            let bytes = secret.to_bytes();
+            prop_assert_eq!(DhTupleProverInput::from_bytes(&bytes), Some(secret));
+        }
+    }
+}
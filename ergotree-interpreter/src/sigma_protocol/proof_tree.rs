@@ -8,10 +8,14 @@ use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjectureItems;
 
 use crate::sigma_protocol::unproven_tree::CandUnproven;
+use crate::sigma_protocol::unproven_tree::CthresholdUnproven;
 use crate::sigma_protocol::unproven_tree::UnprovenConjecture;
 use crate::sigma_protocol::UncheckedSchnorr;
 use crate::sigma_protocol::UnprovenSchnorr;
 
+use super::unchecked_tree::UncheckedDhTuple;
+use super::unproven_tree::UnprovenDhTuple;
+
 use super::challenge::Challenge;
 use super::prover::ProverError;
 use super::unchecked_tree::UncheckedConjecture;
@@ -77,12 +81,24 @@ impl From<UncheckedSchnorr> for ProofTree {
     }
 }
 
+impl From<UncheckedDhTuple> for ProofTree {
+    fn from(v: UncheckedDhTuple) -> Self {
+        UncheckedTree::UncheckedSigmaTree(v.into()).into()
+    }
+}
+
 impl From<UnprovenSchnorr> for ProofTree {
     fn from(v: UnprovenSchnorr) -> Self {
         UnprovenTree::UnprovenLeaf(v.into()).into()
     }
 }
 
+impl From<UnprovenDhTuple> for ProofTree {
+    fn from(v: UnprovenDhTuple) -> Self {
+        UnprovenTree::UnprovenLeaf(v.into()).into()
+    }
+}
+
 impl From<CandUnproven> for ProofTree {
     fn from(v: CandUnproven) -> Self {
         UnprovenTree::UnprovenConjecture(v.into()).into()
@@ -95,6 +111,12 @@ impl From<CorUnproven> for ProofTree {
     }
 }
 
+impl From<CthresholdUnproven> for ProofTree {
+    fn from(v: CthresholdUnproven) -> Self {
+        UnprovenTree::UnprovenConjecture(v.into()).into()
+    }
+}
+
 impl From<UnprovenConjecture> for ProofTree {
     fn from(v: UnprovenConjecture) -> Self {
         UnprovenTree::UnprovenConjecture(v).into()
@@ -131,12 +153,14 @@ pub(crate) trait ProofTreeLeaf: Debug {
 pub(crate) enum ConjectureType {
     And = 0,
     Or = 1,
-    // Threshold = 2,
+    Threshold = 2,
 }
 
 pub(crate) trait ProofTreeConjecture {
     fn conjecture_type(&self) -> ConjectureType;
     fn children(&self) -> SigmaConjectureItems<ProofTree>;
+    /// Number of children to be proven for THRESHOLD, `None` for other conjectures
+    fn threshold_k(&self) -> Option<u8>;
 }
 
 pub(crate) enum ProofTreeKind<'a> {
@@ -182,6 +206,13 @@ where
                     }))
                     .into()
                 }
+                UnprovenConjecture::CthresholdUnproven(ct) => {
+                    UnprovenTree::UnprovenConjecture(UnprovenConjecture::CthresholdUnproven(
+                        ct.clone()
+                            .with_children(ct.children.clone().try_mapped(|c| rewrite(c, f))?),
+                    ))
+                    .into()
+                }
             },
         },
         ProofTree::UncheckedTree(unch_tree) => match unch_tree {
@@ -215,6 +246,23 @@ where
                         }
                         .into()
                     }
+                    UncheckedConjecture::CthresholdUnchecked {
+                        challenge,
+                        children,
+                        k,
+                        polynomial,
+                    } => {
+                        let rewritten_children =
+                            children.clone().try_mapped(|c| rewrite(c.into(), f))?;
+                        let casted_children = cast_to_ust(rewritten_children)?;
+                        UncheckedConjecture::CthresholdUnchecked {
+                            children: casted_children,
+                            challenge: challenge.clone(),
+                            k: *k,
+                            polynomial: polynomial.clone(),
+                        }
+                        .into()
+                    }
                 },
             },
         },
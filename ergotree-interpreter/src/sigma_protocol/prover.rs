@@ -11,10 +11,12 @@ use crate::sigma_protocol::proof_tree::ProofTree;
 use crate::sigma_protocol::unchecked_tree::UncheckedLeaf;
 use crate::sigma_protocol::unproven_tree::CandUnproven;
 use crate::sigma_protocol::unproven_tree::CorUnproven;
+use crate::sigma_protocol::unproven_tree::CthresholdUnproven;
 use crate::sigma_protocol::unproven_tree::NodePosition;
 use crate::sigma_protocol::Challenge;
 use crate::sigma_protocol::UncheckedSigmaTree;
 use crate::sigma_protocol::UnprovenLeaf;
+use ergotree_ir::sigma_protocol::dlog_group;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjectureItems;
 use std::convert::TryInto;
@@ -27,26 +29,45 @@ use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
 pub use prover_result::*;
 
+use self::hint::CommitmentHint;
+use self::hint::Hint;
 use self::hint::HintsBag;
+use self::hint::OwnCommitment;
+use self::hint::RealCommitment;
+use self::hint::RealSecretProof;
+use self::hint::SecretProven;
+use self::hint::SimulatedCommitment;
+use self::hint::SimulatedSecretProof;
 
+use super::crypto_utils::secure_random_bytes;
+use super::dht_protocol;
 use super::dlog_protocol;
 use super::fiat_shamir::FiatShamirTreeSerializationError;
+use super::gf2_192::Gf2_192;
+use super::gf2_192::Gf2_192Poly;
 use super::private_input::PrivateInput;
 use super::proof_tree;
 use super::proof_tree::ProofTreeLeaf;
+use super::sig_serializer::parse_sig_compute_challenges;
 use super::sig_serializer::serialize_sig;
+use super::sig_serializer::SigParsingError;
 use super::unchecked_tree::UncheckedConjecture;
+use super::unchecked_tree::UncheckedDhTuple;
 use super::unchecked_tree::UncheckedSchnorr;
 use super::unchecked_tree::UncheckedTree;
 use super::unproven_tree::UnprovenConjecture;
+use super::unproven_tree::UnprovenDhTuple;
 use super::unproven_tree::UnprovenSchnorr;
 use super::unproven_tree::UnprovenTree;
+use super::verifier::compute_commitments;
+use super::FirstProverMessage;
 
 use crate::eval::context::Context;
 use crate::eval::env::Env;
 use crate::eval::{EvalError, Evaluator};
 
 use derive_more::From;
+use k256::Scalar;
 use thiserror::Error;
 
 /// Prover errors
@@ -102,23 +123,47 @@ pub trait Prover: Evaluator {
         hints_bag: &HintsBag,
     ) -> Result<ProverResult, ProverError> {
         let expr = tree.proposition()?;
-        let proof = self
+        let reduction_result = self
             .reduce_to_crypto(expr.as_ref(), env, ctx)
-            .map_err(ProverError::EvalError)
-            .and_then(|v| match v.sigma_prop {
-                SigmaBoolean::TrivialProp(true) => Ok(UncheckedTree::NoProof),
-                SigmaBoolean::TrivialProp(false) => Err(ProverError::ReducedToFalse),
-                sb => {
-                    let tree = convert_to_unproven(sb);
-                    let unchecked_tree = prove_to_unchecked(self, tree, message, hints_bag)?;
-                    Ok(UncheckedTree::UncheckedSigmaTree(unchecked_tree))
-                }
-            });
+            .map_err(ProverError::EvalError)?;
+        self.generate_proof(reduction_result.sigma_prop, message, hints_bag)
+    }
+
+    /// Generate proofs for the given message for the sigma proposition
+    /// (i.e. for ErgoTree already reduced to Sigma boolean expression, see [`Evaluator::reduce_to_crypto`])
+    fn generate_proof(
+        &self,
+        sigma_bool: SigmaBoolean,
+        message: &[u8],
+        hints_bag: &HintsBag,
+    ) -> Result<ProverResult, ProverError> {
+        let proof = match sigma_bool {
+            SigmaBoolean::TrivialProp(true) => Ok(UncheckedTree::NoProof),
+            SigmaBoolean::TrivialProp(false) => Err(ProverError::ReducedToFalse),
+            sb => {
+                let tree = convert_to_unproven(sb);
+                let unchecked_tree = prove_to_unchecked(self, tree, message, hints_bag)?;
+                Ok(UncheckedTree::UncheckedSigmaTree(unchecked_tree))
+            }
+        };
         proof.map(|v| ProverResult {
             proof: serialize_sig(v),
             extension: ContextExtension::empty(),
         })
     }
+
+    /// Signs an arbitrary message with the given sigma proposition (e.g. a public key, or a ring of
+    /// public keys expressed as an OR of `ProveDlog`s). Returns the signature bytes which can be
+    /// checked with [`super::verifier::verify_signature`]
+    fn sign_message(
+        &self,
+        sigma_tree: SigmaBoolean,
+        message: &[u8],
+        hints_bag: &HintsBag,
+    ) -> Result<Vec<u8>, ProverError> {
+        self.generate_proof(sigma_tree, message, hints_bag)
+            .map(|p| p.proof.into())
+    }
 }
 
 /// The comments in this section are taken from the algorithm for the
@@ -189,65 +234,56 @@ fn mark_real<P: Prover + ?Sized>(
     unproven_tree: UnprovenTree,
     hints_bag: &HintsBag,
 ) -> Result<UnprovenTree, ProverError> {
-    proof_tree::rewrite(unproven_tree.into(), &|tree| {
-        Ok(match tree {
-            ProofTree::UnprovenTree(unp) => match unp {
-                UnprovenTree::UnprovenLeaf(unp_leaf) => match unp_leaf {
-                    UnprovenLeaf::UnprovenSchnorr(us) => {
-                        // If the node is a leaf, mark it "real'' if either the witness for it is
-                        // available or a hint shows the secret is known to an external participant in multi-signing;
-                        // else mark it "simulated"
-                        let secret_known =
-                            hints_bag.real_images().contains(&unp_leaf.proposition())
-                                || prover.secrets().iter().any(|s| match s {
-                                    PrivateInput::DlogProverInput(dl) => {
-                                        dl.public_image() == us.proposition
-                                    }
-                                    _ => false,
-                                });
-                        Some(
-                            UnprovenSchnorr {
-                                simulated: !secret_known,
-                                ..us.clone()
-                            }
-                            .into(),
-                        )
+    Ok(match unproven_tree {
+        UnprovenTree::UnprovenLeaf(unp_leaf) => {
+            // If the node is a leaf, mark it "real'' if either the witness for it is
+            // available or a hint shows the secret is known to an external participant in multi-signing;
+            // else mark it "simulated"
+            let secret_known = hints_bag.real_images().contains(&unp_leaf.proposition())
+                || prover
+                    .secrets()
+                    .iter()
+                    .any(|s| s.public_image() == unp_leaf.proposition());
+            unp_leaf.with_simulated(!secret_known).into()
+        }
+        UnprovenTree::UnprovenConjecture(unp_conj) => {
+            // children are marked first since the marking of a conjecture depends on them
+            let children = cast_to_unp(unp_conj.children())?
+                .try_mapped(|c| mark_real(prover, c, hints_bag))?;
+            match unp_conj {
+                UnprovenConjecture::CandUnproven(cand) => {
+                    // If the node is AND, mark it "real" if all of its children are marked real; else mark it "simulated"
+                    let simulated = children.iter().any(|c| c.simulated());
+                    CandUnproven {
+                        simulated,
+                        children: children.mapped(|c| c.into()),
+                        ..cand
                     }
-                },
-                UnprovenTree::UnprovenConjecture(unp_conj) => match unp_conj {
-                    UnprovenConjecture::CandUnproven(cand) => {
-                        // If the node is AND, mark it "real" if all of its children are marked real; else mark it "simulated"
-                        let simulated = cast_to_unp(cand.children.clone())?
-                            .iter()
-                            .any(|c| c.simulated());
-                        Some(
-                            CandUnproven {
-                                simulated,
-                                ..cand.clone()
-                            }
-                            .into(),
-                        )
+                    .into()
+                }
+                UnprovenConjecture::CorUnproven(cor) => {
+                    // If the node is OR, mark it "real" if at least one child is marked real; else mark it "simulated"
+                    let simulated = children.iter().all(|c| c.simulated());
+                    CorUnproven {
+                        simulated,
+                        children: children.mapped(|c| c.into()),
+                        ..cor
                     }
-                    UnprovenConjecture::CorUnproven(cor) => {
-                        // If the node is OR, mark it "real" if at least one child is marked real; else mark it "simulated"
-                        let simulated = cast_to_unp(cor.children.clone())?
-                            .iter()
-                            .all(|c| c.simulated());
-                        Some(
-                            CorUnproven {
-                                simulated,
-                                ..cor.clone()
-                            }
-                            .into(),
-                        )
+                    .into()
+                }
+                UnprovenConjecture::CthresholdUnproven(ct) => {
+                    // If the node is THRESHOLD(k), mark it "real" if at least k of its children are marked real; else mark it "simulated"
+                    let real_children_count = children.iter().filter(|c| c.is_real()).count();
+                    CthresholdUnproven {
+                        simulated: real_children_count < ct.k() as usize,
+                        children: children.mapped(|c| c.into()),
+                        ..ct
                     }
-                },
-            },
-            ProofTree::UncheckedTree(_) => None,
-        })
-    })?
-    .try_into()
-    .map_err(|e: &str| ProverError::Unexpected(e.to_string()))
+                    .into()
+                }
+            }
+        }
+    })
 }
 
 /// Set positions for children of a unproven inner node (conjecture, so AND/OR/THRESHOLD)
@@ -259,6 +295,7 @@ fn set_positions(uc: UnprovenConjecture) -> UnprovenConjecture {
     match uc {
         UnprovenConjecture::CandUnproven(cand) => cand.with_children(upd_children).into(),
         UnprovenConjecture::CorUnproven(cor) => cor.with_children(upd_children).into(),
+        UnprovenConjecture::CthresholdUnproven(ct) => ct.with_children(upd_children).into(),
     }
 }
 
@@ -268,9 +305,10 @@ fn set_positions(uc: UnprovenConjecture) -> UnprovenConjecture {
 /// the choice can be guided by efficiency or convenience considerations.
 fn make_cor_children_simulated(cor: CorUnproven) -> Result<CorUnproven, ProverError> {
     let casted_children = cast_to_unp(cor.children)?;
-    let first_real_child = casted_children
+    // compare by index since children may be equal (e.g. the same public key repeated in a ring)
+    let first_real_child_idx = casted_children
         .iter()
-        .find(|it| it.is_real())
+        .position(|it| it.is_real())
         .ok_or_else(|| {
             ProverError::Unexpected(format!(
                 "make_cor_children_simulated: no real child is found amoung: {:?}",
@@ -278,9 +316,9 @@ fn make_cor_children_simulated(cor: CorUnproven) -> Result<CorUnproven, ProverEr
             ))
         })?;
     let children = casted_children
-        .clone()
-        .mapped(|c| {
-            if &c == first_real_child || c.simulated() {
+        .enumerated()
+        .mapped(|(idx, c)| {
+            if idx == first_real_child_idx || c.simulated() {
                 c
             } else {
                 c.with_simulated(true)
@@ -290,6 +328,34 @@ fn make_cor_children_simulated(cor: CorUnproven) -> Result<CorUnproven, ProverEr
     Ok(CorUnproven { children, ..cor })
 }
 
+/// If the node is THRESHOLD(k) marked "real", mark all but k of its children "simulated"
+/// (the node is guaranteed, by the previous step, to have at least k "real" children).
+/// Which particular ones are left "real" is not important for security;
+/// the choice can be guided by efficiency or convenience considerations.
+fn make_cthreshold_children_simulated(
+    ct: CthresholdUnproven,
+) -> Result<CthresholdUnproven, ProverError> {
+    let k = ct.k() as usize;
+    let mut count_of_real = 0;
+    let children = cast_to_unp(ct.children.clone())?
+        .mapped(|c| {
+            if c.is_real() && count_of_real < k {
+                count_of_real += 1;
+                c
+            } else {
+                c.with_simulated(true)
+            }
+        })
+        .mapped(|c| c.into());
+    if count_of_real != k {
+        return Err(ProverError::Unexpected(format!(
+            "make_cthreshold_children_simulated: expected {} real children, found {} in {:?}",
+            k, count_of_real, ct
+        )));
+    }
+    Ok(ct.with_children(children))
+}
+
 fn cast_to_unp(
     children: SigmaConjectureItems<ProofTree>,
 ) -> Result<SigmaConjectureItems<UnprovenTree>, ProverError> {
@@ -342,6 +408,19 @@ fn polish_simulated<P: Prover + ?Sized>(
                     };
                     Ok(Some(set_positions(o.into()).into()))
                 }
+                UnprovenConjecture::CthresholdUnproven(ct) => {
+                    // If the node is marked "simulated", mark all of its children "simulated"
+                    let t: CthresholdUnproven = if ct.simulated {
+                        ct.clone().with_children(
+                            cast_to_unp(ct.children.clone())?
+                                .mapped(|c| c.with_simulated(true).into()),
+                        )
+                    } else {
+                        // If the node is THRESHOLD(k) marked "real", mark all but k of its children "simulated"
+                        make_cthreshold_children_simulated(ct.clone())?
+                    };
+                    Ok(Some(set_positions(t.into()).into()))
+                }
             },
         },
         ProofTree::UncheckedTree(_) => Ok(None),
@@ -401,6 +480,30 @@ fn simulate_and_commit(
                 ))
             }
 
+            // real THRESHOLD
+            ProofTree::UnprovenTree(UnprovenTree::UnprovenConjecture(
+                UnprovenConjecture::CthresholdUnproven(ct),
+            )) if ct.is_real() => {
+                let new_children = cast_to_unp(ct.children.clone())?
+                    .mapped(|c| {
+                        if c.is_real() {
+                            c
+                        } else {
+                            // take challenge from previously done proof stored in the hints bag,
+                            // or generate random challenge for simulated child
+                            let new_challenge: Challenge = hints_bag
+                                .proofs()
+                                .into_iter()
+                                .find(|p| p.position() == c.position())
+                                .map(|p| p.challenge().clone())
+                                .unwrap_or_else(Challenge::secure_random);
+                            c.with_challenge(new_challenge)
+                        }
+                    })
+                    .mapped(|c| c.into());
+                Ok(Some(ct.clone().with_children(new_children).into()))
+            }
+
             // Step 4 part 2: If the node is marked "simulated", let e_0 be the challenge computed for it.
             // All of its children are simulated, and thus we compute challenges for all
             // of them, as follows:
@@ -475,6 +578,36 @@ fn simulate_and_commit(
                 }
             }
 
+            ProofTree::UnprovenTree(UnprovenTree::UnprovenConjecture(
+                UnprovenConjecture::CthresholdUnproven(ct),
+            )) => {
+                // If the node is THRESHOLD(k), then pick a random polynomial Q(x) of degree n-k over GF(2^t) such that
+                // Q(0) = e_0 and give Q(i) as the challenge to the i-th child (children are numbered 1..n)
+                if let Some(challenge) = ct.challenge_opt.clone() {
+                    let n = ct.children.len();
+                    let k = ct.k() as usize;
+                    let q = Gf2_192Poly::from_byte_array(
+                        challenge.into(),
+                        secure_random_bytes(super::SOUNDNESS_BYTES * (n - k)).as_slice(),
+                    );
+                    let new_children =
+                        ct.children.clone().enumerated().mapped(|(idx, c)| {
+                            c.with_challenge(q.evaluate((idx + 1) as u8).into())
+                        });
+                    Ok(Some(
+                        ct.clone()
+                            .with_children(new_children)
+                            .with_polynomial(q)
+                            .into(),
+                    ))
+                } else {
+                    Err(ProverError::Unexpected(
+                        "simulate_and_commit: missing CthresholdUnproven(simulated).challenge"
+                            .to_string(),
+                    ))
+                }
+            }
+
             ProofTree::UnprovenTree(UnprovenTree::UnprovenLeaf(UnprovenLeaf::UnprovenSchnorr(
                 us,
             ))) => {
@@ -485,19 +618,19 @@ fn simulate_and_commit(
                 let res: ProofTree = match hints_bag
                     .commitments()
                     .into_iter()
-                    .find(|c| c.position() == us.position)
+                    .find(|c| c.position() == &us.position)
                 {
                     Some(cmt_hint) => {
-                        let pt: ProofTree = UnprovenSchnorr {
-                            commitment_opt: Some(
-                                cmt_hint
-                                    .commitment()
-                                    .try_into()
-                                    .map_err(|e: &str| ProverError::Unexpected(e.to_string()))?,
-                            ),
-                            ..us.clone()
-                        }
-                        .into();
+                        let pt: ProofTree =
+                            UnprovenSchnorr {
+                                commitment_opt: Some(
+                                    cmt_hint.commitment().clone().try_into().map_err(
+                                        |e: &str| ProverError::Unexpected(e.to_string()),
+                                    )?,
+                                ),
+                                ..us.clone()
+                            }
+                            .into();
                         pt
                     }
                     None => {
@@ -537,6 +670,67 @@ fn simulate_and_commit(
                 };
                 Ok(Some(res))
             }
+
+            ProofTree::UnprovenTree(UnprovenTree::UnprovenLeaf(UnprovenLeaf::UnprovenDhTuple(
+                dhu,
+            ))) => {
+                // Steps 5 & 6: first try pulling out commitment from the hints bag. If it exists proceed with it,
+                // otherwise, compute the commitment (if the node is real) or simulate it (if the node is simulated)
+
+                // Step 6 (real leaf -- compute the commitment a or take it from the hints bag)
+                let res: ProofTree = match hints_bag
+                    .commitments()
+                    .into_iter()
+                    .find(|c| c.position() == &dhu.position)
+                {
+                    Some(cmt_hint) => UnprovenDhTuple {
+                        commitment_opt: Some(
+                            cmt_hint
+                                .commitment()
+                                .clone()
+                                .try_into()
+                                .map_err(|e: &str| ProverError::Unexpected(e.to_string()))?,
+                        ),
+                        ..dhu.clone()
+                    }
+                    .into(),
+                    None => {
+                        if dhu.simulated {
+                            // Step 5 (simulated leaf -- complete the simulation)
+                            if let Some(challenge) = dhu.challenge_opt.clone() {
+                                let (fm, sm) = dht_protocol::interactive_prover::simulate(
+                                    &dhu.proposition,
+                                    &challenge,
+                                );
+                                Ok(ProofTree::UncheckedTree(
+                                    UncheckedDhTuple {
+                                        proposition: dhu.proposition.clone(),
+                                        commitment_opt: Some(fm),
+                                        challenge,
+                                        second_message: sm,
+                                    }
+                                    .into(),
+                                ))
+                            } else {
+                                Err(ProverError::SimulatedLeafWithoutChallenge)
+                            }
+                        } else {
+                            // Step 6 (real leaf -- compute the commitment a)
+                            let (r, fm) =
+                                dht_protocol::interactive_prover::first_message(&dhu.proposition);
+                            Ok(ProofTree::UnprovenTree(
+                                UnprovenDhTuple {
+                                    commitment_opt: Some(fm),
+                                    randomness_opt: Some(r),
+                                    ..dhu.clone()
+                                }
+                                .into(),
+                            ))
+                        }?
+                    }
+                };
+                Ok(Some(res))
+            }
             ProofTree::UncheckedTree(_) => Ok(None),
         }
     })?
@@ -621,49 +815,147 @@ fn proving<P: Prover + ?Sized>(
                             Ok(None)
                         }
                     }
+                    UnprovenConjecture::CthresholdUnproven(ct) => {
+                        // If the node is THRESHOLD(k), number its children from 1 to n. Let i_1,..., i_{n-k}
+                        // be the indices of its simulated children, and e_1, ..., e_{n-k} be their challenges.
+                        // Compute the polynomial Q of degree n-k such that Q(0) = e_0 and Q(i_j) = e_j for 1 <= j <= n-k.
+                        // For every real child i, let e_i = Q(i).
+                        if ct.is_real() {
+                            if let Some(root_challenge) = &ct.challenge_opt {
+                                let mut points: Vec<u8> = Vec::new();
+                                let mut values: Vec<Gf2_192> = Vec::new();
+                                for (idx, c) in ct.children.iter().enumerate() {
+                                    let is_real_child =
+                                        matches!(c, ProofTree::UnprovenTree(ut) if ut.is_real());
+                                    if !is_real_child {
+                                        let challenge = c.challenge().ok_or_else(|| {
+                                            ProverError::Unexpected(format!(
+                                                "proving: no challenge in simulated child {:?}",
+                                                c
+                                            ))
+                                        })?;
+                                        points.push((idx + 1) as u8);
+                                        values.push(challenge.into());
+                                    }
+                                }
+                                let q = Gf2_192Poly::interpolate(
+                                    &points,
+                                    &values,
+                                    root_challenge.clone().into(),
+                                );
+                                let children =
+                                    ct.children.clone().enumerated().mapped(|(idx, c)| match c {
+                                        ProofTree::UnprovenTree(ref ut) if ut.is_real() => {
+                                            c.with_challenge(q.evaluate((idx + 1) as u8).into())
+                                        }
+                                        _ => c,
+                                    });
+                                Ok(Some(
+                                    ct.clone().with_children(children).with_polynomial(q).into(),
+                                ))
+                            } else {
+                                Err(ProverError::Unexpected(
+                                    "proving: CthresholdUnproven.challenge_opt is empty"
+                                        .to_string(),
+                                ))
+                            }
+                        } else {
+                            Ok(None)
+                        }
+                    }
                 },
 
                 // If the node is a leaf marked "real", compute its response according to the second prover step
                 // of the Sigma-protocol given the commitment, challenge, and witness, or pull response from the hints bag
-                UnprovenTree::UnprovenLeaf(unp_leaf) if unp_leaf.is_real() => match unp_leaf {
-                    UnprovenLeaf::UnprovenSchnorr(us) => {
-                        if let Some(challenge) = us.challenge_opt.clone() {
-                            if let Some(priv_key) = prover
-                                .secrets()
-                                .iter()
-                                .flat_map(|s| match s {
-                                    PrivateInput::DlogProverInput(dl) => vec![dl],
-                                    _ => vec![],
-                                })
-                                .find(|prover_input| prover_input.public_image() == us.proposition)
-                            {
-                                let z = dlog_protocol::interactive_prover::second_message(
-                                    &priv_key,
-                                    us.randomness_opt.ok_or_else(|| {
-                                        ProverError::Unexpected(format!(
-                                            "empty randomness in {:?}",
-                                            us
-                                        ))
-                                    })?,
-                                    &challenge,
-                                );
-                                Ok(Some(
-                                    UncheckedSchnorr {
+                UnprovenTree::UnprovenLeaf(unp_leaf) if unp_leaf.is_real() => {
+                    let challenge = unp_leaf
+                        .challenge()
+                        .ok_or(ProverError::RealUnprovenTreeWithoutChallenge)?;
+                    // randomness from the own commitment (generated before) has a priority
+                    let own_randomness_opt = hints_bag
+                        .own_commitments()
+                        .into_iter()
+                        .find(|c| &c.position == unp_leaf.position())
+                        .map(|c| c.secret_randomness);
+                    let priv_key_opt = prover
+                        .secrets()
+                        .iter()
+                        .find(|s| s.public_image() == unp_leaf.proposition());
+                    let res: ProofTree = match (unp_leaf, priv_key_opt) {
+                        (
+                            UnprovenLeaf::UnprovenSchnorr(us),
+                            Some(PrivateInput::DlogProverInput(priv_key)),
+                        ) => {
+                            let z = dlog_protocol::interactive_prover::second_message(
+                                priv_key,
+                                own_randomness_opt.or(us.randomness_opt).ok_or_else(|| {
+                                    ProverError::Unexpected(format!("empty randomness in {:?}", us))
+                                })?,
+                                &challenge,
+                            );
+                            UncheckedSchnorr {
+                                proposition: us.proposition.clone(),
+                                commitment_opt: None,
+                                challenge,
+                                second_message: z,
+                            }
+                            .into()
+                        }
+                        (
+                            UnprovenLeaf::UnprovenDhTuple(dhu),
+                            Some(PrivateInput::DhTupleProverInput(priv_key)),
+                        ) => {
+                            let z = dht_protocol::interactive_prover::second_message(
+                                priv_key,
+                                own_randomness_opt.or(dhu.randomness_opt).ok_or_else(|| {
+                                    ProverError::Unexpected(format!(
+                                        "empty randomness in {:?}",
+                                        dhu
+                                    ))
+                                })?,
+                                &challenge,
+                            );
+                            UncheckedDhTuple {
+                                proposition: dhu.proposition.clone(),
+                                commitment_opt: None,
+                                challenge,
+                                second_message: z,
+                            }
+                            .into()
+                        }
+                        (_, Some(_)) => return Err(ProverError::SecretNotFound),
+                        // The secret is known to another party (see multi-signing), so take the proof
+                        // done by that party from the hints bag or, if not there yet,
+                        // produce a partial (invalid) proof which will be completed by that party
+                        (_, None) => match hints_bag
+                            .real_proofs()
+                            .into_iter()
+                            .find(|p| &p.position == unp_leaf.position())
+                        {
+                            Some(proof) => proof.unchecked_tree.into(),
+                            None => {
+                                let z = dlog_group::random_scalar_in_group_range();
+                                match unp_leaf {
+                                    UnprovenLeaf::UnprovenSchnorr(us) => UncheckedSchnorr {
                                         proposition: us.proposition.clone(),
                                         commitment_opt: None,
                                         challenge,
-                                        second_message: z,
+                                        second_message: z.into(),
                                     }
                                     .into(),
-                                ))
-                            } else {
-                                Err(ProverError::SecretNotFound)
+                                    UnprovenLeaf::UnprovenDhTuple(dhu) => UncheckedDhTuple {
+                                        proposition: dhu.proposition.clone(),
+                                        commitment_opt: None,
+                                        challenge,
+                                        second_message: z.into(),
+                                    }
+                                    .into(),
+                                }
                             }
-                        } else {
-                            Err(ProverError::RealUnprovenTreeWithoutChallenge)
-                        }
-                    }
-                },
+                        },
+                    };
+                    Ok(Some(res))
+                }
                 UnprovenTree::UnprovenLeaf(unp_leaf) => {
                     // if the simulated node is proven by someone else, take it from hints bag
                     let res: ProofTree = hints_bag
@@ -679,10 +971,170 @@ fn proving<P: Prover + ?Sized>(
     })
 }
 
+/// Generates commitments (along with the randomness) for the leaves of the given sigma tree whose
+/// propositions are in `generate_for`, to be used in distributed signing.
+/// Returns a bag with [`hint::OwnCommitment`] (to be kept secret) and [`hint::RealCommitment`]
+/// (to be sent to other cosigners) hints for every such leaf.
+pub fn generate_commitments(sigma_tree: &SigmaBoolean, generate_for: &[SigmaBoolean]) -> HintsBag {
+    fn traverse_node(
+        sb: &SigmaBoolean,
+        bag: &mut HintsBag,
+        position: NodePosition,
+        generate_for: &[SigmaBoolean],
+    ) {
+        match sb {
+            SigmaBoolean::SigmaConjecture(sc) => {
+                for (idx, child) in sc.children().iter().enumerate() {
+                    traverse_node(child, bag, position.child(idx), generate_for);
+                }
+            }
+            SigmaBoolean::ProofOfKnowledge(pok) if generate_for.contains(sb) => {
+                let (r, a): (Scalar, FirstProverMessage) = match pok {
+                    SigmaProofOfKnowledgeTree::ProveDlog(_) => {
+                        let (r, a) = dlog_protocol::interactive_prover::first_message();
+                        (r, a.into())
+                    }
+                    SigmaProofOfKnowledgeTree::ProveDhTuple(dht) => {
+                        let (r, a) = dht_protocol::interactive_prover::first_message(dht);
+                        (r, a.into())
+                    }
+                };
+                bag.add_hint(Hint::CommitmentHint(CommitmentHint::OwnCommitment(
+                    OwnCommitment {
+                        image: sb.clone(),
+                        secret_randomness: r,
+                        commitment: a.clone(),
+                        position: position.clone(),
+                    },
+                )));
+                bag.add_hint(Hint::CommitmentHint(CommitmentHint::RealCommitment(
+                    RealCommitment {
+                        image: sb.clone(),
+                        commitment: a,
+                        position,
+                    },
+                )));
+            }
+            _ => (),
+        }
+    }
+
+    let mut bag = HintsBag::empty();
+    traverse_node(
+        sigma_tree,
+        &mut bag,
+        NodePosition::crypto_tree_prefix(),
+        generate_for,
+    );
+    bag
+}
+
+/// Extracts hints from a (partial) proof made by other cosigners in distributed signing.
+/// For the leaves with propositions in `real_propositions` [`hint::RealCommitment`] and
+/// [`hint::RealSecretProof`] hints are extracted, for the leaves with propositions in
+/// `simulated_propositions` - [`hint::SimulatedCommitment`] and [`hint::SimulatedSecretProof`].
+pub fn bag_for_multisig(
+    sigma_tree: &SigmaBoolean,
+    proof: &[u8],
+    real_propositions: &[SigmaBoolean],
+    simulated_propositions: &[SigmaBoolean],
+) -> Result<HintsBag, SigParsingError> {
+    fn traverse_node(
+        tree: UncheckedSigmaTree,
+        bag: &mut HintsBag,
+        position: NodePosition,
+        real_propositions: &[SigmaBoolean],
+        simulated_propositions: &[SigmaBoolean],
+    ) -> Result<(), SigParsingError> {
+        match tree {
+            UncheckedSigmaTree::UncheckedConjecture(conj) => {
+                for (idx, child) in conj.children_ust().into_iter().enumerate() {
+                    traverse_node(
+                        child,
+                        bag,
+                        position.child(idx),
+                        real_propositions,
+                        simulated_propositions,
+                    )?;
+                }
+            }
+            UncheckedSigmaTree::UncheckedLeaf(leaf) => {
+                let image = leaf.proposition();
+                let real_found = real_propositions.contains(&image);
+                let simulated_found = simulated_propositions.contains(&image);
+                if real_found || simulated_found {
+                    let commitment = leaf.commitment_opt().ok_or_else(|| {
+                        SigParsingError::Misc(format!("no commitment in {:?}", leaf))
+                    })?;
+                    let challenge = leaf.challenge();
+                    let unchecked_tree: UncheckedTree =
+                        UncheckedSigmaTree::UncheckedLeaf(leaf).into();
+                    if real_found {
+                        bag.add_hint(Hint::CommitmentHint(CommitmentHint::RealCommitment(
+                            RealCommitment {
+                                image: image.clone(),
+                                commitment,
+                                position: position.clone(),
+                            },
+                        )));
+                        bag.add_hint(Hint::SecretProven(SecretProven::RealSecretProof(
+                            RealSecretProof {
+                                image,
+                                challenge,
+                                unchecked_tree,
+                                position,
+                            },
+                        )));
+                    } else {
+                        bag.add_hint(Hint::CommitmentHint(CommitmentHint::SimulatedCommitment(
+                            SimulatedCommitment {
+                                image: image.clone(),
+                                commitment,
+                                position: position.clone(),
+                            },
+                        )));
+                        bag.add_hint(Hint::SecretProven(SecretProven::SimulatedSecretProof(
+                            SimulatedSecretProof {
+                                image,
+                                challenge,
+                                unchecked_tree,
+                                position,
+                            },
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let mut bag = HintsBag::empty();
+    if let UncheckedTree::UncheckedSigmaTree(ust) =
+        parse_sig_compute_challenges(sigma_tree, proof.to_vec().into())?
+    {
+        traverse_node(
+            compute_commitments(ust),
+            &mut bag,
+            NodePosition::crypto_tree_prefix(),
+            real_propositions,
+            simulated_propositions,
+        )?;
+    }
+    Ok(bag)
+}
+
 fn convert_to_unproven(sb: SigmaBoolean) -> UnprovenTree {
     match sb {
         SigmaBoolean::ProofOfKnowledge(pok) => match pok {
-            SigmaProofOfKnowledgeTree::ProveDhTuple(_) => todo!(),
+            SigmaProofOfKnowledgeTree::ProveDhTuple(prove_dht) => UnprovenDhTuple {
+                proposition: prove_dht,
+                commitment_opt: None,
+                randomness_opt: None,
+                challenge_opt: None,
+                simulated: false,
+                position: NodePosition::crypto_tree_prefix(),
+            }
+            .into(),
             SigmaProofOfKnowledgeTree::ProveDlog(prove_dlog) => UnprovenSchnorr {
                 proposition: prove_dlog,
                 commitment_opt: None,
@@ -709,6 +1161,15 @@ fn convert_to_unproven(sb: SigmaBoolean) -> UnprovenTree {
             position: NodePosition::crypto_tree_prefix(),
         }
         .into(),
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(ct)) => CthresholdUnproven {
+            proposition: ct.clone(),
+            challenge_opt: None,
+            simulated: false,
+            children: ct.children.mapped(|it| convert_to_unproven(it).into()),
+            polynomial_opt: None,
+            position: NodePosition::crypto_tree_prefix(),
+        }
+        .into(),
         SigmaBoolean::TrivialProp(_) => panic!("TrivialProp is not expected here"),
     }
 }
@@ -722,6 +1183,7 @@ fn convert_to_unchecked(tree: ProofTree) -> Result<UncheckedSigmaTree, ProverErr
             UncheckedTree::UncheckedSigmaTree(ust) => match ust {
                 UncheckedSigmaTree::UncheckedLeaf(ul) => match ul {
                     UncheckedLeaf::UncheckedSchnorr(_) => Ok(ust.clone()),
+                    UncheckedLeaf::UncheckedDhTuple(_) => Ok(ust.clone()),
                 },
                 UncheckedSigmaTree::UncheckedConjecture(_) => Err(ProverError::Unexpected(
                     format!("convert_to_unchecked: unexpected {:?}", tree),
@@ -748,6 +1210,19 @@ fn convert_to_unchecked(tree: ProofTree) -> Result<UncheckedSigmaTree, ProverErr
                     children: cor.children.clone().try_mapped(convert_to_unchecked)?,
                 }
                 .into()),
+                UnprovenConjecture::CthresholdUnproven(ct) => {
+                    Ok(UncheckedConjecture::CthresholdUnchecked {
+                        challenge: ct.challenge_opt.clone().ok_or_else(|| {
+                            ProverError::Unexpected(format!("no challenge in {:?}", ct))
+                        })?,
+                        children: ct.children.clone().try_mapped(convert_to_unchecked)?,
+                        k: ct.k(),
+                        polynomial: ct.polynomial_opt.clone().ok_or_else(|| {
+                            ProverError::Unexpected(format!("no polynomial in {:?}", ct))
+                        })?,
+                    }
+                    .into())
+                }
             },
         },
     }
@@ -770,12 +1245,19 @@ impl Prover for TestProver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sigma_protocol::private_input::DhTupleProverInput;
     use crate::sigma_protocol::private_input::DlogProverInput;
+    use crate::sigma_protocol::verifier::TestVerifier;
+    use crate::sigma_protocol::verifier::Verifier;
+    use ergotree_ir::mir::atleast::Atleast;
     use ergotree_ir::mir::constant::Constant;
     use ergotree_ir::mir::expr::Expr;
     use ergotree_ir::mir::sigma_and::SigmaAnd;
     use ergotree_ir::mir::sigma_or::SigmaOr;
     use ergotree_ir::mir::value::Value;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
     use ergotree_ir::types::stype::SType;
     use sigma_test_util::force_any_val;
     use std::rc::Rc;
@@ -841,6 +1323,27 @@ mod tests {
         assert_ne!(res.unwrap().proof, ProofBytes::Empty);
     }
 
+    #[test]
+    fn test_prove_dht_prop() {
+        let secret = DhTupleProverInput::random();
+        let pk = secret.public_image().clone();
+        let tree = ErgoTree::from(Expr::Const(SigmaProp::from(pk).into()));
+        let message = vec![0u8; 100];
+
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DhTupleProverInput(secret)],
+        };
+        let res = prover.prove(
+            &tree,
+            &Env::empty(),
+            Rc::new(force_any_val::<Context>()),
+            message.as_slice(),
+            &HintsBag::empty(),
+        );
+        assert!(res.is_ok());
+        assert_ne!(res.unwrap().proof, ProofBytes::Empty);
+    }
+
     #[test]
     fn test_prove_pk_and_pk() {
         let secret1 = DlogProverInput::random();
@@ -954,4 +1457,122 @@ mod tests {
         );
         assert_ne!(res.unwrap().proof, ProofBytes::Empty);
     }
+
+    fn verify(tree: &ErgoTree, ctx: Rc<Context>, proof: ProofBytes, message: &[u8]) -> bool {
+        TestVerifier
+            .verify(tree, &Env::empty(), ctx, proof, message)
+            .unwrap()
+            .result
+    }
+
+    #[test]
+    fn test_multisig_cand() {
+        let secret_alice = DlogProverInput::random();
+        let secret_bob = DlogProverInput::random();
+        let pk_alice: SigmaBoolean = secret_alice.public_image().into();
+        let pk_bob: SigmaBoolean = secret_bob.public_image().into();
+        let expr: Expr = SigmaAnd::new(vec![
+            Expr::Const(SigmaProp::from(pk_alice.clone()).into()),
+            Expr::Const(SigmaProp::from(pk_bob.clone()).into()),
+        ])
+        .unwrap()
+        .into();
+        let tree: ErgoTree = expr.into();
+        let sigma_tree =
+            Cand::normalized(vec![pk_alice.clone(), pk_bob.clone()].try_into().unwrap());
+        let ctx = Rc::new(force_any_val::<Context>());
+        let message = vec![0u8; 100];
+
+        // Bob generates commitments and sends the public one to Alice
+        let bob_hints = generate_commitments(&sigma_tree, &[pk_bob]);
+        let mut alice_bag = HintsBag::empty();
+        bob_hints.real_commitments().into_iter().for_each(|c| {
+            alice_bag.add_hint(Hint::CommitmentHint(CommitmentHint::RealCommitment(c)))
+        });
+
+        // Alice signs first, the proof is not valid yet
+        let alice_prover = TestProver {
+            secrets: vec![secret_alice.into()],
+        };
+        let partial_proof = alice_prover
+            .prove(&tree, &Env::empty(), ctx.clone(), &message, &alice_bag)
+            .unwrap()
+            .proof;
+        assert!(!verify(&tree, ctx.clone(), partial_proof.clone(), &message));
+
+        // Bob extracts Alice's hints from her proof and completes the signature
+        let mut bob_bag =
+            bag_for_multisig(&sigma_tree, &Vec::from(partial_proof), &[pk_alice], &[]).unwrap();
+        bob_bag.add_hints_from_bag(bob_hints);
+        let bob_prover = TestProver {
+            secrets: vec![secret_bob.into()],
+        };
+        let proof = bob_prover
+            .prove(&tree, &Env::empty(), ctx.clone(), &message, &bob_bag)
+            .unwrap()
+            .proof;
+        assert!(verify(&tree, ctx, proof, &message));
+    }
+
+    #[test]
+    fn test_multisig_threshold() {
+        let secret_alice = DlogProverInput::random();
+        let secret_bob = DhTupleProverInput::random();
+        let secret_carol = DlogProverInput::random();
+        let pk_alice: SigmaBoolean = secret_alice.public_image().into();
+        let pk_bob: SigmaBoolean = secret_bob.public_image().clone().into();
+        let pk_carol: SigmaBoolean = secret_carol.public_image().into();
+        let pks: Vec<SigmaProp> = vec![
+            pk_alice.clone().into(),
+            pk_bob.clone().into(),
+            pk_carol.clone().into(),
+        ];
+        let expr: Expr = Atleast::new(Expr::Const(2i32.into()), Expr::Const(pks.into()))
+            .unwrap()
+            .into();
+        let tree: ErgoTree = expr.into();
+        let sigma_tree = Cthreshold::reduce(
+            2,
+            vec![pk_alice.clone(), pk_bob.clone(), pk_carol.clone()]
+                .try_into()
+                .unwrap(),
+        );
+        let ctx = Rc::new(force_any_val::<Context>());
+        let message = vec![0u8; 100];
+
+        // Bob generates commitments and sends the public one to Alice
+        let bob_hints = generate_commitments(&sigma_tree, &[pk_bob]);
+        let mut alice_bag = HintsBag::empty();
+        bob_hints.real_commitments().into_iter().for_each(|c| {
+            alice_bag.add_hint(Hint::CommitmentHint(CommitmentHint::RealCommitment(c)))
+        });
+
+        // Alice signs first (Carol's part is simulated), the proof is not valid yet
+        let alice_prover = TestProver {
+            secrets: vec![secret_alice.into()],
+        };
+        let partial_proof = alice_prover
+            .prove(&tree, &Env::empty(), ctx.clone(), &message, &alice_bag)
+            .unwrap()
+            .proof;
+        assert!(!verify(&tree, ctx.clone(), partial_proof.clone(), &message));
+
+        // Bob extracts Alice's (real) and Carol's (simulated) hints from the proof and completes the signature
+        let mut bob_bag = bag_for_multisig(
+            &sigma_tree,
+            &Vec::from(partial_proof),
+            &[pk_alice],
+            &[pk_carol],
+        )
+        .unwrap();
+        bob_bag.add_hints_from_bag(bob_hints);
+        let bob_prover = TestProver {
+            secrets: vec![secret_bob.into()],
+        };
+        let proof = bob_prover
+            .prove(&tree, &Env::empty(), ctx.clone(), &message, &bob_bag)
+            .unwrap()
+            .proof;
+        assert!(verify(&tree, ctx, proof, &message));
+    }
 }
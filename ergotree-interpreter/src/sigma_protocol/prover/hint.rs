@@ -1,16 +1,23 @@
 //! Hints for a prover which helps the prover to prove a statement.
 
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
-use num_bigint::BigInt;
+use k256::Scalar;
 
 use crate::sigma_protocol::challenge::Challenge;
 use crate::sigma_protocol::unchecked_tree::UncheckedTree;
 use crate::sigma_protocol::unproven_tree::NodePosition;
 use crate::sigma_protocol::FirstProverMessage;
 
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
 /// A hint for a prover which helps the prover to prove a statement. For example, if the statement is "pk1 && pk2",
 /// and the prover knows only a secret for the public key pk1, the prover fails on proving without a hint. But if the
 /// prover knows that pk2 is known to another party, the prover may prove the statement (with an empty proof for "pk2").
+#[cfg_attr(feature = "json", derive(Deserialize))]
+#[cfg_attr(feature = "json", serde(try_from = "json::HintJson"))]
 #[derive(PartialEq, Debug, Clone)]
 pub enum Hint {
     /// A hint which is indicating that a secret associated with its public image "image" is already proven.
@@ -62,15 +69,35 @@ pub enum SecretProven {
 impl SecretProven {
     /// A hint is related to a subtree (or a leaf) of a tree. This field encodes a position in the tree.
     pub fn position(&self) -> &NodePosition {
-        todo!()
+        match self {
+            SecretProven::RealSecretProof(proof) => &proof.position,
+            SecretProven::SimulatedSecretProof(proof) => &proof.position,
+        }
     }
 
     /// Challenge used for a proof
     pub fn challenge(&self) -> &Challenge {
-        todo!()
+        match self {
+            SecretProven::RealSecretProof(proof) => &proof.challenge,
+            SecretProven::SimulatedSecretProof(proof) => &proof.challenge,
+        }
     }
 }
 
+/// A hint which a commitment to randomness associated with a public image of a secret, as well as randomness itself.
+/// Please note that this randomness should be kept in secret by the prover.
+#[derive(PartialEq, Debug, Clone)]
+pub struct OwnCommitment {
+    ///  image of a secret
+    pub image: SigmaBoolean,
+    /// randomness
+    pub secret_randomness: Scalar,
+    /// commitment to randomness used while proving knowledge of the secret
+    pub commitment: FirstProverMessage,
+    /// A hint is related to a subtree (or a leaf) of a tree. This field encodes a position in the tree.
+    pub position: NodePosition,
+}
+
 /// A hint which contains a commitment to randomness associated with a public image of a secret.
 #[derive(PartialEq, Debug, Clone)]
 pub struct RealCommitment {
@@ -82,52 +109,56 @@ pub struct RealCommitment {
     pub position: NodePosition,
 }
 
+/// A hint which contains a commitment to randomness associated with a public image of a secret.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SimulatedCommitment {
+    /// image of a secret
+    pub image: SigmaBoolean,
+    /// commitment to randomness used while proving knowledge of the secret
+    pub commitment: FirstProverMessage,
+    /// A hint is related to a subtree (or a leaf) of a tree. This field encodes a position in the tree.
+    pub position: NodePosition,
+}
+
 /// A family of hints which are about a correspondence between a public image of a secret image and prover's commitment
 /// to randomness ("a" in a sigma protocol).
 #[derive(PartialEq, Debug, Clone)]
 pub enum CommitmentHint {
-    /// * A hint which a commitment to randomness associated with a public image of a secret, as well as randomness itself.
-    ///  * Please note that this randomness should be kept in secret by the prover.
-    ///  *
-    OwnCommitment {
-        ///  image of a secret
-        image: SigmaBoolean,
-        /// randomness
-        secret_randomness: BigInt,
-        /// commitment to randomness used while proving knowledge of the secret
-        commitment: FirstProverMessage,
-        /// A hint is related to a subtree (or a leaf) of a tree. This field encodes a position in the tree.
-        position: NodePosition,
-    },
+    /// A hint which a commitment to randomness associated with a public image of a secret, as well as randomness itself.
+    /// Please note that this randomness should be kept in secret by the prover.
+    OwnCommitment(OwnCommitment),
     /// A hint which contains a commitment to randomness associated with a public image of a secret.
     RealCommitment(RealCommitment),
     ///A hint which contains a commitment to randomness associated with a public image of a secret.
-    SimulatedCommitment {
-        /// image of a secret
-        image: SigmaBoolean,
-        /// commitment to randomness used while proving knowledge of the secret
-        commitment: FirstProverMessage,
-        /// A hint is related to a subtree (or a leaf) of a tree. This field encodes a position in the tree.
-        position: NodePosition,
-    },
+    SimulatedCommitment(SimulatedCommitment),
 }
 
 impl CommitmentHint {
     /// A hint is related to a subtree (or a leaf) of a tree. Returns position in the tree.
-    pub fn position(&self) -> NodePosition {
-        todo!()
+    pub fn position(&self) -> &NodePosition {
+        match self {
+            CommitmentHint::OwnCommitment(c) => &c.position,
+            CommitmentHint::RealCommitment(c) => &c.position,
+            CommitmentHint::SimulatedCommitment(c) => &c.position,
+        }
     }
 
     /// commitment to randomness used while proving knowledge of the secret
-    pub fn commitment(&self) -> FirstProverMessage {
-        todo!()
+    pub fn commitment(&self) -> &FirstProverMessage {
+        match self {
+            CommitmentHint::OwnCommitment(c) => &c.commitment,
+            CommitmentHint::RealCommitment(c) => &c.commitment,
+            CommitmentHint::SimulatedCommitment(c) => &c.commitment,
+        }
     }
 }
 
 /// Collection of hints to be used by a prover
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
 pub struct HintsBag {
     /// Hints stored in a bag
-    hints: Vec<Hint>,
+    pub hints: Vec<Hint>,
 }
 
 impl HintsBag {
@@ -136,6 +167,31 @@ impl HintsBag {
         HintsBag { hints: vec![] }
     }
 
+    /// Adding a hint to a bag
+    pub fn add_hint(&mut self, hint: Hint) {
+        self.hints.push(hint);
+    }
+
+    /// Adding all the hints from another bag
+    pub fn add_hints_from_bag(&mut self, other: HintsBag) {
+        self.hints.extend(other.hints);
+    }
+
+    /// OwnCommitment hints only
+    pub fn own_commitments(&self) -> Vec<OwnCommitment> {
+        self.hints
+            .clone()
+            .into_iter()
+            .filter_map(|hint| {
+                if let Hint::CommitmentHint(CommitmentHint::OwnCommitment(v)) = hint {
+                    Some(v)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
     /// Commitments from all CommitmentHints in the bag
     pub fn commitments(&self) -> Vec<CommitmentHint> {
         self.hints
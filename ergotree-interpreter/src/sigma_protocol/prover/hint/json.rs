@@ -0,0 +1,432 @@
+//! JSON encoding of hints (compatible with the hints format of the Ergo node wallet API)
+
+use std::convert::TryFrom;
+use std::convert::TryInto;
+
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::dlog_group::EcPoint;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
+use k256::elliptic_curve::ff::PrimeField;
+use k256::Scalar;
+use serde::ser::Error;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::sigma_protocol::challenge::Challenge;
+use crate::sigma_protocol::dht_protocol::FirstDhTupleProverMessage;
+use crate::sigma_protocol::dlog_protocol::FirstDlogProverMessage;
+use crate::sigma_protocol::sig_serializer::parse_sig_compute_challenges;
+use crate::sigma_protocol::sig_serializer::serialize_sig;
+use crate::sigma_protocol::unchecked_tree::UncheckedTree;
+use crate::sigma_protocol::unproven_tree::NodePosition;
+use crate::sigma_protocol::verifier::compute_commitments;
+use crate::sigma_protocol::FirstProverMessage;
+use crate::sigma_protocol::SOUNDNESS_BYTES;
+
+use super::CommitmentHint;
+use super::Hint;
+use super::OwnCommitment;
+use super::RealCommitment;
+use super::RealSecretProof;
+use super::SecretProven;
+use super::SimulatedCommitment;
+use super::SimulatedSecretProof;
+
+/// ProveDlog op code (0xCD) as a signed byte
+const PROVE_DLOG_OP: i8 = -51;
+/// ProveDhTuple op code (0xCE) as a signed byte
+const PROVE_DHT_OP: i8 = -50;
+
+/// Public image of a secret (`ProveDlog` or `ProveDhTuple`), encoded as in the node's API
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub(crate) struct PubkeyJson {
+    op: i8,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    g: Option<String>,
+    h: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    u: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    v: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type")]
+pub(crate) enum FirstProverMessageJson {
+    #[serde(rename = "dlog")]
+    Dlog { a: String },
+    #[serde(rename = "dht")]
+    Dht { a: String, b: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "hint")]
+pub(crate) enum HintJson {
+    #[serde(rename = "cmtWithSecret")]
+    OwnCommitment {
+        pubkey: PubkeyJson,
+        secret: String,
+        #[serde(flatten)]
+        commitment: FirstProverMessageJson,
+        position: String,
+    },
+    #[serde(rename = "cmtReal")]
+    RealCommitment {
+        pubkey: PubkeyJson,
+        #[serde(flatten)]
+        commitment: FirstProverMessageJson,
+        position: String,
+    },
+    #[serde(rename = "cmtSimulated")]
+    SimulatedCommitment {
+        pubkey: PubkeyJson,
+        #[serde(flatten)]
+        commitment: FirstProverMessageJson,
+        position: String,
+    },
+    #[serde(rename = "proofReal")]
+    RealSecretProof {
+        pubkey: PubkeyJson,
+        challenge: String,
+        proof: String,
+        position: String,
+    },
+    #[serde(rename = "proofSimulated")]
+    SimulatedSecretProof {
+        pubkey: PubkeyJson,
+        challenge: String,
+        proof: String,
+        position: String,
+    },
+}
+
+fn encode_point(p: &EcPoint) -> String {
+    base16::encode_lower(&p.sigma_serialize_bytes())
+}
+
+fn decode_point(s: &str) -> Result<EcPoint, String> {
+    let bytes = base16::decode(s).map_err(|e| format!("invalid group element {}: {}", s, e))?;
+    EcPoint::sigma_parse_bytes(&bytes).map_err(|e| format!("invalid group element {}: {}", s, e))
+}
+
+impl TryFrom<SigmaBoolean> for PubkeyJson {
+    type Error = String;
+
+    fn try_from(sb: SigmaBoolean) -> Result<Self, Self::Error> {
+        match sb {
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(pd)) => {
+                Ok(PubkeyJson {
+                    op: PROVE_DLOG_OP,
+                    g: None,
+                    h: encode_point(&pd.h),
+                    u: None,
+                    v: None,
+                })
+            }
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(dht)) => {
+                Ok(PubkeyJson {
+                    op: PROVE_DHT_OP,
+                    g: Some(encode_point(&dht.gv)),
+                    h: encode_point(&dht.hv),
+                    u: Some(encode_point(&dht.uv)),
+                    v: Some(encode_point(&dht.vv)),
+                })
+            }
+            _ => Err(format!("expected ProveDlog or ProveDhTuple, got {:?}", sb)),
+        }
+    }
+}
+
+impl TryFrom<PubkeyJson> for SigmaBoolean {
+    type Error = String;
+
+    fn try_from(pk: PubkeyJson) -> Result<Self, Self::Error> {
+        if pk.op == PROVE_DLOG_OP {
+            Ok(ProveDlog::new(decode_point(&pk.h)?).into())
+        } else if pk.op == PROVE_DHT_OP {
+            let point = |p: Option<String>| {
+                p.ok_or_else(|| "missing ProveDhTuple point".to_string())
+                    .and_then(|s| decode_point(&s))
+            };
+            Ok(ProveDhTuple::new(
+                point(pk.g)?,
+                decode_point(&pk.h)?,
+                point(pk.u)?,
+                point(pk.v)?,
+            )
+            .into())
+        } else {
+            Err(format!("unexpected public key op code: {}", pk.op))
+        }
+    }
+}
+
+impl From<FirstProverMessage> for FirstProverMessageJson {
+    fn from(m: FirstProverMessage) -> Self {
+        match m {
+            FirstProverMessage::FirstDlogProverMessage(dl) => FirstProverMessageJson::Dlog {
+                a: encode_point(&dl.0),
+            },
+            FirstProverMessage::FirstDhtProverMessage(dht) => FirstProverMessageJson::Dht {
+                a: encode_point(&dht.a),
+                b: encode_point(&dht.b),
+            },
+        }
+    }
+}
+
+impl TryFrom<FirstProverMessageJson> for FirstProverMessage {
+    type Error = String;
+
+    fn try_from(m: FirstProverMessageJson) -> Result<Self, Self::Error> {
+        Ok(match m {
+            FirstProverMessageJson::Dlog { a } => FirstDlogProverMessage(decode_point(&a)?).into(),
+            FirstProverMessageJson::Dht { a, b } => {
+                FirstDhTupleProverMessage::new(decode_point(&a)?, decode_point(&b)?).into()
+            }
+        })
+    }
+}
+
+fn encode_challenge(c: Challenge) -> String {
+    let bytes: Vec<u8> = c.into();
+    base16::encode_lower(&bytes)
+}
+
+fn decode_challenge(s: &str) -> Result<Challenge, String> {
+    let bytes = base16::decode(s).map_err(|e| format!("invalid challenge {}: {}", s, e))?;
+    let arr: [u8; SOUNDNESS_BYTES] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| format!("invalid challenge length: {}", bytes.len()))?;
+    Ok(arr.into())
+}
+
+fn decode_scalar(s: &str) -> Result<Scalar, String> {
+    let bytes = base16::decode(s).map_err(|e| format!("invalid secret {}: {}", s, e))?;
+    let arr: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| format!("invalid secret length: {}", bytes.len()))?;
+    Scalar::from_repr(arr.into()).ok_or_else(|| "secret is out of range".to_string())
+}
+
+fn decode_position(s: &str) -> Result<NodePosition, String> {
+    s.parse()
+        .map_err(|e| format!("invalid position {}: {}", s, e))
+}
+
+fn decode_proof(image: &SigmaBoolean, proof: &str) -> Result<UncheckedTree, String> {
+    let bytes = base16::decode(proof).map_err(|e| format!("invalid proof {}: {}", proof, e))?;
+    // commitments are restored the same way as when extracting the hints from a proof
+    parse_sig_compute_challenges(image, bytes.into())
+        .map(|tree| match tree {
+            UncheckedTree::UncheckedSigmaTree(ust) => compute_commitments(ust).into(),
+            UncheckedTree::NoProof => UncheckedTree::NoProof,
+        })
+        .map_err(|e| e.to_string())
+}
+
+impl TryFrom<Hint> for HintJson {
+    type Error = String;
+
+    fn try_from(hint: Hint) -> Result<Self, Self::Error> {
+        Ok(match hint {
+            Hint::CommitmentHint(CommitmentHint::OwnCommitment(c)) => HintJson::OwnCommitment {
+                pubkey: c.image.try_into()?,
+                secret: base16::encode_lower(&c.secret_randomness.to_bytes()),
+                commitment: c.commitment.into(),
+                position: c.position.to_string(),
+            },
+            Hint::CommitmentHint(CommitmentHint::RealCommitment(c)) => HintJson::RealCommitment {
+                pubkey: c.image.try_into()?,
+                commitment: c.commitment.into(),
+                position: c.position.to_string(),
+            },
+            Hint::CommitmentHint(CommitmentHint::SimulatedCommitment(c)) => {
+                HintJson::SimulatedCommitment {
+                    pubkey: c.image.try_into()?,
+                    commitment: c.commitment.into(),
+                    position: c.position.to_string(),
+                }
+            }
+            Hint::SecretProven(SecretProven::RealSecretProof(p)) => HintJson::RealSecretProof {
+                pubkey: p.image.try_into()?,
+                challenge: encode_challenge(p.challenge),
+                proof: serialize_sig(p.unchecked_tree).into(),
+                position: p.position.to_string(),
+            },
+            Hint::SecretProven(SecretProven::SimulatedSecretProof(p)) => {
+                HintJson::SimulatedSecretProof {
+                    pubkey: p.image.try_into()?,
+                    challenge: encode_challenge(p.challenge),
+                    proof: serialize_sig(p.unchecked_tree).into(),
+                    position: p.position.to_string(),
+                }
+            }
+        })
+    }
+}
+
+// hints with a non-leaf image cannot be encoded, hence no `serde(into = ...)`
+impl Serialize for Hint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HintJson::try_from(self.clone())
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl TryFrom<HintJson> for Hint {
+    type Error = String;
+
+    fn try_from(hint: HintJson) -> Result<Self, Self::Error> {
+        Ok(match hint {
+            HintJson::OwnCommitment {
+                pubkey,
+                secret,
+                commitment,
+                position,
+            } => Hint::CommitmentHint(CommitmentHint::OwnCommitment(OwnCommitment {
+                image: pubkey.try_into()?,
+                secret_randomness: decode_scalar(&secret)?,
+                commitment: commitment.try_into()?,
+                position: decode_position(&position)?,
+            })),
+            HintJson::RealCommitment {
+                pubkey,
+                commitment,
+                position,
+            } => Hint::CommitmentHint(CommitmentHint::RealCommitment(RealCommitment {
+                image: pubkey.try_into()?,
+                commitment: commitment.try_into()?,
+                position: decode_position(&position)?,
+            })),
+            HintJson::SimulatedCommitment {
+                pubkey,
+                commitment,
+                position,
+            } => Hint::CommitmentHint(CommitmentHint::SimulatedCommitment(SimulatedCommitment {
+                image: pubkey.try_into()?,
+                commitment: commitment.try_into()?,
+                position: decode_position(&position)?,
+            })),
+            HintJson::RealSecretProof {
+                pubkey,
+                challenge,
+                proof,
+                position,
+            } => {
+                let image: SigmaBoolean = pubkey.try_into()?;
+                Hint::SecretProven(SecretProven::RealSecretProof(RealSecretProof {
+                    unchecked_tree: decode_proof(&image, &proof)?,
+                    image,
+                    challenge: decode_challenge(&challenge)?,
+                    position: decode_position(&position)?,
+                }))
+            }
+            HintJson::SimulatedSecretProof {
+                pubkey,
+                challenge,
+                proof,
+                position,
+            } => {
+                let image: SigmaBoolean = pubkey.try_into()?;
+                Hint::SecretProven(SecretProven::SimulatedSecretProof(SimulatedSecretProof {
+                    unchecked_tree: decode_proof(&image, &proof)?,
+                    image,
+                    challenge: decode_challenge(&challenge)?,
+                    position: decode_position(&position)?,
+                }))
+            }
+        })
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::super::HintsBag;
+    use super::*;
+    use crate::sigma_protocol::private_input::DhTupleProverInput;
+    use crate::sigma_protocol::private_input::DlogProverInput;
+    use crate::sigma_protocol::prover::bag_for_multisig;
+    use crate::sigma_protocol::prover::generate_commitments;
+    use crate::sigma_protocol::prover::Prover;
+    use crate::sigma_protocol::prover::TestProver;
+    use ergotree_ir::ergo_tree::ErgoTree;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::sigma_and::SigmaAnd;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    fn roundtrip(bag: &HintsBag) {
+        let j = serde_json::to_string(bag).unwrap();
+        let parsed: HintsBag = serde_json::from_str(&j).unwrap();
+        assert_eq!(&parsed, bag);
+    }
+
+    #[test]
+    fn commitments_roundtrip() {
+        let pk1: SigmaBoolean = DlogProverInput::random().public_image().into();
+        let pk2: SigmaBoolean = DhTupleProverInput::random().public_image().clone().into();
+        let tree: SigmaBoolean =
+            Cand::normalized(vec![pk1.clone(), pk2.clone()].try_into().unwrap());
+        let bag = generate_commitments(&tree, &[pk1, pk2]);
+        assert_eq!(bag.hints.len(), 4);
+        roundtrip(&bag);
+    }
+
+    #[test]
+    fn proofs_roundtrip() {
+        let secret1 = DlogProverInput::random();
+        let secret2 = DlogProverInput::random();
+        let pk1: SigmaBoolean = secret1.public_image().into();
+        let pk2: SigmaBoolean = secret2.public_image().into();
+        let expr: Expr = SigmaAnd::new(vec![
+            Expr::Const(secret1.public_image().into()),
+            Expr::Const(secret2.public_image().into()),
+        ])
+        .unwrap()
+        .into();
+        let tree: SigmaBoolean =
+            Cand::normalized(vec![pk1.clone(), pk2.clone()].try_into().unwrap());
+        let prover = TestProver {
+            secrets: vec![secret1.into(), secret2.into()],
+        };
+        let message = vec![0u8; 100];
+        let proof = prover
+            .prove(
+                &ErgoTree::from(expr),
+                &crate::eval::env::Env::empty(),
+                Rc::new(force_any_val()),
+                message.as_slice(),
+                &HintsBag::empty(),
+            )
+            .unwrap();
+        let proof_bytes: Vec<u8> = proof.proof.into();
+        let bag = bag_for_multisig(&tree, &proof_bytes, &[pk1], &[pk2]).unwrap();
+        assert!(!bag.real_proofs().is_empty());
+        roundtrip(&bag);
+    }
+
+    #[test]
+    fn node_format() {
+        let pk: SigmaBoolean = DlogProverInput::random().public_image().into();
+        let bag = generate_commitments(&pk, std::slice::from_ref(&pk));
+        let j = serde_json::to_value(&bag).unwrap();
+        let real = &j["hints"][1];
+        assert_eq!(real["hint"], "cmtReal");
+        assert_eq!(real["pubkey"]["op"], -51);
+        assert_eq!(real["type"], "dlog");
+        assert_eq!(real["position"], "0");
+    }
+}
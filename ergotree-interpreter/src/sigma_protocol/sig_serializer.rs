@@ -2,8 +2,10 @@
 
 use std::convert::TryInto;
 
+use super::gf2_192::Gf2_192Poly;
 use super::prover::ProofBytes;
 use super::unchecked_tree::UncheckedConjecture;
+use super::unchecked_tree::UncheckedDhTuple;
 use super::unchecked_tree::UncheckedLeaf;
 use super::unchecked_tree::UncheckedSigmaTree;
 use super::unchecked_tree::UncheckedTree;
@@ -59,6 +61,10 @@ fn sig_write_bytes<W: SigmaByteWrite>(
                 let mut sm_bytes = us.second_message.z.to_bytes();
                 w.write_all(sm_bytes.as_mut_slice())
             }
+            UncheckedLeaf::UncheckedDhTuple(dh) => {
+                let mut sm_bytes = dh.second_message.z.to_bytes();
+                w.write_all(sm_bytes.as_mut_slice())
+            }
         },
         UncheckedSigmaTree::UncheckedConjecture(conj) => match conj {
             UncheckedConjecture::CandUnchecked {
@@ -83,6 +89,21 @@ fn sig_write_bytes<W: SigmaByteWrite>(
                 sig_write_bytes(last, w, false)?;
                 Ok(())
             }
+            UncheckedConjecture::CthresholdUnchecked {
+                challenge: _,
+                children,
+                k: _,
+                polynomial,
+            } => {
+                // write the polynomial (except the free term, it's equal to the challenge of this node)
+                w.write_all(polynomial.to_bytes_without_coeff0().as_slice())?;
+                // don't write children's challenges -- they are computed by the verifier
+                // as the polynomial values at points 1..n
+                for child in children {
+                    sig_write_bytes(child, w, false)?;
+                }
+                Ok(())
+            }
         },
     }
 }
@@ -122,9 +143,9 @@ fn parse_sig_compute_challnges_reader<R: SigmaByteRead>(
     };
 
     match exp {
-        SigmaBoolean::TrivialProp(_) => {
-            panic!("TrivialProp should be handled before this call")
-        }
+        SigmaBoolean::TrivialProp(_) => Err(SigParsingError::Misc(
+            "TrivialProp should be handled before this call".to_string(),
+        )),
         SigmaBoolean::ProofOfKnowledge(tree) => match tree {
             SigmaProofOfKnowledgeTree::ProveDlog(dl) => {
                 // Verifier Step 3: For every leaf node, read the response z provided in the proof.
@@ -139,7 +160,19 @@ fn parse_sig_compute_challnges_reader<R: SigmaByteRead>(
                 }
                 .into())
             }
-            SigmaProofOfKnowledgeTree::ProveDhTuple(_) => todo!("DHT is not yet supported"),
+            SigmaProofOfKnowledgeTree::ProveDhTuple(dh) => {
+                // Verifier Step 3: For every leaf node, read the response z provided in the proof.
+                let mut scalar_bytes: [u8; super::GROUP_SIZE] = [0; super::GROUP_SIZE];
+                r.read_exact(&mut scalar_bytes)?;
+                let z = Scalar::from(GroupSizedBytes(scalar_bytes.into()));
+                Ok(UncheckedDhTuple {
+                    proposition: dh.clone(),
+                    commitment_opt: None,
+                    challenge,
+                    second_message: z.into(),
+                }
+                .into())
+            }
         },
         SigmaBoolean::SigmaConjecture(conj) => match conj {
             SigmaConjecture::Cand(cand) => {
@@ -182,6 +215,34 @@ fn parse_sig_compute_challnges_reader<R: SigmaByteRead>(
                 }
                 .into())
             }
+            SigmaConjecture::Cthreshold(ct) => {
+                // Verifier Step 2: If the node is THRESHOLD,
+                // evaluate the polynomial Q(x) at points 1, 2, ..., n to get challenges for child 1, 2, ..., n, respectively.
+                let n = ct.children.len();
+                let k = ct.k as usize;
+                if k > n {
+                    return Err(SigParsingError::Misc(format!(
+                        "threshold k ({}) is greater than number of children ({})",
+                        k, n
+                    )));
+                }
+                // Read the polynomial -- it has n-k coefficients
+                let mut poly_bytes = vec![0u8; (n - k) * super::SOUNDNESS_BYTES];
+                r.read_exact(&mut poly_bytes)?;
+                let polynomial =
+                    Gf2_192Poly::from_byte_array(challenge.clone().into(), &poly_bytes);
+                let children = ct.children.clone().enumerated().try_mapped(|(i, it)| {
+                    let child_challenge = polynomial.evaluate((i + 1) as u8).into();
+                    parse_sig_compute_challnges_reader(&it, r, Some(child_challenge))
+                })?;
+                Ok(UncheckedConjecture::CthresholdUnchecked {
+                    challenge,
+                    children,
+                    k: ct.k,
+                    polynomial,
+                }
+                .into())
+            }
         },
     }
 }
@@ -195,6 +256,10 @@ pub enum SigParsingError {
     /// Serialization error
     #[error("Serialization error: {0}")]
     SerializationError(SerializationError),
+    /// Invalid signature or sigma proposition
+    #[error("Error: {0}")]
+    #[from(ignore)]
+    Misc(String),
 }
 
 impl From<std::io::Error> for SigParsingError {
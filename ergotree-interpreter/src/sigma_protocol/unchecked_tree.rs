@@ -1,16 +1,19 @@
 //! Unchecked proof tree types
 
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
 use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjectureItems;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
 
+use super::gf2_192::Gf2_192Poly;
 use super::proof_tree::ConjectureType;
 use super::proof_tree::ProofTree;
 use super::proof_tree::ProofTreeConjecture;
 use super::proof_tree::ProofTreeKind;
 use super::proof_tree::ProofTreeLeaf;
 use super::{
+    dht_protocol::{FirstDhTupleProverMessage, SecondDhTupleProverMessage},
     dlog_protocol::{FirstDlogProverMessage, SecondDlogProverMessage},
     Challenge, FirstProverMessage,
 };
@@ -55,9 +58,7 @@ impl UncheckedSigmaTree {
     /// Get challenge
     pub(crate) fn challenge(&self) -> Challenge {
         match self {
-            UncheckedSigmaTree::UncheckedLeaf(UncheckedLeaf::UncheckedSchnorr(us)) => {
-                us.challenge.clone()
-            }
+            UncheckedSigmaTree::UncheckedLeaf(ul) => ul.challenge(),
             UncheckedSigmaTree::UncheckedConjecture(uc) => uc.challenge(),
         }
     }
@@ -76,11 +77,29 @@ impl From<UncheckedSchnorr> for UncheckedSigmaTree {
     }
 }
 
+impl From<UncheckedDhTuple> for UncheckedSigmaTree {
+    fn from(v: UncheckedDhTuple) -> Self {
+        UncheckedSigmaTree::UncheckedLeaf(v.into())
+    }
+}
+
 /// Unchecked leaf
 #[derive(PartialEq, Debug, Clone)]
 pub enum UncheckedLeaf {
     /// Unchecked Schnorr
     UncheckedSchnorr(UncheckedSchnorr),
+    /// Unchecked Diffie-Hellman tuple
+    UncheckedDhTuple(UncheckedDhTuple),
+}
+
+impl UncheckedLeaf {
+    /// Challenge
+    pub(crate) fn challenge(&self) -> Challenge {
+        match self {
+            UncheckedLeaf::UncheckedSchnorr(us) => us.challenge.clone(),
+            UncheckedLeaf::UncheckedDhTuple(ud) => ud.challenge.clone(),
+        }
+    }
 }
 
 impl ProofTreeLeaf for UncheckedLeaf {
@@ -89,11 +108,15 @@ impl ProofTreeLeaf for UncheckedLeaf {
             UncheckedLeaf::UncheckedSchnorr(us) => SigmaBoolean::ProofOfKnowledge(
                 SigmaProofOfKnowledgeTree::ProveDlog(us.proposition.clone()),
             ),
+            UncheckedLeaf::UncheckedDhTuple(ud) => SigmaBoolean::ProofOfKnowledge(
+                SigmaProofOfKnowledgeTree::ProveDhTuple(ud.proposition.clone()),
+            ),
         }
     }
     fn commitment_opt(&self) -> Option<FirstProverMessage> {
         match self {
             UncheckedLeaf::UncheckedSchnorr(us) => us.commitment_opt.clone().map(Into::into),
+            UncheckedLeaf::UncheckedDhTuple(ud) => ud.commitment_opt.clone().map(Into::into),
         }
     }
 }
@@ -118,6 +141,27 @@ impl From<UncheckedSchnorr> for UncheckedTree {
     }
 }
 
+impl From<UncheckedDhTuple> for UncheckedLeaf {
+    fn from(ud: UncheckedDhTuple) -> Self {
+        UncheckedLeaf::UncheckedDhTuple(ud)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct UncheckedDhTuple {
+    pub proposition: ProveDhTuple,
+    pub commitment_opt: Option<FirstDhTupleProverMessage>,
+    pub challenge: Challenge,
+    pub second_message: SecondDhTupleProverMessage,
+}
+
+impl From<UncheckedDhTuple> for UncheckedTree {
+    fn from(ud: UncheckedDhTuple) -> Self {
+        UncheckedTree::UncheckedSigmaTree(ud.into())
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
 #[derive(PartialEq, Debug, Clone)]
 pub enum UncheckedConjecture {
     CandUnchecked {
@@ -128,6 +172,12 @@ pub enum UncheckedConjecture {
         challenge: Challenge,
         children: SigmaConjectureItems<UncheckedSigmaTree>,
     },
+    CthresholdUnchecked {
+        challenge: Challenge,
+        children: SigmaConjectureItems<UncheckedSigmaTree>,
+        k: u8,
+        polynomial: Gf2_192Poly,
+    },
 }
 
 impl UncheckedConjecture {
@@ -147,6 +197,17 @@ impl UncheckedConjecture {
                 challenge,
                 children: new_children,
             },
+            UncheckedConjecture::CthresholdUnchecked {
+                challenge,
+                children: _,
+                k,
+                polynomial,
+            } => UncheckedConjecture::CthresholdUnchecked {
+                challenge,
+                children: new_children,
+                k,
+                polynomial,
+            },
         }
     }
 
@@ -160,6 +221,7 @@ impl UncheckedConjecture {
                 challenge: _,
                 children,
             } => children,
+            UncheckedConjecture::CthresholdUnchecked { children, .. } => children,
         }
     }
 
@@ -173,6 +235,7 @@ impl UncheckedConjecture {
                 challenge,
                 children: _,
             } => challenge.clone(),
+            UncheckedConjecture::CthresholdUnchecked { challenge, .. } => challenge.clone(),
         }
     }
 }
@@ -182,6 +245,7 @@ impl ProofTreeConjecture for UncheckedConjecture {
         match self {
             UncheckedConjecture::CandUnchecked { .. } => ConjectureType::And,
             UncheckedConjecture::CorUnchecked { .. } => ConjectureType::Or,
+            UncheckedConjecture::CthresholdUnchecked { .. } => ConjectureType::Threshold,
         }
     }
 
@@ -195,6 +259,16 @@ impl ProofTreeConjecture for UncheckedConjecture {
                 challenge: _,
                 children,
             } => children.mapped_ref(|ust| ust.clone().into()),
+            UncheckedConjecture::CthresholdUnchecked { children, .. } => {
+                children.mapped_ref(|ust| ust.clone().into())
+            }
+        }
+    }
+
+    fn threshold_k(&self) -> Option<u8> {
+        match self {
+            UncheckedConjecture::CthresholdUnchecked { k, .. } => Some(*k),
+            _ => None,
         }
     }
 }
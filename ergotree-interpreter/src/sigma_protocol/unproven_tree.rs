@@ -1,13 +1,17 @@
 //! Unproven tree types
 
+use super::gf2_192::Gf2_192Poly;
 use super::proof_tree::ConjectureType;
 use super::proof_tree::ProofTree;
 use super::proof_tree::ProofTreeConjecture;
 use super::proof_tree::ProofTreeKind;
-use super::{dlog_protocol::FirstDlogProverMessage, Challenge, FirstProverMessage};
+use super::{dht_protocol::FirstDhTupleProverMessage, dlog_protocol::FirstDlogProverMessage};
+use super::{Challenge, FirstProverMessage};
 use crate::sigma_protocol::proof_tree::ProofTreeLeaf;
 use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
 use ergotree_ir::sigma_protocol::sigma_boolean::cor::Cor;
+use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
 use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjectureItems;
@@ -32,10 +36,11 @@ impl UnprovenTree {
 
     pub(crate) fn simulated(&self) -> bool {
         match self {
-            UnprovenTree::UnprovenLeaf(UnprovenLeaf::UnprovenSchnorr(us)) => us.simulated,
+            UnprovenTree::UnprovenLeaf(ul) => ul.simulated(),
             UnprovenTree::UnprovenConjecture(uc) => match uc {
                 UnprovenConjecture::CandUnproven(cand) => cand.simulated,
                 UnprovenConjecture::CorUnproven(cor) => cor.simulated,
+                UnprovenConjecture::CthresholdUnproven(ct) => ct.simulated,
             },
         }
     }
@@ -89,6 +94,12 @@ impl From<UnprovenSchnorr> for UnprovenTree {
     }
 }
 
+impl From<UnprovenDhTuple> for UnprovenTree {
+    fn from(v: UnprovenDhTuple) -> Self {
+        UnprovenTree::UnprovenLeaf(v.into())
+    }
+}
+
 impl From<CandUnproven> for UnprovenTree {
     fn from(v: CandUnproven) -> Self {
         UnprovenTree::UnprovenConjecture(v.into())
@@ -101,47 +112,65 @@ impl From<CorUnproven> for UnprovenTree {
     }
 }
 
+impl From<CthresholdUnproven> for UnprovenTree {
+    fn from(v: CthresholdUnproven) -> Self {
+        UnprovenTree::UnprovenConjecture(v.into())
+    }
+}
+
 /// Unproven leaf types
 #[derive(PartialEq, Debug, Clone, From)]
 pub(crate) enum UnprovenLeaf {
     /// Unproven Schnorr
     UnprovenSchnorr(UnprovenSchnorr),
+    /// Unproven Diffie-Hellman tuple
+    UnprovenDhTuple(UnprovenDhTuple),
 }
 
 impl UnprovenLeaf {
     fn with_position(self, updated: NodePosition) -> Self {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => us.with_position(updated).into(),
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.with_position(updated).into(),
         }
     }
 
     fn with_challenge(self, challenge: Challenge) -> Self {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => us.with_challenge(challenge).into(),
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.with_challenge(challenge).into(),
         }
     }
 
-    fn with_simulated(self, simulated: bool) -> Self {
+    pub(crate) fn with_simulated(self, simulated: bool) -> Self {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => us.with_simulated(simulated).into(),
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.with_simulated(simulated).into(),
         }
     }
 
     pub(crate) fn is_real(&self) -> bool {
+        !self.simulated()
+    }
+
+    pub(crate) fn simulated(&self) -> bool {
         match self {
-            UnprovenLeaf::UnprovenSchnorr(us) => us.is_real(),
+            UnprovenLeaf::UnprovenSchnorr(us) => us.simulated,
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.simulated,
         }
     }
 
     pub(crate) fn challenge(&self) -> Option<Challenge> {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => us.challenge_opt.clone(),
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.challenge_opt.clone(),
         }
     }
 
     pub(crate) fn position(&self) -> &NodePosition {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => &us.position,
+            UnprovenLeaf::UnprovenDhTuple(ut) => &ut.position,
         }
     }
 }
@@ -152,20 +181,26 @@ impl ProofTreeLeaf for UnprovenLeaf {
             UnprovenLeaf::UnprovenSchnorr(us) => SigmaBoolean::ProofOfKnowledge(
                 SigmaProofOfKnowledgeTree::ProveDlog(us.proposition.clone()),
             ),
+            UnprovenLeaf::UnprovenDhTuple(ut) => SigmaBoolean::ProofOfKnowledge(
+                SigmaProofOfKnowledgeTree::ProveDhTuple(ut.proposition.clone()),
+            ),
         }
     }
 
     fn commitment_opt(&self) -> Option<FirstProverMessage> {
         match self {
             UnprovenLeaf::UnprovenSchnorr(us) => us.commitment_opt.clone().map(Into::into),
+            UnprovenLeaf::UnprovenDhTuple(ut) => ut.commitment_opt.clone().map(Into::into),
         }
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(PartialEq, Debug, Clone, From)]
 pub(crate) enum UnprovenConjecture {
     CandUnproven(CandUnproven),
     CorUnproven(CorUnproven),
+    CthresholdUnproven(CthresholdUnproven),
 }
 
 impl UnprovenConjecture {
@@ -173,6 +208,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.children.clone(),
             UnprovenConjecture::CorUnproven(cor) => cor.children.clone(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.children.clone(),
         }
     }
 
@@ -180,6 +216,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => &cand.position,
             UnprovenConjecture::CorUnproven(cor) => &cor.position,
+            UnprovenConjecture::CthresholdUnproven(ct) => &ct.position,
         }
     }
 
@@ -187,6 +224,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.challenge_opt.clone(),
             UnprovenConjecture::CorUnproven(cor) => cor.challenge_opt.clone(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.challenge_opt.clone(),
         }
     }
 
@@ -194,6 +232,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.with_position(updated).into(),
             UnprovenConjecture::CorUnproven(cor) => cor.with_position(updated).into(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.with_position(updated).into(),
         }
     }
 
@@ -201,6 +240,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.with_challenge(challenge).into(),
             UnprovenConjecture::CorUnproven(cor) => cor.with_challenge(challenge).into(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.with_challenge(challenge).into(),
         }
     }
 
@@ -208,6 +248,7 @@ impl UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.with_simulated(simulated).into(),
             UnprovenConjecture::CorUnproven(cor) => cor.with_simulated(simulated).into(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.with_simulated(simulated).into(),
         }
     }
 }
@@ -217,6 +258,14 @@ impl ProofTreeConjecture for UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(_) => ConjectureType::And,
             UnprovenConjecture::CorUnproven(_) => ConjectureType::Or,
+            UnprovenConjecture::CthresholdUnproven(_) => ConjectureType::Threshold,
+        }
+    }
+
+    fn threshold_k(&self) -> Option<u8> {
+        match self {
+            UnprovenConjecture::CthresholdUnproven(ct) => Some(ct.k()),
+            _ => None,
         }
     }
 
@@ -224,6 +273,7 @@ impl ProofTreeConjecture for UnprovenConjecture {
         match self {
             UnprovenConjecture::CandUnproven(cand) => cand.children.clone(),
             UnprovenConjecture::CorUnproven(cor) => cor.children.clone(),
+            UnprovenConjecture::CthresholdUnproven(ct) => ct.children.clone(),
         }
     }
 }
@@ -256,9 +306,35 @@ impl UnprovenSchnorr {
     fn with_simulated(self, simulated: bool) -> Self {
         UnprovenSchnorr { simulated, ..self }
     }
+}
 
-    pub(crate) fn is_real(&self) -> bool {
-        !self.simulated
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) struct UnprovenDhTuple {
+    pub(crate) proposition: ProveDhTuple,
+    pub(crate) commitment_opt: Option<FirstDhTupleProverMessage>,
+    pub(crate) randomness_opt: Option<Scalar>,
+    pub(crate) challenge_opt: Option<Challenge>,
+    pub(crate) simulated: bool,
+    pub(crate) position: NodePosition,
+}
+
+impl UnprovenDhTuple {
+    fn with_position(self, updated: NodePosition) -> Self {
+        UnprovenDhTuple {
+            position: updated,
+            ..self
+        }
+    }
+
+    fn with_challenge(self, challenge: Challenge) -> Self {
+        UnprovenDhTuple {
+            challenge_opt: Some(challenge),
+            ..self
+        }
+    }
+
+    fn with_simulated(self, simulated: bool) -> Self {
+        UnprovenDhTuple { simulated, ..self }
     }
 }
 
@@ -300,6 +376,26 @@ impl NodePosition {
     }
 }
 
+/// Encoded as positions joined with "-", e.g. "0-1-2"
+impl std::fmt::Display for NodePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let strs: Vec<String> = self.positions.iter().map(|p| p.to_string()).collect();
+        write!(f, "{}", strs.join("-"))
+    }
+}
+
+impl std::str::FromStr for NodePosition {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let positions = s
+            .split('-')
+            .map(|p| p.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok(NodePosition { positions })
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub(crate) struct CandUnproven {
     pub(crate) proposition: Cand,
@@ -373,3 +469,53 @@ impl CorUnproven {
         Self { children, ..self }
     }
 }
+
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) struct CthresholdUnproven {
+    pub(crate) proposition: Cthreshold,
+    pub(crate) challenge_opt: Option<Challenge>,
+    pub(crate) simulated: bool,
+    pub(crate) children: SigmaConjectureItems<ProofTree>,
+    pub(crate) polynomial_opt: Option<Gf2_192Poly>,
+    pub(crate) position: NodePosition,
+}
+
+impl CthresholdUnproven {
+    pub(crate) fn is_real(&self) -> bool {
+        !self.simulated
+    }
+
+    /// Number of children to be proven
+    pub(crate) fn k(&self) -> u8 {
+        self.proposition.k
+    }
+
+    fn with_position(self, updated: NodePosition) -> Self {
+        Self {
+            position: updated,
+            ..self
+        }
+    }
+
+    fn with_challenge(self, challenge: Challenge) -> Self {
+        Self {
+            challenge_opt: Some(challenge),
+            ..self
+        }
+    }
+
+    fn with_simulated(self, simulated: bool) -> Self {
+        Self { simulated, ..self }
+    }
+
+    pub(crate) fn with_children(self, children: SigmaConjectureItems<ProofTree>) -> Self {
+        Self { children, ..self }
+    }
+
+    pub(crate) fn with_polynomial(self, polynomial: Gf2_192Poly) -> Self {
+        Self {
+            polynomial_opt: Some(polynomial),
+            ..self
+        }
+    }
+}
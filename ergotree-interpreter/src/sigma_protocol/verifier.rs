@@ -2,9 +2,12 @@
 
 use std::rc::Rc;
 
+use super::dht_protocol;
+use super::dht_protocol::FirstDhTupleProverMessage;
 use super::fiat_shamir::FiatShamirTreeSerializationError;
 use super::prover::ProofBytes;
 use super::sig_serializer::SigParsingError;
+use super::unchecked_tree::UncheckedDhTuple;
 use super::{
     dlog_protocol,
     fiat_shamir::{fiat_shamir_hash_fn, fiat_shamir_tree_to_bytes},
@@ -13,6 +16,9 @@ use super::{
     SigmaBoolean, UncheckedSigmaTree, UncheckedTree,
 };
 use crate::eval::context::Context;
+use crate::eval::cost_accum::CostError;
+use crate::eval::costs::Cost;
+use crate::eval::costs::Costs;
 use crate::eval::env::Env;
 use crate::eval::{EvalError, Evaluator};
 use dlog_protocol::FirstDlogProverMessage;
@@ -46,7 +52,7 @@ pub enum VerifierError {
 pub struct VerificationResult {
     /// result of SigmaProp condition verification via sigma protocol
     pub result: bool,
-    /// estimated cost of contract execution
+    /// estimated cost of contract execution and proof verification (in block cost units)
     pub cost: u64,
 }
 
@@ -65,7 +71,22 @@ pub trait Verifier: Evaluator {
         message: &[u8],
     ) -> Result<VerificationResult, VerifierError> {
         let expr = tree.proposition()?;
-        let cprop = self.reduce_to_crypto(expr.as_ref(), env, ctx)?.sigma_prop;
+        let cost_limit = ctx.cost_limit;
+        let reduction_result = self.reduce_to_crypto(expr.as_ref(), env, ctx)?;
+        let cprop = reduction_result.sigma_prop;
+        let proof_len = match &proof {
+            ProofBytes::Empty => 0,
+            ProofBytes::Some(bytes) => bytes.len(),
+        };
+        let verification_cost = Cost::to_block_cost(
+            Costs::DEFAULT.sigma_verification_cost(&cprop, proof_len + message.len()),
+        );
+        let cost = reduction_result.cost.saturating_add(verification_cost);
+        if let Some(limit) = cost_limit {
+            if cost > limit {
+                return Err(EvalError::CostError(CostError::LimitExceeded(limit)).into());
+            }
+        }
         let res: bool = match cprop {
             SigmaBoolean::TrivialProp(b) => b,
             sb => {
@@ -79,13 +100,30 @@ pub trait Verifier: Evaluator {
                 }
             }
         };
-        Ok(VerificationResult {
-            result: res,
-            cost: 0,
-        })
+        Ok(VerificationResult { result: res, cost })
     }
 }
 
+/// Verifies the signature of an arbitrary message made with [`super::prover::Prover::sign_message`]
+/// for the given sigma proposition
+pub fn verify_signature(
+    sigma_tree: SigmaBoolean,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, VerifierError> {
+    Ok(match sigma_tree {
+        SigmaBoolean::TrivialProp(b) => b,
+        sb => {
+            // Perform Verifier Steps 1-3
+            match parse_sig_compute_challenges(&sb, signature.to_vec().into())? {
+                // Perform Verifier Steps 4-6
+                UncheckedTree::UncheckedSigmaTree(sp) => check_commitments(sp, message)?,
+                UncheckedTree::NoProof => false,
+            }
+        }
+    })
+}
+
 /// Perform Verifier Steps 4-6
 fn check_commitments(sp: UncheckedSigmaTree, message: &[u8]) -> Result<bool, VerifierError> {
     // Perform Verifier Step 4
@@ -103,7 +141,7 @@ fn check_commitments(sp: UncheckedSigmaTree, message: &[u8]) -> Result<bool, Ver
 /// Verifier Step 4: For every leaf node, compute the commitment a from the challenge e and response $z$,
 /// per the verifier algorithm of the leaf's Sigma-protocol.
 /// If the verifier algorithm of the Sigma-protocol for any of the leaves rejects, then reject the entire proof.
-fn compute_commitments(sp: UncheckedSigmaTree) -> UncheckedSigmaTree {
+pub(crate) fn compute_commitments(sp: UncheckedSigmaTree) -> UncheckedSigmaTree {
     match sp {
         UncheckedSigmaTree::UncheckedLeaf(leaf) => match leaf {
             UncheckedLeaf::UncheckedSchnorr(sn) => {
@@ -118,6 +156,18 @@ fn compute_commitments(sp: UncheckedSigmaTree) -> UncheckedSigmaTree {
                 }
                 .into()
             }
+            UncheckedLeaf::UncheckedDhTuple(dh) => {
+                let (a, b) = dht_protocol::interactive_prover::compute_commitment(
+                    &dh.proposition,
+                    &dh.challenge,
+                    &dh.second_message,
+                );
+                UncheckedDhTuple {
+                    commitment_opt: Some(FirstDhTupleProverMessage::new(a, b)),
+                    ..dh
+                }
+                .into()
+            }
         },
         UncheckedSigmaTree::UncheckedConjecture(conj) => conj
             .clone()
@@ -139,17 +189,26 @@ mod tests {
     use super::*;
     use crate::sigma_protocol::prover::hint::HintsBag;
     use crate::sigma_protocol::{
-        private_input::{DlogProverInput, PrivateInput},
-        prover::{Prover, TestProver},
+        private_input::{DhTupleProverInput, DlogProverInput, PrivateInput},
+        prover::{Prover, ProverError, TestProver},
     };
+    use ergotree_ir::mir::atleast::Atleast;
+    use ergotree_ir::mir::collection::Collection;
+    use ergotree_ir::mir::constant::Constant;
     use ergotree_ir::mir::expr::Expr;
     use ergotree_ir::mir::sigma_and::SigmaAnd;
     use ergotree_ir::mir::sigma_or::SigmaOr;
     use ergotree_ir::serialization::SigmaSerializable;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cor::Cor;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+    use ergotree_ir::types::stype::SType;
     use num_bigint::BigUint;
     use proptest::collection::vec;
     use proptest::prelude::*;
     use sigma_test_util::force_any_val;
+    use std::convert::TryInto;
     use std::rc::Rc;
 
     fn proof_append_byte(proof: &ProofBytes) -> ProofBytes {
@@ -167,6 +226,44 @@ mod tests {
 
         #![proptest_config(ProptestConfig::with_cases(4))]
 
+        #[test]
+        fn test_verification_cost(secret in any::<DlogProverInput>(), message in vec(any::<u8>(), 100..200)) {
+            let pk = secret.public_image();
+            let tree = ErgoTree::from(Expr::Const(pk.into()));
+            let prover = TestProver {
+                secrets: vec![PrivateInput::DlogProverInput(secret)],
+            };
+            let proof = prover.prove(&tree,
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>()),
+                message.as_slice(),
+                &HintsBag::empty()).unwrap().proof;
+            let verifier = TestVerifier;
+            let reduction_cost = verifier.reduce_to_crypto(
+                tree.proposition().unwrap().as_ref(),
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>())).unwrap().cost;
+            let ver_res = verifier.verify(&tree,
+                                          &Env::empty(),
+                                          Rc::new(force_any_val::<Context>()),
+                                          proof.clone(),
+                                          message.as_slice()).unwrap();
+            prop_assert!(ver_res.result);
+            // signature verification (commitments computation) dominates the cost
+            prop_assert!(ver_res.cost > reduction_cost + 300);
+
+            let mut ctx = force_any_val::<Context>();
+            ctx.cost_limit = Some(ver_res.cost - 1);
+            let limit_res = verifier.verify(&tree,
+                                            &Env::empty(),
+                                            Rc::new(ctx),
+                                            proof,
+                                            message.as_slice());
+            prop_assert_eq!(limit_res.err(),
+                            Some(VerifierError::EvalError(EvalError::CostError(
+                                CostError::LimitExceeded(ver_res.cost - 1)))));
+        }
+
         #[test]
         fn test_prover_verifier_p2pk(secret in any::<DlogProverInput>(), message in vec(any::<u8>(), 100..200)) {
             let pk = secret.public_image();
@@ -209,6 +306,77 @@ mod tests {
                             false);
         }
 
+        #[test]
+        fn test_prover_verifier_dht(secret in any::<DhTupleProverInput>(), message in vec(any::<u8>(), 100..200)) {
+            let pk = secret.public_image().clone();
+            let tree = ErgoTree::from(Expr::Const(SigmaProp::from(pk).into()));
+
+            let prover = TestProver {
+                secrets: vec![PrivateInput::DhTupleProverInput(secret)],
+            };
+            let res = prover.prove(&tree,
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>()),
+                message.as_slice(),
+                &HintsBag::empty());
+            let proof = res.unwrap().proof;
+            let verifier = TestVerifier;
+            prop_assert_eq!(verifier.verify(&tree,
+                                            &Env::empty(),
+                                            Rc::new(force_any_val::<Context>()),
+                                            proof.clone(),
+                                            message.as_slice())
+                            .unwrap().result,
+                            true);
+
+            // wrong message
+            prop_assert_eq!(verifier.verify(&tree,
+                                            &Env::empty(),
+                                            Rc::new(force_any_val::<Context>()),
+                                            proof,
+                                            vec![1u8; 100].as_slice())
+                            .unwrap().result,
+                            false);
+        }
+
+        #[test]
+        fn test_prover_verifier_dht_or_dlog(dht_secret in any::<DhTupleProverInput>(),
+                                            dlog_secret in any::<DlogProverInput>(),
+                                            message in vec(any::<u8>(), 100..200)) {
+            let dht_pk = dht_secret.public_image().clone();
+            let dlog_pk = dlog_secret.public_image();
+            let expr: Expr = SigmaOr::new(vec![
+                Expr::Const(SigmaProp::from(dht_pk).into()),
+                Expr::Const(dlog_pk.into()),
+            ])
+            .unwrap()
+            .into();
+            let tree = ErgoTree::from(expr);
+            let secrets = vec![
+                PrivateInput::DhTupleProverInput(dht_secret),
+                PrivateInput::DlogProverInput(dlog_secret),
+            ];
+            // any secret (out of 2) known to prover should be enough
+            for secret in secrets {
+                let prover = TestProver {
+                    secrets: vec![secret.clone()],
+                };
+                let res = prover.prove(&tree,
+                    &Env::empty(),
+                    Rc::new(force_any_val::<Context>()),
+                    message.as_slice(),
+                    &HintsBag::empty());
+                let proof = res.unwrap_or_else(|_| panic!("proof failed for secret: {:?}", secret)).proof;
+                let verifier = TestVerifier;
+                let ver_res = verifier.verify(&tree,
+                                              &Env::empty(),
+                                              Rc::new(force_any_val::<Context>()),
+                                              proof,
+                                              message.as_slice());
+                prop_assert_eq!(ver_res.unwrap().result, true, "verify failed on secret: {:?}", &secret);
+            }
+        }
+
         #[test]
         fn test_prover_verifier_conj_and(secret1 in any::<DlogProverInput>(),
                                          secret2 in any::<DlogProverInput>(),
@@ -347,6 +515,162 @@ mod tests {
         }
     }
 
+    fn atleast_tree(bound: i32, pks: Vec<SigmaProp>) -> ErgoTree {
+        let expr: Expr = Atleast::new(Expr::Const(bound.into()), Expr::Const(Constant::from(pks)))
+            .unwrap()
+            .into();
+        ErgoTree::from(expr)
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(4))]
+
+        #[test]
+        fn test_prover_verifier_atleast(secret1 in any::<DlogProverInput>(),
+                                        secret2 in any::<DlogProverInput>(),
+                                        secret3 in any::<DlogProverInput>(),
+                                        message in vec(any::<u8>(), 100..200)) {
+            let secrets = [secret1, secret2, secret3];
+            let pks: Vec<SigmaProp> = secrets.iter().map(|s| s.public_image().into()).collect();
+            let tree = atleast_tree(2, pks);
+            // any 2 secrets (out of 3) known to prover should be enough
+            for (i, j) in [(0, 1), (0, 2), (1, 2)].iter() {
+                let prover = TestProver {
+                    secrets: vec![secrets[*i].clone().into(), secrets[*j].clone().into()],
+                };
+                let res = prover.prove(&tree,
+                    &Env::empty(),
+                    Rc::new(force_any_val::<Context>()),
+                    message.as_slice(),
+                    &HintsBag::empty());
+                let proof = res.unwrap().proof;
+                let verifier = TestVerifier;
+                let ver_res = verifier.verify(&tree,
+                                              &Env::empty(),
+                                              Rc::new(force_any_val::<Context>()),
+                                              proof.clone(),
+                                              message.as_slice());
+                prop_assert_eq!(ver_res.unwrap().result, true);
+                let ver_res_tampered = verifier.verify(&tree,
+                                              &Env::empty(),
+                                              Rc::new(force_any_val::<Context>()),
+                                              proof,
+                                              vec![1u8; 10].as_slice());
+                prop_assert_eq!(ver_res_tampered.unwrap().result, false);
+            }
+            // a single secret is not enough
+            let prover = TestProver {
+                secrets: vec![secrets[0].clone().into()],
+            };
+            let res = prover.prove(&tree,
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>()),
+                message.as_slice(),
+                &HintsBag::empty());
+            prop_assert!(matches!(res, Err(ProverError::TreeRootIsNotReal)));
+        }
+
+        #[test]
+        fn test_prover_verifier_atleast_nested(secret1 in any::<DlogProverInput>(),
+                                               secret2 in any::<DlogProverInput>(),
+                                               secret3 in any::<DhTupleProverInput>(),
+                                               secret4 in any::<DlogProverInput>(),
+                                               message in vec(any::<u8>(), 100..200)) {
+            // atLeast(2, pk1, pk2 && pk3, atLeast(1, pk1, pk4) || pk2, pk4)
+            let pk1: SigmaProp = secret1.public_image().into();
+            let pk2: SigmaProp = secret2.public_image().into();
+            let pk3: SigmaProp = secret3.public_image().clone().into();
+            let pk4: SigmaProp = secret4.public_image().into();
+            let expr: Expr = Atleast::new(
+                Expr::Const(2i32.into()),
+                Collection::new(SType::SSigmaProp, vec![
+                    Expr::Const(pk1.clone().into()),
+                    SigmaAnd::new(vec![Expr::Const(pk2.clone().into()), Expr::Const(pk3.into())])
+                        .unwrap()
+                        .into(),
+                    SigmaOr::new(vec![
+                        Atleast::new(
+                            Expr::Const(1i32.into()),
+                            Expr::Const(Constant::from(vec![pk1, pk4.clone()])),
+                        ).unwrap().into(),
+                        Expr::Const(pk2.into()),
+                    ]).unwrap().into(),
+                    Expr::Const(pk4.into()),
+                ]).unwrap().into(),
+            )
+            .unwrap()
+            .into();
+            let tree = ErgoTree::from(expr);
+            let prover = TestProver {
+                secrets: vec![secret2.into(), secret3.into(), secret4.into()],
+            };
+            let res = prover.prove(&tree,
+                &Env::empty(),
+                Rc::new(force_any_val::<Context>()),
+                message.as_slice(),
+                &HintsBag::empty());
+            let proof = res.unwrap().proof;
+            let verifier = TestVerifier;
+            let ver_res = verifier.verify(&tree,
+                                          &Env::empty(),
+                                          Rc::new(force_any_val::<Context>()),
+                                          proof,
+                                          message.as_slice());
+            prop_assert_eq!(ver_res.unwrap().result, true);
+        }
+
+        #[test]
+        fn test_sign_message(secret1 in any::<DlogProverInput>(),
+                             secret2 in any::<DhTupleProverInput>(),
+                             message in vec(any::<u8>(), 100..200)) {
+            for secret in [PrivateInput::from(secret1), PrivateInput::from(secret2)].iter() {
+                let sb = secret.public_image();
+                let prover = TestProver {
+                    secrets: vec![secret.clone()],
+                };
+                let signature = prover.sign_message(sb.clone(), message.as_slice(), &HintsBag::empty()).unwrap();
+                prop_assert_eq!(verify_signature(sb.clone(), message.as_slice(), signature.as_slice()).unwrap(), true);
+                prop_assert_eq!(verify_signature(sb, vec![1u8; 10].as_slice(), signature.as_slice()).unwrap(), false);
+            }
+        }
+
+        #[test]
+        fn test_sign_message_ring(secrets in vec(any::<DlogProverInput>(), 2..5),
+                                  message in vec(any::<u8>(), 100..200)) {
+            prop_assume!(secrets.iter().enumerate().all(|(i, s)| secrets.iter().skip(i + 1).all(|o| o != s)));
+            // ring signature: any of the ring members can sign
+            let ring = Cor::normalized(
+                secrets.iter().map(|s| s.public_image().into()).collect::<Vec<SigmaBoolean>>().try_into().unwrap()
+            );
+            for secret in secrets.iter() {
+                let prover = TestProver {
+                    secrets: vec![secret.clone().into()],
+                };
+                let signature = prover.sign_message(ring.clone(), message.as_slice(), &HintsBag::empty()).unwrap();
+                prop_assert_eq!(verify_signature(ring.clone(), message.as_slice(), signature.as_slice()).unwrap(), true);
+            }
+            // non-member can't sign
+            let prover = TestProver {
+                secrets: vec![DlogProverInput::random().into()],
+            };
+            let res = prover.sign_message(ring, message.as_slice(), &HintsBag::empty());
+            prop_assert!(matches!(res, Err(ProverError::TreeRootIsNotReal)));
+        }
+
+        #[test]
+        fn test_sign_message_ring_duplicate_keys(secret in any::<DlogProverInput>(),
+                                                 message in vec(any::<u8>(), 100..200)) {
+            let pk: SigmaBoolean = secret.public_image().into();
+            let ring = Cor::normalized(vec![pk.clone(), pk].try_into().unwrap());
+            let prover = TestProver {
+                secrets: vec![secret.into()],
+            };
+            let signature = prover.sign_message(ring.clone(), message.as_slice(), &HintsBag::empty()).unwrap();
+            prop_assert_eq!(verify_signature(ring, message.as_slice(), signature.as_slice()).unwrap(), true);
+        }
+    }
+
     #[test]
     fn sig_test_vector_provedlog() {
         // test vector data from
@@ -641,4 +965,19 @@ mod tests {
         );
         assert_eq!(ver_res.unwrap().result, true);
     }
+
+    #[test]
+    fn verify_signature_trivial_prop_in_threshold() {
+        let sb: SigmaBoolean = SigmaConjecture::Cthreshold(Cthreshold {
+            k: 1,
+            children: vec![
+                SigmaBoolean::TrivialProp(true),
+                force_any_val::<DlogProverInput>().public_image().into(),
+            ]
+            .try_into()
+            .unwrap(),
+        })
+        .into();
+        assert!(verify_signature(sb, &[1, 2, 3], &[0u8; 100]).is_err());
+    }
 }
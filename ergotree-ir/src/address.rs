@@ -365,7 +365,13 @@ impl AddressEncoder {
                 Address::P2Pk(ProveDlog::new(EcPoint::sigma_parse_bytes(&content_bytes)?))
             }
             AddressTypePrefix::Pay2S => Address::P2S(content_bytes),
-            AddressTypePrefix::Pay2Sh => todo!(),
+            AddressTypePrefix::Pay2Sh => {
+                return Err(AddressEncoderError::DeserializationFailed(
+                    SerializationError::NotImplementedYet(
+                        "P2SH address is not yet supported".to_string(),
+                    ),
+                ))
+            }
         })
     }
 
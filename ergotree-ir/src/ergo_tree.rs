@@ -2,6 +2,7 @@
 use crate::mir::constant::Constant;
 use crate::mir::constant::TryExtractFromError;
 use crate::mir::expr::Expr;
+use crate::pretty_printer::print_expr;
 use crate::serialization::{
     sigma_byte_reader::{SigmaByteRead, SigmaByteReader},
     sigma_byte_writer::{SigmaByteWrite, SigmaByteWriter},
@@ -201,8 +202,16 @@ impl ErgoTree {
         header: ErgoTreeHeader,
         size: u32,
     ) -> Result<Self, SerializationError> {
-        let mut buf = vec![0u8; size as usize];
-        r.read_exact(buf.as_mut_slice())?;
+        // don't trust the size to pre-allocate the buffer, read only what's actually there
+        let mut buf = Vec::new();
+        r.by_ref().take(size as u64).read_to_end(&mut buf)?;
+        if buf.len() != size as usize {
+            return Err(SerializationError::Io(format!(
+                "expected {} bytes of ErgoTree, got {}",
+                size,
+                buf.len()
+            )));
+        }
         if let Ok((constants, mut tree_bytes)) =
             ErgoTree::sigma_parse_tree_bytes(buf.as_mut_slice(), header.is_constant_segregation())
         {
@@ -274,12 +283,9 @@ impl ErgoTree {
                 "too many constants".to_string(),
             ));
         }
-        let mut constants = Vec::with_capacity(constants_len as usize);
-        for _ in 0..constants_len {
-            let c = Constant::sigma_parse(r)?;
-            constants.push(c);
-        }
-        Ok(constants)
+        (0..constants_len)
+            .map(|_| Constant::sigma_parse(r))
+            .collect()
     }
 
     /// Creates a tree using provided header and root expression
@@ -340,15 +346,24 @@ impl ErgoTree {
                 cursor,
                 ConstantStore::new(tree.constants),
             );
-            #[allow(clippy::unwrap_used)]
-            // if it was serialized, then we should deserialize it without error
-            let parsed_expr = Expr::sigma_parse(&mut sr).unwrap();
+            let parsed_expr = Expr::sigma_parse(&mut sr).map_err(|error| {
+                ErgoTreeParsingError::RootParsingError(ErgoTreeRootParsingError {
+                    root_expr_bytes: data.clone(),
+                    error,
+                })
+            })?;
             Ok(Rc::new(parsed_expr))
         } else {
             Ok(root)
         }
     }
 
+    /// Decompiles the tree into ErgoScript-like source code (with constants substituted), see
+    /// [`crate::pretty_printer`]
+    pub fn to_ergoscript(&self) -> Result<String, ErgoTreeParsingError> {
+        Ok(print_expr(self.proposition()?.as_ref()))
+    }
+
     /// Prints with newlines
     pub fn debug_tree(&self) -> String {
         let tree = format!("{:#?}", self);
@@ -546,6 +561,7 @@ mod tests {
     use crate::address::AddressEncoder;
     use crate::address::NetworkPrefix;
     use crate::mir::value::Value;
+    use crate::serialization::op_code::OpCode;
     use proptest::prelude::*;
 
     proptest! {
@@ -616,6 +632,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialization_tree_size_exceeds_input() {
+        // v1(size is set), tree size is way bigger than the input
+        let bytes = [
+            ErgoTreeHeader::v1(false).into(),
+            0xff, // tree size (u32::MAX)
+            0xff,
+            0xff,
+            0xff,
+            0x0f,
+            OpCode::HEIGHT.value(),
+        ];
+        assert!(ErgoTree::sigma_parse_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn deserialization_non_parseable_root_v0() {
         // no constant segregation, Expr is invalid
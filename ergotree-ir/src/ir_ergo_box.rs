@@ -1,7 +1,19 @@
 //! ErgoBox representation in IR
+use crate::ergo_tree::ErgoTree;
 use crate::mir::constant::Constant;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::sigma_byte_writer::SigmaByteWriter;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::util::AsVecI8;
+use crate::util::AsVecU8;
+use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+use sigma_util::hash::blake2b256_hash;
 use sigma_util::DIGEST32_SIZE;
 use std::fmt::Debug;
+use std::io;
+use std::io::Write;
 use std::rc::Rc;
 use thiserror::Error;
 
@@ -29,6 +41,58 @@ impl IrBoxId {
     }
 }
 
+/// Box value, either a reference to a box in the context's box arena or a box parsed from its
+/// serialized bytes (e.g. from a context variable or a register).
+/// Boxes are compared by id, as in the reference implementation.
+#[derive(Debug, Clone)]
+pub enum IrBox {
+    /// Box in the box arena with the given id
+    Ref(IrBoxId),
+    /// Box parsed from bytes
+    Parsed(Box<ParsedErgoBox>),
+}
+
+impl IrBox {
+    /// Box id
+    pub fn id(&self) -> IrBoxId {
+        match self {
+            IrBox::Ref(id) => id.clone(),
+            IrBox::Parsed(b) => b.id(),
+        }
+    }
+
+    /// Gets the box from box arena (for `Ref`) or returns the parsed box itself
+    pub fn get_box(
+        &self,
+        arena: &Rc<dyn IrErgoBoxArena>,
+    ) -> Result<Rc<dyn IrErgoBox>, IrErgoBoxArenaError> {
+        match self {
+            IrBox::Ref(id) => arena.get(id),
+            IrBox::Parsed(b) => Ok(Rc::new(b.as_ref().clone()) as Rc<dyn IrErgoBox>),
+        }
+    }
+}
+
+impl PartialEq for IrBox {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for IrBox {}
+
+impl From<IrBoxId> for IrBox {
+    fn from(id: IrBoxId) -> Self {
+        IrBox::Ref(id)
+    }
+}
+
+impl From<ParsedErgoBox> for IrBox {
+    fn from(b: ParsedErgoBox) -> Self {
+        IrBox::Parsed(Box::new(b))
+    }
+}
+
 /// Arena (store) for boxes
 pub trait IrErgoBoxArena: Debug {
     /// Returns a box with the given id
@@ -59,4 +123,281 @@ pub trait IrErgoBox: Debug {
     /// Tuple of height when block got included into the blockchain and transaction identifier with
     /// box index in the transaction outputs serialized to the byte array.
     fn creation_info(&self) -> (i32, Vec<i8>);
+    /// Serialized box content without the transaction id and box index
+    /// (as box candidate, in the same format as the node)
+    fn bytes_without_ref(&self) -> Vec<i8> {
+        serialize_bytes(|w| serialize_box_without_ref(self, w))
+    }
+    /// Serialized box content followed by the transaction id and box index
+    /// (in the same format as the node)
+    fn bytes(&self) -> Vec<i8> {
+        serialize_bytes(|w| {
+            serialize_box_without_ref(self, w)?;
+            // creation info bytes are tx id followed by the box index (2 bytes, big-endian)
+            let tx_id_box_index = self.creation_info().1.as_vec_u8();
+            let (tx_id, box_index) =
+                tx_id_box_index.split_at(tx_id_box_index.len().saturating_sub(2));
+            w.write_all(tx_id)?;
+            w.put_u16(match box_index {
+                [hi, lo] => u16::from_be_bytes([*hi, *lo]),
+                _ => 0,
+            })
+        })
+    }
+}
+
+/// Box parsed from its serialized bytes (box candidate bytes followed by the transaction id and
+/// box index, as `ErgoBox` is serialized by the node)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParsedErgoBox {
+    value: u64,
+    ergo_tree: ErgoTree,
+    creation_height: u32,
+    tokens: Vec<([u8; DIGEST32_SIZE], u64)>,
+    additional_registers: Vec<Constant>,
+    tx_id: [u8; DIGEST32_SIZE],
+    index: u16,
+}
+
+impl ParsedErgoBox {
+    /// Maximum number of additional registers (R4-R9)
+    pub const MAX_ADDITIONAL_REGISTERS: usize = 6;
+
+    /// Guarding script
+    pub fn ergo_tree(&self) -> &ErgoTree {
+        &self.ergo_tree
+    }
+}
+
+impl IrErgoBox for ParsedErgoBox {
+    fn id(&self) -> IrBoxId {
+        let hash = blake2b256_hash(&self.bytes().as_vec_u8());
+        IrBoxId(hash.map(|b| b as i8))
+    }
+
+    fn value(&self) -> i64 {
+        self.value as i64
+    }
+
+    fn tokens_raw(&self) -> Vec<(Vec<i8>, i64)> {
+        self.tokens
+            .iter()
+            .map(|(id, amount)| (id.to_vec().as_vec_i8(), *amount as i64))
+            .collect()
+    }
+
+    fn additional_registers(&self) -> &[Constant] {
+        self.additional_registers.as_slice()
+    }
+
+    fn get_register(&self, id: i8) -> Option<Constant> {
+        match id {
+            0 => Some(self.value().into()),
+            1 => Some(self.script_bytes().into()),
+            2 => Some(self.tokens_raw().into()),
+            3 => Some(self.creation_info().into()),
+            _ => (id as usize)
+                .checked_sub(4)
+                .and_then(|idx| self.additional_registers.get(idx))
+                .cloned(),
+        }
+    }
+
+    fn creation_height(&self) -> i32 {
+        self.creation_height as i32
+    }
+
+    fn script_bytes(&self) -> Vec<i8> {
+        self.ergo_tree.sigma_serialize_bytes().as_vec_i8()
+    }
+
+    fn creation_info(&self) -> (i32, Vec<i8>) {
+        let mut bytes = Vec::with_capacity(DIGEST32_SIZE + 2);
+        bytes.extend_from_slice(&self.tx_id);
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        (self.creation_height as i32, bytes.as_vec_i8())
+    }
+}
+
+impl SigmaSerializable for ParsedErgoBox {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        serialize_box_without_ref(self, w)?;
+        w.write_all(&self.tx_id)?;
+        w.put_u16(self.index)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        // reference implementation - https://github.com/ScorexFoundation/sigmastate-interpreter/blob/9b20cb110effd1987ff76699d637174a4b2fb441/sigmastate/src/main/scala/org/ergoplatform/ErgoBoxCandidate.scala#L144-L144
+        let value = r.get_u64()?;
+        let ergo_tree = ErgoTree::sigma_parse(r)?;
+        let creation_height = r.get_u32()?;
+        let tokens_count = r.get_u8()?;
+        let tokens = (0..tokens_count)
+            .map(|_| {
+                let mut token_id = [0u8; DIGEST32_SIZE];
+                r.read_exact(&mut token_id)?;
+                Ok((token_id, r.get_u64()?))
+            })
+            .collect::<Result<Vec<_>, SerializationError>>()?;
+        let regs_count = r.get_u8()? as usize;
+        if regs_count > ParsedErgoBox::MAX_ADDITIONAL_REGISTERS {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "too many additional registers: {0}",
+                regs_count
+            )));
+        }
+        let additional_registers = (0..regs_count)
+            .map(|_| Constant::sigma_parse(r))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut tx_id = [0u8; DIGEST32_SIZE];
+        r.read_exact(&mut tx_id)?;
+        let index = r.get_u16()?;
+        Ok(ParsedErgoBox {
+            value,
+            ergo_tree,
+            creation_height,
+            tokens,
+            additional_registers,
+            tx_id,
+            index,
+        })
+    }
+}
+
+fn serialize_bytes<F>(f: F) -> Vec<i8>
+where
+    F: FnOnce(&mut SigmaByteWriter<Vec<u8>>) -> Result<(), io::Error>,
+{
+    let mut data = Vec::new();
+    let mut w = SigmaByteWriter::new(&mut data, None);
+    #[allow(clippy::expect_used)]
+    f(&mut w)
+        // since serialization may fail only for underlying IO errors it's ok to force unwrap
+        .expect("serialization failed");
+    data.as_vec_i8()
+}
+
+// reference implementation - https://github.com/ScorexFoundation/sigmastate-interpreter/blob/9b20cb110effd1987ff76699d637174a4b2fb441/sigmastate/src/main/scala/org/ergoplatform/ErgoBoxCandidate.scala#L95-L95
+fn serialize_box_without_ref<B: IrErgoBox + ?Sized, W: SigmaByteWrite>(
+    b: &B,
+    w: &mut W,
+) -> Result<(), io::Error> {
+    w.put_u64(b.value() as u64)?;
+    w.write_all(&b.script_bytes().as_vec_u8())?;
+    w.put_u32(b.creation_height() as u32)?;
+    let tokens = b.tokens_raw();
+    w.put_u8(tokens.len() as u8)?;
+    tokens.into_iter().try_for_each(|(token_id, amount)| {
+        w.write_all(&token_id.as_vec_u8())?;
+        w.put_u64(amount as u64)
+    })?;
+    let registers = b.additional_registers();
+    w.put_u8(registers.len() as u8)?;
+    registers.iter().try_for_each(|c| c.sigma_serialize(w))
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+pub(crate) mod arbitrary {
+    use super::*;
+    use crate::mir::constant::arbitrary::primitive_type_value;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ParsedErgoBox {
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                1000u64..999999999,
+                any::<ErgoTree>(),
+                0u32..1000000,
+                vec(any::<([u8; DIGEST32_SIZE], u64)>(), 0..3),
+                // no boxes in registers to keep the strategy finite
+                vec(
+                    primitive_type_value(),
+                    0..=ParsedErgoBox::MAX_ADDITIONAL_REGISTERS,
+                ),
+                any::<[u8; DIGEST32_SIZE]>(),
+                any::<u16>(),
+            )
+                .prop_map(
+                    |(
+                        value,
+                        ergo_tree,
+                        creation_height,
+                        tokens,
+                        additional_registers,
+                        tx_id,
+                        index,
+                    )| Self {
+                        value,
+                        ergo_tree,
+                        creation_height,
+                        tokens,
+                        additional_registers,
+                        tx_id,
+                        index,
+                    },
+                )
+                .boxed()
+        }
+        type Strategy = BoxedStrategy<Self>;
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(b in any::<ParsedErgoBox>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&b), b];
+        }
+
+        #[test]
+        fn bytes_are_serialized_box(b in any::<ParsedErgoBox>()) {
+            prop_assert_eq![b.bytes().as_vec_u8(), b.sigma_serialize_bytes()];
+            let bytes_without_ref = b.bytes_without_ref();
+            prop_assert_eq![
+                &b.bytes()[..bytes_without_ref.len()],
+                bytes_without_ref.as_slice()
+            ];
+        }
+
+        #[test]
+        fn id_is_hash_of_bytes(b in any::<ParsedErgoBox>()) {
+            let hash = blake2b256_hash(&b.sigma_serialize_bytes());
+            prop_assert_eq![b.id().to_bytes(), hash.to_vec().as_vec_i8()];
+        }
+
+        #[test]
+        fn ir_box_eq_by_id(b in any::<ParsedErgoBox>()) {
+            prop_assert_eq![IrBox::Ref(b.id()), IrBox::from(b.clone())];
+            prop_assert_ne![IrBox::Ref(IrBoxId::new([0; 32])), IrBox::from(b)];
+        }
+    }
+
+    #[test]
+    fn parse_too_many_registers() {
+        let b = ParsedErgoBox {
+            value: 1000,
+            ergo_tree: force_any_val::<ErgoTree>(),
+            creation_height: 1,
+            tokens: vec![],
+            additional_registers: vec![Constant::from(1i32); 7],
+            tx_id: [0; DIGEST32_SIZE],
+            index: 0,
+        };
+        assert!(ParsedErgoBox::sigma_parse_bytes(&b.sigma_serialize_bytes()).is_err());
+    }
 }
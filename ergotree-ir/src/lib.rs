@@ -21,6 +21,7 @@ pub mod address;
 pub mod ergo_tree;
 pub mod ir_ergo_box;
 pub mod mir;
+pub mod pretty_printer;
 pub mod serialization;
 pub mod sigma_protocol;
 pub mod type_check;
@@ -3,6 +3,7 @@
 pub mod and;
 pub mod apply;
 pub mod atleast;
+pub mod avl_tree_data;
 pub mod bin_op;
 pub mod block;
 pub mod bool_to_sigma;
@@ -12,6 +13,8 @@ pub mod byte_array_to_long;
 pub mod calc_blake2b256;
 /// Calc Sha256 hash
 pub mod calc_sha256;
+/// Concatenation of two collections
+pub mod coll_append;
 /// Get the collection element by index
 pub mod coll_by_index;
 /// Tests whether a predicate holds for at least one element of this collection
@@ -26,6 +29,8 @@ pub mod coll_forall;
 pub mod coll_map;
 /// Collection.size
 pub mod coll_size;
+/// Collection.slice
+pub mod coll_slice;
 /// Collection of elements
 pub mod collection;
 pub mod constant;
@@ -36,9 +41,16 @@ pub mod create_provedlog;
 pub mod decode_point;
 pub mod deserialize_context;
 pub mod deserialize_register;
+pub mod downcast;
+/// Exponentiate op for GroupElement
+pub mod exponentiate;
 pub mod expr;
 /// Box value
 pub mod extract_amount;
+/// Box.bytes
+pub mod extract_bytes;
+/// Box.bytesWithoutRef
+pub mod extract_bytes_with_no_ref;
 /// Box.creationInfo (height, tx id + box index)
 pub mod extract_creation_info;
 /// Box id, Blake2b256 hash of this box's content, basically equals to `blake2b256(bytes)`
@@ -60,8 +72,16 @@ pub mod logical_not;
 pub mod long_to_byte_array;
 /// Object method call
 pub mod method_call;
+/// Subtraction of two BigInt values modulo the group order
+pub mod minus_mod_q;
+/// BigInt modulo the group order
+pub mod mod_q;
+/// Multiplication of two GroupElement's
+pub mod multiply_group;
 /// Negation operation on numeric type.
 pub mod negation;
+/// Empty Option (None)
+pub mod none_value;
 /// Option.get() op
 pub mod option_get;
 /// Returns the Option's value or error if no value
@@ -70,6 +90,8 @@ pub mod option_get_or_else;
 pub mod option_is_defined;
 /// Logical OR op
 pub mod or;
+/// Addition of two BigInt values modulo the group order
+pub mod plus_mod_q;
 /// Object property call
 pub mod property_call;
 /// Select a field of the tuple value
@@ -78,6 +100,10 @@ pub mod sigma_and;
 pub mod sigma_or;
 /// Extract serialized bytes of a SigmaProp value
 pub mod sigma_prop_bytes;
+/// Option with a value (Some)
+pub mod some_value;
+/// Substitution of constants in serialized ErgoTree
+pub mod subst_const;
 /// Tuple of elements
 pub mod tuple;
 pub mod unary_op;
@@ -87,5 +113,7 @@ pub mod val_def;
 /// Variable reference
 pub mod val_use;
 pub mod value;
+/// Generic traversal (visitor, folder) and rewriting of the Expr tree
+pub mod visitor;
 /// Byte-wise XOR op
 pub mod xor;
@@ -51,7 +51,7 @@ impl Apply {
     /// Type
     pub fn tpe(&self) -> SType {
         match self.func.tpe() {
-            SType::SColl(_) => todo!(),
+            SType::SColl(elem_tpe) => *elem_tpe,
             SType::SFunc(f) => *f.t_range,
             _ => panic!("unexpected Apply::func: {0:?}", self.func.tpe()),
         }
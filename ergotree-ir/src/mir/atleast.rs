@@ -7,6 +7,7 @@ use crate::serialization::SigmaSerializable;
 use crate::types::stype::SType;
 
 use super::expr::Expr;
+use super::expr::InvalidArgumentError;
 use crate::has_opcode::HasStaticOpCode;
 
 /// THRESHOLD composition for sigma expressions
@@ -19,6 +20,26 @@ pub struct Atleast {
 }
 
 impl Atleast {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(n_required: Expr, expressions: Expr) -> Result<Self, InvalidArgumentError> {
+        if n_required.post_eval_tpe() != SType::SInt {
+            return Err(InvalidArgumentError(format!(
+                "Atleast: expected n_required to be of type SInt, got {:?}",
+                n_required.post_eval_tpe()
+            )));
+        }
+        if expressions.post_eval_tpe() != SType::SColl(SType::SSigmaProp.into()) {
+            return Err(InvalidArgumentError(format!(
+                "Atleast: expected expressions to be of type SColl(SSigmaProp), got {:?}",
+                expressions.post_eval_tpe()
+            )));
+        }
+        Ok(Self {
+            n_required: n_required.into(),
+            expressions: expressions.into(),
+        })
+    }
+
     /// Type
     pub fn tpe(&self) -> SType {
         SType::SSigmaProp
@@ -0,0 +1,200 @@
+//! AVL tree (authenticated dictionary) data
+
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::io;
+
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+
+/// Authenticated AVL+ tree digest: root node hash (32 bytes) followed by the tree height (1 byte)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ADDigest(pub [u8; ADDigest::SIZE]);
+
+impl ADDigest {
+    /// Digest size in bytes
+    pub const SIZE: usize = 33;
+}
+
+impl TryFrom<Vec<u8>> for ADDigest {
+    type Error = SerializationError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let arr: [u8; ADDigest::SIZE] = bytes.as_slice().try_into().map_err(|_| {
+            SerializationError::ValueOutOfBounds(format!(
+                "expected AVL tree digest of {0} bytes, got {1} bytes",
+                ADDigest::SIZE,
+                bytes.len()
+            ))
+        })?;
+        Ok(ADDigest(arr))
+    }
+}
+
+impl From<ADDigest> for Vec<u8> {
+    fn from(d: ADDigest) -> Self {
+        d.0.to_vec()
+    }
+}
+
+/// Operations allowed to be performed on the AVL tree (insert, update, remove)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct AvlTreeFlags(u8);
+
+impl AvlTreeFlags {
+    const INSERT_ALLOWED: u8 = 0x01;
+    const UPDATE_ALLOWED: u8 = 0x02;
+    const REMOVE_ALLOWED: u8 = 0x04;
+
+    /// Create flags with the given allowed operations
+    pub fn new(insert_allowed: bool, update_allowed: bool, remove_allowed: bool) -> Self {
+        let mut flags = 0u8;
+        if insert_allowed {
+            flags |= Self::INSERT_ALLOWED;
+        }
+        if update_allowed {
+            flags |= Self::UPDATE_ALLOWED;
+        }
+        if remove_allowed {
+            flags |= Self::REMOVE_ALLOWED;
+        }
+        AvlTreeFlags(flags)
+    }
+
+    /// Parse flags from the byte (unknown bits are ignored)
+    pub fn parse(flags: u8) -> Self {
+        AvlTreeFlags::new(
+            flags & Self::INSERT_ALLOWED != 0,
+            flags & Self::UPDATE_ALLOWED != 0,
+            flags & Self::REMOVE_ALLOWED != 0,
+        )
+    }
+
+    /// Flags encoded as a byte
+    pub fn serialize(&self) -> u8 {
+        self.0
+    }
+
+    /// Insertion of the new keys is allowed
+    pub fn insert_allowed(&self) -> bool {
+        self.0 & Self::INSERT_ALLOWED != 0
+    }
+
+    /// Update of the values of the existing keys is allowed
+    pub fn update_allowed(&self) -> bool {
+        self.0 & Self::UPDATE_ALLOWED != 0
+    }
+
+    /// Removal of the existing keys is allowed
+    pub fn remove_allowed(&self) -> bool {
+        self.0 & Self::REMOVE_ALLOWED != 0
+    }
+}
+
+/// AVL tree data (`AvlTree` value in ErgoTree), an authenticated dictionary committed by its
+/// root digest. Operations on the tree are checked against the digest with the proofs
+/// generated by the holder of the full tree.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AvlTreeData {
+    /// Authenticated tree digest: root hash along with tree height
+    pub digest: ADDigest,
+    /// Allowed modifications
+    pub tree_flags: AvlTreeFlags,
+    /// All the elements under the tree have the same length
+    pub key_length: u32,
+    /// If non-empty, all the values under the tree are of the same length
+    pub value_length_opt: Option<u32>,
+}
+
+impl SigmaSerializable for AvlTreeData {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        w.write_all(&self.digest.0)?;
+        w.put_u8(self.tree_flags.serialize())?;
+        w.put_u32(self.key_length)?;
+        match self.value_length_opt {
+            Some(value_length) => {
+                w.put_u8(1)?;
+                w.put_u32(value_length)
+            }
+            None => w.put_u8(0),
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let mut digest = [0u8; ADDigest::SIZE];
+        r.read_exact(&mut digest)?;
+        let tree_flags = AvlTreeFlags::parse(r.get_u8()?);
+        let key_length = r.get_u32()?;
+        let value_length_opt = if r.get_u8()? != 0 {
+            Some(r.get_u32()?)
+        } else {
+            None
+        };
+        Ok(AvlTreeData {
+            digest: ADDigest(digest),
+            tree_flags,
+            key_length,
+            value_length_opt,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod arbitrary {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for AvlTreeData {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<u8>(), ADDigest::SIZE),
+                any::<u8>(),
+                1..64u32,
+                proptest::option::of(0..1024u32),
+            )
+                .prop_map(|(digest, flags, key_length, value_length_opt)| Self {
+                    digest: ADDigest::try_from(digest).unwrap(),
+                    tree_flags: AvlTreeFlags::parse(flags),
+                    key_length,
+                    value_length_opt,
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    #[test]
+    fn flags() {
+        let flags = AvlTreeFlags::new(true, false, true);
+        assert!(flags.insert_allowed());
+        assert!(!flags.update_allowed());
+        assert!(flags.remove_allowed());
+        assert_eq!(AvlTreeFlags::parse(flags.serialize()), flags);
+        assert_eq!(
+            AvlTreeFlags::parse(0xff),
+            AvlTreeFlags::new(true, true, true)
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<AvlTreeData>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+    }
+}
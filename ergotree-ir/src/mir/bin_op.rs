@@ -23,6 +23,9 @@ pub enum ArithOp {
     Multiply,
     /// Division
     Divide,
+    /// Remainder of the division. For Byte, Short, Int and Long the sign follows the dividend,
+    /// for BigInt the modulus must be positive and the result is non-negative
+    Modulo,
     /// Max of two values
     Max,
     /// Min of two values
@@ -42,6 +45,7 @@ impl From<ArithOp> for OpCode {
             ArithOp::Minus => OpCode::MINUS,
             ArithOp::Multiply => OpCode::MULTIPLY,
             ArithOp::Divide => OpCode::DIVISION,
+            ArithOp::Modulo => OpCode::MODULO,
             ArithOp::Max => OpCode::MAX,
             ArithOp::Min => OpCode::MIN,
             ArithOp::BitOr => OpCode::BIT_OR,
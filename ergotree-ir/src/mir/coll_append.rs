@@ -0,0 +1,108 @@
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::stype::SType;
+
+/// Puts the elements of the second collection after the elements of the first one
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Append {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Collection to append (same type as `input`)
+    pub col_2: Box<Expr>,
+}
+
+impl Append {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, col_2: Expr) -> Result<Self, InvalidArgumentError> {
+        match (input.post_eval_tpe(), col_2.post_eval_tpe()) {
+            (SType::SColl(x), SType::SColl(y)) if x == y => Ok(Append {
+                input: input.into(),
+                col_2: col_2.into(),
+            }),
+            (l, r) => Err(InvalidArgumentError(format!(
+                "Expected Append input and col_2 to be collections of the same type, got {0:?}",
+                (l, r)
+            ))),
+        }
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.input.post_eval_tpe()
+    }
+}
+
+impl HasStaticOpCode for Append {
+    const OP_CODE: OpCode = OpCode::APPEND;
+}
+
+impl SigmaSerializable for Append {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        self.input.sigma_serialize(w)?;
+        self.col_2.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let col_2 = Expr::sigma_parse(r)?;
+        Ok(Append::new(input, col_2)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Append {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                Just(SType::SColl(SType::SByte.into())),
+                Just(SType::SColl(SType::SBoolean.into())),
+            ]
+            .prop_flat_map(|tpe| {
+                (
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: tpe.clone(),
+                        depth: 0,
+                    }),
+                    any_with::<Expr>(ArbExprParams { tpe, depth: 0 }),
+                )
+            })
+            .prop_map(|(input, col_2)| Append::new(input, col_2).unwrap())
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<Append>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
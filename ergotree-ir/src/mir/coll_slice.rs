@@ -0,0 +1,117 @@
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::stype::SType;
+
+/// Selects an interval of elements
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Slice {
+    /// Collection
+    pub input: Box<Expr>,
+    /// The lowest index to include from this collection
+    pub from: Box<Expr>,
+    /// The lowest index to EXCLUDE from this collection
+    pub until: Box<Expr>,
+}
+
+impl Slice {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, from: Expr, until: Expr) -> Result<Self, InvalidArgumentError> {
+        match input.post_eval_tpe() {
+            SType::SColl(_) => Ok(()),
+            tpe => Err(InvalidArgumentError(format!(
+                "Expected Slice input to be SColl, got {0:?}",
+                tpe
+            ))),
+        }?;
+        from.check_post_eval_tpe(SType::SInt)?;
+        until.check_post_eval_tpe(SType::SInt)?;
+        Ok(Slice {
+            input: input.into(),
+            from: from.into(),
+            until: until.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.input.post_eval_tpe()
+    }
+}
+
+impl HasStaticOpCode for Slice {
+    const OP_CODE: OpCode = OpCode::SLICE;
+}
+
+impl SigmaSerializable for Slice {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        self.input.sigma_serialize(w)?;
+        self.from.sigma_serialize(w)?;
+        self.until.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let from = Expr::sigma_parse(r)?;
+        let until = Expr::sigma_parse(r)?;
+        Ok(Slice::new(input, from, until)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Slice {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SByte.into()),
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 0,
+                }),
+            )
+                .prop_map(|(input, from, until)| Slice::new(input, from, until).unwrap())
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<Slice>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -93,10 +93,9 @@ pub(crate) fn coll_sigma_parse<R: SigmaByteRead>(
 ) -> Result<Collection, SerializationError> {
     let items_count = r.get_u16()?;
     let elem_tpe = SType::sigma_parse(r)?;
-    let mut items = Vec::with_capacity(items_count as usize);
-    for _ in 0..items_count {
-        items.push(Expr::sigma_parse(r)?);
-    }
+    let items = (0..items_count)
+        .map(|_| Expr::sigma_parse(r))
+        .collect::<Result<Vec<_>, _>>()?;
     Ok(Collection::Exprs { elem_tpe, items })
 }
 
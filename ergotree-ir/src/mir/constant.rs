@@ -1,6 +1,8 @@
 //! Constant(Literal) IR node
 
 use crate::ir_ergo_box::IrBoxId;
+use crate::ir_ergo_box::ParsedErgoBox;
+use crate::mir::avl_tree_data::AvlTreeData;
 use crate::sigma_protocol::sigma_boolean::ProveDlog;
 use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
 use crate::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
@@ -103,6 +105,24 @@ impl From<IrBoxId> for Constant {
     }
 }
 
+impl From<ParsedErgoBox> for Constant {
+    fn from(b: ParsedErgoBox) -> Self {
+        Constant {
+            tpe: SType::SBox,
+            v: b.into(),
+        }
+    }
+}
+
+impl From<AvlTreeData> for Constant {
+    fn from(v: AvlTreeData) -> Self {
+        Constant {
+            tpe: SType::SAvlTree,
+            v: v.into(),
+        }
+    }
+}
+
 impl From<Vec<u8>> for Constant {
     fn from(v: Vec<u8>) -> Self {
         Constant {
@@ -219,7 +239,7 @@ pub(crate) mod arbitrary {
     use derive_more::From;
     use derive_more::TryInto;
 
-    fn primitive_type_value() -> BoxedStrategy<Constant> {
+    pub(crate) fn primitive_type_value() -> BoxedStrategy<Constant> {
         prop_oneof![
             any::<bool>().prop_map_into(),
             any::<i8>().prop_map_into(),
@@ -229,12 +249,21 @@ pub(crate) mod arbitrary {
             any::<i64>().prop_map(|v| v.to_bigint().unwrap().into()),
             any::<EcPoint>().prop_map_into(),
             any::<SigmaProp>().prop_map_into(),
+            any::<AvlTreeData>().prop_map_into(),
             // although it's not strictly a primitive type, byte array is widely used as one
             vec(any::<i8>(), 0..100).prop_map_into(),
         ]
         .boxed()
     }
 
+    fn primitive_or_box_value() -> BoxedStrategy<Constant> {
+        prop_oneof![
+            8 => primitive_type_value(),
+            1 => any::<ParsedErgoBox>().prop_map_into(),
+        ]
+        .boxed()
+    }
+
     fn coll_from_constant(c: Constant, length: usize) -> Constant {
         Constant {
             tpe: SType::SColl(Box::new(c.tpe.clone())),
@@ -271,8 +300,8 @@ pub(crate) mod arbitrary {
                 .boxed(),
             SType::SGroupElement => any::<EcPoint>().prop_map_into().boxed(),
             SType::SSigmaProp => any::<SigmaProp>().prop_map_into().boxed(),
-            // SType::SBox => {}
-            // SType::SAvlTree => {}
+            SType::SBox => any::<ParsedErgoBox>().prop_map_into().boxed(),
+            SType::SAvlTree => any::<AvlTreeData>().prop_map_into().boxed(),
             // SType::SOption(tpe) =>
             SType::SColl(elem_tpe) => match *elem_tpe {
                 SType::SByte => vec(any::<u8>(), 0..400).prop_map_into().boxed(),
@@ -306,7 +335,7 @@ pub(crate) mod arbitrary {
         fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
             match args {
                 ArbConstantParams::AnyWithDepth(depth) => {
-                    prop_oneof![primitive_type_value().prop_recursive(
+                    prop_oneof![primitive_or_box_value().prop_recursive(
                         depth as u32,
                         16,
                         8,
@@ -418,6 +447,11 @@ pub mod tests {
             test_constant_roundtrip(v);
         }
 
+        #[test]
+        fn avl_tree_roundtrip(v in any::<AvlTreeData>()) {
+            test_constant_roundtrip(v);
+        }
+
         #[test]
         fn sigma_prop_roundtrip(v in any::<SigmaProp>()) {
             test_constant_roundtrip(v);
@@ -39,6 +39,7 @@ impl SigmaSerializable for DeserializeContext {
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
         let tpe = SType::sigma_parse(r)?;
         let id = r.get_u8()?;
+        r.set_deserialize();
         Ok(Self { tpe, id })
     }
 }
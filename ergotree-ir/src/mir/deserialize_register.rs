@@ -42,6 +42,7 @@ impl SigmaSerializable for DeserializeRegister {
         let reg = r.get_u8()?;
         let tpe = SType::sigma_parse(r)?;
         let default = Option::<Box<Expr>>::sigma_parse(r)?;
+        r.set_deserialize();
         Ok(Self { reg, tpe, default })
     }
 }
@@ -16,6 +16,7 @@ use super::bool_to_sigma::BoolToSigmaProp;
 use super::byte_array_to_long::ByteArrayToLong;
 use super::calc_blake2b256::CalcBlake2b256;
 use super::calc_sha256::CalcSha256;
+use super::coll_append::Append;
 use super::coll_by_index::ByIndex;
 use super::coll_exists::Exists;
 use super::coll_filter::Filter;
@@ -23,6 +24,7 @@ use super::coll_fold::Fold;
 use super::coll_forall::ForAll;
 use super::coll_map::Map;
 use super::coll_size::SizeOf;
+use super::coll_slice::Slice;
 use super::collection::Collection;
 use super::constant::Constant;
 use super::constant::ConstantPlaceholder;
@@ -30,7 +32,11 @@ use super::constant::TryExtractFrom;
 use super::constant::TryExtractFromError;
 use super::create_provedlog::CreateProveDlog;
 use super::decode_point::DecodePoint;
+use super::downcast::Downcast;
+use super::exponentiate::Exponentiate;
 use super::extract_amount::ExtractAmount;
+use super::extract_bytes::ExtractBytes;
+use super::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
 use super::extract_creation_info::ExtractCreationInfo;
 use super::extract_id::ExtractId;
 use super::extract_reg_as::ExtractRegisterAs;
@@ -41,16 +47,23 @@ use super::if_op::If;
 use super::logical_not::LogicalNot;
 use super::long_to_byte_array::LongToByteArray;
 use super::method_call::MethodCall;
+use super::minus_mod_q::MinusModQ;
+use super::mod_q::ModQ;
+use super::multiply_group::MultiplyGroup;
 use super::negation::Negation;
+use super::none_value::NoneValue;
 use super::option_get::OptionGet;
 use super::option_get_or_else::OptionGetOrElse;
 use super::option_is_defined::OptionIsDefined;
 use super::or::Or;
+use super::plus_mod_q::PlusModQ;
 use super::property_call::PropertyCall;
 use super::select_field::SelectField;
 use super::sigma_and::SigmaAnd;
 use super::sigma_or::SigmaOr;
 use super::sigma_prop_bytes::SigmaPropBytes;
+use super::some_value::SomeValue;
+use super::subst_const::SubstConstants;
 use super::tuple::Tuple;
 use super::upcast::Upcast;
 use super::val_def::ValDef;
@@ -111,7 +124,7 @@ pub enum Expr {
     ValDef(ValDef),
     /// Reference to ValDef
     ValUse(ValUse),
-    /// If, non-lazy - evaluate both branches
+    /// If-then-else (lazy, only the taken branch is evaluated)
     If(If),
     /// Binary operation
     BinOp(BinOp),
@@ -133,12 +146,20 @@ pub enum Expr {
     OptionIsDefined(OptionIsDefined),
     /// Returns the option's value if the option is nonempty, otherwise return the result of evaluating `default`.
     OptionGetOrElse(OptionGetOrElse),
+    /// Option with a value
+    SomeValue(SomeValue),
+    /// Empty Option
+    NoneValue(NoneValue),
     /// Box monetary value
     ExtractAmount(ExtractAmount),
     /// Extract register's value (box.RX properties)
     ExtractRegisterAs(ExtractRegisterAs),
     /// Extract box's guarding script serialized to bytes
     ExtractScriptBytes(ExtractScriptBytes),
+    /// Extract serialized box bytes
+    ExtractBytes(ExtractBytes),
+    /// Extract serialized box bytes excluding transaction id and box index
+    ExtractBytesWithNoRef(ExtractBytesWithNoRef),
     /// Tuple of height when block got included into the blockchain and transaction identifier with
     /// box index in the transaction outputs serialized to the byte array.
     ExtractCreationInfo(ExtractCreationInfo),
@@ -148,6 +169,10 @@ pub enum Expr {
     ByIndex(ByIndex),
     /// Collection size
     SizeOf(SizeOf),
+    /// Select an interval of elements of the collection
+    Slice(Slice),
+    /// Concatenation of two collections
+    Append(Append),
     /// Collection fold op
     Fold(Fold),
     /// Collection map op
@@ -164,6 +189,8 @@ pub enum Expr {
     BoolToSigmaProp(BoolToSigmaProp),
     /// Upcast numeric value
     Upcast(Upcast),
+    /// Downcast numeric value
+    Downcast(Downcast),
     /// Create proveDlog from GroupElement(PK)
     CreateProveDlog(CreateProveDlog),
     /// Create proveDlog from GroupElement(PK)
@@ -172,6 +199,18 @@ pub enum Expr {
     SigmaPropBytes(SigmaPropBytes),
     /// Decode byte array to EC point
     DecodePoint(DecodePoint),
+    /// Group generator
+    GroupGenerator,
+    /// Exponentiate op for GroupElement
+    Exponentiate(Exponentiate),
+    /// Multiplication of two GroupElement's
+    MultiplyGroup(MultiplyGroup),
+    /// BigInt modulo the secp256k1 group order
+    ModQ(ModQ),
+    /// Addition of two BigInt values modulo the secp256k1 group order
+    PlusModQ(PlusModQ),
+    /// Subtraction of two BigInt values modulo the secp256k1 group order
+    MinusModQ(MinusModQ),
     /// AND conjunction for sigma propositions
     SigmaAnd(SigmaAnd),
     /// OR conjunction for sigma propositions
@@ -186,6 +225,9 @@ pub enum Expr {
     /// available as `getVar[Coll[Byte]](id)` On evaluation returns the result of the
     /// script execution in the current context
     DeserializeContext(DeserializeContext),
+    /// Transforms serialized bytes of ErgoTree with segregated constants by replacing constants
+    /// at given positions with new values
+    SubstConstants(SubstConstants),
 }
 
 impl Expr {
@@ -204,6 +246,8 @@ impl Expr {
             Expr::Global => OpCode::GLOBAL,
             Expr::Context => OpCode::CONTEXT,
             Expr::OptionGet(op) => op.op_code(),
+            Expr::SomeValue(op) => op.op_code(),
+            Expr::NoneValue(op) => op.op_code(),
             Expr::ExtractRegisterAs(op) => op.op_code(),
             Expr::BinOp(op) => op.op_code(),
             Expr::BlockValue(op) => op.op_code(),
@@ -225,10 +269,15 @@ impl Expr {
             Expr::Filter(op) => op.op_code(),
             Expr::BoolToSigmaProp(op) => op.op_code(),
             Expr::Upcast(op) => op.op_code(),
+            Expr::Downcast(op) => op.op_code(),
             Expr::If(op) => op.op_code(),
             Expr::ByIndex(op) => op.op_code(),
             Expr::ExtractScriptBytes(op) => op.op_code(),
+            Expr::ExtractBytes(op) => op.op_code(),
+            Expr::ExtractBytesWithNoRef(op) => op.op_code(),
             Expr::SizeOf(op) => op.op_code(),
+            Expr::Slice(op) => op.op_code(),
+            Expr::Append(op) => op.op_code(),
             Expr::CreateProveDlog(op) => op.op_code(),
             Expr::CreateProveDhTuple(op) => op.op_code(),
             Expr::ExtractCreationInfo(op) => op.op_code(),
@@ -241,6 +290,13 @@ impl Expr {
             Expr::ForAll(op) => op.op_code(),
             Expr::Tuple(op) => op.op_code(),
             Expr::DecodePoint(op) => op.op_code(),
+            Expr::GroupGenerator => OpCode::GROUP_GENERATOR,
+            Expr::Exponentiate(op) => op.op_code(),
+            Expr::MultiplyGroup(op) => op.op_code(),
+            Expr::ModQ(op) => op.op_code(),
+            Expr::PlusModQ(op) => op.op_code(),
+            Expr::SubstConstants(op) => op.op_code(),
+            Expr::MinusModQ(op) => op.op_code(),
             Expr::SigmaAnd(op) => op.op_code(),
             Expr::SigmaOr(op) => op.op_code(),
             Expr::GetVar(op) => op.op_code(),
@@ -272,6 +328,8 @@ impl Expr {
             Expr::ValUse(v) => v.tpe.clone(),
             Expr::BinOp(v) => v.tpe(),
             Expr::OptionGet(v) => v.tpe(),
+            Expr::SomeValue(v) => v.tpe(),
+            Expr::NoneValue(v) => v.tpe(),
             Expr::ExtractRegisterAs(v) => v.tpe(),
             Expr::Fold(v) => v.tpe(),
             Expr::SelectField(v) => v.tpe(),
@@ -285,10 +343,15 @@ impl Expr {
             Expr::Filter(v) => v.tpe(),
             Expr::BoolToSigmaProp(v) => v.tpe(),
             Expr::Upcast(v) => v.tpe(),
+            Expr::Downcast(v) => v.tpe(),
             Expr::If(v) => v.tpe(),
             Expr::ByIndex(v) => v.tpe(),
             Expr::ExtractScriptBytes(v) => v.tpe(),
+            Expr::ExtractBytes(v) => v.tpe(),
+            Expr::ExtractBytesWithNoRef(v) => v.tpe(),
             Expr::SizeOf(v) => v.tpe(),
+            Expr::Slice(v) => v.tpe(),
+            Expr::Append(v) => v.tpe(),
             Expr::CreateProveDlog(v) => v.tpe(),
             Expr::CreateProveDhTuple(v) => v.tpe(),
             Expr::ExtractCreationInfo(v) => v.tpe(),
@@ -301,6 +364,13 @@ impl Expr {
             Expr::ForAll(v) => v.tpe(),
             Expr::Tuple(v) => v.tpe(),
             Expr::DecodePoint(v) => v.tpe(),
+            Expr::GroupGenerator => SType::SGroupElement,
+            Expr::Exponentiate(v) => v.tpe(),
+            Expr::MultiplyGroup(v) => v.tpe(),
+            Expr::ModQ(v) => v.tpe(),
+            Expr::PlusModQ(v) => v.tpe(),
+            Expr::SubstConstants(v) => v.tpe(),
+            Expr::MinusModQ(v) => v.tpe(),
             Expr::SigmaAnd(v) => v.tpe(),
             Expr::SigmaOr(v) => v.tpe(),
             Expr::DeserializeRegister(v) => v.tpe(),
@@ -0,0 +1,58 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::UnaryOp;
+use super::unary_op::UnaryOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+
+/// Serialized box bytes (including the transaction id and box index)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractBytes {
+    /// Box, type of SBox
+    pub input: Box<Expr>,
+}
+
+impl ExtractBytes {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(SType::SByte.into())
+    }
+}
+
+impl HasStaticOpCode for ExtractBytes {
+    const OP_CODE: OpCode = OpCode::EXTRACT_BYTES;
+}
+
+impl UnaryOp for ExtractBytes {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl UnaryOpTryBuild for ExtractBytes {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(SType::SBox)?;
+        Ok(ExtractBytes {
+            input: input.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let e: Expr = ExtractBytes {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&e), e];
+    }
+}
@@ -0,0 +1,58 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::UnaryOp;
+use super::unary_op::UnaryOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+
+/// Serialized box bytes without the transaction id and box index
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractBytesWithNoRef {
+    /// Box, type of SBox
+    pub input: Box<Expr>,
+}
+
+impl ExtractBytesWithNoRef {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(SType::SByte.into())
+    }
+}
+
+impl HasStaticOpCode for ExtractBytesWithNoRef {
+    const OP_CODE: OpCode = OpCode::EXTRACT_BYTES_WITH_NO_REF;
+}
+
+impl UnaryOp for ExtractBytesWithNoRef {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl UnaryOpTryBuild for ExtractBytesWithNoRef {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(SType::SBox)?;
+        Ok(ExtractBytesWithNoRef {
+            input: input.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let e: Expr = ExtractBytesWithNoRef {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&e), e];
+    }
+}
@@ -77,6 +77,11 @@ impl FuncValue {
     pub fn tpe(&self) -> SType {
         self.tpe.clone()
     }
+
+    /// Function arguments and body (consumes the function)
+    pub(crate) fn into_args_and_body(self) -> (Vec<FuncArg>, Expr) {
+        (self.args, *self.body)
+    }
 }
 
 impl HasStaticOpCode for FuncValue {
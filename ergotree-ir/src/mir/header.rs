@@ -1,5 +1,68 @@
+use num_bigint::BigInt;
+
+use crate::mir::avl_tree_data::ADDigest;
 use crate::sigma_protocol::dlog_group;
 
+/// Block header
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Header {
+    /// Block version, to be increased on every soft and hardfork
+    pub version: u8,
+    /// Block id
+    pub id: Vec<u8>,
+    /// Hash of parent block
+    pub parent_id: Vec<u8>,
+    /// Hash of ADProofs for transactions in a block
+    pub ad_proofs_root: Vec<u8>,
+    /// AvlTree digest of a state after transactions application
+    pub state_root: ADDigest,
+    /// Root hash (for a Merkle tree) of transactions in a block
+    pub transaction_root: Vec<u8>,
+    /// Timestamp of a block in ms from UNIX epoch
+    pub timestamp: u64,
+    /// Current difficulty in a compressed view.
+    pub n_bits: u64,
+    /// Block height
+    pub height: u32,
+    /// Root hash of extension section
+    pub extension_root: Vec<u8>,
+    /// Public key of miner. Part of Autolykos solution.
+    pub miner_pk: Box<dlog_group::EcPoint>,
+    /// One-time public key. Prevents revealing of miners secret.
+    pub pow_onetime_pk: Box<dlog_group::EcPoint>,
+    /// Nonce. Part of Autolykos solution.
+    pub pow_nonce: Vec<u8>,
+    /// Distance between pseudo-random number, corresponding to nonce `pow_nonce` and a secret,
+    /// corresponding to `miner_pk`. The lower `pow_distance` is, the harder it was to find this
+    /// solution.
+    pub pow_distance: BigInt,
+    /// Miner votes for changing system parameters.
+    pub votes: Vec<u8>,
+}
+
+impl Header {
+    /// Dummy instance intended for tests where actual values are not used
+    pub fn dummy() -> Self {
+        Header {
+            version: 1,
+            id: vec![0; 32],
+            parent_id: vec![0; 32],
+            ad_proofs_root: vec![0; 32],
+            state_root: ADDigest([0; ADDigest::SIZE]),
+            transaction_root: vec![0; 32],
+            timestamp: 0,
+            n_bits: 0,
+            height: 0,
+            extension_root: vec![0; 32],
+            miner_pk: dlog_group::generator().into(),
+            pow_onetime_pk: dlog_group::generator().into(),
+            pow_nonce: vec![0; 8],
+            pow_distance: BigInt::from(0),
+            votes: vec![0; 3],
+        }
+    }
+}
+
 /// Block header with the current `spendingTransaction`, that can be predicted
 /// by a miner before it's formation
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -37,11 +100,82 @@ impl PreHeader {
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary {
+    use std::convert::TryFrom;
+
+    use crate::mir::avl_tree_data::ADDigest;
+    use crate::mir::header::Header;
     use crate::mir::header::PreHeader;
     use crate::sigma_protocol::dlog_group::EcPoint;
+    use num_bigint::BigInt;
+    use proptest::array::uniform32;
     use proptest::collection::vec;
     use proptest::prelude::*;
 
+    impl Arbitrary for Header {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Header>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                (
+                    uniform32(any::<u8>()),
+                    uniform32(any::<u8>()),
+                    uniform32(any::<u8>()),
+                    vec(any::<u8>(), ADDigest::SIZE),
+                    uniform32(any::<u8>()),
+                    uniform32(any::<u8>()),
+                ),
+                // Timestamps between 2000-2050
+                946_674_000_000..2_500_400_300_000u64,
+                any::<u64>(),
+                0..1_000_000u32,
+                any::<Box<EcPoint>>(),
+                any::<Box<EcPoint>>(),
+                vec(any::<u8>(), 8),
+                any::<u64>(),
+                vec(any::<u8>(), 3),
+            )
+                .prop_map(
+                    |(
+                        (
+                            id,
+                            parent_id,
+                            ad_proofs_root,
+                            state_root,
+                            transaction_root,
+                            extension_root,
+                        ),
+                        timestamp,
+                        n_bits,
+                        height,
+                        miner_pk,
+                        pow_onetime_pk,
+                        pow_nonce,
+                        pow_distance,
+                        votes,
+                    )| Self {
+                        version: 1,
+                        id: id.to_vec(),
+                        parent_id: parent_id.to_vec(),
+                        ad_proofs_root: ad_proofs_root.to_vec(),
+                        #[allow(clippy::unwrap_used)]
+                        state_root: ADDigest::try_from(state_root).unwrap(),
+                        transaction_root: transaction_root.to_vec(),
+                        timestamp,
+                        n_bits,
+                        height,
+                        extension_root: extension_root.to_vec(),
+                        miner_pk,
+                        pow_onetime_pk,
+                        pow_nonce,
+                        pow_distance: BigInt::from(pow_distance),
+                        votes,
+                    },
+                )
+                .boxed()
+        }
+    }
+
     impl Arbitrary for PreHeader {
         type Parameters = ();
         type Strategy = BoxedStrategy<PreHeader>;
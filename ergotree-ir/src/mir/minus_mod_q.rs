@@ -0,0 +1,103 @@
+//! Subtraction modulo the secp256k1 group order
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::stype::SType;
+
+/// Subtraction of two BigInt values modulo the secp256k1 group order
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MinusModQ {
+    /// BigInt
+    pub left: Box<Expr>,
+    /// BigInt
+    pub right: Box<Expr>,
+}
+
+impl MinusModQ {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(left: Expr, right: Expr) -> Result<Self, InvalidArgumentError> {
+        left.check_post_eval_tpe(SType::SBigInt)?;
+        right.check_post_eval_tpe(SType::SBigInt)?;
+        Ok(MinusModQ {
+            left: left.into(),
+            right: right.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SBigInt
+    }
+}
+
+impl HasStaticOpCode for MinusModQ {
+    const OP_CODE: OpCode = OpCode::MINUS_MOD_Q;
+}
+
+impl SigmaSerializable for MinusModQ {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        self.left.sigma_serialize(w)?;
+        self.right.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(MinusModQ::new(left, right)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+#[allow(clippy::unwrap_used)]
+mod arbitrary {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use proptest::prelude::*;
+
+    impl Arbitrary for MinusModQ {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBigInt,
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBigInt,
+                    depth: 0,
+                }),
+            )
+                .prop_map(|(left, right)| MinusModQ::new(left, right).unwrap())
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<MinusModQ>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
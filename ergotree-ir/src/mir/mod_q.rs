@@ -0,0 +1,87 @@
+//! Modulo operation on BigInt by the secp256k1 group order
+
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::UnaryOp;
+use super::unary_op::UnaryOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+
+/// Returns the BigInt input modulo the secp256k1 group order
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ModQ {
+    /// Input value of SBigInt type
+    pub input: Box<Expr>,
+}
+
+impl ModQ {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SBigInt
+    }
+}
+
+impl HasStaticOpCode for ModQ {
+    const OP_CODE: OpCode = OpCode::MOD_Q;
+}
+
+impl UnaryOp for ModQ {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl UnaryOpTryBuild for ModQ {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(SType::SBigInt)?;
+        Ok(ModQ {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ModQ {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any_with::<Expr>(ArbExprParams {
+                tpe: SType::SBigInt,
+                depth: 0,
+            })
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<ModQ>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
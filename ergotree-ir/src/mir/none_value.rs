@@ -0,0 +1,76 @@
+//! Empty Option (None)
+
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::stype::SType;
+
+/// Empty Option (None) of the given element type
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct NoneValue {
+    /// Type of the element of the Option
+    pub elem_tpe: SType,
+}
+
+impl NoneValue {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SOption(self.elem_tpe.clone().into())
+    }
+}
+
+impl HasStaticOpCode for NoneValue {
+    const OP_CODE: OpCode = OpCode::NONE_VALUE;
+}
+
+impl SigmaSerializable for NoneValue {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        self.elem_tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let elem_tpe = SType::sigma_parse(r)?;
+        Ok(NoneValue { elem_tpe })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for NoneValue {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<SType>()
+                .prop_map(|elem_tpe| NoneValue { elem_tpe })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<NoneValue>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
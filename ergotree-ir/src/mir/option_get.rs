@@ -16,7 +16,7 @@ pub struct OptionGet {
 impl OptionGet {
     /// Type
     pub fn tpe(&self) -> SType {
-        match self.input.tpe() {
+        match self.input.post_eval_tpe() {
             SType::SOption(o) => *o,
             _ => panic!(
                 "expected OptionGet::input type to be SOption, got: {0:?}",
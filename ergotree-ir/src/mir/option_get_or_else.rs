@@ -37,7 +37,7 @@ impl OptionGetOrElse {
 
     /// Type
     pub fn tpe(&self) -> SType {
-        match self.input.tpe() {
+        match self.input.post_eval_tpe() {
             SType::SOption(o) => *o,
             _ => panic!(
                 "expected OptionGetOrElse::input type to be SOption, got: {0:?}",
@@ -114,9 +114,9 @@ impl SigmaSerializable for SelectField {
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
-        let input = Expr::sigma_parse(r)?.into();
+        let input = Expr::sigma_parse(r)?;
         let field_index = TupleFieldIndex::sigma_parse(r)?;
-        Ok(SelectField { input, field_index })
+        Ok(SelectField::new(input, field_index)?)
     }
 }
 
@@ -137,4 +137,15 @@ mod tests {
             .into();
         assert_eq![sigma_serialize_roundtrip(&e), e];
     }
+
+    #[test]
+    fn parse_field_index_out_of_bounds() {
+        let e: Expr = SelectField::new(Expr::Const((1i64, true).into()), 2u8.try_into().unwrap())
+            .unwrap()
+            .into();
+        let mut bytes = e.sigma_serialize_bytes();
+        // replace field index with the one out of tuple bounds
+        *bytes.last_mut().unwrap() = 3;
+        assert!(Expr::sigma_parse_bytes(&bytes).is_err());
+    }
 }
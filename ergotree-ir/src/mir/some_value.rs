@@ -0,0 +1,85 @@
+//! Option with a value (Some)
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::UnaryOp;
+use super::unary_op::UnaryOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Wraps the input value into the Option (Some)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SomeValue {
+    /// Value to be wrapped
+    pub input: Box<Expr>,
+}
+
+impl SomeValue {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SOption(self.input.post_eval_tpe().into())
+    }
+}
+
+impl HasStaticOpCode for SomeValue {
+    const OP_CODE: OpCode = OpCode::SOME_VALUE;
+}
+
+impl UnaryOp for SomeValue {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl UnaryOpTryBuild for SomeValue {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Ok(SomeValue {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for SomeValue {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any_with::<Expr>(ArbExprParams {
+                tpe: SType::SInt,
+                depth: 0,
+            })
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<SomeValue>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
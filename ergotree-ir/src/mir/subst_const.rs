@@ -0,0 +1,125 @@
+//! Substitution of constants in serialized ErgoTree
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::stype::SType;
+
+/// Transforms serialized bytes of ErgoTree with segregated constants by replacing constants
+/// at given positions with new values. This operation allow to use serialized scripts as
+/// pre-defined templates.
+/// The constants at the given positions are replaced only if the type of the new value
+/// matches the type of the old one.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SubstConstants {
+    /// Serialized ErgoTree with segregated constants (Coll[Byte])
+    pub script_bytes: Box<Expr>,
+    /// Zero based indexes in ErgoTree.constants array which should be replaced with new values
+    /// (Coll[Int])
+    pub positions: Box<Expr>,
+    /// New values to be injected into the corresponding positions in ErgoTree.constants array
+    /// (Coll[T])
+    pub new_values: Box<Expr>,
+}
+
+impl SubstConstants {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(
+        script_bytes: Expr,
+        positions: Expr,
+        new_values: Expr,
+    ) -> Result<Self, InvalidArgumentError> {
+        script_bytes.check_post_eval_tpe(SType::SColl(SType::SByte.into()))?;
+        positions.check_post_eval_tpe(SType::SColl(SType::SInt.into()))?;
+        match new_values.post_eval_tpe() {
+            SType::SColl(_) => Ok(SubstConstants {
+                script_bytes: script_bytes.into(),
+                positions: positions.into(),
+                new_values: new_values.into(),
+            }),
+            tpe => Err(InvalidArgumentError(format!(
+                "SubstConstants: expected new_values to be a collection, got {0:?}",
+                tpe
+            ))),
+        }
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(SType::SByte.into())
+    }
+}
+
+impl HasStaticOpCode for SubstConstants {
+    const OP_CODE: OpCode = OpCode::SUBST_CONSTANTS;
+}
+
+impl SigmaSerializable for SubstConstants {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        self.script_bytes.sigma_serialize(w)?;
+        self.positions.sigma_serialize(w)?;
+        self.new_values.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let script_bytes = Expr::sigma_parse(r)?;
+        let positions = Expr::sigma_parse(r)?;
+        let new_values = Expr::sigma_parse(r)?;
+        Ok(SubstConstants::new(script_bytes, positions, new_values)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+#[allow(clippy::unwrap_used)]
+mod arbitrary {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for SubstConstants {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SByte.into()),
+                    depth: 0,
+                }),
+                vec(any::<i32>(), 0..10).prop_map(|v| Expr::Const(v.into())),
+                vec(any::<i64>(), 0..10).prop_map(|v| Expr::Const(v.into())),
+            )
+                .prop_map(|(script_bytes, positions, new_values)| {
+                    SubstConstants::new(script_bytes, positions, new_values).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ser_roundtrip(v in any::<SubstConstants>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
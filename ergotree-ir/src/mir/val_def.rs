@@ -21,6 +21,13 @@ use proptest_derive::Arbitrary;
 #[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
 pub struct ValId(u32);
 
+impl ValId {
+    /// Variable id as a number
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
 impl SigmaSerializable for ValId {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
         w.put_u32(self.0)
@@ -6,7 +6,12 @@ use std::convert::TryInto;
 use impl_trait_for_tuples::impl_for_tuples;
 use num_bigint::BigInt;
 
+use crate::ir_ergo_box::IrBox;
 use crate::ir_ergo_box::IrBoxId;
+use crate::ir_ergo_box::ParsedErgoBox;
+use crate::mir::avl_tree_data::AvlTreeData;
+use crate::mir::header::Header;
+use crate::mir::header::PreHeader;
 use crate::sigma_protocol::dlog_group::EcPoint;
 use crate::sigma_protocol::sigma_boolean::ProveDlog;
 use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
@@ -94,11 +99,24 @@ impl CollKind {
     /// Collection element type
     pub fn elem_tpe(&self) -> &SType {
         match self {
-            cp @ CollKind::NativeColl(_) => cp.elem_tpe(),
+            CollKind::NativeColl(ncoll) => ncoll.elem_tpe(),
             CollKind::WrappedColl { elem_tpe, .. } => elem_tpe,
         }
     }
 
+    /// Number of items in the collection
+    pub fn len(&self) -> usize {
+        match self {
+            CollKind::NativeColl(NativeColl::CollByte(coll_byte)) => coll_byte.len(),
+            CollKind::WrappedColl { items, .. } => items.len(),
+        }
+    }
+
+    /// Returns true if the collection is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return items, as vector of Values
     pub fn as_vec(&self) -> Vec<Value> {
         match self {
@@ -144,9 +162,13 @@ pub enum Value {
     /// Sigma property
     SigmaProp(Box<SigmaProp>),
     /// Box
-    CBox(IrBoxId),
+    CBox(IrBox),
     /// AVL tree
-    AvlTree,
+    AvlTree(Box<AvlTreeData>),
+    /// Block header
+    Header(Box<Header>),
+    /// Block pre-header
+    PreHeader(Box<PreHeader>),
     /// Collection of values of the same type
     Coll(CollKind),
     /// Tuple (arbitrary type values)
@@ -180,6 +202,36 @@ impl From<EcPoint> for Value {
     }
 }
 
+impl From<IrBoxId> for Value {
+    fn from(id: IrBoxId) -> Self {
+        Value::CBox(id.into())
+    }
+}
+
+impl From<ParsedErgoBox> for Value {
+    fn from(b: ParsedErgoBox) -> Self {
+        Value::CBox(b.into())
+    }
+}
+
+impl From<AvlTreeData> for Value {
+    fn from(v: AvlTreeData) -> Self {
+        Value::AvlTree(Box::new(v))
+    }
+}
+
+impl From<Header> for Value {
+    fn from(v: Header) -> Self {
+        Value::Header(Box::new(v))
+    }
+}
+
+impl From<PreHeader> for Value {
+    fn from(v: PreHeader) -> Self {
+        Value::PreHeader(Box::new(v))
+    }
+}
+
 impl From<Vec<i8>> for Value {
     fn from(v: Vec<i8>) -> Self {
         Value::Coll(CollKind::NativeColl(NativeColl::CollByte(v)))
@@ -207,8 +259,11 @@ impl StoreWrapped for i32 {}
 impl StoreWrapped for i64 {}
 impl StoreWrapped for BigInt {}
 impl StoreWrapped for IrBoxId {}
+impl StoreWrapped for IrBox {}
 impl StoreWrapped for EcPoint {}
 impl StoreWrapped for SigmaProp {}
+impl StoreWrapped for AvlTreeData {}
+impl StoreWrapped for Header {}
 impl<T: StoreWrapped> StoreWrapped for Option<T> {}
 impl<T> StoreWrapped for Vec<T> {}
 impl StoreWrapped for Value {}
@@ -307,7 +362,7 @@ impl TryExtractFrom<Value> for SigmaProp {
     }
 }
 
-impl TryExtractFrom<Value> for IrBoxId {
+impl TryExtractFrom<Value> for IrBox {
     fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
         match c {
             Value::CBox(b) => Ok(b),
@@ -319,6 +374,54 @@ impl TryExtractFrom<Value> for IrBoxId {
     }
 }
 
+impl TryExtractFrom<Value> for IrBoxId {
+    fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
+        match c {
+            Value::CBox(IrBox::Ref(id)) => Ok(id),
+            _ => Err(TryExtractFromError(format!(
+                "expected IrErgoBox from the box arena, found {:?}",
+                c
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for AvlTreeData {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::AvlTree(t) => Ok(*t),
+            _ => Err(TryExtractFromError(format!(
+                "expected AvlTreeData, found {:?}",
+                v
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for Header {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Header(h) => Ok(*h),
+            _ => Err(TryExtractFromError(format!(
+                "expected Header, found {:?}",
+                v
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for PreHeader {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::PreHeader(h) => Ok(*h),
+            _ => Err(TryExtractFromError(format!(
+                "expected PreHeader, found {:?}",
+                v
+            ))),
+        }
+    }
+}
+
 impl<T: TryExtractFrom<Value> + StoreWrapped> TryExtractFrom<Value> for Vec<T> {
     fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
         match c {
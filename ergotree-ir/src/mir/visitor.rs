@@ -0,0 +1,542 @@
+//! Generic traversal of [`Expr`] trees
+//!
+//! [`Visitor`] walks the tree by reference, [`Folder`] rebuilds it (by value), both visit children
+//! of every node by default so that implementors only override what they are interested in.
+//! Child nodes of every [`Expr`] variant are enumerated in [`Expr::children`] and
+//! [`Expr::try_map_children`], so the code built on top of them does not need to be updated when
+//! new nodes are added.
+
+use super::and::And;
+use super::apply::Apply;
+use super::atleast::Atleast;
+use super::bin_op::BinOp;
+use super::block::BlockValue;
+use super::bool_to_sigma::BoolToSigmaProp;
+use super::byte_array_to_bigint::ByteArrayToBigInt;
+use super::byte_array_to_long::ByteArrayToLong;
+use super::calc_blake2b256::CalcBlake2b256;
+use super::calc_sha256::CalcSha256;
+use super::coll_append::Append;
+use super::coll_by_index::ByIndex;
+use super::coll_exists::Exists;
+use super::coll_filter::Filter;
+use super::coll_fold::Fold;
+use super::coll_forall::ForAll;
+use super::coll_map::Map;
+use super::coll_size::SizeOf;
+use super::coll_slice::Slice;
+use super::collection::Collection;
+use super::create_prove_dh_tuple::CreateProveDhTuple;
+use super::create_provedlog::CreateProveDlog;
+use super::decode_point::DecodePoint;
+use super::deserialize_register::DeserializeRegister;
+use super::downcast::Downcast;
+use super::exponentiate::Exponentiate;
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::extract_amount::ExtractAmount;
+use super::extract_bytes::ExtractBytes;
+use super::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use super::extract_creation_info::ExtractCreationInfo;
+use super::extract_id::ExtractId;
+use super::extract_reg_as::ExtractRegisterAs;
+use super::extract_script_bytes::ExtractScriptBytes;
+use super::func_value::FuncValue;
+use super::if_op::If;
+use super::logical_not::LogicalNot;
+use super::long_to_byte_array::LongToByteArray;
+use super::method_call::MethodCall;
+use super::minus_mod_q::MinusModQ;
+use super::mod_q::ModQ;
+use super::multiply_group::MultiplyGroup;
+use super::negation::Negation;
+use super::option_get::OptionGet;
+use super::option_get_or_else::OptionGetOrElse;
+use super::option_is_defined::OptionIsDefined;
+use super::or::Or;
+use super::plus_mod_q::PlusModQ;
+use super::property_call::PropertyCall;
+use super::select_field::SelectField;
+use super::sigma_and::SigmaAnd;
+use super::sigma_or::SigmaOr;
+use super::sigma_prop_bytes::SigmaPropBytes;
+use super::some_value::SomeValue;
+use super::subst_const::SubstConstants;
+use super::tuple::Tuple;
+use super::unary_op::UnaryOpTryBuild;
+use super::upcast::Upcast;
+use super::val_def::ValDef;
+use super::xor::Xor;
+
+/// Read-only traversal of the [`Expr`] tree
+pub trait Visitor {
+    /// Called for every node, the default implementation visits the node's children
+    /// (see [`walk_expr`])
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr)
+    }
+}
+
+/// Visits all children of the given node (in the order of [`Expr::children`])
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    expr.children().for_each(|child| visitor.visit_expr(child))
+}
+
+/// Rebuilding traversal of the [`Expr`] tree
+pub trait Folder {
+    /// Error type returned by the folder (an error of rebuilding a node with the folded children
+    /// is converted into it)
+    type Error: From<InvalidArgumentError>;
+
+    /// Called for every node, the default implementation folds the node's children and returns
+    /// the node with folded children (see [`fold_children`])
+    fn fold_expr(&mut self, expr: Expr) -> Result<Expr, Self::Error> {
+        fold_children(self, expr)
+    }
+}
+
+/// Folds all children of the given node and returns the node with the folded children
+pub fn fold_children<F: Folder + ?Sized>(folder: &mut F, expr: Expr) -> Result<Expr, F::Error> {
+    expr.try_map_children(|child| folder.fold_expr(child))
+}
+
+/// Traverses the tree in the bottom-up manner (children first), calling `f` for every node and
+/// replacing the node with the returned value (if `Some`) or leaving it as is on `None`
+pub fn rewrite_bottom_up<E, F>(expr: Expr, f: F) -> Result<Expr, E>
+where
+    E: From<InvalidArgumentError>,
+    F: Fn(&Expr) -> Result<Option<Expr>, E>,
+{
+    struct BottomUp<F>(F);
+
+    impl<E, F> Folder for BottomUp<F>
+    where
+        E: From<InvalidArgumentError>,
+        F: Fn(&Expr) -> Result<Option<Expr>, E>,
+    {
+        type Error = E;
+
+        fn fold_expr(&mut self, expr: Expr) -> Result<Expr, E> {
+            let expr = fold_children(self, expr)?;
+            Ok((self.0)(&expr)?.unwrap_or(expr))
+        }
+    }
+
+    BottomUp(f).fold_expr(expr)
+}
+
+fn map_box<E, F: FnMut(Expr) -> Result<Expr, E>>(
+    mut b: Box<Expr>,
+    f: &mut F,
+) -> Result<Box<Expr>, E> {
+    // reuse the allocation
+    *b = f(*b)?;
+    Ok(b)
+}
+
+fn map_vec<E, F: FnMut(Expr) -> Result<Expr, E>>(v: Vec<Expr>, f: &mut F) -> Result<Vec<Expr>, E> {
+    v.into_iter().map(f).collect()
+}
+
+impl Expr {
+    /// Direct children of this node (in the order of evaluation)
+    pub fn children(&self) -> impl Iterator<Item = &Expr> {
+        let children: Vec<&Expr> = match self {
+            Expr::Const(_)
+            | Expr::ConstPlaceholder(_)
+            | Expr::Context
+            | Expr::Global
+            | Expr::GlobalVars(_)
+            | Expr::ValUse(_)
+            | Expr::NoneValue(_)
+            | Expr::GroupGenerator
+            | Expr::GetVar(_)
+            | Expr::DeserializeContext(_)
+            | Expr::Collection(Collection::BoolConstants(_)) => vec![],
+            Expr::Collection(Collection::Exprs { items, .. }) => items.iter().collect(),
+            Expr::Tuple(op) => op.items.iter().collect(),
+            Expr::SigmaAnd(op) => op.items.iter().collect(),
+            Expr::SigmaOr(op) => op.items.iter().collect(),
+            Expr::BlockValue(op) => op.items.iter().chain(Some(op.result.as_ref())).collect(),
+            Expr::Apply(op) => Some(op.func.as_ref()).into_iter().chain(&op.args).collect(),
+            Expr::MethodCall(op) => Some(op.obj.as_ref()).into_iter().chain(&op.args).collect(),
+            Expr::ProperyCall(op) => vec![&op.obj],
+            Expr::FuncValue(op) => vec![op.body()],
+            Expr::ValDef(op) => vec![&op.rhs],
+            Expr::If(op) => vec![&op.condition, &op.true_branch, &op.false_branch],
+            Expr::BinOp(op) => vec![&op.left, &op.right],
+            Expr::Xor(op) => vec![&op.left, &op.right],
+            Expr::Exponentiate(op) => vec![&op.left, &op.right],
+            Expr::MultiplyGroup(op) => vec![&op.left, &op.right],
+            Expr::PlusModQ(op) => vec![&op.left, &op.right],
+            Expr::MinusModQ(op) => vec![&op.left, &op.right],
+            Expr::Atleast(op) => vec![&op.n_required, &op.expressions],
+            Expr::OptionGetOrElse(op) => vec![&op.input, &op.default],
+            Expr::ByIndex(op) => Some(op.input.as_ref())
+                .into_iter()
+                .chain(Some(op.index.as_ref()))
+                .chain(op.default.as_deref())
+                .collect(),
+            Expr::Slice(op) => vec![&op.input, &op.from, &op.until],
+            Expr::Append(op) => vec![&op.input, &op.col_2],
+            Expr::Fold(op) => vec![&op.input, &op.zero, &op.fold_op],
+            Expr::Map(op) => vec![&op.input, &op.mapper],
+            Expr::Filter(op) => vec![&op.input, &op.condition],
+            Expr::Exists(op) => vec![&op.input, &op.condition],
+            Expr::ForAll(op) => vec![&op.input, &op.condition],
+            Expr::CreateProveDhTuple(op) => vec![&op.gv, &op.hv, &op.uv, &op.vv],
+            Expr::DeserializeRegister(op) => op.default.as_deref().into_iter().collect(),
+            Expr::SubstConstants(op) => vec![&op.script_bytes, &op.positions, &op.new_values],
+            Expr::ByteArrayToLong(op) => vec![&op.input],
+            Expr::ByteArrayToBigInt(op) => vec![&op.input],
+            Expr::LongToByteArray(op) => vec![&op.input],
+            Expr::CalcBlake2b256(op) => vec![&op.input],
+            Expr::CalcSha256(op) => vec![&op.input],
+            Expr::And(op) => vec![&op.input],
+            Expr::Or(op) => vec![&op.input],
+            Expr::LogicalNot(op) => vec![&op.input],
+            Expr::Negation(op) => vec![&op.input],
+            Expr::OptionGet(op) => vec![&op.input],
+            Expr::OptionIsDefined(op) => vec![&op.input],
+            Expr::SomeValue(op) => vec![&op.input],
+            Expr::ExtractAmount(op) => vec![&op.input],
+            Expr::ExtractRegisterAs(op) => vec![&op.input],
+            Expr::ExtractScriptBytes(op) => vec![&op.input],
+            Expr::ExtractBytes(op) => vec![&op.input],
+            Expr::ExtractBytesWithNoRef(op) => vec![&op.input],
+            Expr::ExtractCreationInfo(op) => vec![&op.input],
+            Expr::ExtractId(op) => vec![&op.input],
+            Expr::SizeOf(op) => vec![&op.input],
+            Expr::SelectField(op) => vec![&op.input],
+            Expr::BoolToSigmaProp(op) => vec![&op.input],
+            Expr::Upcast(op) => vec![&op.input],
+            Expr::Downcast(op) => vec![&op.input],
+            Expr::CreateProveDlog(op) => vec![&op.input],
+            Expr::SigmaPropBytes(op) => vec![&op.input],
+            Expr::DecodePoint(op) => vec![&op.input],
+            Expr::ModQ(op) => vec![&op.input],
+        };
+        children.into_iter()
+    }
+
+    /// Returns this node with every direct child replaced with the result of `f` applied to it.
+    /// The node is rebuilt with its checked constructor (if it has one), so the types cached in
+    /// the node (e.g. collection element type) are re-checked against the new children, and the
+    /// error is returned if `f` changed the type of a child in an incompatible way.
+    pub fn try_map_children<E, F>(self, f: F) -> Result<Expr, E>
+    where
+        E: From<InvalidArgumentError>,
+        F: FnMut(Expr) -> Result<Expr, E>,
+    {
+        let mut f = f;
+        let f = &mut f;
+        Ok(match self {
+            Expr::Const(_)
+            | Expr::ConstPlaceholder(_)
+            | Expr::Context
+            | Expr::Global
+            | Expr::GlobalVars(_)
+            | Expr::ValUse(_)
+            | Expr::NoneValue(_)
+            | Expr::GroupGenerator
+            | Expr::GetVar(_)
+            | Expr::DeserializeContext(_)
+            | Expr::Collection(Collection::BoolConstants(_)) => self,
+            Expr::Collection(Collection::Exprs { elem_tpe, items }) => {
+                Collection::new(elem_tpe, map_vec(items, f)?)?.into()
+            }
+            Expr::Tuple(op) => Tuple::new(map_vec(op.items.into(), f)?)?.into(),
+            Expr::SigmaAnd(op) => SigmaAnd::new(map_vec(op.items.into(), f)?)?.into(),
+            Expr::SigmaOr(op) => SigmaOr::new(map_vec(op.items.into(), f)?)?.into(),
+            Expr::BlockValue(op) => BlockValue {
+                items: map_vec(op.items, f)?,
+                result: map_box(op.result, f)?,
+            }
+            .into(),
+            Expr::Apply(op) => Apply::new(f(*op.func)?, map_vec(op.args, f)?)?.into(),
+            Expr::MethodCall(op) => {
+                MethodCall::new(f(*op.obj)?, op.method, map_vec(op.args, f)?)?.into()
+            }
+            Expr::ProperyCall(op) => PropertyCall::new(f(*op.obj)?, op.method)?.into(),
+            Expr::FuncValue(op) => {
+                let (args, body) = op.into_args_and_body();
+                FuncValue::new(args, f(body)?).into()
+            }
+            Expr::ValDef(op) => ValDef {
+                rhs: map_box(op.rhs, f)?,
+                ..op
+            }
+            .into(),
+            Expr::If(op) => If {
+                condition: map_box(op.condition, f)?,
+                true_branch: map_box(op.true_branch, f)?,
+                false_branch: map_box(op.false_branch, f)?,
+            }
+            .into(),
+            Expr::BinOp(op) => BinOp {
+                left: map_box(op.left, f)?,
+                right: map_box(op.right, f)?,
+                ..op
+            }
+            .into(),
+            Expr::Xor(op) => Xor::new(f(*op.left)?, f(*op.right)?)?.into(),
+            Expr::Exponentiate(op) => Exponentiate::new(f(*op.left)?, f(*op.right)?)?.into(),
+            Expr::MultiplyGroup(op) => MultiplyGroup::new(f(*op.left)?, f(*op.right)?)?.into(),
+            Expr::PlusModQ(op) => PlusModQ::new(f(*op.left)?, f(*op.right)?)?.into(),
+            Expr::MinusModQ(op) => MinusModQ::new(f(*op.left)?, f(*op.right)?)?.into(),
+            Expr::Atleast(op) => Atleast::new(f(*op.n_required)?, f(*op.expressions)?)?.into(),
+            Expr::OptionGetOrElse(op) => {
+                OptionGetOrElse::new(f(*op.input)?, f(*op.default)?)?.into()
+            }
+            Expr::ByIndex(op) => ByIndex::new(
+                f(*op.input)?,
+                f(*op.index)?,
+                op.default.map(|d| map_box(d, f)).transpose()?,
+            )?
+            .into(),
+            Expr::Slice(op) => Slice::new(f(*op.input)?, f(*op.from)?, f(*op.until)?)?.into(),
+            Expr::Append(op) => Append::new(f(*op.input)?, f(*op.col_2)?)?.into(),
+            Expr::Fold(op) => Fold::new(f(*op.input)?, f(*op.zero)?, f(*op.fold_op)?)?.into(),
+            Expr::Map(op) => Map::new(f(*op.input)?, f(*op.mapper)?)?.into(),
+            Expr::Filter(op) => Filter::new(f(*op.input)?, f(*op.condition)?)?.into(),
+            Expr::Exists(op) => Exists::new(f(*op.input)?, f(*op.condition)?)?.into(),
+            Expr::ForAll(op) => ForAll::new(f(*op.input)?, f(*op.condition)?)?.into(),
+            Expr::CreateProveDhTuple(op) => {
+                CreateProveDhTuple::new(f(*op.gv)?, f(*op.hv)?, f(*op.uv)?, f(*op.vv)?)?.into()
+            }
+            Expr::DeserializeRegister(op) => DeserializeRegister {
+                default: op.default.map(|d| map_box(d, f)).transpose()?,
+                ..op
+            }
+            .into(),
+            Expr::SubstConstants(op) => {
+                SubstConstants::new(f(*op.script_bytes)?, f(*op.positions)?, f(*op.new_values)?)?
+                    .into()
+            }
+            Expr::ByteArrayToLong(op) => ByteArrayToLong::try_build(f(*op.input)?)?.into(),
+            Expr::ByteArrayToBigInt(op) => ByteArrayToBigInt::try_build(f(*op.input)?)?.into(),
+            Expr::LongToByteArray(op) => LongToByteArray::try_build(f(*op.input)?)?.into(),
+            Expr::CalcBlake2b256(op) => CalcBlake2b256::try_build(f(*op.input)?)?.into(),
+            Expr::CalcSha256(op) => CalcSha256::try_build(f(*op.input)?)?.into(),
+            Expr::And(op) => And {
+                input: map_box(op.input, f)?,
+            }
+            .into(),
+            Expr::Or(op) => Or {
+                input: map_box(op.input, f)?,
+            }
+            .into(),
+            Expr::LogicalNot(op) => LogicalNot::try_build(f(*op.input)?)?.into(),
+            Expr::Negation(op) => Negation::try_build(f(*op.input)?)?.into(),
+            Expr::OptionGet(op) => OptionGet::try_build(f(*op.input)?)?.into(),
+            Expr::OptionIsDefined(op) => OptionIsDefined::try_build(f(*op.input)?)?.into(),
+            Expr::SomeValue(op) => SomeValue::try_build(f(*op.input)?)?.into(),
+            Expr::ExtractAmount(op) => ExtractAmount::try_build(f(*op.input)?)?.into(),
+            Expr::ExtractRegisterAs(op) => {
+                let tpe = op.tpe();
+                ExtractRegisterAs::new(f(*op.input)?, op.register_id, tpe)?.into()
+            }
+            Expr::ExtractScriptBytes(op) => ExtractScriptBytes::try_build(f(*op.input)?)?.into(),
+            Expr::ExtractBytes(op) => ExtractBytes::try_build(f(*op.input)?)?.into(),
+            Expr::ExtractBytesWithNoRef(op) => {
+                ExtractBytesWithNoRef::try_build(f(*op.input)?)?.into()
+            }
+            Expr::ExtractCreationInfo(op) => ExtractCreationInfo::try_build(f(*op.input)?)?.into(),
+            Expr::ExtractId(op) => ExtractId::try_build(f(*op.input)?)?.into(),
+            Expr::SizeOf(op) => SizeOf::try_build(f(*op.input)?)?.into(),
+            Expr::SelectField(op) => SelectField::new(f(*op.input)?, op.field_index)?.into(),
+            Expr::BoolToSigmaProp(op) => BoolToSigmaProp::try_build(f(*op.input)?)?.into(),
+            Expr::Upcast(op) => Upcast::new(f(*op.input)?, op.tpe)?.into(),
+            Expr::Downcast(op) => Downcast::new(f(*op.input)?, op.tpe)?.into(),
+            Expr::CreateProveDlog(op) => CreateProveDlog::try_build(f(*op.input)?)?.into(),
+            Expr::SigmaPropBytes(op) => SigmaPropBytes::try_build(f(*op.input)?)?.into(),
+            Expr::DecodePoint(op) => DecodePoint::try_build(f(*op.input)?)?.into(),
+            Expr::ModQ(op) => ModQ::try_build(f(*op.input)?)?.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mir::bin_op::ArithOp;
+    use crate::mir::bin_op::BinOpKind;
+    use crate::mir::constant::Constant;
+    use crate::mir::global_vars::GlobalVars;
+    use crate::mir::value::Value;
+    use crate::types::stype::SType;
+
+    fn plus(left: Expr, right: Expr) -> Expr {
+        BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into()
+    }
+
+    fn int_const(v: i32) -> Expr {
+        Expr::Const(v.into())
+    }
+
+    #[test]
+    fn children_order() {
+        let e: Expr = If {
+            condition: Box::new(Expr::Const(true.into())),
+            true_branch: Box::new(int_const(1)),
+            false_branch: Box::new(int_const(2)),
+        }
+        .into();
+        assert_eq!(
+            e.children().cloned().collect::<Vec<Expr>>(),
+            vec![Expr::Const(true.into()), int_const(1), int_const(2)]
+        );
+        assert_eq!(int_const(1).children().count(), 0);
+    }
+
+    #[test]
+    fn rewrite_bottom_up_folds_constants() {
+        // (1 + 2) + (HEIGHT + 3)
+        let e = plus(
+            plus(int_const(1), int_const(2)),
+            plus(GlobalVars::Height.into(), int_const(3)),
+        );
+        let folded = rewrite_bottom_up(e, |e| {
+            Ok::<_, InvalidArgumentError>(match e {
+                Expr::BinOp(BinOp {
+                    kind: BinOpKind::Arith(ArithOp::Plus),
+                    left,
+                    right,
+                }) => match (left.as_ref(), right.as_ref()) {
+                    (
+                        Expr::Const(Constant {
+                            v: Value::Int(l), ..
+                        }),
+                        Expr::Const(Constant {
+                            v: Value::Int(r), ..
+                        }),
+                    ) => Some(int_const(l + r)),
+                    _ => None,
+                },
+                _ => None,
+            })
+        })
+        .unwrap();
+        assert_eq!(
+            folded,
+            plus(int_const(3), plus(GlobalVars::Height.into(), int_const(3)))
+        );
+    }
+
+    #[test]
+    fn rewrite_bottom_up_error() {
+        let e = plus(int_const(1), GlobalVars::Height.into());
+        let res = rewrite_bottom_up(e, |e| match e {
+            Expr::GlobalVars(_) => Err(InvalidArgumentError("global var".to_string())),
+            _ => Ok(None),
+        });
+        assert_eq!(res, Err(InvalidArgumentError("global var".to_string())));
+    }
+
+    #[test]
+    fn visitor_collects_global_vars() {
+        struct GlobalVarsCollector(Vec<GlobalVars>);
+
+        impl Visitor for GlobalVarsCollector {
+            fn visit_expr(&mut self, expr: &Expr) {
+                if let Expr::GlobalVars(gv) = expr {
+                    self.0.push(gv.clone());
+                }
+                walk_expr(self, expr)
+            }
+        }
+
+        let e = plus(
+            GlobalVars::Height.into(),
+            ExtractAmount {
+                input: Box::new(GlobalVars::SelfBox.into()),
+            }
+            .into(),
+        );
+        let mut collector = GlobalVarsCollector(vec![]);
+        collector.visit_expr(&e);
+        assert_eq!(collector.0, vec![GlobalVars::Height, GlobalVars::SelfBox]);
+    }
+
+    #[test]
+    fn folder_replaces_nodes() {
+        struct HeightToConst;
+
+        impl Folder for HeightToConst {
+            type Error = InvalidArgumentError;
+
+            fn fold_expr(&mut self, expr: Expr) -> Result<Expr, InvalidArgumentError> {
+                match expr {
+                    Expr::GlobalVars(GlobalVars::Height) => Ok(int_const(100)),
+                    _ => fold_children(self, expr),
+                }
+            }
+        }
+
+        let e: Expr = Collection::new(SType::SInt, vec![GlobalVars::Height.into(), int_const(1)])
+            .unwrap()
+            .into();
+        let expected: Expr = Collection::new(SType::SInt, vec![int_const(100), int_const(1)])
+            .unwrap()
+            .into();
+        assert_eq!(HeightToConst.fold_expr(e).unwrap(), expected);
+    }
+
+    #[test]
+    fn rewrite_bottom_up_checks_child_types() {
+        let e: Expr = Collection::new(SType::SInt, vec![GlobalVars::Height.into(), int_const(1)])
+            .unwrap()
+            .into();
+        let res = rewrite_bottom_up(e, |e| {
+            Ok::<_, InvalidArgumentError>(match e {
+                Expr::GlobalVars(GlobalVars::Height) => Some(Expr::Const(100i64.into())),
+                _ => None,
+            })
+        });
+        assert!(res.is_err());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    struct NodeCounter(usize);
+
+    impl Visitor for NodeCounter {
+        fn visit_expr(&mut self, expr: &Expr) {
+            self.0 += 1;
+            walk_expr(self, expr)
+        }
+    }
+
+    fn count_nodes(e: &Expr) -> usize {
+        1 + e.children().map(count_nodes).sum::<usize>()
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn identity_rewrite(e in any::<Expr>()) {
+            prop_assert_eq![rewrite_bottom_up(e.clone(), |_| Ok::<_, InvalidArgumentError>(None)).unwrap(), e];
+        }
+
+        #[test]
+        fn visitor_visits_every_node(e in any::<Expr>()) {
+            let mut counter = NodeCounter(0);
+            counter.visit_expr(&e);
+            prop_assert_eq![counter.0, count_nodes(&e)];
+        }
+    }
+}
@@ -0,0 +1,810 @@
+//! Pretty printer turning IR ([`Expr`]) back into ErgoScript-like source code
+//!
+//! The output is meant to be read by humans (e.g. when auditing a contract available only as a
+//! serialized `ErgoTree`). Values defined with `ValDef` are named after their ids (`v1`, `v2`,
+//! etc.), constants without an ErgoScript literal syntax (boxes, AVL trees, etc.) are printed as
+//! pseudo-constructors over their serialized bytes.
+
+use crate::ir_ergo_box::IrBox;
+use crate::ir_ergo_box::IrErgoBox;
+use crate::mir::bin_op::ArithOp;
+use crate::mir::bin_op::BinOpKind;
+use crate::mir::bin_op::RelationOp;
+use crate::mir::collection::Collection;
+use crate::mir::expr::Expr;
+use crate::mir::func_value::FuncArg;
+use crate::mir::global_vars::GlobalVars;
+use crate::mir::val_def::ValDef;
+use crate::mir::val_def::ValId;
+use crate::mir::value::CollKind;
+use crate::mir::value::NativeColl;
+use crate::mir::value::Value;
+use crate::serialization::SigmaSerializable;
+use crate::sigma_protocol::dlog_group::EcPoint;
+use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
+use crate::sigma_protocol::sigma_boolean::SigmaConjecture;
+use crate::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
+use crate::types::stype::SType;
+use crate::util::AsVecU8;
+
+const INDENT: &str = "  ";
+
+/// Prints the expression as ErgoScript-like source code
+pub fn print_expr(expr: &Expr) -> String {
+    let mut p = Printer::default();
+    p.expr(expr);
+    p.out
+}
+
+/// Prints the type in ErgoScript syntax (e.g. `Coll[(Int, Box)]`)
+pub fn print_type(tpe: &SType) -> String {
+    match tpe {
+        SType::STypeVar(v) => v.name.clone(),
+        SType::SAny => "Any".to_string(),
+        SType::SBoolean => "Boolean".to_string(),
+        SType::SByte => "Byte".to_string(),
+        SType::SShort => "Short".to_string(),
+        SType::SInt => "Int".to_string(),
+        SType::SLong => "Long".to_string(),
+        SType::SBigInt => "BigInt".to_string(),
+        SType::SGroupElement => "GroupElement".to_string(),
+        SType::SSigmaProp => "SigmaProp".to_string(),
+        SType::SBox => "Box".to_string(),
+        SType::SAvlTree => "AvlTree".to_string(),
+        SType::SOption(elem_tpe) => format!("Option[{}]", print_type(elem_tpe)),
+        SType::SColl(elem_tpe) => format!("Coll[{}]", print_type(elem_tpe)),
+        SType::STuple(t) => format!("({})", join(t.items.iter().map(print_type))),
+        SType::SFunc(f) => format!(
+            "({}) => {}",
+            join(f.t_dom.iter().map(print_type)),
+            print_type(&f.t_range)
+        ),
+        SType::SContext => "Context".to_string(),
+        SType::SHeader => "Header".to_string(),
+        SType::SPreHeader => "PreHeader".to_string(),
+        SType::SGlobal => "Global".to_string(),
+    }
+}
+
+fn join<I: Iterator<Item = String>>(items: I) -> String {
+    items.collect::<Vec<String>>().join(", ")
+}
+
+fn val_name(id: &ValId) -> String {
+    format!("v{}", id.as_u32())
+}
+
+fn base16_bytes(bytes: &[u8]) -> String {
+    format!("fromBase16(\"{}\")", base16::encode_lower(bytes))
+}
+
+fn print_ec_point(p: &EcPoint) -> String {
+    format!("decodePoint({})", base16_bytes(&p.sigma_serialize_bytes()))
+}
+
+fn print_sigma_boolean(sb: &SigmaBoolean) -> String {
+    match sb {
+        SigmaBoolean::TrivialProp(b) => format!("sigmaProp({})", b),
+        SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(pd)) => {
+            format!("proveDlog({})", print_ec_point(&pd.h))
+        }
+        SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(pdht)) => {
+            format!(
+                "proveDHTuple({}, {}, {}, {})",
+                print_ec_point(&pdht.gv),
+                print_ec_point(&pdht.hv),
+                print_ec_point(&pdht.uv),
+                print_ec_point(&pdht.vv)
+            )
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(cand)) => format!(
+            "({})",
+            cand.items
+                .iter()
+                .map(print_sigma_boolean)
+                .collect::<Vec<String>>()
+                .join(" && ")
+        ),
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(cor)) => format!(
+            "({})",
+            cor.items
+                .iter()
+                .map(print_sigma_boolean)
+                .collect::<Vec<String>>()
+                .join(" || ")
+        ),
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(ct)) => format!(
+            "atLeast({}, Coll({}))",
+            ct.k,
+            join(ct.children.iter().map(print_sigma_boolean))
+        ),
+    }
+}
+
+fn arith_op(op: &ArithOp) -> &'static str {
+    match op {
+        ArithOp::Plus => "+",
+        ArithOp::Minus => "-",
+        ArithOp::Multiply => "*",
+        ArithOp::Divide => "/",
+        ArithOp::Modulo => "%",
+        ArithOp::Max => "max",
+        ArithOp::Min => "min",
+        ArithOp::BitOr => "|",
+        ArithOp::BitAnd => "&",
+        ArithOp::BitXor => "^",
+    }
+}
+
+fn relation_op(op: &RelationOp) -> &'static str {
+    match op {
+        RelationOp::Eq => "==",
+        RelationOp::NEq => "!=",
+        RelationOp::Ge => ">=",
+        RelationOp::Gt => ">",
+        RelationOp::Le => "<=",
+        RelationOp::Lt => "<",
+        RelationOp::And => "&&",
+        RelationOp::Or => "||",
+    }
+}
+
+fn numeric_cast(tpe: &SType) -> String {
+    match tpe {
+        SType::SByte => "toByte".to_string(),
+        SType::SShort => "toShort".to_string(),
+        SType::SInt => "toInt".to_string(),
+        SType::SLong => "toLong".to_string(),
+        SType::SBigInt => "toBigInt".to_string(),
+        _ => format!("asInstanceOf[{}]", print_type(tpe)),
+    }
+}
+
+/// Whether the expression has to be put in parentheses when used as an operand
+/// (of an infix/prefix operator or as an object of a method call)
+fn is_compound(e: &Expr) -> bool {
+    match e {
+        Expr::BinOp(_)
+        | Expr::Append(_)
+        | Expr::If(_)
+        | Expr::LogicalNot(_)
+        | Expr::Negation(_)
+        | Expr::SigmaAnd(_)
+        | Expr::SigmaOr(_)
+        | Expr::FuncValue(_)
+        | Expr::BlockValue(_) => true,
+        Expr::Const(c) => match c.v {
+            Value::Byte(v) => v < 0,
+            Value::Short(v) => v < 0,
+            Value::Int(v) => v < 0,
+            Value::Long(v) => v < 0,
+            Value::Lambda(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    indent: usize,
+}
+
+impl Printer {
+    fn push(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn operand(&mut self, e: &Expr) {
+        if is_compound(e) {
+            self.push("(");
+            self.expr(e);
+            self.push(")");
+        } else {
+            self.expr(e);
+        }
+    }
+
+    fn args(&mut self, args: &[&Expr]) {
+        for (i, a) in args.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.expr(a);
+        }
+    }
+
+    /// `name(args)`
+    fn call(&mut self, name: &str, args: &[&Expr]) {
+        self.push(name);
+        self.push("(");
+        self.args(args);
+        self.push(")");
+    }
+
+    /// `obj.name`
+    fn property(&mut self, obj: &Expr, name: &str) {
+        self.operand(obj);
+        self.push(".");
+        self.push(name);
+    }
+
+    /// `obj.name(args)`
+    fn method(&mut self, obj: &Expr, name: &str, args: &[&Expr]) {
+        self.operand(obj);
+        self.push(".");
+        self.call(name, args);
+    }
+
+    fn infix(&mut self, left: &Expr, op: &str, right: &Expr) {
+        self.operand(left);
+        self.push(" ");
+        self.push(op);
+        self.push(" ");
+        self.operand(right);
+    }
+
+    fn prefix(&mut self, op: &str, input: &Expr) {
+        self.push(op);
+        self.operand(input);
+    }
+
+    fn val_def(&mut self, vd: &ValDef) {
+        self.push("val ");
+        self.push(&val_name(&vd.id));
+        self.push(" = ");
+        self.expr(&vd.rhs);
+    }
+
+    fn block_body(&mut self, items: &[Expr], result: &Expr) {
+        self.indent += 1;
+        for item in items {
+            self.newline();
+            self.expr(item);
+        }
+        self.newline();
+        self.expr(result);
+        self.indent -= 1;
+        self.newline();
+    }
+
+    fn lambda(&mut self, args: &[FuncArg], body: &Expr) {
+        self.push("{ (");
+        self.push(&join(
+            args.iter()
+                .map(|a| format!("{}: {}", val_name(&a.idx), print_type(&a.tpe))),
+        ));
+        self.push(") =>");
+        match body {
+            Expr::BlockValue(b) => {
+                self.block_body(&b.items, &b.result);
+                self.push("}");
+            }
+            _ => {
+                self.push(" ");
+                self.expr(body);
+                self.push(" }");
+            }
+        }
+    }
+
+    fn value(&mut self, v: &Value) {
+        match v {
+            Value::Boolean(b) => self.push(&b.to_string()),
+            Value::Byte(b) => self.push(&format!("{}.toByte", b)),
+            Value::Short(s) => self.push(&format!("{}.toShort", s)),
+            Value::Int(i) => self.push(&i.to_string()),
+            Value::Long(l) => self.push(&format!("{}L", l)),
+            Value::BigInt(bi) => self.push(&format!("bigInt(\"{}\")", bi)),
+            Value::GroupElement(p) => self.push(&print_ec_point(p)),
+            Value::SigmaProp(sp) => self.push(&print_sigma_boolean(sp.value())),
+            Value::CBox(IrBox::Ref(id)) => self.push(&format!(
+                "Box(id = {})",
+                base16_bytes(&id.to_bytes().as_vec_u8())
+            )),
+            Value::CBox(IrBox::Parsed(b)) => {
+                self.push(&format!("Box({})", base16_bytes(&b.bytes().as_vec_u8())))
+            }
+            Value::AvlTree(t) => self.push(&format!(
+                "AvlTree({})",
+                base16_bytes(&t.sigma_serialize_bytes())
+            )),
+            Value::Header(h) => self.push(&format!("Header(id = {})", base16_bytes(&h.id))),
+            Value::PreHeader(h) => self.push(&format!(
+                "PreHeader(parentId = {}, height = {})",
+                base16_bytes(&h.parent_id),
+                h.height
+            )),
+            Value::Coll(CollKind::NativeColl(NativeColl::CollByte(bytes))) => {
+                self.push(&base16_bytes(&bytes.clone().as_vec_u8()))
+            }
+            Value::Coll(CollKind::WrappedColl { elem_tpe, items }) => {
+                if items.is_empty() {
+                    self.push(&format!("Coll[{}]()", print_type(elem_tpe)));
+                } else {
+                    self.push("Coll(");
+                    self.values(items);
+                    self.push(")");
+                }
+            }
+            Value::Tup(items) => {
+                self.push("(");
+                self.values(items.as_slice());
+                self.push(")");
+            }
+            Value::Context => self.push("CONTEXT"),
+            Value::Global => self.push("Global"),
+            Value::Opt(opt) => match opt.as_ref() {
+                Some(v) => {
+                    self.push("Some(");
+                    self.value(v);
+                    self.push(")");
+                }
+                None => self.push("None"),
+            },
+            Value::Lambda(l) => self.lambda(&l.args, &l.body),
+        }
+    }
+
+    fn values(&mut self, values: &[Value]) {
+        for (i, v) in values.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.value(v);
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Const(c) => self.value(&c.v),
+            Expr::ConstPlaceholder(cp) => self.push(&format!("placeholder_{}", cp.id)),
+            Expr::ByteArrayToLong(op) => self.call("byteArrayToLong", &[&op.input]),
+            Expr::ByteArrayToBigInt(op) => self.call("byteArrayToBigInt", &[&op.input]),
+            Expr::LongToByteArray(op) => self.call("longToByteArray", &[&op.input]),
+            Expr::Collection(Collection::BoolConstants(bools)) => self.push(&format!(
+                "Coll({})",
+                join(bools.iter().map(|b| b.to_string()))
+            )),
+            Expr::Collection(Collection::Exprs { elem_tpe, items }) => {
+                if items.is_empty() {
+                    self.push(&format!("Coll[{}]()", print_type(elem_tpe)));
+                } else {
+                    self.call("Coll", &items.iter().collect::<Vec<&Expr>>());
+                }
+            }
+            Expr::Tuple(t) => self.call("", &t.items.iter().collect::<Vec<&Expr>>()),
+            Expr::CalcBlake2b256(op) => self.call("blake2b256", &[&op.input]),
+            Expr::CalcSha256(op) => self.call("sha256", &[&op.input]),
+            Expr::Context => self.push("CONTEXT"),
+            Expr::Global => self.push("Global"),
+            Expr::GlobalVars(gv) => self.push(match gv {
+                GlobalVars::Inputs => "INPUTS",
+                GlobalVars::Outputs => "OUTPUTS",
+                GlobalVars::Height => "HEIGHT",
+                GlobalVars::SelfBox => "SELF",
+                GlobalVars::MinerPubKey => "minerPubKey",
+            }),
+            Expr::FuncValue(f) => self.lambda(f.args(), f.body()),
+            Expr::Apply(op) => {
+                self.operand(&op.func);
+                self.call("", &op.args.iter().collect::<Vec<&Expr>>());
+            }
+            Expr::MethodCall(mc) => self.method(
+                &mc.obj,
+                mc.method.name(),
+                &mc.args.iter().collect::<Vec<&Expr>>(),
+            ),
+            Expr::ProperyCall(pc) => self.property(&pc.obj, pc.method.name()),
+            Expr::BlockValue(b) => {
+                self.push("{");
+                self.block_body(&b.items, &b.result);
+                self.push("}");
+            }
+            Expr::ValDef(vd) => self.val_def(vd),
+            Expr::ValUse(vu) => self.push(&val_name(&vu.val_id)),
+            Expr::If(op) => {
+                self.push("if (");
+                self.expr(&op.condition);
+                self.push(") ");
+                self.operand(&op.true_branch);
+                self.push(" else ");
+                self.operand(&op.false_branch);
+            }
+            Expr::BinOp(op) => match &op.kind {
+                BinOpKind::Arith(a @ ArithOp::Max) | BinOpKind::Arith(a @ ArithOp::Min) => {
+                    self.call(arith_op(a), &[&op.left, &op.right])
+                }
+                BinOpKind::Arith(a) => self.infix(&op.left, arith_op(a), &op.right),
+                BinOpKind::Relation(r) => self.infix(&op.left, relation_op(r), &op.right),
+            },
+            Expr::And(op) => self.call("allOf", &[&op.input]),
+            Expr::Or(op) => self.call("anyOf", &[&op.input]),
+            Expr::Xor(op) => self.call("xor", &[&op.left, &op.right]),
+            Expr::Atleast(op) => self.call("atLeast", &[&op.n_required, &op.expressions]),
+            Expr::LogicalNot(op) => self.prefix("!", &op.input),
+            Expr::Negation(op) => self.prefix("-", &op.input),
+            Expr::OptionGet(op) => self.property(&op.input, "get"),
+            Expr::OptionIsDefined(op) => self.property(&op.input, "isDefined"),
+            Expr::OptionGetOrElse(op) => self.method(&op.input, "getOrElse", &[&op.default]),
+            Expr::SomeValue(op) => self.call("Some", &[&op.input]),
+            Expr::NoneValue(_) => self.push("None"),
+            Expr::ExtractAmount(op) => self.property(&op.input, "value"),
+            Expr::ExtractRegisterAs(op) => self.property(
+                &op.input,
+                &format!("R{}[{}]", op.register_id, print_type(&op.elem_tpe)),
+            ),
+            Expr::ExtractScriptBytes(op) => self.property(&op.input, "propositionBytes"),
+            Expr::ExtractBytes(op) => self.property(&op.input, "bytes"),
+            Expr::ExtractBytesWithNoRef(op) => self.property(&op.input, "bytesWithoutRef"),
+            Expr::ExtractCreationInfo(op) => self.property(&op.input, "creationInfo"),
+            Expr::ExtractId(op) => self.property(&op.input, "id"),
+            Expr::ByIndex(op) => match &op.default {
+                Some(default) => self.method(&op.input, "getOrElse", &[&op.index, default]),
+                None => {
+                    self.operand(&op.input);
+                    self.call("", &[&op.index]);
+                }
+            },
+            Expr::SizeOf(op) => self.property(&op.input, "size"),
+            Expr::Slice(op) => self.method(&op.input, "slice", &[&op.from, &op.until]),
+            Expr::Append(op) => self.infix(&op.input, "++", &op.col_2),
+            Expr::Fold(op) => self.method(&op.input, "fold", &[&op.zero, &op.fold_op]),
+            Expr::Map(op) => self.method(&op.input, "map", &[&op.mapper]),
+            Expr::Filter(op) => self.method(&op.input, "filter", &[&op.condition]),
+            Expr::Exists(op) => self.method(&op.input, "exists", &[&op.condition]),
+            Expr::ForAll(op) => self.method(&op.input, "forall", &[&op.condition]),
+            Expr::SelectField(op) => self.property(
+                &op.input,
+                &format!("_{}", op.field_index.zero_based_index() + 1),
+            ),
+            Expr::BoolToSigmaProp(op) => self.call("sigmaProp", &[&op.input]),
+            Expr::Upcast(op) => self.property(&op.input, &numeric_cast(&op.tpe)),
+            Expr::Downcast(op) => self.property(&op.input, &numeric_cast(&op.tpe)),
+            Expr::CreateProveDlog(op) => self.call("proveDlog", &[&op.input]),
+            Expr::CreateProveDhTuple(op) => {
+                self.call("proveDHTuple", &[&op.gv, &op.hv, &op.uv, &op.vv])
+            }
+            Expr::SigmaPropBytes(op) => self.property(&op.input, "propBytes"),
+            Expr::DecodePoint(op) => self.call("decodePoint", &[&op.input]),
+            Expr::GroupGenerator => self.push("groupGenerator"),
+            Expr::Exponentiate(op) => self.method(&op.left, "exp", &[&op.right]),
+            Expr::MultiplyGroup(op) => self.method(&op.left, "multiply", &[&op.right]),
+            Expr::ModQ(op) => self.property(&op.input, "modQ"),
+            Expr::PlusModQ(op) => self.method(&op.left, "plusModQ", &[&op.right]),
+            Expr::MinusModQ(op) => self.method(&op.left, "minusModQ", &[&op.right]),
+            Expr::SigmaAnd(op) => {
+                for (i, item) in op.items.iter().enumerate() {
+                    if i > 0 {
+                        self.push(" && ");
+                    }
+                    self.operand(item);
+                }
+            }
+            Expr::SigmaOr(op) => {
+                for (i, item) in op.items.iter().enumerate() {
+                    if i > 0 {
+                        self.push(" || ");
+                    }
+                    self.operand(item);
+                }
+            }
+            Expr::GetVar(op) => self.push(&format!(
+                "getVar[{}]({})",
+                print_type(&op.var_tpe),
+                op.var_id
+            )),
+            Expr::DeserializeRegister(op) => {
+                self.push(&format!(
+                    "executeFromSelfReg[{}]({}",
+                    print_type(&op.tpe),
+                    op.reg
+                ));
+                if let Some(default) = &op.default {
+                    self.push(", ");
+                    self.expr(default);
+                }
+                self.push(")");
+            }
+            Expr::DeserializeContext(op) => self.push(&format!(
+                "executeFromVar[{}]({})",
+                print_type(&op.tpe),
+                op.id
+            )),
+            Expr::SubstConstants(op) => self.call(
+                "substConstants",
+                &[&op.script_bytes, &op.positions, &op.new_values],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::ergo_tree::ErgoTree;
+    use crate::ergo_tree::ErgoTreeHeader;
+    use crate::mir::bin_op::BinOp;
+    use crate::mir::block::BlockValue;
+    use crate::mir::bool_to_sigma::BoolToSigmaProp;
+    use crate::mir::coll_append::Append;
+    use crate::mir::coll_exists::Exists;
+    use crate::mir::coll_size::SizeOf;
+    use crate::mir::constant::Constant;
+    use crate::mir::extract_amount::ExtractAmount;
+    use crate::mir::extract_reg_as::ExtractRegisterAs;
+    use crate::mir::func_value::FuncValue;
+    use crate::mir::logical_not::LogicalNot;
+    use crate::mir::option_get::OptionGet;
+    use crate::mir::property_call::PropertyCall;
+    use crate::mir::sigma_or::SigmaOr;
+    use crate::mir::upcast::Upcast;
+    use crate::mir::val_use::ValUse;
+    use crate::sigma_protocol::dlog_group;
+    use crate::sigma_protocol::sigma_boolean::ProveDlog;
+    use crate::types::scontext;
+    use std::convert::TryInto;
+
+    #[test]
+    fn print_types() {
+        assert_eq!(
+            print_type(&SType::SColl(Box::new(SType::SOption(Box::new(
+                SType::SBox
+            ))))),
+            "Coll[Option[Box]]"
+        );
+    }
+
+    #[test]
+    fn print_p2pk_tree() {
+        let pk = ProveDlog::new(dlog_group::generator());
+        let tree = ErgoTree::new(ErgoTreeHeader::v0(true), &Expr::Const(pk.clone().into()));
+        assert_eq!(
+            tree.to_ergoscript().unwrap(),
+            format!(
+                "proveDlog(decodePoint(fromBase16(\"{}\")))",
+                base16::encode_lower(&pk.h.sigma_serialize_bytes())
+            )
+        );
+    }
+
+    #[test]
+    fn print_tree_with_segregated_constants() {
+        let tree_bytes = base16::decode("100204a00b08cd021dde34603426402615658f1d970cfa7c7bd92ac81a8b16eeebff264d59ce4604ea02d192a39a8cc7a70173007301").unwrap();
+        let tree = ErgoTree::sigma_parse_bytes(&tree_bytes).unwrap();
+        assert_eq!(
+            tree.to_ergoscript().unwrap(),
+            "sigmaProp(HEIGHT >= (SELF.creationInfo._1 + 720)) && proveDlog(decodePoint(fromBase16(\"021dde34603426402615658f1d970cfa7c7bd92ac81a8b16eeebff264d59ce4604\")))"
+        );
+    }
+
+    #[test]
+    fn print_block_lambda_and_registers() {
+        let self_r4: Expr = OptionGet {
+            input: Box::new(
+                ExtractRegisterAs::new(
+                    GlobalVars::SelfBox.into(),
+                    4,
+                    SType::SOption(Box::new(SType::SLong)),
+                )
+                .unwrap()
+                .into(),
+            ),
+        }
+        .into();
+        let min_value = ValDef {
+            id: 1.into(),
+            rhs: Box::new(self_r4),
+        };
+        let min_value_use: Expr = ValUse {
+            val_id: 1.into(),
+            tpe: SType::SLong,
+        }
+        .into();
+        let box_arg: Expr = ValUse {
+            val_id: 2.into(),
+            tpe: SType::SBox,
+        }
+        .into();
+        let condition = FuncValue::new(
+            vec![FuncArg {
+                idx: 2.into(),
+                tpe: SType::SBox,
+            }],
+            BinOp {
+                kind: RelationOp::Ge.into(),
+                left: Box::new(
+                    ExtractAmount {
+                        input: Box::new(box_arg),
+                    }
+                    .into(),
+                ),
+                right: Box::new(min_value_use),
+            }
+            .into(),
+        );
+        let data_inputs: Expr =
+            PropertyCall::new(Expr::Context, scontext::DATA_INPUTS_PROPERTY.clone())
+                .unwrap()
+                .into();
+        let exists: Expr = Exists::new(data_inputs, condition.into()).unwrap().into();
+        let block: Expr = BlockValue {
+            items: vec![min_value.into()],
+            result: Box::new(
+                SigmaOr::new(vec![
+                    BoolToSigmaProp {
+                        input: Box::new(exists),
+                    }
+                    .into(),
+                    Expr::Const(ProveDlog::new(dlog_group::generator()).into()),
+                ])
+                .unwrap()
+                .into(),
+            ),
+        }
+        .into();
+        let expected = format!(
+            "{{
+  val v1 = SELF.R4[Long].get
+  sigmaProp(CONTEXT.dataInputs.exists({{ (v2: Box) => v2.value >= v1 }})) || proveDlog({})
+}}",
+            print_ec_point(&dlog_group::generator())
+        );
+        assert_eq!(print_expr(&block), expected);
+    }
+
+    #[test]
+    fn print_nested_lambda_block() {
+        let body: Expr = BlockValue {
+            items: vec![ValDef {
+                id: 2.into(),
+                rhs: Box::new(
+                    BinOp {
+                        kind: ArithOp::Plus.into(),
+                        left: Box::new(
+                            ValUse {
+                                val_id: 1.into(),
+                                tpe: SType::SInt,
+                            }
+                            .into(),
+                        ),
+                        right: Box::new(Expr::Const((-1i32).into())),
+                    }
+                    .into(),
+                ),
+            }
+            .into()],
+            result: Box::new(
+                BinOp {
+                    kind: ArithOp::Max.into(),
+                    left: Box::new(
+                        ValUse {
+                            val_id: 2.into(),
+                            tpe: SType::SInt,
+                        }
+                        .into(),
+                    ),
+                    right: Box::new(Expr::Const(0i32.into())),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let f = FuncValue::new(
+            vec![FuncArg {
+                idx: 1.into(),
+                tpe: SType::SInt,
+            }],
+            body,
+        );
+        assert_eq!(
+            print_expr(&f.into()),
+            "{ (v1: Int) =>
+  val v2 = v1 + (-1)
+  max(v2, 0)
+}"
+        );
+    }
+
+    #[test]
+    fn print_values() {
+        let coll: Constant = vec![1i64, 2].into();
+        assert_eq!(print_expr(&coll.into()), "Coll(1L, 2L)");
+        let bytes: Constant = vec![1u8, 255].into();
+        assert_eq!(print_expr(&bytes.into()), "fromBase16(\"01ff\")");
+        let tup: Constant = (1i8, Some(true)).into();
+        assert_eq!(print_expr(&tup.into()), "(1.toByte, Some(true))");
+        let empty: Constant = Vec::<i32>::new().into();
+        assert_eq!(print_expr(&empty.into()), "Coll[Int]()");
+        let items: Vec<SigmaBoolean> = vec![
+            SigmaBoolean::TrivialProp(true),
+            SigmaBoolean::TrivialProp(false),
+        ];
+        let cand = crate::sigma_protocol::sigma_boolean::cand::Cand {
+            items: items.try_into().unwrap(),
+        };
+        assert_eq!(
+            print_sigma_boolean(&SigmaConjecture::Cand(cand).into()),
+            "(sigmaProp(true) && sigmaProp(false))"
+        );
+    }
+
+    #[test]
+    fn print_operator_precedence() {
+        let ints = |v: Vec<i32>| -> Expr { Constant::from(v).into() };
+        let int = |v: i32| -> Expr { Expr::Const(v.into()) };
+        let minus = |l: Expr, r: Expr| -> Expr {
+            BinOp {
+                kind: ArithOp::Minus.into(),
+                left: l.into(),
+                right: r.into(),
+            }
+            .into()
+        };
+        let append: Expr = Append::new(ints(vec![1]), ints(vec![2])).unwrap().into();
+        let size: Expr = SizeOf {
+            input: append.clone().into(),
+        }
+        .into();
+        assert_eq!(print_expr(&size), "(Coll(1) ++ Coll(2)).size");
+        let append_2: Expr = Append::new(append.clone(), ints(vec![3])).unwrap().into();
+        assert_eq!(print_expr(&append_2), "(Coll(1) ++ Coll(2)) ++ Coll(3)");
+        let append_3: Expr = Append::new(ints(vec![3]), append).unwrap().into();
+        assert_eq!(print_expr(&append_3), "Coll(3) ++ (Coll(1) ++ Coll(2))");
+        assert_eq!(
+            print_expr(&minus(int(1), minus(int(2), int(3)))),
+            "1 - (2 - 3)"
+        );
+        assert_eq!(
+            print_expr(&minus(minus(int(1), int(2)), int(-3))),
+            "(1 - 2) - (-3)"
+        );
+        let not: Expr = LogicalNot {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Eq.into(),
+                    left: int(1).into(),
+                    right: int(2).into(),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq!(print_expr(&not), "!(1 == 2)");
+        let upcast: Expr = Upcast::new(int(-1), SType::SLong).unwrap().into();
+        assert_eq!(print_expr(&upcast), "(-1).toLong");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod proptests {
+    use super::*;
+    use crate::ergo_tree::ErgoTree;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn print_any_expr(e in any::<Expr>()) {
+            prop_assert!(!print_expr(&e).is_empty());
+        }
+
+        #[test]
+        fn print_any_tree(t in any::<ErgoTree>()) {
+            prop_assert!(!t.to_ergoscript().unwrap().is_empty());
+        }
+    }
+}
@@ -54,7 +54,7 @@ pub fn bin_op_sigma_parse<R: SigmaByteRead>(
         }
         .into()
     } else {
-        let left = Expr::parse_with_tag(r, tag)?;
+        let left = r.nested(|r| Expr::parse_with_tag(r, tag))?;
         let right = Expr::sigma_parse(r)?;
         BinOp {
             kind: op_kind,
@@ -34,6 +34,7 @@ impl SigmaSerializable for Constant {
 
 #[cfg(test)]
 #[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
     use crate::mir::constant::arbitrary::ArbConstantParams;
@@ -46,5 +47,28 @@ mod tests {
         fn ser_roundtrip(v in any_with::<Constant>(ArbConstantParams::AnyWithDepth(4))) {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
+
+        #[test]
+        fn ser_roundtrip_option(v in any::<Option<i64>>()) {
+            let c: Constant = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+        }
+
+        #[test]
+        fn ser_roundtrip_option_nested(v in any::<Option<Vec<(i64, bool)>>>()) {
+            let c: Constant = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+        }
+    }
+
+    #[test]
+    fn ser_option_bytes() {
+        // type code, then 1 followed by the value for Some and 0 for None
+        let some: Constant = Some(5i8).into();
+        assert_eq!(some.sigma_serialize_bytes(), vec![38, 1, 5]);
+        let none: Constant = Option::<i8>::None.into();
+        assert_eq!(none.sigma_serialize_bytes(), vec![38, 0]);
+        // any non-zero flag byte is parsed as Some
+        assert_eq!(Constant::sigma_parse_bytes(&[38, 2, 5]).unwrap(), some);
     }
 }
@@ -1,5 +1,8 @@
 use num_bigint::BigInt;
 
+use crate::ir_ergo_box::IrBox;
+use crate::ir_ergo_box::ParsedErgoBox;
+use crate::mir::avl_tree_data::AvlTreeData;
 use crate::mir::constant::TryExtractFromError;
 use crate::mir::constant::TryExtractInto;
 use crate::mir::value::CollKind;
@@ -37,8 +40,15 @@ impl DataSerializer {
             }
             Value::GroupElement(ecp) => ecp.sigma_serialize(w),
             Value::SigmaProp(s) => s.value().sigma_serialize(w),
-            Value::CBox(_) => todo!(),
-            Value::AvlTree => todo!(),
+            Value::CBox(IrBox::Parsed(b)) => b.sigma_serialize(w),
+            Value::CBox(IrBox::Ref(id)) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "cannot serialize box {0:?} referenced by id (box data is in the box arena)",
+                    id
+                ),
+            )),
+            Value::AvlTree(a) => a.sigma_serialize(w),
             Value::Coll(ct) => match ct {
                 CollKind::NativeColl(NativeColl::CollByte(b)) => {
                     w.put_usize_as_u16(b.len())?;
@@ -49,13 +59,13 @@ impl DataSerializer {
                     items: v,
                 } => {
                     w.put_usize_as_u16(v.len())?;
-                    let maybe_bools: Result<Vec<bool>, TryExtractFromError> = v
+                    let bools: Vec<bool> = v
                         .clone()
                         .into_iter()
                         .map(|i| i.try_extract_into::<bool>())
-                        .collect();
-                    #[allow(clippy::unwrap_used)]
-                    w.put_bits(maybe_bools.unwrap().as_slice())
+                        .collect::<Result<_, TryExtractFromError>>()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.0))?;
+                    w.put_bits(bools.as_slice())
                 }
                 CollKind::WrappedColl {
                     elem_tpe: _,
@@ -69,14 +79,32 @@ impl DataSerializer {
             Value::Tup(items) => items
                 .iter()
                 .try_for_each(|i| DataSerializer::sigma_serialize(i, w)),
-            Value::Opt(_) => panic!("Option is not yet supported"), // unsupported, see https://github.com/ScorexFoundation/sigmastate-interpreter/issues/659
-            _ => panic!("serialization is not supported for value: {0:?}", c),
+            Value::Opt(opt) => match opt.as_ref() {
+                // flag byte followed by the value (if any), see
+                // https://github.com/ScorexFoundation/sigmastate-interpreter/issues/659
+                Some(v) => {
+                    w.put_u8(1)?;
+                    DataSerializer::sigma_serialize(v, w)
+                }
+                None => w.put_u8(0),
+            },
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("serialization is not supported for value: {0:?}", c),
+            )),
         }
     }
 
     pub fn sigma_parse<R: SigmaByteRead>(
         tpe: &SType,
         r: &mut R,
+    ) -> Result<Value, SerializationError> {
+        r.nested_data(|r| DataSerializer::sigma_parse_value(tpe, r))
+    }
+
+    fn sigma_parse_value<R: SigmaByteRead>(
+        tpe: &SType,
+        r: &mut R,
     ) -> Result<Value, SerializationError> {
         // for reference see http://github.com/ScorexFoundation/sigmastate-interpreter/blob/25251c1313b0131835f92099f02cef8a5d932b5e/sigmastate/src/main/scala/sigmastate/serialization/DataSerializer.scala#L84-L84
         use SType::*;
@@ -100,6 +128,8 @@ impl DataSerializer {
             }
             SGroupElement => Value::GroupElement(Box::new(EcPoint::sigma_parse(r)?)),
             SSigmaProp => Value::sigma_prop(SigmaProp::new(SigmaBoolean::sigma_parse(r)?)),
+            SBox => ParsedErgoBox::sigma_parse(r)?.into(),
+            SAvlTree => Value::AvlTree(Box::new(AvlTreeData::sigma_parse(r)?)),
             SColl(elem_type) if **elem_type == SByte => {
                 let len = r.get_u16()? as usize;
                 let mut buf = vec![0u8; len];
@@ -118,10 +148,9 @@ impl DataSerializer {
             }
             SColl(elem_type) => {
                 let len = r.get_u16()? as usize;
-                let mut elems = Vec::with_capacity(len as usize);
-                for _ in 0..len {
-                    elems.push(DataSerializer::sigma_parse(elem_type, r)?);
-                }
+                let elems = (0..len)
+                    .map(|_| DataSerializer::sigma_parse(elem_type, r))
+                    .collect::<Result<Vec<_>, _>>()?;
                 Value::Coll(CollKind::WrappedColl {
                     elem_tpe: *elem_type.clone(),
                     items: elems,
@@ -137,7 +166,11 @@ impl DataSerializer {
                 // is correct
                 Value::Tup(items.try_into()?)
             }
-
+            // as in sigmastate, any non-zero flag byte means Some
+            SOption(elem_type) => match r.get_u8()? {
+                0 => Value::Opt(Box::new(None)),
+                _ => Value::Opt(Box::new(Some(DataSerializer::sigma_parse(elem_type, r)?))),
+            },
             c => {
                 return Err(SerializationError::NotImplementedYet(format!(
                     "parsing of constant value of type {:?} is not yet supported",
@@ -14,6 +14,7 @@ use crate::mir::byte_array_to_bigint::ByteArrayToBigInt;
 use crate::mir::byte_array_to_long::ByteArrayToLong;
 use crate::mir::calc_blake2b256::CalcBlake2b256;
 use crate::mir::calc_sha256::CalcSha256;
+use crate::mir::coll_append::Append;
 use crate::mir::coll_by_index::ByIndex;
 use crate::mir::coll_exists::Exists;
 use crate::mir::coll_filter::Filter;
@@ -21,6 +22,7 @@ use crate::mir::coll_fold::Fold;
 use crate::mir::coll_forall::ForAll;
 use crate::mir::coll_map::Map;
 use crate::mir::coll_size::SizeOf;
+use crate::mir::coll_slice::Slice;
 use crate::mir::collection::bool_const_coll_sigma_parse;
 use crate::mir::collection::coll_sigma_parse;
 use crate::mir::collection::coll_sigma_serialize;
@@ -31,8 +33,12 @@ use crate::mir::create_provedlog::CreateProveDlog;
 use crate::mir::decode_point::DecodePoint;
 use crate::mir::deserialize_context::DeserializeContext;
 use crate::mir::deserialize_register::DeserializeRegister;
+use crate::mir::downcast::Downcast;
+use crate::mir::exponentiate::Exponentiate;
 use crate::mir::expr::Expr;
 use crate::mir::extract_amount::ExtractAmount;
+use crate::mir::extract_bytes::ExtractBytes;
+use crate::mir::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
 use crate::mir::extract_creation_info::ExtractCreationInfo;
 use crate::mir::extract_id::ExtractId;
 use crate::mir::extract_reg_as::ExtractRegisterAs;
@@ -44,16 +50,23 @@ use crate::mir::if_op::If;
 use crate::mir::logical_not::LogicalNot;
 use crate::mir::long_to_byte_array::LongToByteArray;
 use crate::mir::method_call::MethodCall;
+use crate::mir::minus_mod_q::MinusModQ;
+use crate::mir::mod_q::ModQ;
+use crate::mir::multiply_group::MultiplyGroup;
 use crate::mir::negation::Negation;
+use crate::mir::none_value::NoneValue;
 use crate::mir::option_get::OptionGet;
 use crate::mir::option_get_or_else::OptionGetOrElse;
 use crate::mir::option_is_defined::OptionIsDefined;
 use crate::mir::or::Or;
+use crate::mir::plus_mod_q::PlusModQ;
 use crate::mir::property_call::PropertyCall;
 use crate::mir::select_field::SelectField;
 use crate::mir::sigma_and::SigmaAnd;
 use crate::mir::sigma_or::SigmaOr;
 use crate::mir::sigma_prop_bytes::SigmaPropBytes;
+use crate::mir::some_value::SomeValue;
+use crate::mir::subst_const::SubstConstants;
 use crate::mir::tuple::Tuple;
 use crate::mir::upcast::Upcast;
 use crate::mir::val_def::ValDef;
@@ -80,13 +93,14 @@ impl Expr {
         } else {
             let op_code = OpCode::parse(tag);
             match op_code {
-                OpCode::FOLD => Ok(Fold::sigma_parse(r)?.into()),
+                OpCode::FOLD => parse_node::<Fold, R>(r),
                 ConstantPlaceholder::OP_CODE => {
                     let cp = ConstantPlaceholder::sigma_parse(r)?;
                     if r.substitute_placeholders() {
-                        // ConstantPlaceholder itself can be created only if a corresponding
-                        // constant is in the constant_store, thus unwrap() is safe here
-                        let c = r.constant_store().get(cp.id).unwrap();
+                        let c = r
+                            .constant_store()
+                            .get(cp.id)
+                            .ok_or(SerializationError::ConstantForPlaceholderNotFound(cp.id))?;
                         Ok(Expr::Const(c.clone()))
                     } else {
                         Ok(Expr::ConstPlaceholder(cp))
@@ -98,72 +112,87 @@ impl Expr {
                 OpCode::OUTPUTS => Ok(Expr::GlobalVars(GlobalVars::Outputs)),
                 OpCode::MINER_PUBKEY => Ok(Expr::GlobalVars(GlobalVars::MinerPubKey)),
                 OpCode::GLOBAL => Ok(Expr::Global),
-                OpCode::PROPERTY_CALL => Ok(Expr::ProperyCall(PropertyCall::sigma_parse(r)?)),
-                OpCode::METHOD_CALL => Ok(Expr::MethodCall(MethodCall::sigma_parse(r)?)),
+                OpCode::GROUP_GENERATOR => Ok(Expr::GroupGenerator),
+                OpCode::PROPERTY_CALL => parse_node::<PropertyCall, R>(r),
+                OpCode::METHOD_CALL => parse_node::<MethodCall, R>(r),
                 OpCode::CONTEXT => Ok(Expr::Context),
-                OptionGet::OP_CODE => Ok(OptionGet::sigma_parse(r)?.into()),
-                OptionIsDefined::OP_CODE => Ok(OptionIsDefined::sigma_parse(r)?.into()),
-                OptionGetOrElse::OP_CODE => Ok(OptionGetOrElse::sigma_parse(r)?.into()),
-                ExtractRegisterAs::OP_CODE => Ok(ExtractRegisterAs::sigma_parse(r)?.into()),
-                ExtractScriptBytes::OP_CODE => Ok(ExtractScriptBytes::sigma_parse(r)?.into()),
-                ExtractCreationInfo::OP_CODE => Ok(ExtractCreationInfo::sigma_parse(r)?.into()),
-                ExtractId::OP_CODE => Ok(ExtractId::sigma_parse(r)?.into()),
-                OpCode::EQ => Ok(bin_op_sigma_parse(RelationOp::Eq.into(), r)?),
-                OpCode::NEQ => Ok(bin_op_sigma_parse(RelationOp::NEq.into(), r)?),
-                Negation::OP_CODE => Ok(Negation::sigma_parse(r)?.into()),
-                OpCode::LOGICAL_NOT => Ok(LogicalNot::sigma_parse(r)?.into()),
-                OpCode::BIN_AND => Ok(bin_op_sigma_parse(RelationOp::And.into(), r)?),
-                OpCode::BIN_OR => Ok(bin_op_sigma_parse(RelationOp::Or.into(), r)?),
-                OpCode::GT => Ok(bin_op_sigma_parse(RelationOp::Gt.into(), r)?),
-                OpCode::LT => Ok(bin_op_sigma_parse(RelationOp::Lt.into(), r)?),
-                OpCode::GE => Ok(bin_op_sigma_parse(RelationOp::Ge.into(), r)?),
-                OpCode::LE => Ok(bin_op_sigma_parse(RelationOp::Le.into(), r)?),
-                OpCode::PLUS => Ok(bin_op_sigma_parse(ArithOp::Plus.into(), r)?),
-                OpCode::MINUS => Ok(bin_op_sigma_parse(ArithOp::Minus.into(), r)?),
-                OpCode::MULTIPLY => Ok(bin_op_sigma_parse(ArithOp::Multiply.into(), r)?),
-                OpCode::DIVISION => Ok(bin_op_sigma_parse(ArithOp::Divide.into(), r)?),
-                OpCode::MAX => Ok(bin_op_sigma_parse(ArithOp::Max.into(), r)?),
-                OpCode::MIN => Ok(bin_op_sigma_parse(ArithOp::Min.into(), r)?),
-                OpCode::BIT_OR => Ok(bin_op_sigma_parse(ArithOp::BitOr.into(), r)?),
-                OpCode::BIT_AND => Ok(bin_op_sigma_parse(ArithOp::BitAnd.into(), r)?),
-                OpCode::BIT_XOR => Ok(bin_op_sigma_parse(ArithOp::BitXor.into(), r)?),
-                OpCode::BLOCK_VALUE => Ok(Expr::BlockValue(BlockValue::sigma_parse(r)?)),
-                OpCode::FUNC_VALUE => Ok(Expr::FuncValue(FuncValue::sigma_parse(r)?)),
-                OpCode::APPLY => Ok(Expr::Apply(Apply::sigma_parse(r)?)),
-                OpCode::VAL_DEF => Ok(Expr::ValDef(ValDef::sigma_parse(r)?)),
-                OpCode::VAL_USE => Ok(Expr::ValUse(ValUse::sigma_parse(r)?)),
-                ExtractAmount::OP_CODE => Ok(Expr::ExtractAmount(ExtractAmount::sigma_parse(r)?)),
-                OpCode::SELECT_FIELD => Ok(Expr::SelectField(SelectField::sigma_parse(r)?)),
-                OpCode::CALC_BLAKE2B256 => Ok(CalcBlake2b256::sigma_parse(r)?.into()),
-                CalcSha256::OP_CODE => Ok(CalcSha256::sigma_parse(r)?.into()),
-                And::OP_CODE => Ok(And::sigma_parse(r)?.into()),
-                Or::OP_CODE => Ok(Or::sigma_parse(r)?.into()),
-                Xor::OP_CODE => Ok(Xor::sigma_parse(r)?.into()),
-                Atleast::OP_CODE => Ok(Atleast::sigma_parse(r)?.into()),
+                OptionGet::OP_CODE => parse_node::<OptionGet, R>(r),
+                OptionIsDefined::OP_CODE => parse_node::<OptionIsDefined, R>(r),
+                OptionGetOrElse::OP_CODE => parse_node::<OptionGetOrElse, R>(r),
+                SomeValue::OP_CODE => parse_node::<SomeValue, R>(r),
+                NoneValue::OP_CODE => parse_node::<NoneValue, R>(r),
+                ExtractRegisterAs::OP_CODE => parse_node::<ExtractRegisterAs, R>(r),
+                ExtractScriptBytes::OP_CODE => parse_node::<ExtractScriptBytes, R>(r),
+                ExtractBytes::OP_CODE => parse_node::<ExtractBytes, R>(r),
+                ExtractBytesWithNoRef::OP_CODE => parse_node::<ExtractBytesWithNoRef, R>(r),
+                ExtractCreationInfo::OP_CODE => parse_node::<ExtractCreationInfo, R>(r),
+                ExtractId::OP_CODE => parse_node::<ExtractId, R>(r),
+                OpCode::EQ => bin_op_sigma_parse(RelationOp::Eq.into(), r),
+                OpCode::NEQ => bin_op_sigma_parse(RelationOp::NEq.into(), r),
+                Negation::OP_CODE => parse_node::<Negation, R>(r),
+                OpCode::LOGICAL_NOT => parse_node::<LogicalNot, R>(r),
+                OpCode::BIN_AND => bin_op_sigma_parse(RelationOp::And.into(), r),
+                OpCode::BIN_OR => bin_op_sigma_parse(RelationOp::Or.into(), r),
+                OpCode::GT => bin_op_sigma_parse(RelationOp::Gt.into(), r),
+                OpCode::LT => bin_op_sigma_parse(RelationOp::Lt.into(), r),
+                OpCode::GE => bin_op_sigma_parse(RelationOp::Ge.into(), r),
+                OpCode::LE => bin_op_sigma_parse(RelationOp::Le.into(), r),
+                OpCode::PLUS => bin_op_sigma_parse(ArithOp::Plus.into(), r),
+                OpCode::MINUS => bin_op_sigma_parse(ArithOp::Minus.into(), r),
+                OpCode::MULTIPLY => bin_op_sigma_parse(ArithOp::Multiply.into(), r),
+                OpCode::DIVISION => bin_op_sigma_parse(ArithOp::Divide.into(), r),
+                OpCode::MODULO => bin_op_sigma_parse(ArithOp::Modulo.into(), r),
+                OpCode::MAX => bin_op_sigma_parse(ArithOp::Max.into(), r),
+                OpCode::MIN => bin_op_sigma_parse(ArithOp::Min.into(), r),
+                OpCode::BIT_OR => bin_op_sigma_parse(ArithOp::BitOr.into(), r),
+                OpCode::BIT_AND => bin_op_sigma_parse(ArithOp::BitAnd.into(), r),
+                OpCode::BIT_XOR => bin_op_sigma_parse(ArithOp::BitXor.into(), r),
+                OpCode::BLOCK_VALUE => parse_node::<BlockValue, R>(r),
+                OpCode::FUNC_VALUE => parse_node::<FuncValue, R>(r),
+                OpCode::APPLY => parse_node::<Apply, R>(r),
+                OpCode::VAL_DEF => parse_node::<ValDef, R>(r),
+                OpCode::VAL_USE => parse_node::<ValUse, R>(r),
+                ExtractAmount::OP_CODE => parse_node::<ExtractAmount, R>(r),
+                OpCode::SELECT_FIELD => parse_node::<SelectField, R>(r),
+                OpCode::CALC_BLAKE2B256 => parse_node::<CalcBlake2b256, R>(r),
+                CalcSha256::OP_CODE => parse_node::<CalcSha256, R>(r),
+                And::OP_CODE => parse_node::<And, R>(r),
+                Or::OP_CODE => parse_node::<Or, R>(r),
+                Xor::OP_CODE => parse_node::<Xor, R>(r),
+                Atleast::OP_CODE => parse_node::<Atleast, R>(r),
                 OpCode::COLL => Ok(coll_sigma_parse(r)?.into()),
                 OpCode::COLL_OF_BOOL_CONST => Ok(bool_const_coll_sigma_parse(r)?.into()),
-                Map::OP_CODE => Ok(Map::sigma_parse(r)?.into()),
-                Filter::OP_CODE => Ok(Filter::sigma_parse(r)?.into()),
-                Exists::OP_CODE => Ok(Exists::sigma_parse(r)?.into()),
-                ForAll::OP_CODE => Ok(ForAll::sigma_parse(r)?.into()),
-                BoolToSigmaProp::OP_CODE => Ok(BoolToSigmaProp::sigma_parse(r)?.into()),
-                Upcast::OP_CODE => Ok(Upcast::sigma_parse(r)?.into()),
-                If::OP_CODE => Ok(If::sigma_parse(r)?.into()),
-                ByIndex::OP_CODE => Ok(ByIndex::sigma_parse(r)?.into()),
-                SizeOf::OP_CODE => Ok(SizeOf::sigma_parse(r)?.into()),
-                CreateProveDlog::OP_CODE => Ok(CreateProveDlog::sigma_parse(r)?.into()),
-                CreateProveDhTuple::OP_CODE => Ok(CreateProveDhTuple::sigma_parse(r)?.into()),
-                SigmaPropBytes::OP_CODE => Ok(SigmaPropBytes::sigma_parse(r)?.into()),
-                Tuple::OP_CODE => Ok(Tuple::sigma_parse(r)?.into()),
-                DecodePoint::OP_CODE => Ok(DecodePoint::sigma_parse(r)?.into()),
-                ByteArrayToLong::OP_CODE => Ok(ByteArrayToLong::sigma_parse(r)?.into()),
-                ByteArrayToBigInt::OP_CODE => Ok(ByteArrayToBigInt::sigma_parse(r)?.into()),
-                LongToByteArray::OP_CODE => Ok(LongToByteArray::sigma_parse(r)?.into()),
-                SigmaAnd::OP_CODE => Ok(SigmaAnd::sigma_parse(r)?.into()),
-                SigmaOr::OP_CODE => Ok(SigmaOr::sigma_parse(r)?.into()),
-                GetVar::OP_CODE => Ok(GetVar::sigma_parse(r)?.into()),
-                DeserializeRegister::OP_CODE => Ok(DeserializeRegister::sigma_parse(r)?.into()),
-                DeserializeContext::OP_CODE => Ok(DeserializeContext::sigma_parse(r)?.into()),
+                Map::OP_CODE => parse_node::<Map, R>(r),
+                Filter::OP_CODE => parse_node::<Filter, R>(r),
+                Exists::OP_CODE => parse_node::<Exists, R>(r),
+                ForAll::OP_CODE => parse_node::<ForAll, R>(r),
+                BoolToSigmaProp::OP_CODE => parse_node::<BoolToSigmaProp, R>(r),
+                Upcast::OP_CODE => parse_node::<Upcast, R>(r),
+                Downcast::OP_CODE => parse_node::<Downcast, R>(r),
+                If::OP_CODE => parse_node::<If, R>(r),
+                ByIndex::OP_CODE => parse_node::<ByIndex, R>(r),
+                SizeOf::OP_CODE => parse_node::<SizeOf, R>(r),
+                Slice::OP_CODE => parse_node::<Slice, R>(r),
+                Append::OP_CODE => parse_node::<Append, R>(r),
+                CreateProveDlog::OP_CODE => parse_node::<CreateProveDlog, R>(r),
+                CreateProveDhTuple::OP_CODE => parse_node::<CreateProveDhTuple, R>(r),
+                SigmaPropBytes::OP_CODE => parse_node::<SigmaPropBytes, R>(r),
+                Tuple::OP_CODE => parse_node::<Tuple, R>(r),
+                DecodePoint::OP_CODE => parse_node::<DecodePoint, R>(r),
+                Exponentiate::OP_CODE => parse_node::<Exponentiate, R>(r),
+                MultiplyGroup::OP_CODE => parse_node::<MultiplyGroup, R>(r),
+                ModQ::OP_CODE => parse_node::<ModQ, R>(r),
+                PlusModQ::OP_CODE => parse_node::<PlusModQ, R>(r),
+                MinusModQ::OP_CODE => parse_node::<MinusModQ, R>(r),
+                ByteArrayToLong::OP_CODE => parse_node::<ByteArrayToLong, R>(r),
+                ByteArrayToBigInt::OP_CODE => parse_node::<ByteArrayToBigInt, R>(r),
+                LongToByteArray::OP_CODE => parse_node::<LongToByteArray, R>(r),
+                SigmaAnd::OP_CODE => parse_node::<SigmaAnd, R>(r),
+                SigmaOr::OP_CODE => parse_node::<SigmaOr, R>(r),
+                GetVar::OP_CODE => parse_node::<GetVar, R>(r),
+                DeserializeRegister::OP_CODE => parse_node::<DeserializeRegister, R>(r),
+                DeserializeContext::OP_CODE => parse_node::<DeserializeContext, R>(r),
+                SubstConstants::OP_CODE => parse_node::<SubstConstants, R>(r),
                 o => Err(SerializationError::NotImplementedOpCode(format!(
                     "{0}(shift {1})",
                     o.value(),
@@ -175,6 +204,14 @@ impl Expr {
     }
 }
 
+/// Parses the node and converts it into [`Expr`]. Keeps the node's temporaries out of the
+/// `parse_with_tag` frame, which is on the stack once per nesting level of the parsed tree.
+fn parse_node<T: SigmaSerializable + Into<Expr>, R: SigmaByteRead>(
+    r: &mut R,
+) -> Result<Expr, SerializationError> {
+    Ok(T::sigma_parse(r)?.into())
+}
+
 impl SigmaSerializable for Expr {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         match self {
@@ -200,8 +237,11 @@ impl SigmaSerializable for Expr {
                     Expr::MethodCall(mc) => mc.sigma_serialize(w),
                     Expr::ProperyCall(pc) => pc.sigma_serialize(w),
                     Expr::Global => Ok(()),
+                    Expr::GroupGenerator => Ok(()),
                     Expr::Context => Ok(()),
                     Expr::OptionGet(v) => v.sigma_serialize(w),
+                    Expr::SomeValue(v) => v.sigma_serialize(w),
+                    Expr::NoneValue(v) => v.sigma_serialize(w),
                     Expr::ExtractRegisterAs(v) => v.sigma_serialize(w),
                     Expr::BinOp(op) => bin_op_sigma_serialize(op, w),
                     Expr::BlockValue(op) => op.sigma_serialize(w),
@@ -223,10 +263,15 @@ impl SigmaSerializable for Expr {
                     Expr::Filter(op) => op.sigma_serialize(w),
                     Expr::BoolToSigmaProp(op) => op.sigma_serialize(w),
                     Expr::Upcast(op) => op.sigma_serialize(w),
+                    Expr::Downcast(op) => op.sigma_serialize(w),
                     Expr::If(op) => op.sigma_serialize(w),
                     Expr::ByIndex(op) => op.sigma_serialize(w),
                     Expr::ExtractScriptBytes(op) => op.sigma_serialize(w),
+                    Expr::ExtractBytes(op) => op.sigma_serialize(w),
+                    Expr::ExtractBytesWithNoRef(op) => op.sigma_serialize(w),
                     Expr::SizeOf(op) => op.sigma_serialize(w),
+                    Expr::Slice(op) => op.sigma_serialize(w),
+                    Expr::Append(op) => op.sigma_serialize(w),
                     Expr::CreateProveDlog(op) => op.sigma_serialize(w),
                     Expr::CreateProveDhTuple(op) => op.sigma_serialize(w),
                     Expr::ExtractCreationInfo(op) => op.sigma_serialize(w),
@@ -239,6 +284,12 @@ impl SigmaSerializable for Expr {
                     Expr::ForAll(op) => op.sigma_serialize(w),
                     Expr::Tuple(op) => op.sigma_serialize(w),
                     Expr::DecodePoint(op) => op.sigma_serialize(w),
+                    Expr::Exponentiate(op) => op.sigma_serialize(w),
+                    Expr::MultiplyGroup(op) => op.sigma_serialize(w),
+                    Expr::ModQ(op) => op.sigma_serialize(w),
+                    Expr::PlusModQ(op) => op.sigma_serialize(w),
+                    Expr::SubstConstants(op) => op.sigma_serialize(w),
+                    Expr::MinusModQ(op) => op.sigma_serialize(w),
                     Expr::SigmaAnd(op) => op.sigma_serialize(w),
                     Expr::SigmaOr(op) => op.sigma_serialize(w),
                     Expr::GetVar(op) => op.sigma_serialize(w),
@@ -250,8 +301,10 @@ impl SigmaSerializable for Expr {
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
-        let tag = r.get_u8()?;
-        Self::parse_with_tag(r, tag)
+        r.nested(|r| {
+            let tag = r.get_u8()?;
+            Self::parse_with_tag(r, tag)
+        })
     }
 }
 
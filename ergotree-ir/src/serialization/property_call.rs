@@ -23,10 +23,8 @@ impl SigmaSerializable for PropertyCall {
         let type_id = TypeCode::sigma_parse(r)?;
         let method_id = MethodId::sigma_parse(r)?;
         let obj = Expr::sigma_parse(r)?;
-        Ok(PropertyCall::new(
-            obj,
-            SMethod::from_ids(type_id, method_id)?,
-        )?)
+        let method = SMethod::from_ids(type_id, method_id)?.specialize_for(obj.tpe(), vec![])?;
+        Ok(PropertyCall::new(obj, method)?)
     }
 }
 
@@ -37,7 +35,9 @@ mod tests {
     use crate::mir::expr::Expr;
     use crate::mir::property_call::PropertyCall;
     use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::scoll;
     use crate::types::scontext;
+    use crate::types::stype::SType;
 
     #[test]
     fn ser_roundtrip_property() {
@@ -45,4 +45,16 @@ mod tests {
         let expr = Expr::ProperyCall(mc);
         assert_eq![sigma_serialize_roundtrip(&expr), expr];
     }
+
+    #[test]
+    fn ser_roundtrip_generic_property() {
+        let obj: Expr = vec![1i64, 2i64].into();
+        let method = scoll::SIZE_METHOD
+            .clone()
+            .specialize_for(obj.tpe(), vec![])
+            .unwrap();
+        assert_eq!(method.tpe().t_dom, vec![SType::SColl(SType::SLong.into())]);
+        let expr: Expr = PropertyCall::new(obj, method).unwrap().into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
 }
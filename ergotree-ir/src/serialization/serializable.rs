@@ -60,6 +60,9 @@ pub enum SerializationError {
     /// Unknown method ID for given type code
     #[error("No method id {0:?} found in type companion with type id {1:?} ")]
     UnknownMethodId(MethodId, TypeCode),
+    /// Unknown type ID (no type companion for given type code)
+    #[error("No type companion found for type id {0:?}")]
+    UnknownTypeId(TypeCode),
 }
 
 impl From<io::Error> for SerializationError {
@@ -134,12 +137,8 @@ impl<T: SigmaSerializable> SigmaSerializable for Vec<T> {
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
-        let items_count = r.get_u32()?;
-        let mut items = Vec::with_capacity(items_count as usize);
-        for _ in 0..items_count {
-            items.push(T::sigma_parse(r)?);
-        }
-        Ok(items)
+        let items_count = r.get_coll_len()?;
+        (0..items_count).map(|_| T::sigma_parse(r)).collect()
     }
 }
 
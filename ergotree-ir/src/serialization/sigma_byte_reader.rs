@@ -1,16 +1,40 @@
 //! Sigma byte stream writer
 use super::constant_store::ConstantStore;
 use super::val_def_type_store::ValDefTypeStore;
+use super::SerializationError;
 use sigma_ser::vlq_encode::ReadSigmaVlqExt;
 use std::io::Cursor;
 use std::io::Read;
 
+/// Maximum nesting depth of the parsed expressions, same as the maximum tree depth of the
+/// reference implementation (110), which limits only the nesting of expressions (types, data
+/// values and sigma propositions are limited separately by [`MAX_DATA_DEPTH`]).
+/// Protects the parser from the stack overflow on a maliciously crafted input: an expression
+/// nesting level takes up to ~12Kb of stack in the debug build (~2Kb in release), so that a tree
+/// of the maximum depth can be parsed on a thread with the default (2Mb) stack size.
+pub const MAX_TREE_DEPTH: usize = 110;
+
+/// Maximum nesting depth of the parsed types, data values and sigma propositions (counted
+/// separately from the expressions). The reference implementation has no explicit limit for
+/// them, so it is set well above the type nesting an expression tree of the maximum depth can
+/// produce, only to protect the parser from the stack overflow. A tree of the maximum depth with
+/// a constant of the maximum depth takes less than 1Mb of stack in the release build (but up to
+/// 4Mb in the debug build).
+pub const MAX_DATA_DEPTH: usize = 256;
+
+/// Maximum number of items in the parsed collections.
+/// Protects the parser from allocating huge amounts of memory on a maliciously crafted input.
+pub const MAX_COLL_LENGTH: u32 = u16::MAX as u32;
+
 /// Implementation of SigmaByteRead
 pub struct SigmaByteReader<R> {
     inner: R,
     constant_store: ConstantStore,
     substitute_placeholders: bool,
     val_def_type_store: ValDefTypeStore,
+    was_deserialize: bool,
+    depth: usize,
+    data_depth: usize,
 }
 
 impl<R: Read> SigmaByteReader<R> {
@@ -21,6 +45,9 @@ impl<R: Read> SigmaByteReader<R> {
             constant_store,
             substitute_placeholders: false,
             val_def_type_store: ValDefTypeStore::new(),
+            was_deserialize: false,
+            depth: 0,
+            data_depth: 0,
         }
     }
 
@@ -35,6 +62,9 @@ impl<R: Read> SigmaByteReader<R> {
             constant_store,
             substitute_placeholders: true,
             val_def_type_store: ValDefTypeStore::new(),
+            was_deserialize: false,
+            depth: 0,
+            data_depth: 0,
         }
     }
 }
@@ -46,6 +76,9 @@ pub fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> SigmaByteReader<Cursor<T>> {
         constant_store: ConstantStore::empty(),
         substitute_placeholders: false,
         val_def_type_store: ValDefTypeStore::new(),
+        was_deserialize: false,
+        depth: 0,
+        data_depth: 0,
     }
 }
 
@@ -62,6 +95,74 @@ pub trait SigmaByteRead: ReadSigmaVlqExt {
 
     /// ValDef types store (resolves tpe on ValUse parsing)
     fn val_def_type_store(&mut self) -> &mut ValDefTypeStore;
+
+    /// Returns true if DeserializeContext/DeserializeRegister was parsed by this reader
+    fn was_deserialize(&self) -> bool;
+
+    /// Marks that DeserializeContext/DeserializeRegister was parsed by this reader
+    fn set_deserialize(&mut self);
+
+    /// Current nesting depth of the parsed expression tree
+    fn depth(&self) -> usize;
+
+    /// Set current nesting depth of the parsed expression tree
+    fn set_depth(&mut self, depth: usize);
+
+    /// Current nesting depth of the parsed type, data value or sigma proposition
+    fn data_depth(&self) -> usize;
+
+    /// Set current nesting depth of the parsed type, data value or sigma proposition
+    fn set_data_depth(&mut self, depth: usize);
+
+    /// Run expression `parse` one nesting level deeper, fails if [`MAX_TREE_DEPTH`] is exceeded
+    /// (the root expression is at level 0, as in the reference implementation)
+    fn nested<T, F>(&mut self, parse: F) -> Result<T, SerializationError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, SerializationError>,
+    {
+        let depth = self.depth();
+        if depth > MAX_TREE_DEPTH {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "expression nesting depth exceeds the limit of {}",
+                MAX_TREE_DEPTH
+            )));
+        }
+        self.set_depth(depth + 1);
+        let res = parse(self);
+        self.set_depth(depth);
+        res
+    }
+
+    /// Run type, data value or sigma proposition `parse` one nesting level deeper, fails if
+    /// [`MAX_DATA_DEPTH`] is exceeded
+    fn nested_data<T, F>(&mut self, parse: F) -> Result<T, SerializationError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, SerializationError>,
+    {
+        let depth = self.data_depth();
+        if depth >= MAX_DATA_DEPTH {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "type/data nesting depth exceeds the limit of {}",
+                MAX_DATA_DEPTH
+            )));
+        }
+        self.set_data_depth(depth + 1);
+        let res = parse(self);
+        self.set_data_depth(depth);
+        res
+    }
+
+    /// Read collection length, fails if it exceeds [`MAX_COLL_LENGTH`]
+    fn get_coll_len(&mut self) -> Result<u32, SerializationError> {
+        let len = self.get_u32()?;
+        if len > MAX_COLL_LENGTH {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "collection length {} exceeds the limit of {}",
+                len, MAX_COLL_LENGTH
+            )));
+        }
+        Ok(len)
+    }
 }
 
 impl<R: Read> Read for SigmaByteReader<R> {
@@ -86,4 +187,149 @@ impl<R: ReadSigmaVlqExt> SigmaByteRead for SigmaByteReader<R> {
     fn val_def_type_store(&mut self) -> &mut ValDefTypeStore {
         &mut self.val_def_type_store
     }
+
+    fn was_deserialize(&self) -> bool {
+        self.was_deserialize
+    }
+
+    fn set_deserialize(&mut self) {
+        self.was_deserialize = true;
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn data_depth(&self) -> usize {
+        self.data_depth
+    }
+
+    fn set_data_depth(&mut self, depth: usize) {
+        self.data_depth = depth;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mir::bin_op::BinOp;
+    use crate::mir::bin_op::RelationOp;
+    use crate::mir::collection::Collection;
+    use crate::mir::constant::Constant;
+    use crate::mir::expr::Expr;
+    use crate::mir::logical_not::LogicalNot;
+    use crate::mir::value::CollKind;
+    use crate::mir::value::Value;
+    use crate::serialization::sigma_byte_writer::SigmaByteWriter;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+    use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+
+    fn nested_not(depth: usize) -> Expr {
+        (0..depth).fold(Expr::Const(true.into()), |acc, _| {
+            LogicalNot {
+                input: Box::new(acc),
+            }
+            .into()
+        })
+    }
+
+    #[test]
+    fn expr_depth_limit() {
+        // types and data of the constant are not counted
+        let expr = nested_not(MAX_TREE_DEPTH);
+        assert_eq!(
+            Expr::sigma_parse_bytes(&expr.sigma_serialize_bytes()).unwrap(),
+            expr
+        );
+        let bytes = nested_not(MAX_TREE_DEPTH + 1).sigma_serialize_bytes();
+        assert!(matches!(
+            Expr::sigma_parse_bytes(&bytes),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn coll_expr_depth_limit() {
+        // collections take the most stack per nesting level
+        let expr = (0..MAX_TREE_DEPTH).fold(Expr::Const(true.into()), |acc, _| {
+            Collection::new(acc.tpe(), vec![acc]).unwrap().into()
+        });
+        assert_eq!(
+            Expr::sigma_parse_bytes(&expr.sigma_serialize_bytes()).unwrap(),
+            expr
+        );
+    }
+
+    // unoptimized (debug) build needs more stack than the default for the test thread
+    fn with_big_stack<F: FnOnce() + Send + 'static>(f: F) {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn type_depth_limit() {
+        with_big_stack(|| {
+            let nested_option =
+                |depth: usize| (0..depth).fold(SType::SInt, |acc, _| SType::SOption(acc.into()));
+            // innermost Option[Int] is serialized as a single type code
+            let tpe = nested_option(MAX_DATA_DEPTH);
+            assert_eq!(
+                SType::sigma_parse_bytes(&tpe.sigma_serialize_bytes()).unwrap(),
+                tpe
+            );
+            assert!(matches!(
+                SType::sigma_parse_bytes(
+                    &nested_option(MAX_DATA_DEPTH + 1).sigma_serialize_bytes()
+                ),
+                Err(SerializationError::ValueOutOfBounds(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn expr_and_data_depth_limits_combined() {
+        with_big_stack(|| {
+            let value = (0..MAX_DATA_DEPTH - 1).fold(Constant::from(1i32), |acc, _| Constant {
+                tpe: SType::SColl(acc.tpe.clone().into()),
+                v: Value::Coll(CollKind::from_vec(acc.tpe, vec![acc.v]).unwrap()),
+            });
+            let eq: Expr = BinOp {
+                kind: RelationOp::Eq.into(),
+                left: Box::new(value.clone().into()),
+                right: Box::new(value.into()),
+            }
+            .into();
+            let expr = (0..MAX_TREE_DEPTH - 1).fold(eq, |acc, _| {
+                LogicalNot {
+                    input: Box::new(acc),
+                }
+                .into()
+            });
+            assert_eq!(
+                Expr::sigma_parse_bytes(&expr.sigma_serialize_bytes()).unwrap(),
+                expr
+            );
+        });
+    }
+
+    #[test]
+    fn coll_length_limit() {
+        let mut bytes = Vec::new();
+        let mut w = SigmaByteWriter::new(&mut bytes, None);
+        w.put_u32(MAX_COLL_LENGTH + 1).unwrap();
+        assert!(matches!(
+            Vec::<Constant>::sigma_parse_bytes(&bytes),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
 }
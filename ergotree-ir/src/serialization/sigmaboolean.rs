@@ -3,11 +3,16 @@ use crate::has_opcode::HasOpCode;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
 };
+use crate::sigma_protocol::sigma_boolean::cand::Cand;
+use crate::sigma_protocol::sigma_boolean::cor::Cor;
+use crate::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+use crate::sigma_protocol::sigma_boolean::SigmaConjecture;
 use crate::sigma_protocol::{
     dlog_group::EcPoint,
-    sigma_boolean::{ProveDlog, SigmaBoolean, SigmaProofOfKnowledgeTree},
+    sigma_boolean::{ProveDhTuple, ProveDlog, SigmaBoolean, SigmaProofOfKnowledgeTree},
 };
 
+use std::convert::TryInto;
 use std::io;
 
 impl SigmaSerializable for SigmaBoolean {
@@ -15,25 +20,90 @@ impl SigmaSerializable for SigmaBoolean {
         self.op_code().sigma_serialize(w)?;
         match self {
             SigmaBoolean::ProofOfKnowledge(proof) => match proof {
-                SigmaProofOfKnowledgeTree::ProveDhTuple { .. } => todo!(),
+                SigmaProofOfKnowledgeTree::ProveDhTuple(v) => v.sigma_serialize(w),
                 SigmaProofOfKnowledgeTree::ProveDlog(v) => v.sigma_serialize(w),
             },
-            SigmaBoolean::SigmaConjecture(_) => todo!(),
+            SigmaBoolean::SigmaConjecture(conj) => match conj {
+                SigmaConjecture::Cthreshold(th) => {
+                    w.put_u16(th.k as u16)?;
+                    w.put_u16(th.children.len() as u16)?;
+                    th.children.iter().try_for_each(|c| c.sigma_serialize(w))
+                }
+                SigmaConjecture::Cand(cand) => {
+                    w.put_u16(cand.items.len() as u16)?;
+                    cand.items.iter().try_for_each(|c| c.sigma_serialize(w))
+                }
+                SigmaConjecture::Cor(cor) => {
+                    w.put_u16(cor.items.len() as u16)?;
+                    cor.items.iter().try_for_each(|c| c.sigma_serialize(w))
+                }
+            },
             SigmaBoolean::TrivialProp(_) => Ok(()), // besides opCode no additional bytes
         }
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
-        let op_code = OpCode::sigma_parse(r)?;
-        match op_code {
-            OpCode::PROVE_DLOG => Ok(SigmaBoolean::ProofOfKnowledge(
-                SigmaProofOfKnowledgeTree::ProveDlog(ProveDlog::sigma_parse(r)?),
-            )),
-            _ => todo!(),
+        r.nested_data(sigma_boolean_parse)
+    }
+}
+
+fn sigma_boolean_parse<R: SigmaByteRead>(r: &mut R) -> Result<SigmaBoolean, SerializationError> {
+    let op_code = OpCode::sigma_parse(r)?;
+    match op_code {
+        OpCode::PROVE_DLOG => Ok(SigmaBoolean::ProofOfKnowledge(
+            SigmaProofOfKnowledgeTree::ProveDlog(ProveDlog::sigma_parse(r)?),
+        )),
+        OpCode::PROVE_DIFFIE_HELLMAN_TUPLE => Ok(SigmaBoolean::ProofOfKnowledge(
+            SigmaProofOfKnowledgeTree::ProveDhTuple(ProveDhTuple::sigma_parse(r)?),
+        )),
+        OpCode::ATLEAST => {
+            let k = r.get_u16()?;
+            let n = r.get_u16()?;
+            if k as usize > Cthreshold::MAX_CHILDREN_COUNT
+                || n as usize > Cthreshold::MAX_CHILDREN_COUNT
+            {
+                return Err(SerializationError::ValueOutOfBounds(format!(
+                    "CTHRESHOLD: k ({}) and number of children ({}) should be <= {}",
+                    k,
+                    n,
+                    Cthreshold::MAX_CHILDREN_COUNT
+                )));
+            }
+            let children = parse_children(r, n)?;
+            Ok(SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(
+                Cthreshold {
+                    k: k as u8,
+                    children: children.try_into()?,
+                },
+            )))
         }
+        OpCode::TRIVIAL_PROP_FALSE => Ok(SigmaBoolean::TrivialProp(false)),
+        OpCode::TRIVIAL_PROP_TRUE => Ok(SigmaBoolean::TrivialProp(true)),
+        OpCode::AND => {
+            let n = r.get_u16()?;
+            let items = parse_children(r, n)?;
+            Ok(SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(Cand {
+                items: items.try_into()?,
+            })))
+        }
+        OpCode::OR => {
+            let n = r.get_u16()?;
+            let items = parse_children(r, n)?;
+            Ok(SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(Cor {
+                items: items.try_into()?,
+            })))
+        }
+        _ => Err(SerializationError::InvalidOpCode(op_code.value())),
     }
 }
 
+fn parse_children<R: SigmaByteRead>(
+    r: &mut R,
+    n: u16,
+) -> Result<Vec<SigmaBoolean>, SerializationError> {
+    (0..n).map(|_| SigmaBoolean::sigma_parse(r)).collect()
+}
+
 impl SigmaSerializable for ProveDlog {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         self.h.sigma_serialize(w)
@@ -44,3 +114,91 @@ impl SigmaSerializable for ProveDlog {
         Ok(ProveDlog::new(p))
     }
 }
+
+impl SigmaSerializable for ProveDhTuple {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        self.gv.sigma_serialize(w)?;
+        self.hv.sigma_serialize(w)?;
+        self.uv.sigma_serialize(w)?;
+        self.vv.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let gv = EcPoint::sigma_parse(r)?;
+        let hv = EcPoint::sigma_parse(r)?;
+        let uv = EcPoint::sigma_parse(r)?;
+        let vv = EcPoint::sigma_parse(r)?;
+        Ok(ProveDhTuple::new(gv, hv, uv, vv))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::sigma_boolean::SigmaConjectureItems;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<SigmaBoolean>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+
+        #[test]
+        fn ser_roundtrip_cthreshold(children in vec(any::<SigmaBoolean>(), 2..10), k in 1..10u8) {
+            let v: SigmaBoolean = SigmaConjecture::Cthreshold(Cthreshold {
+                k,
+                children: children.try_into().unwrap(),
+            })
+            .into();
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+    }
+
+    #[test]
+    fn cand_cor_bytes() {
+        // opcodes and layout as in sigmastate
+        let pk1: SigmaBoolean = force_any_val::<ProveDlog>().into();
+        let pk2: SigmaBoolean = force_any_val::<ProveDhTuple>().into();
+        let items: SigmaConjectureItems<SigmaBoolean> =
+            vec![pk1.clone(), pk2.clone()].try_into().unwrap();
+        let cand: SigmaBoolean = SigmaConjecture::Cand(Cand {
+            items: items.clone(),
+        })
+        .into();
+        let cor: SigmaBoolean = SigmaConjecture::Cor(Cor { items }).into();
+        let children_bytes = [pk1.sigma_serialize_bytes(), pk2.sigma_serialize_bytes()].concat();
+        assert_eq!(
+            cand.sigma_serialize_bytes(),
+            [vec![0x96, 2], children_bytes.clone()].concat()
+        );
+        assert_eq!(
+            cor.sigma_serialize_bytes(),
+            [vec![0x97, 2], children_bytes].concat()
+        );
+        assert_eq!(sigma_serialize_roundtrip(&cand), cand);
+        assert_eq!(sigma_serialize_roundtrip(&cor), cor);
+    }
+
+    #[test]
+    fn ser_roundtrip_trivial_prop() {
+        let v = SigmaBoolean::TrivialProp(true);
+        assert_eq![sigma_serialize_roundtrip(&v), v];
+        let v = SigmaBoolean::TrivialProp(false);
+        assert_eq![sigma_serialize_roundtrip(&v), v];
+    }
+
+    #[test]
+    fn parse_invalid_op_code() {
+        assert_eq!(
+            SigmaBoolean::sigma_parse_bytes(&[OpCode::HEIGHT.value()]),
+            Err(SerializationError::InvalidOpCode(OpCode::HEIGHT.value()))
+        );
+    }
+}
@@ -41,6 +41,8 @@ impl TypeCode {
     pub const SHEADER: TypeCode = Self::new(104);
     pub const SPRE_HEADER: TypeCode = Self::new(105);
     pub const SGLOBAL: TypeCode = Self::new(106);
+    /// Function type (not serializable, used only as a method signature type)
+    pub const SFUNC: TypeCode = Self::new(112);
 
     const COLLECTION_CONSTR_ID: u8 = 1;
     pub const COLLECTION: TypeCode =
@@ -270,7 +272,10 @@ impl SigmaSerializable for SType {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         // for reference see http://github.com/ScorexFoundation/sigmastate-interpreter/blob/25251c1313b0131835f92099f02cef8a5d932b5e/sigmastate/src/main/scala/sigmastate/serialization/TypeSerializer.scala#L25-L25
         match self {
-            SType::SFunc(_) => panic!("SFunc is not supposed to be here"),
+            SType::SFunc(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "SFunc type serialization is not supported",
+            )),
             SType::SAny => self.type_code().sigma_serialize(w),
             SType::SBoolean => self.type_code().sigma_serialize(w),
             SType::SByte => self.type_code().sigma_serialize(w),
@@ -366,8 +371,10 @@ impl SigmaSerializable for SType {
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
         // for reference see http://github.com/ScorexFoundation/sigmastate-interpreter/blob/25251c1313b0131835f92099f02cef8a5d932b5e/sigmastate/src/main/scala/sigmastate/serialization/TypeSerializer.scala#L118-L118
-        let c = TypeCode::sigma_parse(r)?;
-        Self::parse_with_type_code(r, c)
+        r.nested_data(|r| {
+            let c = TypeCode::sigma_parse(r)?;
+            Self::parse_with_type_code(r, c)
+        })
     }
 }
 
@@ -19,7 +19,8 @@ use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
 };
 use k256::elliptic_curve::sec1::ToEncodedPoint;
-use k256::{ProjectivePoint, PublicKey, Scalar};
+use k256::{FieldBytes, ProjectivePoint, PublicKey, Scalar};
+use num_bigint::{BigInt, Sign};
 use sigma_ser::vlq_encode;
 
 use std::{
@@ -93,6 +94,33 @@ pub fn exponentiate(base: &EcPoint, exponent: &Scalar) -> EcPoint {
     }
 }
 
+/// Order of the group (secp256k1 curve order n)
+const GROUP_ORDER_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Order of the group (secp256k1 curve order n) as an integer
+pub fn order_bigint() -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &GROUP_ORDER_BYTES)
+}
+
+/// Non-negative remainder of the division by the group order, i.e. a value in [0, n)
+pub fn mod_order(bi: &BigInt) -> BigInt {
+    let order = order_bigint();
+    ((bi % &order) + &order) % &order
+}
+
+/// Converts the integer to the scalar (exponent) by reducing it modulo the group order.
+/// Negative integers are mapped to the corresponding inverse exponents.
+pub fn bigint_to_scalar(bi: &BigInt) -> Scalar {
+    let reduced = mod_order(bi);
+    let (_, bytes) = reduced.to_bytes_be();
+    let mut buf = [0u8; 32];
+    buf[32 - bytes.len()..].copy_from_slice(&bytes);
+    Scalar::from_bytes_reduced(&FieldBytes::from(buf))
+}
+
 // /// Creates a random member of this Dlog group
 // pub fn random_element() -> EcPoint {
 //     let sk = DlogProverInput::random();
@@ -168,4 +196,13 @@ mod tests {
             prop_assert_eq![sigma_serialize_roundtrip(&e), e];
         }
     }
+
+    #[test]
+    fn bigint_to_scalar_reduction() {
+        let order = order_bigint();
+        assert_eq!(bigint_to_scalar(&BigInt::from(1)), Scalar::one());
+        assert_eq!(bigint_to_scalar(&order), Scalar::zero());
+        assert_eq!(bigint_to_scalar(&(order + 2)), Scalar::from(2u32));
+        assert_eq!(bigint_to_scalar(&BigInt::from(-1)), -Scalar::one());
+    }
 }
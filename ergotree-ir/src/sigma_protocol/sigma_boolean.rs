@@ -2,6 +2,7 @@
 
 use self::cand::Cand;
 use self::cor::Cor;
+use self::cthreshold::Cthreshold;
 
 use super::dlog_group::EcPoint;
 use crate::ergo_tree::ErgoTree;
@@ -21,6 +22,7 @@ use derive_more::TryInto;
 
 pub mod cand;
 pub mod cor;
+pub mod cthreshold;
 
 /// Sigma conjecture items type with bounds check (2..=1000)
 pub type SigmaConjectureItems<T> = BoundedVec<T, 2, 1000>;
@@ -61,6 +63,18 @@ pub struct ProveDhTuple {
     pub vv: Box<EcPoint>,
 }
 
+impl ProveDhTuple {
+    /// Create new instance
+    pub fn new(gv: EcPoint, hv: EcPoint, uv: EcPoint, vv: EcPoint) -> Self {
+        Self {
+            gv: gv.into(),
+            hv: hv.into(),
+            uv: uv.into(),
+            vv: vv.into(),
+        }
+    }
+}
+
 /// Sigma proposition
 #[derive(PartialEq, Eq, Debug, Clone, From)]
 pub enum SigmaProofOfKnowledgeTree {
@@ -77,6 +91,19 @@ pub enum SigmaConjecture {
     Cand(Cand),
     /// OR
     Cor(Cor),
+    /// THRESHOLD
+    Cthreshold(Cthreshold),
+}
+
+impl SigmaConjecture {
+    /// Children of the conjecture
+    pub fn children(&self) -> &SigmaConjectureItems<SigmaBoolean> {
+        match self {
+            SigmaConjecture::Cand(cand) => &cand.items,
+            SigmaConjecture::Cor(cor) => &cor.items,
+            SigmaConjecture::Cthreshold(ct) => &ct.children,
+        }
+    }
 }
 
 /// Algebraic data type of sigma proposition expressions
@@ -91,6 +118,18 @@ pub enum SigmaBoolean {
     SigmaConjecture(SigmaConjecture),
 }
 
+impl SigmaBoolean {
+    /// Number of nodes in the proposition tree
+    pub fn size(&self) -> usize {
+        match self {
+            SigmaBoolean::SigmaConjecture(conj) => {
+                1 + conj.children().iter().map(|c| c.size()).sum::<usize>()
+            }
+            _ => 1,
+        }
+    }
+}
+
 impl HasOpCode for SigmaBoolean {
     /// get OpCode for serialization
     fn op_code(&self) -> OpCode {
@@ -98,7 +137,14 @@ impl HasOpCode for SigmaBoolean {
             SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(_)) => {
                 OpCode::PROVE_DLOG
             }
-            _ => todo!(),
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(_)) => {
+                OpCode::PROVE_DIFFIE_HELLMAN_TUPLE
+            }
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(_)) => OpCode::AND,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(_)) => OpCode::OR,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(_)) => OpCode::ATLEAST,
+            SigmaBoolean::TrivialProp(true) => OpCode::TRIVIAL_PROP_TRUE,
+            SigmaBoolean::TrivialProp(false) => OpCode::TRIVIAL_PROP_FALSE,
         }
     }
 }
@@ -123,6 +169,12 @@ impl From<ProveDlog> for SigmaBoolean {
     }
 }
 
+impl From<ProveDhTuple> for SigmaBoolean {
+    fn from(v: ProveDhTuple) -> Self {
+        SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(v))
+    }
+}
+
 /// Proposition which can be proven and verified by sigma protocol.
 #[derive(PartialEq, Eq, Debug, Clone, From, Into)]
 pub struct SigmaProp(SigmaBoolean);
@@ -172,10 +224,12 @@ impl From<ProveDhTuple> for SigmaProp {
         ))
     }
 }
-/// Arbitrary impl for ProveDlog
+/// Arbitrary impls
 #[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
 mod arbitrary {
     use super::*;
+    use proptest::collection::vec;
     use proptest::prelude::*;
 
     impl Arbitrary for ProveDlog {
@@ -187,19 +241,57 @@ mod arbitrary {
         }
     }
 
-    impl Arbitrary for SigmaBoolean {
+    impl Arbitrary for ProveDhTuple {
         type Parameters = ();
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-            (any::<ProveDlog>())
-                .prop_map(|p| {
-                    SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(p))
-                })
+            (
+                any::<EcPoint>(),
+                any::<EcPoint>(),
+                any::<EcPoint>(),
+                any::<EcPoint>(),
+            )
+                .prop_map(|(gv, hv, uv, vv)| ProveDhTuple::new(gv, hv, uv, vv))
                 .boxed()
         }
     }
 
+    impl Arbitrary for SigmaBoolean {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            let leaf = prop_oneof![
+                any::<ProveDlog>().prop_map_into(),
+                any::<ProveDhTuple>().prop_map_into(),
+            ];
+            leaf.prop_recursive(2, 16, 4, |elem| {
+                prop_oneof![
+                    vec(elem.clone(), 2..4).prop_map(|items| {
+                        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(Cand {
+                            items: items.try_into().unwrap(),
+                        }))
+                    }),
+                    vec(elem.clone(), 2..4).prop_map(|items| {
+                        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(Cor {
+                            items: items.try_into().unwrap(),
+                        }))
+                    }),
+                    vec(elem, 2..4).prop_flat_map(|children| {
+                        (1..=children.len() as u8).prop_map(move |k| {
+                            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(Cthreshold {
+                                k,
+                                children: children.clone().try_into().unwrap(),
+                            }))
+                        })
+                    }),
+                ]
+            })
+            .boxed()
+        }
+    }
+
     impl Arbitrary for SigmaProp {
         type Parameters = ();
         type Strategy = BoxedStrategy<Self>;
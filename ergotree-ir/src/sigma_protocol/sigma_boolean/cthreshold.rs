@@ -0,0 +1,198 @@
+//! THRESHOLD conjunction for sigma proposition
+use std::convert::TryInto;
+
+use super::cand::Cand;
+use super::cor::Cor;
+use super::SigmaBoolean;
+use super::SigmaConjectureItems;
+use crate::sigma_protocol::sigma_boolean::SigmaConjecture;
+
+/// THRESHOLD conjunction for sigma proposition
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Cthreshold {
+    /// Number of conjectures to be proven
+    pub k: u8,
+    /// Items of the conjecture
+    pub children: SigmaConjectureItems<SigmaBoolean>,
+}
+
+impl Cthreshold {
+    /// Maximum number of children
+    pub const MAX_CHILDREN_COUNT: usize = 255;
+
+    /// Connects the given sigma propositions into CTHRESHOLD proposition performing
+    /// partial evaluation when some of them are trivial propositioins.
+    /// Reduces to COR if `k == 1` and to CAND if `k` is equal to the number of non-trivial children.
+    /// Expects no more than [`Cthreshold::MAX_CHILDREN_COUNT`] children.
+    pub fn reduce(k: usize, children: SigmaConjectureItems<SigmaBoolean>) -> SigmaBoolean {
+        if k == 0 {
+            return true.into();
+        }
+        if k > children.len() {
+            return false.into();
+        }
+
+        let mut cur_bound = k;
+        let mut children_left = children.len();
+        // invariant due to the two if statements above: 0<cur_bound<=children_left
+
+        let mut sigmas: Vec<SigmaBoolean> = Vec::with_capacity(children.len());
+        let all_children: Vec<SigmaBoolean> = children.into();
+        for (i_child, child) in all_children.iter().enumerate() {
+            if cur_bound == 1 {
+                sigmas.extend_from_slice(&all_children[i_child..]);
+                return normalized_or(sigmas);
+            }
+            // If at any point bound == number of children, convert to AND.
+            if cur_bound == children_left {
+                sigmas.extend_from_slice(&all_children[i_child..]);
+                return normalized_and(sigmas);
+            }
+            // at this point 1<cur_bound<children_left
+            match child {
+                // If child is true, remove child and reduce bound.
+                SigmaBoolean::TrivialProp(true) => {
+                    children_left -= 1;
+                    cur_bound -= 1;
+                }
+                // If child is false, remove child, leave bound unchanged.
+                SigmaBoolean::TrivialProp(false) => children_left -= 1,
+                sigma => sigmas.push(sigma.clone()),
+            }
+            // at this point 0<=cur_bound<=children_left
+        }
+        if cur_bound == 1 {
+            return normalized_or(sigmas);
+        }
+        if cur_bound == children_left {
+            return normalized_and(sigmas);
+        }
+        #[allow(clippy::unwrap_used)]
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(Cthreshold {
+            // cur_bound <= k <= children count which is expected to be <= MAX_CHILDREN_COUNT
+            k: cur_bound as u8,
+            // cur_bound > 1 and cur_bound < children_left so there is 3 or more children
+            children: sigmas.try_into().unwrap(),
+        }))
+    }
+}
+
+fn normalized_or(items: Vec<SigmaBoolean>) -> SigmaBoolean {
+    if items.len() < 2 {
+        single_or_trivial(items, false)
+    } else {
+        #[allow(clippy::unwrap_used)]
+        // should be 2 or more so unwrap is safe here
+        Cor::normalized(items.try_into().unwrap())
+    }
+}
+
+fn normalized_and(items: Vec<SigmaBoolean>) -> SigmaBoolean {
+    if items.len() < 2 {
+        single_or_trivial(items, true)
+    } else {
+        #[allow(clippy::unwrap_used)]
+        // should be 2 or more so unwrap is safe here
+        Cand::normalized(items.try_into().unwrap())
+    }
+}
+
+/// Handles the case of less than two items which cannot be turned into a conjecture
+fn single_or_trivial(items: Vec<SigmaBoolean>, if_empty: bool) -> SigmaBoolean {
+    items
+        .into_iter()
+        .next()
+        .unwrap_or(SigmaBoolean::TrivialProp(if_empty))
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sigma_protocol::sigma_boolean::ProveDlog;
+    use sigma_test_util::force_any_val;
+    use std::convert::TryInto;
+
+    #[test]
+    fn k_zero() {
+        let pk = force_any_val::<ProveDlog>();
+        let th = Cthreshold::reduce(0, vec![pk.into(), false.into()].try_into().unwrap());
+        assert!(matches!(th, SigmaBoolean::TrivialProp(true)));
+    }
+
+    #[test]
+    fn k_too_big() {
+        let pk = force_any_val::<ProveDlog>();
+        let th = Cthreshold::reduce(3, vec![pk.into(), true.into()].try_into().unwrap());
+        assert!(matches!(th, SigmaBoolean::TrivialProp(false)));
+    }
+
+    #[test]
+    fn k_one_is_or() {
+        let pk1 = force_any_val::<ProveDlog>();
+        let pk2 = force_any_val::<ProveDlog>();
+        let th = Cthreshold::reduce(1, vec![pk1.into(), pk2.into()].try_into().unwrap());
+        assert!(matches!(
+            th,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(_))
+        ));
+    }
+
+    #[test]
+    fn k_eq_n_is_and() {
+        let pk1 = force_any_val::<ProveDlog>();
+        let pk2 = force_any_val::<ProveDlog>();
+        let th = Cthreshold::reduce(2, vec![pk1.into(), pk2.into()].try_into().unwrap());
+        assert!(matches!(
+            th,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(_))
+        ));
+    }
+
+    #[test]
+    fn trivial_true_reduces_bound() {
+        let pk1 = force_any_val::<ProveDlog>();
+        let pk2 = force_any_val::<ProveDlog>();
+        let pk3 = force_any_val::<ProveDlog>();
+        // atLeast(2, true, pk1, pk2, pk3) == atLeast(1, pk1, pk2, pk3) == pk1 || pk2 || pk3
+        let th = Cthreshold::reduce(
+            2,
+            vec![true.into(), pk1.into(), pk2.into(), pk3.into()]
+                .try_into()
+                .unwrap(),
+        );
+        assert!(matches!(
+            th,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(Cor { items })) if items.len() == 3
+        ));
+    }
+
+    #[test]
+    fn trivial_false_removed() {
+        let pk = force_any_val::<ProveDlog>();
+        // atLeast(2, false, pk, true) == atLeast(2, pk, true) == pk && true == pk
+        let th = Cthreshold::reduce(
+            2,
+            vec![false.into(), pk.clone().into(), true.into()]
+                .try_into()
+                .unwrap(),
+        );
+        let res: ProveDlog = th.try_into().unwrap();
+        assert_eq!(res, pk);
+    }
+
+    #[test]
+    fn threshold() {
+        let pks: Vec<SigmaBoolean> = (0..4)
+            .map(|_| force_any_val::<ProveDlog>().into())
+            .collect();
+        let th = Cthreshold::reduce(2, pks.clone().try_into().unwrap());
+        assert_eq!(
+            th,
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(Cthreshold {
+                k: 2,
+                children: pks.try_into().unwrap()
+            }))
+        );
+    }
+}
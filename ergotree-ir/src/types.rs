@@ -2,6 +2,8 @@
 
 pub mod stype;
 
+/// AvlTree object type companion
+pub mod savltree;
 /// Box object type companion
 pub mod sbox;
 /// Collection object type companion
@@ -11,10 +13,16 @@ pub mod scontext;
 pub mod sfunc;
 /// Global methods
 pub mod sglobal;
+/// GroupElement object type companion
+pub mod sgroup_elem;
 /// Header's methods
 pub mod sheader;
 /// Object method(property) signature type
 pub mod smethod;
+/// Numeric types (Byte, Short, Int, Long, BigInt) object type companions
+pub mod snumeric;
+/// Option object type companion
+pub mod soption;
 /// PreHeader's methods
 pub mod spreheader;
 /// Tuple type
@@ -0,0 +1,262 @@
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stuple::STuple;
+use super::stype::SType;
+use super::stype::SType::{SAvlTree, SBoolean, SByte, SColl, SInt, SOption};
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use lazy_static::lazy_static;
+
+/// SAvlTree type id
+pub const TYPE_ID: TypeCode = TypeCode::SAVL_TREE;
+/// AvlTree.digest property
+pub const DIGEST_METHOD_ID: MethodId = MethodId(1);
+/// AvlTree.enabledOperations property
+pub const ENABLED_OPERATIONS_METHOD_ID: MethodId = MethodId(2);
+/// AvlTree.keyLength property
+pub const KEY_LENGTH_METHOD_ID: MethodId = MethodId(3);
+/// AvlTree.valueLengthOpt property
+pub const VALUE_LENGTH_OPT_METHOD_ID: MethodId = MethodId(4);
+/// AvlTree.isInsertAllowed property
+pub const IS_INSERT_ALLOWED_METHOD_ID: MethodId = MethodId(5);
+/// AvlTree.isUpdateAllowed property
+pub const IS_UPDATE_ALLOWED_METHOD_ID: MethodId = MethodId(6);
+/// AvlTree.isRemoveAllowed property
+pub const IS_REMOVE_ALLOWED_METHOD_ID: MethodId = MethodId(7);
+/// AvlTree.updateOperations method
+pub const UPDATE_OPERATIONS_METHOD_ID: MethodId = MethodId(8);
+/// AvlTree.contains method
+pub const CONTAINS_METHOD_ID: MethodId = MethodId(9);
+/// AvlTree.get method
+pub const GET_METHOD_ID: MethodId = MethodId(10);
+/// AvlTree.getMany method
+pub const GET_MANY_METHOD_ID: MethodId = MethodId(11);
+/// AvlTree.insert method
+pub const INSERT_METHOD_ID: MethodId = MethodId(12);
+/// AvlTree.update method
+pub const UPDATE_METHOD_ID: MethodId = MethodId(13);
+/// AvlTree.remove method
+pub const REMOVE_METHOD_ID: MethodId = MethodId(14);
+/// AvlTree.updateDigest method
+pub const UPDATE_DIGEST_METHOD_ID: MethodId = MethodId(15);
+
+static S_AVL_TREE_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TYPE_ID,
+    type_name: "AvlTree",
+};
+
+lazy_static! {
+    /// AvlTree object type companion
+    pub static ref S_AVL_TREE_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_AVL_TREE_TYPE_COMPANION_HEAD,
+        vec![
+            &DIGEST_METHOD_DESC,
+            &ENABLED_OPERATIONS_METHOD_DESC,
+            &KEY_LENGTH_METHOD_DESC,
+            &VALUE_LENGTH_OPT_METHOD_DESC,
+            &IS_INSERT_ALLOWED_METHOD_DESC,
+            &IS_UPDATE_ALLOWED_METHOD_DESC,
+            &IS_REMOVE_ALLOWED_METHOD_DESC,
+            &UPDATE_OPERATIONS_METHOD_DESC,
+            &CONTAINS_METHOD_DESC,
+            &GET_METHOD_DESC,
+            &GET_MANY_METHOD_DESC,
+            &INSERT_METHOD_DESC,
+            &UPDATE_METHOD_DESC,
+            &REMOVE_METHOD_DESC,
+            &UPDATE_DIGEST_METHOD_DESC,
+        ]
+    );
+}
+
+fn coll_byte() -> SType {
+    SColl(SByte.into())
+}
+
+fn key_value_colls() -> SType {
+    SColl(SType::STuple(STuple::pair(coll_byte(), coll_byte())).into())
+}
+
+lazy_static! {
+    static ref DIGEST_METHOD_DESC: SMethodDesc =
+        property("digest", coll_byte(), DIGEST_METHOD_ID);
+    /// AvlTree.digest
+    pub static ref DIGEST_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, DIGEST_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref ENABLED_OPERATIONS_METHOD_DESC: SMethodDesc =
+        property("enabledOperations", SByte, ENABLED_OPERATIONS_METHOD_ID);
+    /// AvlTree.enabledOperations
+    pub static ref ENABLED_OPERATIONS_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, ENABLED_OPERATIONS_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref KEY_LENGTH_METHOD_DESC: SMethodDesc =
+        property("keyLength", SInt, KEY_LENGTH_METHOD_ID);
+    /// AvlTree.keyLength
+    pub static ref KEY_LENGTH_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, KEY_LENGTH_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref VALUE_LENGTH_OPT_METHOD_DESC: SMethodDesc =
+        property("valueLengthOpt", SOption(SInt.into()), VALUE_LENGTH_OPT_METHOD_ID);
+    /// AvlTree.valueLengthOpt
+    pub static ref VALUE_LENGTH_OPT_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, VALUE_LENGTH_OPT_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref IS_INSERT_ALLOWED_METHOD_DESC: SMethodDesc =
+        property("isInsertAllowed", SBoolean, IS_INSERT_ALLOWED_METHOD_ID);
+    /// AvlTree.isInsertAllowed
+    pub static ref IS_INSERT_ALLOWED_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, IS_INSERT_ALLOWED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref IS_UPDATE_ALLOWED_METHOD_DESC: SMethodDesc =
+        property("isUpdateAllowed", SBoolean, IS_UPDATE_ALLOWED_METHOD_ID);
+    /// AvlTree.isUpdateAllowed
+    pub static ref IS_UPDATE_ALLOWED_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, IS_UPDATE_ALLOWED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref IS_REMOVE_ALLOWED_METHOD_DESC: SMethodDesc =
+        property("isRemoveAllowed", SBoolean, IS_REMOVE_ALLOWED_METHOD_ID);
+    /// AvlTree.isRemoveAllowed
+    pub static ref IS_REMOVE_ALLOWED_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, IS_REMOVE_ALLOWED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref UPDATE_OPERATIONS_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_OPERATIONS_METHOD_ID,
+        name: "updateOperations",
+        tpe: SFunc::new(vec![SAvlTree, SByte], SAvlTree),
+    };
+    /// AvlTree.updateOperations
+    pub static ref UPDATE_OPERATIONS_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, UPDATE_OPERATIONS_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref CONTAINS_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: CONTAINS_METHOD_ID,
+        name: "contains",
+        tpe: SFunc::new(vec![SAvlTree, coll_byte(), coll_byte()], SBoolean),
+    };
+    /// AvlTree.contains
+    pub static ref CONTAINS_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, CONTAINS_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref GET_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_METHOD_ID,
+        name: "get",
+        tpe: SFunc::new(
+            vec![SAvlTree, coll_byte(), coll_byte()],
+            SOption(coll_byte().into())
+        ),
+    };
+    /// AvlTree.get
+    pub static ref GET_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, GET_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref GET_MANY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_MANY_METHOD_ID,
+        name: "getMany",
+        tpe: SFunc::new(
+            vec![SAvlTree, SColl(coll_byte().into()), coll_byte()],
+            SColl(SOption(coll_byte().into()).into())
+        ),
+    };
+    /// AvlTree.getMany
+    pub static ref GET_MANY_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, GET_MANY_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref INSERT_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: INSERT_METHOD_ID,
+        name: "insert",
+        tpe: SFunc::new(
+            vec![SAvlTree, key_value_colls(), coll_byte()],
+            SOption(SAvlTree.into())
+        ),
+    };
+    /// AvlTree.insert
+    pub static ref INSERT_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, INSERT_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref UPDATE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_METHOD_ID,
+        name: "update",
+        tpe: SFunc::new(
+            vec![SAvlTree, key_value_colls(), coll_byte()],
+            SOption(SAvlTree.into())
+        ),
+    };
+    /// AvlTree.update
+    pub static ref UPDATE_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, UPDATE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref REMOVE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: REMOVE_METHOD_ID,
+        name: "remove",
+        tpe: SFunc::new(
+            vec![SAvlTree, SColl(coll_byte().into()), coll_byte()],
+            SOption(SAvlTree.into())
+        ),
+    };
+    /// AvlTree.remove
+    pub static ref REMOVE_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, REMOVE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref UPDATE_DIGEST_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_DIGEST_METHOD_ID,
+        name: "updateDigest",
+        tpe: SFunc::new(vec![SAvlTree, coll_byte()], SAvlTree),
+    };
+    /// AvlTree.updateDigest
+    pub static ref UPDATE_DIGEST_METHOD: SMethod =
+        SMethod::new(&S_AVL_TREE_TYPE_COMPANION, UPDATE_DIGEST_METHOD_DESC.clone());
+}
+
+fn property(name: &'static str, res_tpe: SType, id: MethodId) -> SMethodDesc {
+    SMethodDesc::property(SAvlTree, name, res_tpe, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ids() {
+        assert!(SMethod::from_ids(TYPE_ID, DIGEST_METHOD_ID).map(|e| e.name()) == Ok("digest"));
+        assert!(SMethod::from_ids(TYPE_ID, CONTAINS_METHOD_ID).map(|e| e.name()) == Ok("contains"));
+        assert!(SMethod::from_ids(TYPE_ID, GET_MANY_METHOD_ID).map(|e| e.name()) == Ok("getMany"));
+        assert!(SMethod::from_ids(TYPE_ID, INSERT_METHOD_ID).map(|e| e.name()) == Ok("insert"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, UPDATE_DIGEST_METHOD_ID).map(|e| e.name())
+                == Ok("updateDigest")
+        );
+    }
+}
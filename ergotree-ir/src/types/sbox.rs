@@ -15,10 +15,40 @@ use lazy_static::lazy_static;
 pub const TYPE_ID: TypeCode = TypeCode::SBOX;
 /// Box.value property
 pub const VALUE_METHOD_ID: MethodId = MethodId(1);
+/// Box.propositionBytes property
+pub const PROPOSITION_BYTES_METHOD_ID: MethodId = MethodId(2);
+/// Box.bytes property
+pub const BYTES_METHOD_ID: MethodId = MethodId(3);
+/// Box.bytesWithoutRef property
+pub const BYTES_WITHOUT_REF_METHOD_ID: MethodId = MethodId(4);
+/// Box.id property
+pub const ID_METHOD_ID: MethodId = MethodId(5);
+/// Box.creationInfo property
+pub const CREATION_INFO_METHOD_ID: MethodId = MethodId(6);
 /// Box.Rx property
 pub const GET_REG_METHOD_ID: MethodId = MethodId(7);
 /// Box.tokens property
 pub const TOKENS_METHOD_ID: MethodId = MethodId(8);
+/// Box.R0 property
+pub const R0_METHOD_ID: MethodId = MethodId(9);
+/// Box.R1 property
+pub const R1_METHOD_ID: MethodId = MethodId(10);
+/// Box.R2 property
+pub const R2_METHOD_ID: MethodId = MethodId(11);
+/// Box.R3 property
+pub const R3_METHOD_ID: MethodId = MethodId(12);
+/// Box.R4 property
+pub const R4_METHOD_ID: MethodId = MethodId(13);
+/// Box.R5 property
+pub const R5_METHOD_ID: MethodId = MethodId(14);
+/// Box.R6 property
+pub const R6_METHOD_ID: MethodId = MethodId(15);
+/// Box.R7 property
+pub const R7_METHOD_ID: MethodId = MethodId(16);
+/// Box.R8 property
+pub const R8_METHOD_ID: MethodId = MethodId(17);
+/// Box.R9 property
+pub const R9_METHOD_ID: MethodId = MethodId(18);
 
 static S_BOX_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
     type_id: TYPE_ID,
@@ -32,7 +62,22 @@ lazy_static! {
         vec![
             &GET_REG_METHOD_DESC,
             &VALUE_METHOD_DESC,
-            &TOKENS_METHOD_DESC
+            &TOKENS_METHOD_DESC,
+            &PROPOSITION_BYTES_METHOD_DESC,
+            &BYTES_METHOD_DESC,
+            &BYTES_WITHOUT_REF_METHOD_DESC,
+            &ID_METHOD_DESC,
+            &CREATION_INFO_METHOD_DESC,
+            &R0_METHOD_DESC,
+            &R1_METHOD_DESC,
+            &R2_METHOD_DESC,
+            &R3_METHOD_DESC,
+            &R4_METHOD_DESC,
+            &R5_METHOD_DESC,
+            &R6_METHOD_DESC,
+            &R7_METHOD_DESC,
+            &R8_METHOD_DESC,
+            &R9_METHOD_DESC,
         ]
     );
 }
@@ -85,6 +130,110 @@ lazy_static! {
         SMethod::new(&S_BOX_TYPE_COMPANION, TOKENS_METHOD_DESC.clone(),);
 }
 
+lazy_static! {
+    static ref PROPOSITION_BYTES_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SBox, "propositionBytes", SType::SColl(SType::SByte.into()), PROPOSITION_BYTES_METHOD_ID);
+    /// Box.propositionBytes
+    pub static ref PROPOSITION_BYTES_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, PROPOSITION_BYTES_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref BYTES_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SBox, "bytes", SType::SColl(SType::SByte.into()), BYTES_METHOD_ID);
+    /// Box.bytes
+    pub static ref BYTES_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, BYTES_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref BYTES_WITHOUT_REF_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SBox, "bytesWithoutRef", SType::SColl(SType::SByte.into()), BYTES_WITHOUT_REF_METHOD_ID);
+    /// Box.bytesWithoutRef
+    pub static ref BYTES_WITHOUT_REF_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, BYTES_WITHOUT_REF_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref ID_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SBox, "id", SType::SColl(SType::SByte.into()), ID_METHOD_ID);
+    /// Box.id
+    pub static ref ID_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, ID_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref CREATION_INFO_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SBox, "creationInfo", STuple::pair(SType::SInt, SType::SColl(SType::SByte.into())).into(), CREATION_INFO_METHOD_ID);
+    /// Box.creationInfo
+    pub static ref CREATION_INFO_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, CREATION_INFO_METHOD_DESC.clone(),);
+}
+
+fn register_method_desc(name: &'static str, method_id: MethodId) -> SMethodDesc {
+    SMethodDesc::property(
+        SType::SBox,
+        name,
+        SType::SOption(Box::new(STypeVar::t().into())),
+        method_id,
+    )
+}
+
+lazy_static! {
+    static ref R0_METHOD_DESC: SMethodDesc = register_method_desc("R0", R0_METHOD_ID);
+    /// Box.R0
+    pub static ref R0_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R0_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R1_METHOD_DESC: SMethodDesc = register_method_desc("R1", R1_METHOD_ID);
+    /// Box.R1
+    pub static ref R1_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R1_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R2_METHOD_DESC: SMethodDesc = register_method_desc("R2", R2_METHOD_ID);
+    /// Box.R2
+    pub static ref R2_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R2_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R3_METHOD_DESC: SMethodDesc = register_method_desc("R3", R3_METHOD_ID);
+    /// Box.R3
+    pub static ref R3_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R3_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R4_METHOD_DESC: SMethodDesc = register_method_desc("R4", R4_METHOD_ID);
+    /// Box.R4
+    pub static ref R4_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R4_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R5_METHOD_DESC: SMethodDesc = register_method_desc("R5", R5_METHOD_ID);
+    /// Box.R5
+    pub static ref R5_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R5_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R6_METHOD_DESC: SMethodDesc = register_method_desc("R6", R6_METHOD_ID);
+    /// Box.R6
+    pub static ref R6_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R6_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R7_METHOD_DESC: SMethodDesc = register_method_desc("R7", R7_METHOD_ID);
+    /// Box.R7
+    pub static ref R7_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R7_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R8_METHOD_DESC: SMethodDesc = register_method_desc("R8", R8_METHOD_ID);
+    /// Box.R8
+    pub static ref R8_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R8_METHOD_DESC.clone(),);
+}
+
+lazy_static! {
+    static ref R9_METHOD_DESC: SMethodDesc = register_method_desc("R9", R9_METHOD_ID);
+    /// Box.R9
+    pub static ref R9_METHOD: SMethod = SMethod::new(&S_BOX_TYPE_COMPANION, R9_METHOD_DESC.clone(),);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,5 +243,21 @@ mod tests {
         assert!(SMethod::from_ids(TYPE_ID, VALUE_METHOD_ID).map(|e| e.name()) == Ok("value"));
         assert!(SMethod::from_ids(TYPE_ID, GET_REG_METHOD_ID).map(|e| e.name()) == Ok("getReg"));
         assert!(SMethod::from_ids(TYPE_ID, TOKENS_METHOD_ID).map(|e| e.name()) == Ok("tokens"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, PROPOSITION_BYTES_METHOD_ID).map(|e| e.name())
+                == Ok("propositionBytes")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, BYTES_METHOD_ID).map(|e| e.name()) == Ok("bytes"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, BYTES_WITHOUT_REF_METHOD_ID).map(|e| e.name())
+                == Ok("bytesWithoutRef")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, ID_METHOD_ID).map(|e| e.name()) == Ok("id"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, CREATION_INFO_METHOD_ID).map(|e| e.name())
+                == Ok("creationInfo")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, R0_METHOD_ID).map(|e| e.name()) == Ok("R0"));
+        assert!(SMethod::from_ids(TYPE_ID, R9_METHOD_ID).map(|e| e.name()) == Ok("R9"));
     }
 }
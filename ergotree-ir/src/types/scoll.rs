@@ -4,6 +4,7 @@ use super::sfunc::SFunc;
 use super::smethod::MethodId;
 use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
+use super::stuple::STuple;
 use super::stype::SType;
 use super::stype_companion::STypeCompanion;
 use super::stype_companion::STypeCompanionHead;
@@ -12,10 +13,40 @@ use lazy_static::lazy_static;
 
 /// type id
 pub const TYPE_ID: TypeCode = TypeCode::COLLECTION;
-/// Coll.indexOf
-pub const INDEX_OF_METHOD_ID: MethodId = MethodId(26);
+/// Coll.size
+pub const SIZE_METHOD_ID: MethodId = MethodId(1);
+/// Coll.getOrElse
+pub const GET_OR_ELSE_METHOD_ID: MethodId = MethodId(2);
+/// Coll.map
+pub const MAP_METHOD_ID: MethodId = MethodId(3);
+/// Coll.exists
+pub const EXISTS_METHOD_ID: MethodId = MethodId(4);
+/// Coll.fold
+pub const FOLD_METHOD_ID: MethodId = MethodId(5);
+/// Coll.forall
+pub const FORALL_METHOD_ID: MethodId = MethodId(6);
+/// Coll.slice
+pub const SLICE_METHOD_ID: MethodId = MethodId(7);
+/// Coll.filter
+pub const FILTER_METHOD_ID: MethodId = MethodId(8);
+/// Coll.append
+pub const APPEND_METHOD_ID: MethodId = MethodId(9);
+/// Coll.apply
+pub const APPLY_METHOD_ID: MethodId = MethodId(10);
+/// Coll.indices
+pub const INDICES_METHOD_ID: MethodId = MethodId(14);
 /// Coll.flatmap
 pub const FLATMAP_METHOD_ID: MethodId = MethodId(15);
+/// Coll.patch
+pub const PATCH_METHOD_ID: MethodId = MethodId(19);
+/// Coll.updated
+pub const UPDATED_METHOD_ID: MethodId = MethodId(20);
+/// Coll.updateMany
+pub const UPDATE_MANY_METHOD_ID: MethodId = MethodId(21);
+/// Coll.indexOf
+pub const INDEX_OF_METHOD_ID: MethodId = MethodId(26);
+/// Coll.zip
+pub const ZIP_METHOD_ID: MethodId = MethodId(29);
 
 static S_COLL_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
     type_id: TYPE_ID,
@@ -27,12 +58,164 @@ lazy_static! {
     pub static ref S_COLL_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
         &S_COLL_TYPE_COMPANION_HEAD,
         vec![
+            &SIZE_METHOD_DESC,
+            &GET_OR_ELSE_METHOD_DESC,
+            &MAP_METHOD_DESC,
+            &EXISTS_METHOD_DESC,
+            &FOLD_METHOD_DESC,
+            &FORALL_METHOD_DESC,
+            &SLICE_METHOD_DESC,
+            &FILTER_METHOD_DESC,
+            &APPEND_METHOD_DESC,
+            &APPLY_METHOD_DESC,
+            &INDICES_METHOD_DESC,
+            &FLATMAP_METHOD_DESC,
+            &PATCH_METHOD_DESC,
+            &UPDATED_METHOD_DESC,
+            &UPDATE_MANY_METHOD_DESC,
             &INDEX_OF_METHOD_DESC,
-            &FLATMAP_METHOD_DESC
+            &ZIP_METHOD_DESC,
         ]
     );
 }
 
+fn coll_t() -> SType {
+    SType::SColl(SType::STypeVar(STypeVar::t()).into())
+}
+
+fn t() -> SType {
+    STypeVar::t().into()
+}
+
+fn predicate_t() -> SType {
+    SFunc::new(vec![t()], SType::SBoolean).into()
+}
+
+lazy_static! {
+    static ref SIZE_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(coll_t(), "size", SType::SInt, SIZE_METHOD_ID);
+    /// Coll.size
+    pub static ref SIZE_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, SIZE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref GET_OR_ELSE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_OR_ELSE_METHOD_ID,
+        name: "getOrElse",
+        tpe: SFunc::new(vec![coll_t(), SType::SInt, t()], t()),
+    };
+    /// Coll.getOrElse
+    pub static ref GET_OR_ELSE_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, GET_OR_ELSE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref MAP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: MAP_METHOD_ID,
+        name: "map",
+        tpe: SFunc::new(
+            vec![
+                SType::SColl(SType::STypeVar(STypeVar::iv()).into()),
+                SFunc::new(vec![STypeVar::iv().into()], STypeVar::ov().into()).into(),
+            ],
+            SType::SColl(SType::STypeVar(STypeVar::ov()).into()),
+        ),
+    };
+    /// Coll.map
+    pub static ref MAP_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, MAP_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref EXISTS_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: EXISTS_METHOD_ID,
+        name: "exists",
+        tpe: SFunc::new(vec![coll_t(), predicate_t()], SType::SBoolean),
+    };
+    /// Coll.exists
+    pub static ref EXISTS_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, EXISTS_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref FOLD_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: FOLD_METHOD_ID,
+        name: "fold",
+        tpe: SFunc::new(
+            vec![
+                SType::SColl(SType::STypeVar(STypeVar::iv()).into()),
+                STypeVar::ov().into(),
+                SFunc::new(
+                    vec![STuple::pair(STypeVar::ov().into(), STypeVar::iv().into()).into()],
+                    STypeVar::ov().into(),
+                )
+                .into(),
+            ],
+            STypeVar::ov().into(),
+        ),
+    };
+    /// Coll.fold
+    pub static ref FOLD_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, FOLD_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref FORALL_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: FORALL_METHOD_ID,
+        name: "forall",
+        tpe: SFunc::new(vec![coll_t(), predicate_t()], SType::SBoolean),
+    };
+    /// Coll.forall
+    pub static ref FORALL_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, FORALL_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref SLICE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: SLICE_METHOD_ID,
+        name: "slice",
+        tpe: SFunc::new(vec![coll_t(), SType::SInt, SType::SInt], coll_t()),
+    };
+    /// Coll.slice
+    pub static ref SLICE_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, SLICE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref FILTER_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: FILTER_METHOD_ID,
+        name: "filter",
+        tpe: SFunc::new(vec![coll_t(), predicate_t()], coll_t()),
+    };
+    /// Coll.filter
+    pub static ref FILTER_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, FILTER_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref APPEND_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: APPEND_METHOD_ID,
+        name: "append",
+        tpe: SFunc::new(vec![coll_t(), coll_t()], coll_t()),
+    };
+    /// Coll.append
+    pub static ref APPEND_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, APPEND_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref APPLY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: APPLY_METHOD_ID,
+        name: "apply",
+        tpe: SFunc::new(vec![coll_t(), SType::SInt], t()),
+    };
+    /// Coll.apply
+    pub static ref APPLY_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, APPLY_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref INDICES_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        coll_t(),
+        "indices",
+        SType::SColl(SType::SInt.into()),
+        INDICES_METHOD_ID
+    );
+    /// Coll.indices
+    pub static ref INDICES_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, INDICES_METHOD_DESC.clone());
+}
+
 lazy_static! {
     static ref INDEX_OF_METHOD_DESC: SMethodDesc = SMethodDesc {
         method_id: INDEX_OF_METHOD_ID,
@@ -66,6 +249,54 @@ lazy_static! {
     pub static ref FLATMAP_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, FLATMAP_METHOD_DESC.clone());
 }
 
+lazy_static! {
+    static ref PATCH_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: PATCH_METHOD_ID,
+        name: "patch",
+        tpe: SFunc::new(vec![coll_t(), SType::SInt, coll_t(), SType::SInt], coll_t()),
+    };
+    /// Coll.patch
+    pub static ref PATCH_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, PATCH_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref UPDATED_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATED_METHOD_ID,
+        name: "updated",
+        tpe: SFunc::new(vec![coll_t(), SType::SInt, t()], coll_t()),
+    };
+    /// Coll.updated
+    pub static ref UPDATED_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, UPDATED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref UPDATE_MANY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_MANY_METHOD_ID,
+        name: "updateMany",
+        tpe: SFunc::new(vec![coll_t(), SType::SColl(SType::SInt.into()), coll_t()], coll_t()),
+    };
+    /// Coll.updateMany
+    pub static ref UPDATE_MANY_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, UPDATE_MANY_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref ZIP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: ZIP_METHOD_ID,
+        name: "zip",
+        tpe: SFunc::new(
+            vec![
+                SType::SColl(SType::STypeVar(STypeVar::iv()).into()),
+                SType::SColl(SType::STypeVar(STypeVar::ov()).into()),
+            ],
+            SType::SColl(
+                SType::STuple(STuple::pair(STypeVar::iv().into(), STypeVar::ov().into())).into()
+            ),
+        ),
+    };
+    /// Coll.zip
+    pub static ref ZIP_METHOD: SMethod = SMethod::new(&S_COLL_TYPE_COMPANION, ZIP_METHOD_DESC.clone());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,5 +305,12 @@ mod tests {
     fn test_from_ids() {
         assert!(SMethod::from_ids(TYPE_ID, INDEX_OF_METHOD_ID).map(|e| e.name()) == Ok("indexOf"));
         assert!(SMethod::from_ids(TYPE_ID, FLATMAP_METHOD_ID).map(|e| e.name()) == Ok("flatMap"));
+        assert!(SMethod::from_ids(TYPE_ID, SIZE_METHOD_ID).map(|e| e.name()) == Ok("size"));
+        assert!(SMethod::from_ids(TYPE_ID, FOLD_METHOD_ID).map(|e| e.name()) == Ok("fold"));
+        assert!(SMethod::from_ids(TYPE_ID, PATCH_METHOD_ID).map(|e| e.name()) == Ok("patch"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, UPDATE_MANY_METHOD_ID).map(|e| e.name()) == Ok("updateMany")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, ZIP_METHOD_ID).map(|e| e.name()) == Ok("zip"));
     }
 }
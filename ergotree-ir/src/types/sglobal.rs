@@ -2,6 +2,7 @@ use crate::serialization::types::TypeCode;
 
 use super::sfunc::SFunc;
 use super::smethod::MethodId;
+use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
 use super::stype::SType;
 use super::stype_companion::STypeCompanion;
@@ -42,6 +43,9 @@ lazy_static! {
             tpe_params: vec![],
         },
     };
+    /// Global.groupGenerator
+    pub static ref GROUP_GENERATOR_METHOD: SMethod =
+        SMethod::new(&S_GLOBAL_TYPE_COMPANION, GROUP_GENERATOR_METHOD_DESC.clone());
 }
 
 lazy_static! {
@@ -0,0 +1,97 @@
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype::SType::{SBigInt, SByte, SColl, SGroupElement};
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use lazy_static::lazy_static;
+
+/// SGroupElement type id
+pub const TYPE_ID: TypeCode = TypeCode::SGROUP_ELEMENT;
+/// GroupElement.getEncoded property
+pub const GET_ENCODED_METHOD_ID: MethodId = MethodId(2);
+/// GroupElement.exp method
+pub const EXP_METHOD_ID: MethodId = MethodId(3);
+/// GroupElement.multiply method
+pub const MULTIPLY_METHOD_ID: MethodId = MethodId(4);
+/// GroupElement.negate property
+pub const NEGATE_METHOD_ID: MethodId = MethodId(5);
+
+static S_GROUP_ELEMENT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TYPE_ID,
+    type_name: "GroupElement",
+};
+
+lazy_static! {
+    /// GroupElement object type companion
+    pub static ref S_GROUP_ELEMENT_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_GROUP_ELEMENT_TYPE_COMPANION_HEAD,
+        vec![
+            &GET_ENCODED_METHOD_DESC,
+            &EXP_METHOD_DESC,
+            &MULTIPLY_METHOD_DESC,
+            &NEGATE_METHOD_DESC,
+        ]
+    );
+}
+
+lazy_static! {
+    static ref GET_ENCODED_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SGroupElement,
+        "getEncoded",
+        SColl(SByte.into()),
+        GET_ENCODED_METHOD_ID
+    );
+    /// GroupElement.getEncoded
+    pub static ref GET_ENCODED_METHOD: SMethod =
+        SMethod::new(&S_GROUP_ELEMENT_TYPE_COMPANION, GET_ENCODED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref EXP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: EXP_METHOD_ID,
+        name: "exp",
+        tpe: SFunc::new(vec![SGroupElement, SBigInt], SGroupElement),
+    };
+    /// GroupElement.exp
+    pub static ref EXP_METHOD: SMethod =
+        SMethod::new(&S_GROUP_ELEMENT_TYPE_COMPANION, EXP_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref MULTIPLY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: MULTIPLY_METHOD_ID,
+        name: "multiply",
+        tpe: SFunc::new(vec![SGroupElement, SGroupElement], SGroupElement),
+    };
+    /// GroupElement.multiply
+    pub static ref MULTIPLY_METHOD: SMethod =
+        SMethod::new(&S_GROUP_ELEMENT_TYPE_COMPANION, MULTIPLY_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref NEGATE_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SGroupElement, "negate", SType::SGroupElement, NEGATE_METHOD_ID);
+    /// GroupElement.negate
+    pub static ref NEGATE_METHOD: SMethod =
+        SMethod::new(&S_GROUP_ELEMENT_TYPE_COMPANION, NEGATE_METHOD_DESC.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ids() {
+        assert!(
+            SMethod::from_ids(TYPE_ID, GET_ENCODED_METHOD_ID).map(|e| e.name()) == Ok("getEncoded")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, EXP_METHOD_ID).map(|e| e.name()) == Ok("exp"));
+        assert!(SMethod::from_ids(TYPE_ID, MULTIPLY_METHOD_ID).map(|e| e.name()) == Ok("multiply"));
+        assert!(SMethod::from_ids(TYPE_ID, NEGATE_METHOD_ID).map(|e| e.name()) == Ok("negate"));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::serialization::types::TypeCode;
 
 use super::smethod::MethodId;
+use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
 use super::stype::SType;
 use super::stype_companion::STypeCompanion;
@@ -8,8 +9,38 @@ use super::stype_companion::STypeCompanionHead;
 use crate::types::stype::SType::{SByte, SColl};
 use lazy_static::lazy_static;
 
-/// SBox type id
+/// SHeader type id
 pub const TYPE_ID: TypeCode = TypeCode::SHEADER;
+/// Header.id property
+pub const ID_METHOD_ID: MethodId = MethodId(1);
+/// Header.version property
+pub const VERSION_METHOD_ID: MethodId = MethodId(2);
+/// Header.parentId property
+pub const PARENT_ID_METHOD_ID: MethodId = MethodId(3);
+/// Header.ADProofsRoot property
+pub const AD_PROOFS_ROOT_METHOD_ID: MethodId = MethodId(4);
+/// Header.stateRoot property
+pub const STATE_ROOT_METHOD_ID: MethodId = MethodId(5);
+/// Header.transactionsRoot property
+pub const TRANSACTIONS_ROOT_METHOD_ID: MethodId = MethodId(6);
+/// Header.timestamp property
+pub const TIMESTAMP_METHOD_ID: MethodId = MethodId(7);
+/// Header.nBits property
+pub const N_BITS_METHOD_ID: MethodId = MethodId(8);
+/// Header.height property
+pub const HEIGHT_METHOD_ID: MethodId = MethodId(9);
+/// Header.extensionRoot property
+pub const EXTENSION_ROOT_METHOD_ID: MethodId = MethodId(10);
+/// Header.minerPk property
+pub const MINER_PK_METHOD_ID: MethodId = MethodId(11);
+/// Header.powOnetimePk property
+pub const POW_ONETIME_PK_METHOD_ID: MethodId = MethodId(12);
+/// Header.powNonce property
+pub const POW_NONCE_METHOD_ID: MethodId = MethodId(13);
+/// Header.powDistance property
+pub const POW_DISTANCE_METHOD_ID: MethodId = MethodId(14);
+/// Header.votes property
+pub const VOTES_METHOD_ID: MethodId = MethodId(15);
 
 static S_HEADER_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
     type_id: TYPE_ID,
@@ -17,97 +48,166 @@ static S_HEADER_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
 };
 
 lazy_static! {
-    /// Box object type companion
+    /// Header object type companion
     pub static ref S_HEADER_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
         &S_HEADER_TYPE_COMPANION_HEAD,
         vec![
             &ID_METHOD_DESC,
             &VERSION_METHOD_DESC,
-            &PARENT_ID_METHOD,
-            &AD_PROOF_ROOT_METHOD,
-            &STATE_ROOT_METHOD,
-            &TRANSACTIONS_ROOT_METHOD,
-            &TIMESTAMP_METHOD,
-            &N_BITS_METHOD,
-            &HEIGHT_METHOD,
-            &EXTENSION_ROOT_METHOD,
-            &MINER_PK_METHOD,
-            &POW_ONETIME_PK_METHOD,
-            &POW_NONCE_METHOD,
-            &POW_DISTANCE_METHOD,
-            &VOTES_METHOD,
+            &PARENT_ID_METHOD_DESC,
+            &AD_PROOFS_ROOT_METHOD_DESC,
+            &STATE_ROOT_METHOD_DESC,
+            &TRANSACTIONS_ROOT_METHOD_DESC,
+            &TIMESTAMP_METHOD_DESC,
+            &N_BITS_METHOD_DESC,
+            &HEIGHT_METHOD_DESC,
+            &EXTENSION_ROOT_METHOD_DESC,
+            &MINER_PK_METHOD_DESC,
+            &POW_ONETIME_PK_METHOD_DESC,
+            &POW_NONCE_METHOD_DESC,
+            &POW_DISTANCE_METHOD_DESC,
+            &VOTES_METHOD_DESC,
         ]
     );
 }
 
 lazy_static! {
-    static ref ID_METHOD_DESC: SMethodDesc = property("id", SColl(SByte.into()), MethodId(1));
+    static ref ID_METHOD_DESC: SMethodDesc = property("id", SColl(SByte.into()), ID_METHOD_ID);
+    /// Header.id
+    pub static ref ID_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, ID_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref VERSION_METHOD_DESC: SMethodDesc = property("id", SByte, MethodId(2));
+    static ref VERSION_METHOD_DESC: SMethodDesc = property("version", SByte, VERSION_METHOD_ID);
+    /// Header.version
+    pub static ref VERSION_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, VERSION_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref PARENT_ID_METHOD: SMethodDesc =
-        property("parentId", SColl(SByte.into()), MethodId(3));
+    static ref PARENT_ID_METHOD_DESC: SMethodDesc = property("parentId", SColl(SByte.into()), PARENT_ID_METHOD_ID);
+    /// Header.parentId
+    pub static ref PARENT_ID_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, PARENT_ID_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref AD_PROOF_ROOT_METHOD: SMethodDesc =
-        property("ADProofsRoot", SColl(SByte.into()), MethodId(4));
+    static ref AD_PROOFS_ROOT_METHOD_DESC: SMethodDesc = property("ADProofsRoot", SColl(SByte.into()), AD_PROOFS_ROOT_METHOD_ID);
+    /// Header.ADProofsRoot
+    pub static ref AD_PROOFS_ROOT_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, AD_PROOFS_ROOT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref STATE_ROOT_METHOD: SMethodDesc = property("stateRoot", SType::SAvlTree, MethodId(5));
+    static ref STATE_ROOT_METHOD_DESC: SMethodDesc = property("stateRoot", SType::SAvlTree, STATE_ROOT_METHOD_ID);
+    /// Header.stateRoot
+    pub static ref STATE_ROOT_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, STATE_ROOT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref TRANSACTIONS_ROOT_METHOD: SMethodDesc =
-        property("transactionsRoot", SColl(SByte.into()), MethodId(6));
+    static ref TRANSACTIONS_ROOT_METHOD_DESC: SMethodDesc = property("transactionsRoot", SColl(SByte.into()), TRANSACTIONS_ROOT_METHOD_ID);
+    /// Header.transactionsRoot
+    pub static ref TRANSACTIONS_ROOT_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, TRANSACTIONS_ROOT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref TIMESTAMP_METHOD: SMethodDesc = property("timestamp", SType::SLong, MethodId(7));
+    static ref TIMESTAMP_METHOD_DESC: SMethodDesc = property("timestamp", SType::SLong, TIMESTAMP_METHOD_ID);
+    /// Header.timestamp
+    pub static ref TIMESTAMP_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, TIMESTAMP_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref N_BITS_METHOD: SMethodDesc = property("nBits", SType::SLong, MethodId(8));
+    static ref N_BITS_METHOD_DESC: SMethodDesc = property("nBits", SType::SLong, N_BITS_METHOD_ID);
+    /// Header.nBits
+    pub static ref N_BITS_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, N_BITS_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref HEIGHT_METHOD: SMethodDesc = property("height", SType::SInt, MethodId(9));
+    static ref HEIGHT_METHOD_DESC: SMethodDesc = property("height", SType::SInt, HEIGHT_METHOD_ID);
+    /// Header.height
+    pub static ref HEIGHT_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, HEIGHT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref EXTENSION_ROOT_METHOD: SMethodDesc =
-        property("extensionRoot", SColl(SByte.into()), MethodId(10));
+    static ref EXTENSION_ROOT_METHOD_DESC: SMethodDesc = property("extensionRoot", SColl(SByte.into()), EXTENSION_ROOT_METHOD_ID);
+    /// Header.extensionRoot
+    pub static ref EXTENSION_ROOT_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, EXTENSION_ROOT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref MINER_PK_METHOD: SMethodDesc =
-        property("minerPk", SType::SGroupElement, MethodId(11));
+    static ref MINER_PK_METHOD_DESC: SMethodDesc = property("minerPk", SType::SGroupElement, MINER_PK_METHOD_ID);
+    /// Header.minerPk
+    pub static ref MINER_PK_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, MINER_PK_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref POW_ONETIME_PK_METHOD: SMethodDesc =
-        property("powOnetimePk", SType::SGroupElement, MethodId(12));
+    static ref POW_ONETIME_PK_METHOD_DESC: SMethodDesc = property("powOnetimePk", SType::SGroupElement, POW_ONETIME_PK_METHOD_ID);
+    /// Header.powOnetimePk
+    pub static ref POW_ONETIME_PK_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, POW_ONETIME_PK_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref POW_NONCE_METHOD: SMethodDesc =
-        property("powNonce", SColl(SByte.into()), MethodId(13));
+    static ref POW_NONCE_METHOD_DESC: SMethodDesc = property("powNonce", SColl(SByte.into()), POW_NONCE_METHOD_ID);
+    /// Header.powNonce
+    pub static ref POW_NONCE_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, POW_NONCE_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref POW_DISTANCE_METHOD: SMethodDesc =
-        property("powDistance", SType::SBigInt, MethodId(14));
+    static ref POW_DISTANCE_METHOD_DESC: SMethodDesc = property("powDistance", SType::SBigInt, POW_DISTANCE_METHOD_ID);
+    /// Header.powDistance
+    pub static ref POW_DISTANCE_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, POW_DISTANCE_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref VOTES_METHOD: SMethodDesc = property("votes", SColl(SByte.into()), MethodId(15));
+    static ref VOTES_METHOD_DESC: SMethodDesc = property("votes", SColl(SByte.into()), VOTES_METHOD_ID);
+    /// Header.votes
+    pub static ref VOTES_METHOD: SMethod = SMethod::new(&S_HEADER_TYPE_COMPANION, VOTES_METHOD_DESC.clone());
 }
 
 fn property(name: &'static str, res_tpe: SType, id: MethodId) -> SMethodDesc {
     SMethodDesc::property(SType::SHeader, name, res_tpe, id)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ids() {
+        assert!(SMethod::from_ids(TYPE_ID, ID_METHOD_ID).map(|e| e.name()) == Ok("id"));
+        assert!(SMethod::from_ids(TYPE_ID, VERSION_METHOD_ID).map(|e| e.name()) == Ok("version"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, PARENT_ID_METHOD_ID).map(|e| e.name()) == Ok("parentId")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, AD_PROOFS_ROOT_METHOD_ID).map(|e| e.name())
+                == Ok("ADProofsRoot")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, STATE_ROOT_METHOD_ID).map(|e| e.name()) == Ok("stateRoot")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, TRANSACTIONS_ROOT_METHOD_ID).map(|e| e.name())
+                == Ok("transactionsRoot")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, TIMESTAMP_METHOD_ID).map(|e| e.name()) == Ok("timestamp")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, N_BITS_METHOD_ID).map(|e| e.name()) == Ok("nBits"));
+        assert!(SMethod::from_ids(TYPE_ID, HEIGHT_METHOD_ID).map(|e| e.name()) == Ok("height"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, EXTENSION_ROOT_METHOD_ID).map(|e| e.name())
+                == Ok("extensionRoot")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, MINER_PK_METHOD_ID).map(|e| e.name()) == Ok("minerPk"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, POW_ONETIME_PK_METHOD_ID).map(|e| e.name())
+                == Ok("powOnetimePk")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, POW_NONCE_METHOD_ID).map(|e| e.name()) == Ok("powNonce")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, POW_DISTANCE_METHOD_ID).map(|e| e.name())
+                == Ok("powDistance")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, VOTES_METHOD_ID).map(|e| e.name()) == Ok("votes"));
+    }
+}
@@ -13,6 +13,7 @@ use super::stype_param::STypeVar;
 use super::type_unify::unify_many;
 use super::type_unify::TypeUnificationError;
 use crate::serialization::SerializationError::UnknownMethodId;
+use crate::serialization::SerializationError::UnknownTypeId;
 
 /// Method id unique among the methods of the same object
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -47,7 +48,7 @@ impl SMethod {
 
     /// Get method from type and method ids
     pub fn from_ids(type_id: TypeCode, method_id: MethodId) -> Result<Self, SerializationError> {
-        let obj_type = STypeCompanion::type_by_id(type_id);
+        let obj_type = STypeCompanion::type_by_id(type_id).ok_or(UnknownTypeId(type_id))?;
         match obj_type.method_by_id(&method_id) {
             Some(m) => Ok(m),
             None => Err(UnknownMethodId(method_id, type_id)),
@@ -127,3 +128,25 @@ impl SMethodDesc {
         Self { tpe, ..self }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::soption;
+
+    #[test]
+    fn from_ids_unknown_type_id() {
+        assert_eq!(
+            SMethod::from_ids(TypeCode::SBOOLEAN, MethodId(1)),
+            Err(UnknownTypeId(TypeCode::SBOOLEAN))
+        );
+    }
+
+    #[test]
+    fn from_ids_unknown_method_id() {
+        assert_eq!(
+            SMethod::from_ids(soption::TYPE_ID, MethodId(100)),
+            Err(UnknownMethodId(MethodId(100), soption::TYPE_ID))
+        );
+    }
+}
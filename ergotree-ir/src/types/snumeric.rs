@@ -0,0 +1,152 @@
+use crate::serialization::types::TypeCode;
+
+use super::smethod::MethodId;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype::SType::{SBigInt, SBoolean, SByte, SColl, SInt, SLong, SShort};
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use lazy_static::lazy_static;
+
+/// SByte type id
+pub const BYTE_TYPE_ID: TypeCode = TypeCode::SBYTE;
+/// SShort type id
+pub const SHORT_TYPE_ID: TypeCode = TypeCode::SSHORT;
+/// SInt type id
+pub const INT_TYPE_ID: TypeCode = TypeCode::SINT;
+/// SLong type id
+pub const LONG_TYPE_ID: TypeCode = TypeCode::SLONG;
+/// SBigInt type id
+pub const BIGINT_TYPE_ID: TypeCode = TypeCode::SBIGINT;
+
+/// Numeric.toByte property
+pub const TO_BYTE_METHOD_ID: MethodId = MethodId(1);
+/// Numeric.toShort property
+pub const TO_SHORT_METHOD_ID: MethodId = MethodId(2);
+/// Numeric.toInt property
+pub const TO_INT_METHOD_ID: MethodId = MethodId(3);
+/// Numeric.toLong property
+pub const TO_LONG_METHOD_ID: MethodId = MethodId(4);
+/// Numeric.toBigInt property
+pub const TO_BIGINT_METHOD_ID: MethodId = MethodId(5);
+/// Numeric.toBytes property
+pub const TO_BYTES_METHOD_ID: MethodId = MethodId(6);
+/// Numeric.toBits property
+pub const TO_BITS_METHOD_ID: MethodId = MethodId(7);
+
+/// Check if the given type id is one of the numeric types
+pub fn is_numeric_type_id(type_id: TypeCode) -> bool {
+    type_id == BYTE_TYPE_ID
+        || type_id == SHORT_TYPE_ID
+        || type_id == INT_TYPE_ID
+        || type_id == LONG_TYPE_ID
+        || type_id == BIGINT_TYPE_ID
+}
+
+// All numeric types share the same set of methods specialized for the object type
+fn method_descs(obj_tpe: SType) -> Vec<SMethodDesc> {
+    vec![
+        SMethodDesc::property(obj_tpe.clone(), "toByte", SByte, TO_BYTE_METHOD_ID),
+        SMethodDesc::property(obj_tpe.clone(), "toShort", SShort, TO_SHORT_METHOD_ID),
+        SMethodDesc::property(obj_tpe.clone(), "toInt", SInt, TO_INT_METHOD_ID),
+        SMethodDesc::property(obj_tpe.clone(), "toLong", SLong, TO_LONG_METHOD_ID),
+        SMethodDesc::property(obj_tpe.clone(), "toBigInt", SBigInt, TO_BIGINT_METHOD_ID),
+        SMethodDesc::property(
+            obj_tpe.clone(),
+            "toBytes",
+            SColl(SByte.into()),
+            TO_BYTES_METHOD_ID,
+        ),
+        SMethodDesc::property(obj_tpe, "toBits", SColl(SBoolean.into()), TO_BITS_METHOD_ID),
+    ]
+}
+
+static S_BYTE_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: BYTE_TYPE_ID,
+    type_name: "Byte",
+};
+
+static S_SHORT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: SHORT_TYPE_ID,
+    type_name: "Short",
+};
+
+static S_INT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: INT_TYPE_ID,
+    type_name: "Int",
+};
+
+static S_LONG_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: LONG_TYPE_ID,
+    type_name: "Long",
+};
+
+static S_BIGINT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: BIGINT_TYPE_ID,
+    type_name: "BigInt",
+};
+
+lazy_static! {
+    static ref S_BYTE_METHOD_DESCS: Vec<SMethodDesc> = method_descs(SByte);
+    static ref S_SHORT_METHOD_DESCS: Vec<SMethodDesc> = method_descs(SShort);
+    static ref S_INT_METHOD_DESCS: Vec<SMethodDesc> = method_descs(SInt);
+    static ref S_LONG_METHOD_DESCS: Vec<SMethodDesc> = method_descs(SLong);
+    static ref S_BIGINT_METHOD_DESCS: Vec<SMethodDesc> = method_descs(SBigInt);
+}
+
+lazy_static! {
+    /// Byte object type companion
+    pub static ref S_BYTE_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_BYTE_TYPE_COMPANION_HEAD, S_BYTE_METHOD_DESCS.iter().collect());
+    /// Short object type companion
+    pub static ref S_SHORT_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_SHORT_TYPE_COMPANION_HEAD, S_SHORT_METHOD_DESCS.iter().collect());
+    /// Int object type companion
+    pub static ref S_INT_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_INT_TYPE_COMPANION_HEAD, S_INT_METHOD_DESCS.iter().collect());
+    /// Long object type companion
+    pub static ref S_LONG_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_LONG_TYPE_COMPANION_HEAD, S_LONG_METHOD_DESCS.iter().collect());
+    /// BigInt object type companion
+    pub static ref S_BIGINT_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_BIGINT_TYPE_COMPANION_HEAD, S_BIGINT_METHOD_DESCS.iter().collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::smethod::SMethod;
+
+    #[test]
+    fn test_from_ids() {
+        for type_id in &[
+            BYTE_TYPE_ID,
+            SHORT_TYPE_ID,
+            INT_TYPE_ID,
+            LONG_TYPE_ID,
+            BIGINT_TYPE_ID,
+        ] {
+            let type_id = *type_id;
+            assert!(is_numeric_type_id(type_id));
+            assert!(
+                SMethod::from_ids(type_id, TO_BYTE_METHOD_ID).map(|e| e.name()) == Ok("toByte")
+            );
+            assert!(
+                SMethod::from_ids(type_id, TO_SHORT_METHOD_ID).map(|e| e.name()) == Ok("toShort")
+            );
+            assert!(SMethod::from_ids(type_id, TO_INT_METHOD_ID).map(|e| e.name()) == Ok("toInt"));
+            assert!(
+                SMethod::from_ids(type_id, TO_LONG_METHOD_ID).map(|e| e.name()) == Ok("toLong")
+            );
+            assert!(
+                SMethod::from_ids(type_id, TO_BIGINT_METHOD_ID).map(|e| e.name()) == Ok("toBigInt")
+            );
+            assert!(
+                SMethod::from_ids(type_id, TO_BYTES_METHOD_ID).map(|e| e.name()) == Ok("toBytes")
+            );
+            assert!(
+                SMethod::from_ids(type_id, TO_BITS_METHOD_ID).map(|e| e.name()) == Ok("toBits")
+            );
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use super::stype_param::STypeVar;
+use lazy_static::lazy_static;
+
+/// type id
+pub const TYPE_ID: TypeCode = TypeCode::OPTION;
+/// Option.isEmpty
+pub const IS_EMPTY_METHOD_ID: MethodId = MethodId(1);
+/// Option.isDefined
+pub const IS_DEFINED_METHOD_ID: MethodId = MethodId(2);
+/// Option.get
+pub const GET_METHOD_ID: MethodId = MethodId(3);
+/// Option.getOrElse
+pub const GET_OR_ELSE_METHOD_ID: MethodId = MethodId(4);
+/// Option.map
+pub const MAP_METHOD_ID: MethodId = MethodId(7);
+/// Option.filter
+pub const FILTER_METHOD_ID: MethodId = MethodId(8);
+
+static S_OPTION_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TYPE_ID,
+    type_name: "Option",
+};
+
+lazy_static! {
+    /// Option object type companion
+    pub static ref S_OPTION_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_OPTION_TYPE_COMPANION_HEAD,
+        vec![
+            &IS_EMPTY_METHOD_DESC,
+            &IS_DEFINED_METHOD_DESC,
+            &GET_METHOD_DESC,
+            &GET_OR_ELSE_METHOD_DESC,
+            &MAP_METHOD_DESC,
+            &FILTER_METHOD_DESC,
+        ]
+    );
+}
+
+fn option_t() -> SType {
+    SType::SOption(SType::STypeVar(STypeVar::t()).into())
+}
+
+fn t() -> SType {
+    STypeVar::t().into()
+}
+
+lazy_static! {
+    static ref IS_EMPTY_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(option_t(), "isEmpty", SType::SBoolean, IS_EMPTY_METHOD_ID);
+    /// Option.isEmpty
+    pub static ref IS_EMPTY_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, IS_EMPTY_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref IS_DEFINED_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(option_t(), "isDefined", SType::SBoolean, IS_DEFINED_METHOD_ID);
+    /// Option.isDefined
+    pub static ref IS_DEFINED_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, IS_DEFINED_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref GET_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(option_t(), "get", t(), GET_METHOD_ID);
+    /// Option.get
+    pub static ref GET_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, GET_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref GET_OR_ELSE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_OR_ELSE_METHOD_ID,
+        name: "getOrElse",
+        tpe: SFunc::new(vec![option_t(), t()], t()),
+    };
+    /// Option.getOrElse
+    pub static ref GET_OR_ELSE_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, GET_OR_ELSE_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref MAP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: MAP_METHOD_ID,
+        name: "map",
+        tpe: SFunc::new(
+            vec![
+                SType::SOption(SType::STypeVar(STypeVar::iv()).into()),
+                SFunc::new(vec![STypeVar::iv().into()], STypeVar::ov().into()).into(),
+            ],
+            SType::SOption(SType::STypeVar(STypeVar::ov()).into()),
+        ),
+    };
+    /// Option.map
+    pub static ref MAP_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, MAP_METHOD_DESC.clone());
+}
+
+lazy_static! {
+    static ref FILTER_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: FILTER_METHOD_ID,
+        name: "filter",
+        tpe: SFunc::new(
+            vec![option_t(), SFunc::new(vec![t()], SType::SBoolean).into()],
+            option_t(),
+        ),
+    };
+    /// Option.filter
+    pub static ref FILTER_METHOD: SMethod =
+        SMethod::new(&S_OPTION_TYPE_COMPANION, FILTER_METHOD_DESC.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ids() {
+        assert!(SMethod::from_ids(TYPE_ID, IS_EMPTY_METHOD_ID).map(|e| e.name()) == Ok("isEmpty"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, IS_DEFINED_METHOD_ID).map(|e| e.name()) == Ok("isDefined")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, GET_METHOD_ID).map(|e| e.name()) == Ok("get"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, GET_OR_ELSE_METHOD_ID).map(|e| e.name()) == Ok("getOrElse")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, MAP_METHOD_ID).map(|e| e.name()) == Ok("map"));
+        assert!(SMethod::from_ids(TYPE_ID, FILTER_METHOD_ID).map(|e| e.name()) == Ok("filter"));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::serialization::types::TypeCode;
 
 use super::smethod::MethodId;
+use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
 use super::stype::SType;
 use super::stype_companion::STypeCompanion;
@@ -8,8 +9,22 @@ use super::stype_companion::STypeCompanionHead;
 use crate::types::stype::SType::{SByte, SColl};
 use lazy_static::lazy_static;
 
-/// SBox type id
+/// SPreHeader type id
 pub const TYPE_ID: TypeCode = TypeCode::SPRE_HEADER;
+/// PreHeader.version property
+pub const VERSION_METHOD_ID: MethodId = MethodId(1);
+/// PreHeader.parentId property
+pub const PARENT_ID_METHOD_ID: MethodId = MethodId(2);
+/// PreHeader.timestamp property
+pub const TIMESTAMP_METHOD_ID: MethodId = MethodId(3);
+/// PreHeader.nBits property
+pub const N_BITS_METHOD_ID: MethodId = MethodId(4);
+/// PreHeader.height property
+pub const HEIGHT_METHOD_ID: MethodId = MethodId(5);
+/// PreHeader.minerPk property
+pub const MINER_PK_METHOD_ID: MethodId = MethodId(6);
+/// PreHeader.votes property
+pub const VOTES_METHOD_ID: MethodId = MethodId(7);
 
 static S_PRE_HEADER_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
     type_id: TYPE_ID,
@@ -17,51 +32,83 @@ static S_PRE_HEADER_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead
 };
 
 lazy_static! {
-    /// Box object type companion
+    /// PreHeader object type companion
     pub static ref S_PRE_HEADER_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
         &S_PRE_HEADER_TYPE_COMPANION_HEAD,
         vec![
-            &VERSION_METHOD,
-            &PARENT_ID_METHOD,
-            &TIMESTAMP_METHOD,
-            &N_BITS_METHOD,
-            &HEIGHT_METHOD,
-            &MINER_PK_METHOD,
-            &VOTES_METHOD,
+            &VERSION_METHOD_DESC,
+            &PARENT_ID_METHOD_DESC,
+            &TIMESTAMP_METHOD_DESC,
+            &N_BITS_METHOD_DESC,
+            &HEIGHT_METHOD_DESC,
+            &MINER_PK_METHOD_DESC,
+            &VOTES_METHOD_DESC,
         ]
     );
 }
 
 lazy_static! {
-    static ref VERSION_METHOD: SMethodDesc = property("version", SByte, MethodId(1));
+    static ref VERSION_METHOD_DESC: SMethodDesc = property("version", SByte, VERSION_METHOD_ID);
+    /// PreHeader.version
+    pub static ref VERSION_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, VERSION_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref PARENT_ID_METHOD: SMethodDesc =
-        property("parentId", SColl(SByte.into()), MethodId(2));
+    static ref PARENT_ID_METHOD_DESC: SMethodDesc = property("parentId", SColl(SByte.into()), PARENT_ID_METHOD_ID);
+    /// PreHeader.parentId
+    pub static ref PARENT_ID_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, PARENT_ID_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref TIMESTAMP_METHOD: SMethodDesc = property("timestamp", SType::SLong, MethodId(3));
+    static ref TIMESTAMP_METHOD_DESC: SMethodDesc = property("timestamp", SType::SLong, TIMESTAMP_METHOD_ID);
+    /// PreHeader.timestamp
+    pub static ref TIMESTAMP_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, TIMESTAMP_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref N_BITS_METHOD: SMethodDesc = property("nBits", SType::SLong, MethodId(4));
+    static ref N_BITS_METHOD_DESC: SMethodDesc = property("nBits", SType::SLong, N_BITS_METHOD_ID);
+    /// PreHeader.nBits
+    pub static ref N_BITS_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, N_BITS_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref HEIGHT_METHOD: SMethodDesc = property("height", SType::SInt, MethodId(5));
+    static ref HEIGHT_METHOD_DESC: SMethodDesc = property("height", SType::SInt, HEIGHT_METHOD_ID);
+    /// PreHeader.height
+    pub static ref HEIGHT_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, HEIGHT_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref MINER_PK_METHOD: SMethodDesc =
-        property("minerPk", SType::SGroupElement, MethodId(6));
+    static ref MINER_PK_METHOD_DESC: SMethodDesc = property("minerPk", SType::SGroupElement, MINER_PK_METHOD_ID);
+    /// PreHeader.minerPk
+    pub static ref MINER_PK_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, MINER_PK_METHOD_DESC.clone());
 }
 
 lazy_static! {
-    static ref VOTES_METHOD: SMethodDesc = property("votes", SColl(SByte.into()), MethodId(7));
+    static ref VOTES_METHOD_DESC: SMethodDesc = property("votes", SColl(SByte.into()), VOTES_METHOD_ID);
+    /// PreHeader.votes
+    pub static ref VOTES_METHOD: SMethod = SMethod::new(&S_PRE_HEADER_TYPE_COMPANION, VOTES_METHOD_DESC.clone());
 }
 
 fn property(name: &'static str, res_tpe: SType, id: MethodId) -> SMethodDesc {
     SMethodDesc::property(SType::SPreHeader, name, res_tpe, id)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ids() {
+        assert!(SMethod::from_ids(TYPE_ID, VERSION_METHOD_ID).map(|e| e.name()) == Ok("version"));
+        assert!(
+            SMethod::from_ids(TYPE_ID, PARENT_ID_METHOD_ID).map(|e| e.name()) == Ok("parentId")
+        );
+        assert!(
+            SMethod::from_ids(TYPE_ID, TIMESTAMP_METHOD_ID).map(|e| e.name()) == Ok("timestamp")
+        );
+        assert!(SMethod::from_ids(TYPE_ID, N_BITS_METHOD_ID).map(|e| e.name()) == Ok("nBits"));
+        assert!(SMethod::from_ids(TYPE_ID, HEIGHT_METHOD_ID).map(|e| e.name()) == Ok("height"));
+        assert!(SMethod::from_ids(TYPE_ID, MINER_PK_METHOD_ID).map(|e| e.name()) == Ok("minerPk"));
+        assert!(SMethod::from_ids(TYPE_ID, VOTES_METHOD_ID).map(|e| e.name()) == Ok("votes"));
+    }
+}
@@ -6,7 +6,11 @@ use std::convert::TryInto;
 use impl_trait_for_tuples::impl_for_tuples;
 use num_bigint::BigInt;
 
+use crate::ir_ergo_box::IrBox;
 use crate::ir_ergo_box::IrBoxId;
+use crate::mir::avl_tree_data::AvlTreeData;
+use crate::mir::header::Header;
+use crate::mir::header::PreHeader;
 use crate::serialization::types::TypeCode;
 use crate::sigma_protocol::dlog_group::EcPoint;
 use crate::sigma_protocol::sigma_boolean::ProveDlog;
@@ -68,7 +72,7 @@ impl SType {
     /// Type code used in serialization of SType values.
     pub fn type_code(&self) -> TypeCode {
         match self {
-            SType::SFunc(_) => todo!(),
+            SType::SFunc(_) => TypeCode::SFUNC,
             SType::SAny => TypeCode::SANY,
             SType::SBoolean => TypeCode::SBOOLEAN,
             SType::SByte => TypeCode::SBYTE,
@@ -92,8 +96,8 @@ impl SType {
     }
 
     /// Get STypeCompanion instance associated with this SType
-    pub fn type_companion(&self) -> Option<Box<STypeCompanion>> {
-        todo!()
+    pub fn type_companion(&self) -> Option<&'static STypeCompanion> {
+        STypeCompanion::type_by_id(self.type_code())
     }
 
     /// Check if type is numeric
@@ -204,6 +208,12 @@ impl LiftIntoSType for IrBoxId {
     }
 }
 
+impl LiftIntoSType for IrBox {
+    fn stype() -> SType {
+        SType::SBox
+    }
+}
+
 impl LiftIntoSType for SigmaBoolean {
     fn stype() -> SType {
         SType::SSigmaProp
@@ -234,6 +244,24 @@ impl LiftIntoSType for EcPoint {
     }
 }
 
+impl LiftIntoSType for AvlTreeData {
+    fn stype() -> SType {
+        SType::SAvlTree
+    }
+}
+
+impl LiftIntoSType for Header {
+    fn stype() -> SType {
+        SType::SHeader
+    }
+}
+
+impl LiftIntoSType for PreHeader {
+    fn stype() -> SType {
+        SType::SPreHeader
+    }
+}
+
 impl LiftIntoSType for BigInt {
     fn stype() -> SType {
         SType::SBigInt
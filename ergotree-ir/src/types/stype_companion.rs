@@ -2,14 +2,18 @@ use std::fmt::Debug;
 
 use crate::serialization::types::TypeCode;
 
+use super::savltree;
 use super::sbox;
 use super::scoll;
 use super::scontext;
 use super::sglobal;
+use super::sgroup_elem;
 use super::sheader;
 use super::smethod::MethodId;
 use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
+use super::snumeric;
+use super::soption;
 use super::spreheader;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -33,22 +37,38 @@ impl STypeCompanion {
         STypeCompanion { head, methods }
     }
 
-    /// Get type companion for a givec type id
-    pub fn type_by_id(type_id: TypeCode) -> &'static STypeCompanion {
+    /// Get type companion for a given type id (None if there is no such type companion)
+    pub fn type_by_id(type_id: TypeCode) -> Option<&'static STypeCompanion> {
         if type_id == scontext::S_CONTEXT_TYPE_COMPANION.type_id() {
-            &scontext::S_CONTEXT_TYPE_COMPANION
+            Some(&scontext::S_CONTEXT_TYPE_COMPANION)
         } else if type_id == sbox::S_BOX_TYPE_COMPANION.type_id() {
-            &sbox::S_BOX_TYPE_COMPANION
+            Some(&sbox::S_BOX_TYPE_COMPANION)
         } else if type_id == scoll::S_COLL_TYPE_COMPANION.type_id() {
-            &scoll::S_COLL_TYPE_COMPANION
+            Some(&scoll::S_COLL_TYPE_COMPANION)
         } else if type_id == sglobal::S_GLOBAL_TYPE_COMPANION.type_id() {
-            &sglobal::S_GLOBAL_TYPE_COMPANION
+            Some(&sglobal::S_GLOBAL_TYPE_COMPANION)
         } else if type_id == sheader::S_HEADER_TYPE_COMPANION.type_id() {
-            &sheader::S_HEADER_TYPE_COMPANION
+            Some(&sheader::S_HEADER_TYPE_COMPANION)
         } else if type_id == spreheader::S_PRE_HEADER_TYPE_COMPANION.type_id() {
-            &spreheader::S_PRE_HEADER_TYPE_COMPANION
+            Some(&spreheader::S_PRE_HEADER_TYPE_COMPANION)
+        } else if type_id == savltree::S_AVL_TREE_TYPE_COMPANION.type_id() {
+            Some(&savltree::S_AVL_TREE_TYPE_COMPANION)
+        } else if type_id == sgroup_elem::S_GROUP_ELEMENT_TYPE_COMPANION.type_id() {
+            Some(&sgroup_elem::S_GROUP_ELEMENT_TYPE_COMPANION)
+        } else if type_id == soption::S_OPTION_TYPE_COMPANION.type_id() {
+            Some(&soption::S_OPTION_TYPE_COMPANION)
+        } else if type_id == TypeCode::SBYTE {
+            Some(&snumeric::S_BYTE_TYPE_COMPANION)
+        } else if type_id == TypeCode::SSHORT {
+            Some(&snumeric::S_SHORT_TYPE_COMPANION)
+        } else if type_id == TypeCode::SINT {
+            Some(&snumeric::S_INT_TYPE_COMPANION)
+        } else if type_id == TypeCode::SLONG {
+            Some(&snumeric::S_LONG_TYPE_COMPANION)
+        } else if type_id == TypeCode::SBIGINT {
+            Some(&snumeric::S_BIGINT_TYPE_COMPANION)
         } else {
-            todo!("cannot find STypeCompanion for {0:?} type id", type_id)
+            None
         }
     }
 
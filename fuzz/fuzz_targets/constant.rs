@@ -0,0 +1,10 @@
+#![no_main]
+use ergotree_ir::mir::constant::Constant;
+use ergotree_ir::serialization::SigmaSerializable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(c) = Constant::sigma_parse_bytes(data) {
+        let _ = c.sigma_serialize_bytes();
+    }
+});
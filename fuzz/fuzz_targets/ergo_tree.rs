@@ -0,0 +1,11 @@
+#![no_main]
+use ergotree_ir::ergo_tree::ErgoTree;
+use ergotree_ir::serialization::SigmaSerializable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(tree) = ErgoTree::sigma_parse_bytes(data) {
+        let _ = tree.proposition();
+        let _ = tree.sigma_serialize_bytes();
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+use std::io::Read;
+
+use ergotree_interpreter::sigma_protocol::verifier::verify_signature;
+use ergotree_ir::serialization::sigma_byte_reader;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use libfuzzer_sys::fuzz_target;
+
+// input is a serialized sigma proposition followed by the proof bytes
+fuzz_target!(|data: &[u8]| {
+    let mut r = sigma_byte_reader::from_bytes(data);
+    if let Ok(sb) = SigmaBoolean::sigma_parse(&mut r) {
+        let mut proof = Vec::new();
+        if r.read_to_end(&mut proof).is_ok() {
+            let _ = verify_signature(sb, b"message", &proof);
+        }
+    }
+});